@@ -369,6 +369,17 @@ impl pallet_scheduler::Config for Runtime {
 	type MaxScheduledPerBlock = ConstU32<512>;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type MaxScheduledPerBlock = ConstU32<50>;
+	type MaxRootScheduledPerBlock = ConstU32<10>;
+	type MaxSchedules = ConstU32<10_000>;
+	type MaxSchedulesPerOrigin = ();
+	type AllowCurrentBlockScheduling = ConstBool<false>;
+	type MaxPeriodicCount = ConstU32<1_000>;
+	type DedupInlineCalls = ConstBool<false>;
+	type PeriodicJitter = ();
+	type MaxRecordedResults = ConstU32<0>;
+	type MaxScheduleDelay = ();
+	type MaxReappendSearch = ConstU32<0>;
+	type DispatchContext = ();
 	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
@@ -1613,6 +1624,8 @@ impl pallet_core_fellowship::Config for Runtime {
 parameter_types! {
 	pub Features: PalletFeatures = PalletFeatures::all_enabled();
 	pub const MaxAttributesPerCall: u32 = 10;
+	pub const MaxBundle: u32 = 10;
+	pub const MaxBatch: u32 = 20;
 }
 
 impl pallet_nfts::Config for Runtime {
@@ -1634,6 +1647,8 @@ impl pallet_nfts::Config for Runtime {
 	type MaxTips = MaxTips;
 	type MaxDeadlineDuration = MaxDeadlineDuration;
 	type MaxAttributesPerCall = MaxAttributesPerCall;
+	type MaxBundle = MaxBundle;
+	type MaxBatch = MaxBatch;
 	type Features = Features;
 	type OffchainSignature = Signature;
 	type OffchainPublic = <Signature as traits::Verify>::Signer;
@@ -1642,6 +1657,8 @@ impl pallet_nfts::Config for Runtime {
 	type Helper = ();
 	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
 	type Locker = ();
+	type OnCollectionCreated = ();
+	type OnCollectionDestroyed = ();
 }
 
 impl pallet_transaction_storage::Config for Runtime {
@@ -1878,6 +1895,10 @@ type Migrations = (
 	pallet_nomination_pools::migration::v2::MigrateToV2<Runtime>,
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
+	pallet_nfts::migration::v1::MigrateToV1<Runtime>,
+	pallet_nfts::migration::v2::MigrateToV2<Runtime>,
+	pallet_nfts::migration::v3::MigrateToV3<Runtime>,
+	pallet_nfts::migration::v4::MigrateToV4<Runtime>,
 );
 
 /// MMR helper types.
@@ -2068,6 +2089,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_scheduler_runtime_api::SchedulerApi<Block, BlockNumber, AccountId> for Runtime {
+		fn weight_components() -> pallet_scheduler::SchedulerWeights {
+			Scheduler::weight_components()
+		}
+
+		fn would_fit(when: BlockNumber, priority: u8, call_weight: Weight) -> bool {
+			Scheduler::would_fit(when, priority, call_weight)
+		}
+
+		fn task_signer(when: BlockNumber, index: u32) -> Option<AccountId> {
+			Scheduler::task_signer((when, index))
+		}
+	}
+
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
 		fn configuration() -> sp_consensus_babe::BabeConfiguration {
 			let epoch_config = Babe::epoch_config().unwrap_or(BABE_GENESIS_EPOCH_CONFIG);
@@ -2248,11 +2283,15 @@ impl_runtime_apis! {
 		}
 	}
 
-	impl pallet_nfts_runtime_api::NftsApi<Block, AccountId, u32, u32> for Runtime {
+	impl pallet_nfts_runtime_api::NftsApi<Block, AccountId, u32, u32, BlockNumber, Balance> for Runtime {
 		fn owner(collection: u32, item: u32) -> Option<AccountId> {
 			<Nfts as Inspect<AccountId>>::owner(&collection, &item)
 		}
 
+		fn item_mint_block(collection: u32, item: u32) -> Option<BlockNumber> {
+			Nfts::item_mint_block(collection, item)
+		}
+
 		fn collection_owner(collection: u32) -> Option<AccountId> {
 			<Nfts as Inspect<AccountId>>::collection_owner(&collection)
 		}
@@ -2290,6 +2329,49 @@ impl_runtime_apis! {
 		fn collection_attribute(collection: u32, key: Vec<u8>) -> Option<Vec<u8>> {
 			<Nfts as Inspect<AccountId>>::collection_attribute(&collection, &key)
 		}
+
+		fn effective_attribute(collection: u32, item: u32, key: Vec<u8>) -> Option<Vec<u8>> {
+			Nfts::effective_attribute(collection, item, &key)
+		}
+
+		fn has_attribute(collection: u32, item: Option<u32>, key: Vec<u8>) -> bool {
+			Nfts::has_attribute(collection, item, &key)
+		}
+
+		fn collection_settings(collection: u32) -> Option<pallet_nfts::CollectionSettingsInfo> {
+			Nfts::collection_config(collection).map(|config| config.settings.into())
+		}
+
+		fn item_settings(collection: u32, item: u32) -> Option<pallet_nfts::ItemSettingsInfo> {
+			Nfts::item_config(collection, item).map(|config| config.settings.into())
+		}
+
+		fn collection_stats(collection: u32) -> Option<pallet_nfts::CollectionStats<AccountId>> {
+			Nfts::collection_stats(collection)
+		}
+
+		fn collections(
+			start: Option<u32>,
+			limit: u32,
+		) -> Vec<(u32, pallet_nfts::CollectionStats<AccountId>)> {
+			Nfts::collections(start, limit)
+		}
+
+		fn item_price(collection: u32, item: u32) -> Option<(Balance, Option<AccountId>)> {
+			Nfts::item_price(collection, item)
+		}
+
+		fn collection_metadata(collection: u32) -> Option<Vec<u8>> {
+			Nfts::collection_metadata(collection)
+		}
+
+		fn item_metadata(collection: u32, item: u32) -> Option<Vec<u8>> {
+			Nfts::item_metadata(collection, item)
+		}
+
+		fn is_transferable(collection: u32, item: u32) -> bool {
+			Nfts::is_transferable(collection, item)
+		}
 	}
 
 	impl pallet_mmr::primitives::MmrApi<
@@ -21,16 +21,23 @@
 
 use codec::{Decode, Encode};
 use frame_support::dispatch::Vec;
+use pallet_nfts::{CollectionSettingsInfo, CollectionStats, ItemSettingsInfo};
 
 sp_api::decl_runtime_apis! {
-	pub trait NftsApi<AccountId, CollectionId, ItemId>
+	pub trait NftsApi<AccountId, CollectionId, ItemId, BlockNumber, Balance>
 	where
 		AccountId: Encode + Decode,
-		CollectionId: Encode,
+		CollectionId: Encode + Decode,
 		ItemId: Encode,
+		BlockNumber: Decode,
+		Balance: Decode,
 	{
 		fn owner(collection: CollectionId, item: ItemId) -> Option<AccountId>;
 
+		/// The block in which the item was minted. Items minted before the pallet recorded it
+		/// report the block of the upgrade which started recording it.
+		fn item_mint_block(collection: CollectionId, item: ItemId) -> Option<BlockNumber>;
+
 		fn collection_owner(collection: CollectionId) -> Option<AccountId>;
 
 		fn attribute(
@@ -53,5 +60,32 @@ sp_api::decl_runtime_apis! {
 		) -> Option<Vec<u8>>;
 
 		fn collection_attribute(collection: CollectionId, key: Vec<u8>) -> Option<Vec<u8>>;
+
+		fn effective_attribute(
+			collection: CollectionId,
+			item: ItemId,
+			key: Vec<u8>,
+		) -> Option<Vec<u8>>;
+
+		fn has_attribute(collection: CollectionId, item: Option<ItemId>, key: Vec<u8>) -> bool;
+
+		fn collection_settings(collection: CollectionId) -> Option<CollectionSettingsInfo>;
+
+		fn item_settings(collection: CollectionId, item: ItemId) -> Option<ItemSettingsInfo>;
+
+		fn collection_stats(collection: CollectionId) -> Option<CollectionStats<AccountId>>;
+
+		fn collections(
+			start: Option<CollectionId>,
+			limit: u32,
+		) -> Vec<(CollectionId, CollectionStats<AccountId>)>;
+
+		fn item_price(collection: CollectionId, item: ItemId) -> Option<(Balance, Option<AccountId>)>;
+
+		fn collection_metadata(collection: CollectionId) -> Option<Vec<u8>>;
+
+		fn item_metadata(collection: CollectionId, item: ItemId) -> Option<Vec<u8>>;
+
+		fn is_transferable(collection: CollectionId, item: ItemId) -> bool;
 	}
 }
@@ -104,6 +104,14 @@ fn mint_item<T: Config<I>, I: 'static>(
 	(item, caller, caller_lookup)
 }
 
+fn mint_bundles<T: Config<I>, I: 'static>(n: u32) -> (BundleOf<T, I>, BundleOf<T, I>) {
+	let collection = T::Helper::collection(0);
+	let items: Vec<_> =
+		(0..n).map(|index| (collection, mint_item::<T, I>(index as u16).0)).collect();
+	let (offered, desired) = items.split_at(((n + 1) / 2) as usize);
+	(offered.to_vec().try_into().unwrap(), desired.to_vec().try_into().unwrap())
+}
+
 fn lock_item<T: Config<I>, I: 'static>(
 	index: u16,
 ) -> (T::ItemId, T::AccountId, AccountIdLookupOf<T>) {
@@ -210,6 +218,7 @@ fn make_collection_config<T: Config<I>, I: 'static>(
 		settings: CollectionSettings::from_disabled(disable_settings),
 		max_supply: None,
 		mint_settings: MintSettings::default(),
+		item_id_range: None,
 	}
 }
 
@@ -286,19 +295,33 @@ benchmarks_instance_pallet! {
 	}
 
 	mint {
+		let data: BoundedVec<_, _> = vec![0u8; T::StringLimit::get() as usize].try_into().unwrap();
+
 		let (collection, caller, caller_lookup) = create_collection::<T, I>();
 		let item = T::Helper::item(0);
+		Nfts::<T, I>::set_default_item_metadata(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			Some(data.clone()),
+		)?;
 	}: _(SystemOrigin::Signed(caller.clone()), collection, item, caller_lookup, None)
 	verify {
-		assert_last_event::<T, I>(Event::Issued { collection, item, owner: caller }.into());
+		assert_last_event::<T, I>(Event::ItemMetadataSet { collection, item, data }.into());
 	}
 
 	force_mint {
+		let data: BoundedVec<_, _> = vec![0u8; T::StringLimit::get() as usize].try_into().unwrap();
+
 		let (collection, caller, caller_lookup) = create_collection::<T, I>();
 		let item = T::Helper::item(0);
+		Nfts::<T, I>::set_default_item_metadata(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			Some(data.clone()),
+		)?;
 	}: _(SystemOrigin::Signed(caller.clone()), collection, item, caller_lookup, default_item_config())
 	verify {
-		assert_last_event::<T, I>(Event::Issued { collection, item, owner: caller }.into());
+		assert_last_event::<T, I>(Event::ItemMetadataSet { collection, item, data }.into());
 	}
 
 	burn {
@@ -309,6 +332,22 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Burned { collection, item, owner: caller }.into());
 	}
 
+	burn_many {
+		let n in 1 .. T::MaxBatch::get();
+
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		let mut items = Vec::new();
+		for i in 0..n {
+			let (item, ..) = mint_item::<T, I>(i as u16);
+			items.push(item);
+		}
+		let items: BoundedVec<_, _> = items.try_into().unwrap();
+	}: _(SystemOrigin::Signed(caller.clone()), collection, items, Some(caller_lookup), true)
+	verify {
+		assert_eq!(Item::<T, I>::iter_prefix(collection).count(), 0);
+		assert_last_event::<T, I>(Event::Burned { collection, item: T::Helper::item((n - 1) as u16), owner: caller }.into());
+	}
+
 	transfer {
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
@@ -366,7 +405,8 @@ benchmarks_instance_pallet! {
 		);
 	}: _(SystemOrigin::Signed(caller.clone()), collection, lock_settings)
 	verify {
-		assert_last_event::<T, I>(Event::CollectionLocked { collection }.into());
+		let settings = CollectionConfigOf::<T, I>::get(collection).unwrap().settings;
+		assert_last_event::<T, I>(Event::CollectionLocked { collection, settings }.into());
 	}
 
 	transfer_ownership {
@@ -396,6 +436,38 @@ benchmarks_instance_pallet! {
 		}.into());
 	}
 
+	set_team_roles {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let target0 = Some(T::Lookup::unlookup(account("target", 0, SEED)));
+	}: _(SystemOrigin::Signed(caller.clone()), collection, target0, None, None)
+	verify {
+		assert_last_event::<T, I>(Event::TeamChanged{
+			collection,
+			issuer: Some(account("target", 0, SEED)),
+			admin: Some(caller.clone()),
+			freezer: Some(caller),
+		}.into());
+	}
+
+	add_allowed_buyer {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
+	}: _(SystemOrigin::Signed(caller), collection, buyer_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::AllowedBuyerAdded { collection, buyer }.into());
+	}
+
+	remove_allowed_buyer {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		CollectionAllowlist::<T, I>::insert(&collection, &buyer, ());
+		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
+	}: _(SystemOrigin::Signed(caller), collection, buyer_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::AllowedBuyerRemoved { collection, buyer }.into());
+	}
+
 	force_collection_owner {
 		let (collection, _, _) = create_collection::<T, I>();
 		let origin =
@@ -558,6 +630,23 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::ItemMetadataSet { collection, item, data }.into());
 	}
 
+	set_metadata_many {
+		let n in 1 .. T::MaxBatch::get();
+
+		let data: BoundedVec<_, _> = vec![0u8; T::StringLimit::get() as usize].try_into().unwrap();
+		let (collection, caller, _) = create_collection::<T, I>();
+		let mut items = Vec::new();
+		for i in 0..n {
+			let (item, ..) = mint_item::<T, I>(i as u16);
+			items.push((item, data.clone()));
+		}
+		let items: BoundedVec<_, _> = items.try_into().unwrap();
+	}: _(SystemOrigin::Signed(caller), collection, items)
+	verify {
+		let item = T::Helper::item((n - 1) as u16);
+		assert_last_event::<T, I>(Event::ItemMetadataSet { collection, item, data }.into());
+	}
+
 	clear_metadata {
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
@@ -589,9 +678,10 @@ benchmarks_instance_pallet! {
 		let (item, ..) = mint_item::<T, I>(0);
 		let delegate: T::AccountId = account("delegate", 0, SEED);
 		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
-		let deadline = T::BlockNumber::max_value();
-	}: _(SystemOrigin::Signed(caller.clone()), collection, item, delegate_lookup, Some(deadline))
+		let duration = T::MaxDeadlineDuration::get();
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, delegate_lookup, Some(duration))
 	verify {
+		let deadline = frame_system::Pallet::<T>::block_number().saturating_add(duration);
 		assert_last_event::<T, I>(Event::TransferApproved { collection, item, owner: caller, delegate, deadline: Some(deadline) }.into());
 	}
 
@@ -601,8 +691,8 @@ benchmarks_instance_pallet! {
 		let delegate: T::AccountId = account("delegate", 0, SEED);
 		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
 		let origin = SystemOrigin::Signed(caller.clone()).into();
-		let deadline = T::BlockNumber::max_value();
-		Nfts::<T, I>::approve_transfer(origin, collection, item, delegate_lookup.clone(), Some(deadline))?;
+		let duration = T::MaxDeadlineDuration::get();
+		Nfts::<T, I>::approve_transfer(origin, collection, item, delegate_lookup.clone(), Some(duration))?;
 	}: _(SystemOrigin::Signed(caller.clone()), collection, item, delegate_lookup)
 	verify {
 		assert_last_event::<T, I>(Event::ApprovalCancelled { collection, item, owner: caller, delegate }.into());
@@ -614,8 +704,8 @@ benchmarks_instance_pallet! {
 		let delegate: T::AccountId = account("delegate", 0, SEED);
 		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
 		let origin = SystemOrigin::Signed(caller.clone()).into();
-		let deadline = T::BlockNumber::max_value();
-		Nfts::<T, I>::approve_transfer(origin, collection, item, delegate_lookup.clone(), Some(deadline))?;
+		let duration = T::MaxDeadlineDuration::get();
+		Nfts::<T, I>::approve_transfer(origin, collection, item, delegate_lookup.clone(), Some(duration))?;
 	}: _(SystemOrigin::Signed(caller.clone()), collection, item)
 	verify {
 		assert_last_event::<T, I>(Event::AllApprovalsCancelled {collection, item, owner: caller}.into());
@@ -654,7 +744,11 @@ benchmarks_instance_pallet! {
 		};
 	}: _(SystemOrigin::Signed(caller.clone()), collection, mint_settings)
 	verify {
-		assert_last_event::<T, I>(Event::CollectionMintSettingsUpdated { collection }.into());
+		assert_last_event::<T, I>(Event::CollectionMintSettingsUpdated {
+			collection,
+			mint_type: MintType::HolderOf(T::Helper::collection(0)),
+			price: Some(ItemPrice::<T, I>::from(1u32)),
+		}.into());
 	}
 
 	set_price {
@@ -663,13 +757,14 @@ benchmarks_instance_pallet! {
 		let delegate: T::AccountId = account("delegate", 0, SEED);
 		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
 		let price = ItemPrice::<T, I>::from(100u32);
-	}: _(SystemOrigin::Signed(caller.clone()), collection, item, Some(price), Some(delegate_lookup))
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, Some(price), Some(delegate_lookup), None)
 	verify {
 		assert_last_event::<T, I>(Event::ItemPriceSet {
 			collection,
 			item,
 			price,
 			whitelisted_buyer: Some(delegate),
+			deadline: None,
 		}.into());
 	}
 
@@ -680,7 +775,7 @@ benchmarks_instance_pallet! {
 		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
 		let price = ItemPrice::<T, I>::from(0u32);
 		let origin = SystemOrigin::Signed(seller.clone()).into();
-		Nfts::<T, I>::set_price(origin, collection, item, Some(price.clone()), Some(buyer_lookup))?;
+		Nfts::<T, I>::set_price(origin, collection, item, Some(price.clone()), Some(buyer_lookup), None)?;
 		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
 	}: _(SystemOrigin::Signed(buyer.clone()), collection, item, price.clone())
 	verify {
@@ -696,9 +791,8 @@ benchmarks_instance_pallet! {
 	pay_tips {
 		let n in 0 .. T::MaxTips::get() as u32;
 		let amount = BalanceOf::<T, I>::from(100u32);
-		let caller: T::AccountId = whitelisted_caller();
-		let collection = T::Helper::collection(0);
-		let item = T::Helper::item(0);
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
 		let tips: BoundedVec<_, _> = vec![
 			ItemTip
 				{ collection, item, receiver: caller.clone(), amount }; n as usize
@@ -721,11 +815,11 @@ benchmarks_instance_pallet! {
 		let (item1, ..) = mint_item::<T, I>(0);
 		let (item2, ..) = mint_item::<T, I>(1);
 		let price = ItemPrice::<T, I>::from(100u32);
-		let price_direction = PriceDirection::Receive;
+		let price_direction = PriceDirection::Send;
 		let price_with_direction = PriceWithDirection { amount: price, direction: price_direction };
 		let duration = T::MaxDeadlineDuration::get();
 		frame_system::Pallet::<T>::set_block_number(One::one());
-	}: _(SystemOrigin::Signed(caller.clone()), collection, item1, collection, Some(item2), Some(price_with_direction.clone()), duration)
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item1, collection, Some(item2), Some(price_with_direction.clone()), duration, true)
 	verify {
 		let current_block = frame_system::Pallet::<T>::block_number();
 		assert_last_event::<T, I>(Event::SwapCreated {
@@ -745,10 +839,10 @@ benchmarks_instance_pallet! {
 		let price = ItemPrice::<T, I>::from(100u32);
 		let origin = SystemOrigin::Signed(caller.clone()).into();
 		let duration = T::MaxDeadlineDuration::get();
-		let price_direction = PriceDirection::Receive;
+		let price_direction = PriceDirection::Send;
 		let price_with_direction = PriceWithDirection { amount: price, direction: price_direction };
 		frame_system::Pallet::<T>::set_block_number(One::one());
-		Nfts::<T, I>::create_swap(origin, collection, item1, collection, Some(item2), Some(price_with_direction.clone()), duration)?;
+		Nfts::<T, I>::create_swap(origin, collection, item1, collection, Some(item2), Some(price_with_direction.clone()), duration, true)?;
 	}: _(SystemOrigin::Signed(caller.clone()), collection, item1)
 	verify {
 		assert_last_event::<T, I>(Event::SwapCancelled {
@@ -783,6 +877,7 @@ benchmarks_instance_pallet! {
 			Some(item2),
 			Some(price_with_direction.clone()),
 			duration,
+			false,
 		)?;
 	}: _(SystemOrigin::Signed(target.clone()), collection, item2, collection, item1, Some(price_with_direction.clone()))
 	verify {
@@ -891,5 +986,265 @@ benchmarks_instance_pallet! {
 		);
 	}
 
+	lock_item_transfer_by_owner {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::ItemTransferLockedByOwner { collection, item }.into());
+	}
+
+	unlock_item_transfer_by_owner {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		Nfts::<T, I>::lock_item_transfer_by_owner(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			item,
+		)?;
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::ItemTransferUnlockedByOwner { collection, item }.into());
+	}
+
+	force_create_system {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+	}: _(SystemOrigin::Root, caller_lookup, default_collection_config::<T, I>())
+	verify {
+		assert!(SystemCollection::<T, I>::contains_key(T::Helper::collection(0)));
+		assert_last_event::<T, I>(Event::ForceCreated { collection: T::Helper::collection(0), owner: caller }.into());
+	}
+
+	set_attributes {
+		let n in 0 .. T::MaxAttributesPerCall::get() as u32;
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+
+		let mut attributes = vec![];
+		let value: BoundedVec<_, _> = vec![0u8; T::ValueLimit::get() as usize].try_into().unwrap();
+		for i in 0..n {
+			let key: BoundedVec<_, _> =
+				make_filled_vec(i as u16, T::KeyLimit::get() as usize).try_into().unwrap();
+			attributes.push((key, value.clone()));
+		}
+		let attributes: AttributesOf<T, I> = attributes.try_into().unwrap();
+	}: _(SystemOrigin::Signed(caller), collection, Some(item), AttributeNamespace::CollectionOwner, attributes)
+	verify {
+		assert_eq!(Collection::<T, I>::get(collection).unwrap().attributes, n);
+	}
+
+	set_mint_paused {
+		let (collection, caller, _) = create_collection::<T, I>();
+	}: _(SystemOrigin::Signed(caller.clone()), collection, true)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionMintPaused { collection, paused: true }.into());
+	}
+
+	force_destroy {
+		let n in 0 .. 1_000;
+
+		let (collection, _, _) = create_collection::<T, I>();
+		for i in 0..n {
+			mint_item::<T, I>(i as u16);
+		}
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call = Call::<T, I>::force_destroy { collection, max_items: n };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(Item::<T, I>::iter_prefix(collection).next().is_none());
+	}
+
+	recompute_collection_status {
+		let n in 0 .. 1_000;
+
+		let (collection, _, _) = create_collection::<T, I>();
+		for i in 0..n {
+			mint_item::<T, I>(i as u16);
+		}
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call = Call::<T, I>::recompute_collection_status { collection, max_items: 2 * n };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Collection::<T, I>::get(collection).unwrap().items, n);
+	}
+
+	set_collection_deposit_override {
+		let (collection, _, _) = create_collection::<T, I>();
+		let deposits = CollectionDeposits {
+			item: 1u32.into(),
+			metadata_base: 2u32.into(),
+			attribute_base: 3u32.into(),
+			per_byte: 4u32.into(),
+		};
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call = Call::<T, I>::set_collection_deposit_override {
+			collection,
+			maybe_deposits: Some(deposits),
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::CollectionDepositOverrideSet {
+			collection,
+			deposits: Some(deposits),
+		}.into());
+	}
+
+	clear_expired_price {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let price = ItemPrice::<T, I>::from(100u32);
+		let deadline = frame_system::Pallet::<T>::block_number();
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Nfts::<T, I>::set_price(origin, collection, item, Some(price), None, Some(deadline))?;
+		frame_system::Pallet::<T>::set_block_number(deadline + One::one());
+	}: _(SystemOrigin::Signed(caller), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::ItemPriceRemoved { collection, item }.into());
+	}
+
+	force_transfer_all {
+		let n in 0 .. 1_000;
+
+		let (collection, _, _) = create_collection::<T, I>();
+		for i in 0..n {
+			mint_item::<T, I>(i as u16);
+		}
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call = Call::<T, I>::force_transfer_all {
+			collection,
+			new_owner: target_lookup,
+			max_items: n,
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Item::<T, I>::iter_prefix(collection).filter(|(_, d)| d.owner == target).count(), n as usize);
+	}
+
+	thaw_all {
+		let n in 0 .. 1_000;
+
+		let (collection, caller, _) = create_collection::<T, I>();
+		for i in 0..n {
+			let (item, ..) = mint_item::<T, I>(i as u16);
+			Nfts::<T, I>::lock_item_transfer(
+				SystemOrigin::Signed(caller.clone()).into(),
+				collection,
+				item,
+			)?;
+		}
+	}: _(SystemOrigin::Signed(caller), collection, n)
+	verify {
+		assert!(ItemConfigOf::<T, I>::iter_prefix(collection)
+			.all(|(_, c)| c.is_setting_enabled(ItemSetting::Transferable)));
+	}
+
+	set_default_item_metadata {
+		let data: BoundedVec<_, _> = vec![0u8; T::StringLimit::get() as usize].try_into().unwrap();
+
+		let (collection, caller, _) = create_collection::<T, I>();
+	}: _(SystemOrigin::Signed(caller), collection, Some(data.clone()))
+	verify {
+		assert_last_event::<T, I>(Event::DefaultItemMetadataSet { collection, data: Some(data) }.into());
+	}
+
+	create_with_deposit {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let deposit = T::CollectionDeposit::get();
+	}: _(SystemOrigin::Root, caller_lookup, default_collection_config::<T, I>(), deposit)
+	verify {
+		assert_last_event::<T, I>(Event::ForceCreated { collection: T::Helper::collection(0), owner: caller }.into());
+	}
+
+	create_with_id {
+		let collection = T::Helper::collection(1);
+		let origin = T::CreateOrigin::try_successful_origin(&collection)
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let caller = T::CreateOrigin::ensure_origin(origin.clone(), &collection).unwrap();
+		whitelist_account!(caller);
+		let admin = T::Lookup::unlookup(caller.clone());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let call = Call::<T, I>::create_with_id {
+			collection,
+			admin,
+			config: default_collection_config::<T, I>(),
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_last_event::<T, I>(Event::Created { collection, creator: caller.clone(), owner: caller }.into());
+	}
+
+	create_bundle_swap {
+		let n in 2 .. T::MaxBundle::get().saturating_mul(2);
+		let (_, caller, _) = create_collection::<T, I>();
+		let (offered, desired) = mint_bundles::<T, I>(n);
+		let price = ItemPrice::<T, I>::from(0u32);
+		let price_with_direction = PriceWithDirection { amount: price, direction: PriceDirection::Send };
+		let duration = T::MaxDeadlineDuration::get();
+		frame_system::Pallet::<T>::set_block_number(One::one());
+	}: _(SystemOrigin::Signed(caller.clone()), offered.clone(), desired.clone(), Some(price_with_direction.clone()), duration)
+	verify {
+		assert_last_event::<T, I>(Event::BundleSwapCreated {
+			swap_id: 0,
+			creator: caller,
+			offered,
+			desired,
+			price: Some(price_with_direction),
+			deadline: duration.saturating_add(One::one()),
+		}.into());
+	}
+
+	cancel_bundle_swap {
+		let (_, caller, _) = create_collection::<T, I>();
+		let (offered, desired) = mint_bundles::<T, I>(2);
+		let duration = T::MaxDeadlineDuration::get();
+		frame_system::Pallet::<T>::set_block_number(One::one());
+		let origin = SystemOrigin::Signed(caller.clone());
+		Nfts::<T, I>::create_bundle_swap(origin.clone().into(), offered, desired, None, duration)?;
+	}: _(origin, 0)
+	verify {
+		assert_last_event::<T, I>(Event::BundleSwapCancelled { swap_id: 0 }.into());
+	}
+
+	claim_bundle_swap {
+		let n in 2 .. T::MaxBundle::get().saturating_mul(2);
+		let (_, caller, _) = create_collection::<T, I>();
+		let (offered, desired) = mint_bundles::<T, I>(n);
+		let price = ItemPrice::<T, I>::from(0u32);
+		let price_with_direction = PriceWithDirection { amount: price, direction: PriceDirection::Receive };
+		let duration = T::MaxDeadlineDuration::get();
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		let origin = SystemOrigin::Signed(caller.clone());
+		frame_system::Pallet::<T>::set_block_number(One::one());
+		for (collection, item) in desired.iter() {
+			Nfts::<T, I>::transfer(origin.clone().into(), *collection, *item, target_lookup.clone())?;
+		}
+		Nfts::<T, I>::create_bundle_swap(
+			origin.into(),
+			offered,
+			desired,
+			Some(price_with_direction.clone()),
+			duration,
+		)?;
+	}: _(SystemOrigin::Signed(target.clone()), 0, Some(price_with_direction.clone()))
+	verify {
+		assert_last_event::<T, I>(Event::BundleSwapClaimed {
+			swap_id: 0,
+			creator: caller,
+			claimer: target,
+			price: Some(price_with_direction),
+		}.into());
+	}
+
 	impl_benchmark_test_suite!(Nfts, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -18,7 +18,7 @@
 //! Various pieces of common functionality.
 
 use crate::*;
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, BoundedSlice};
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Get the owner of the item, if the item exists.
@@ -26,11 +26,168 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Item::<T, I>::get(collection, item).map(|i| i.owner)
 	}
 
+	/// Get the block in which the item was minted, if the item exists.
+	///
+	/// Items minted before the mint block was recorded report the block of that upgrade instead.
+	pub fn item_mint_block(collection: T::CollectionId, item: T::ItemId) -> Option<T::BlockNumber> {
+		Item::<T, I>::get(collection, item).map(|i| i.mint_block)
+	}
+
 	/// Get the owner of the collection, if the collection exists.
 	pub fn collection_owner(collection: T::CollectionId) -> Option<T::AccountId> {
 		Collection::<T, I>::get(collection).map(|i| i.owner)
 	}
 
+	/// Get the aggregate statistics of the collection, if the collection exists.
+	///
+	/// Only the cached counters and the config are read, no storage is iterated.
+	pub fn collection_stats(collection: T::CollectionId) -> Option<CollectionStats<T::AccountId>> {
+		let details = Collection::<T, I>::get(collection)?;
+		let config = CollectionConfigOf::<T, I>::get(collection)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let mint_settings = config.mint_settings;
+		let mint_open = !matches!(mint_settings.start_block, Some(start) if start > now) &&
+			!matches!(mint_settings.end_block, Some(end) if end < now) &&
+			!MintPaused::<T, I>::contains_key(collection) &&
+			!matches!(config.max_supply, Some(max) if details.items >= max);
+		Some(CollectionStats {
+			owner: details.owner,
+			items: details.items,
+			item_metadatas: details.item_metadatas,
+			attributes: details.attributes,
+			mint_open,
+		})
+	}
+
+	/// Get the statistics of up to `limit` collections, starting after the collection `start`,
+	/// or from the first one if `start` is `None`.
+	///
+	/// Collections are listed in storage order, which is not the order of their ids. The last
+	/// collection of a page is the `start` of the next one; a page with fewer than `limit`
+	/// collections is the last.
+	pub fn collections(
+		start: Option<T::CollectionId>,
+		limit: u32,
+	) -> Vec<(T::CollectionId, CollectionStats<T::AccountId>)> {
+		let iter = match start {
+			Some(start) =>
+				Collection::<T, I>::iter_keys_from(Collection::<T, I>::hashed_key_for(start)),
+			None => Collection::<T, I>::iter_keys(),
+		};
+		iter.filter_map(|collection| Some((collection, Self::collection_stats(collection)?)))
+			.take(limit as usize)
+			.collect()
+	}
+
+	/// Get the price of the item and its whitelisted buyer, if the item is listed for sale.
+	///
+	/// A listing whose deadline has passed is reported as `None`, as it can't be bought.
+	pub fn item_price(collection: T::CollectionId, item: T::ItemId) -> Option<ItemPriceInfo<T, I>> {
+		let (price, whitelisted_buyer, maybe_deadline) =
+			ItemPriceOf::<T, I>::get(collection, item)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		if matches!(maybe_deadline, Some(deadline) if deadline < now) {
+			return None
+		}
+		Some((price, whitelisted_buyer))
+	}
+
+	/// Get the metadata of the collection, if it's set.
+	pub fn collection_metadata(collection: T::CollectionId) -> Option<Vec<u8>> {
+		CollectionMetadataOf::<T, I>::get(collection).map(|m| m.data.into())
+	}
+
+	/// Get the metadata of the item, if it's set.
+	pub fn item_metadata(collection: T::CollectionId, item: T::ItemId) -> Option<Vec<u8>> {
+		ItemMetadataOf::<T, I>::get(collection, item).map(|m| m.data.into())
+	}
+
+	/// Whether the `item` of `collection` exists and may currently be transferred.
+	///
+	/// This combines the lock of `T::Locker`, the `TransferableItems` setting of the collection
+	/// and the `Transferable` and `OwnerTransferable` settings of the item.
+	pub fn is_transferable(collection: T::CollectionId, item: T::ItemId) -> bool {
+		if !Item::<T, I>::contains_key(collection, item) || T::Locker::is_locked(collection, item) {
+			return false
+		}
+		match (
+			CollectionConfigOf::<T, I>::get(collection),
+			ItemConfigOf::<T, I>::get(collection, item),
+		) {
+			(Some(collection_config), Some(item_config)) =>
+				collection_config.is_setting_enabled(CollectionSetting::TransferableItems) &&
+					item_config.is_setting_enabled(ItemSetting::Transferable) &&
+					item_config.is_setting_enabled(ItemSetting::OwnerTransferable),
+			_ => false,
+		}
+	}
+
+	/// Get the details of the item, telling an unknown collection apart from an unknown item.
+	pub(crate) fn item_details(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> Result<ItemDetailsFor<T, I>, DispatchError> {
+		Item::<T, I>::get(collection, item).ok_or_else(|| {
+			if Collection::<T, I>::contains_key(collection) {
+				Error::<T, I>::UnknownItem.into()
+			} else {
+				Error::<T, I>::UnknownCollection.into()
+			}
+		})
+	}
+
+	/// Get the config of the collection, if the collection exists.
+	pub fn collection_config(collection: T::CollectionId) -> Option<CollectionConfigFor<T, I>> {
+		CollectionConfigOf::<T, I>::get(collection)
+	}
+
+	/// Get the config of the item, if the item exists.
+	pub fn item_config(collection: T::CollectionId, item: T::ItemId) -> Option<ItemConfig> {
+		ItemConfigOf::<T, I>::get(collection, item)
+	}
+
+	/// Get the value of the collection owner's attribute `key` of the `collection`, or of its
+	/// item if `maybe_item` is given.
+	pub fn attribute(
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		key: &[u8],
+	) -> Option<Vec<u8>> {
+		let key = BoundedSlice::<_, T::KeyLimit>::try_from(key).ok()?;
+		Attribute::<T, I>::get((collection, maybe_item, AttributeNamespace::CollectionOwner, key))
+			.map(|(value, _)| value.into())
+	}
+
+	/// Get the value of the collection owner's attribute `key` of the `item`, falling back to the
+	/// attribute of the same `key` set on the `collection` when the item doesn't have it.
+	///
+	/// This lets a collection define the attributes shared by all its items once.
+	pub fn effective_attribute(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		key: &[u8],
+	) -> Option<Vec<u8>> {
+		Self::attribute(collection, Some(item), key)
+			.or_else(|| Self::attribute(collection, None, key))
+	}
+
+	/// Whether the collection owner's attribute `key` is set on the `collection`, or on its item
+	/// if `maybe_item` is given.
+	pub fn has_attribute(
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		key: &[u8],
+	) -> bool {
+		BoundedSlice::<_, T::KeyLimit>::try_from(key).is_ok_and(|key| {
+			Attribute::<T, I>::contains_key((
+				collection,
+				maybe_item,
+				AttributeNamespace::CollectionOwner,
+				key,
+			))
+		})
+	}
+
 	/// Validate the `data` was signed by `signer` and the `signature` is correct.
 	pub fn validate_signature(
 		data: &Vec<u8>,
@@ -18,7 +18,7 @@
 use crate::*;
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement::KeepAlive},
+	traits::{BalanceStatus, Currency, ExistenceRequirement::KeepAlive, ReservableCurrency},
 };
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -30,17 +30,35 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		maybe_desired_item_id: Option<T::ItemId>,
 		maybe_price: Option<PriceWithDirection<ItemPrice<T, I>>>,
 		duration: <T as SystemConfig>::BlockNumber,
+		escrow: bool,
 	) -> DispatchResult {
 		ensure!(
 			Self::is_pallet_feature_enabled(PalletFeature::Swaps),
 			Error::<T, I>::MethodDisabled
 		);
 		ensure!(duration <= T::MaxDeadlineDuration::get(), Error::<T, I>::WrongDuration);
+		let maybe_escrow = match (escrow, &maybe_price) {
+			(false, _) => None,
+			(true, Some(price)) if price.direction == PriceDirection::Send => Some(price.amount),
+			(true, _) => return Err(Error::<T, I>::IncorrectData.into()),
+		};
 
 		let item = Item::<T, I>::get(&offered_collection_id, &offered_item_id)
 			.ok_or(Error::<T, I>::UnknownItem)?;
 		ensure!(item.owner == caller, Error::<T, I>::NoPermission);
 
+		let item_config = Self::get_item_config(&offered_collection_id, &offered_item_id)?;
+		ensure!(
+			item_config.is_setting_enabled(ItemSetting::OwnerTransferable),
+			Error::<T, I>::ItemLockedByOwner
+		);
+		// An item can't be offered in a swap while it's listed for sale, the price must be
+		// removed first.
+		ensure!(
+			!ItemPriceOf::<T, I>::contains_key(offered_collection_id, offered_item_id),
+			Error::<T, I>::ItemListed
+		);
+
 		match maybe_desired_item_id {
 			Some(desired_item_id) => ensure!(
 				Item::<T, I>::contains_key(&desired_collection_id, &desired_item_id),
@@ -55,6 +73,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let now = frame_system::Pallet::<T>::block_number();
 		let deadline = duration.saturating_add(now);
 
+		// Replacing a swap gives back whatever the previous one held in escrow.
+		Self::release_swap_escrow(&offered_collection_id, &offered_item_id);
+		if let Some(amount) = maybe_escrow {
+			T::Currency::reserve(&caller, amount).map_err(|_| Error::<T, I>::InsufficientEscrow)?;
+			SwapEscrowOf::<T, I>::insert(
+				&offered_collection_id,
+				&offered_item_id,
+				(caller.clone(), amount),
+			);
+		}
+
 		PendingSwapOf::<T, I>::insert(
 			&offered_collection_id,
 			&offered_item_id,
@@ -86,14 +115,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let swap = PendingSwapOf::<T, I>::get(&offered_collection_id, &offered_item_id)
 			.ok_or(Error::<T, I>::UnknownSwap)?;
 
+		// Anyone can clean up an expired swap. The only funds a swap can hold are its escrow,
+		// which goes back to the offerer, so removing it early can't take anyone's balance.
 		let now = frame_system::Pallet::<T>::block_number();
 		if swap.deadline > now {
 			let item = Item::<T, I>::get(&offered_collection_id, &offered_item_id)
 				.ok_or(Error::<T, I>::UnknownItem)?;
-			ensure!(item.owner == caller, Error::<T, I>::NoPermission);
+			ensure!(item.owner == caller, Error::<T, I>::SwapNotExpired);
 		}
 
 		PendingSwapOf::<T, I>::remove(&offered_collection_id, &offered_item_id);
+		Self::release_swap_escrow(&offered_collection_id, &offered_item_id);
 
 		Self::deposit_event(Event::SwapCancelled {
 			offered_collection: offered_collection_id,
@@ -140,14 +172,29 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let now = frame_system::Pallet::<T>::block_number();
 		ensure!(now <= swap.deadline, Error::<T, I>::DeadlineExpired);
 
+		Self::ensure_allowed_buyer(&receive_collection_id, &send_item.owner)?;
+		Self::ensure_allowed_buyer(&send_collection_id, &receive_item.owner)?;
+
+		let maybe_escrow = SwapEscrowOf::<T, I>::take(&receive_collection_id, &receive_item_id);
 		if let Some(ref price) = swap.price {
 			match price.direction {
-				PriceDirection::Send => T::Currency::transfer(
-					&receive_item.owner,
-					&send_item.owner,
-					price.amount,
-					KeepAlive,
-				)?,
+				PriceDirection::Send => match maybe_escrow {
+					Some((payer, amount)) => {
+						let missing = T::Currency::repatriate_reserved(
+							&payer,
+							&send_item.owner,
+							amount,
+							BalanceStatus::Free,
+						)?;
+						ensure!(missing.is_zero(), Error::<T, I>::InsufficientEscrow);
+					},
+					None => T::Currency::transfer(
+						&receive_item.owner,
+						&send_item.owner,
+						price.amount,
+						KeepAlive,
+					)?,
+				},
 				PriceDirection::Receive => T::Currency::transfer(
 					&send_item.owner,
 					&receive_item.owner,
@@ -157,7 +204,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			};
 		}
 
-		// This also removes the swap.
+		PendingSwapOf::<T, I>::remove(receive_collection_id, receive_item_id);
 		Self::do_transfer(send_collection_id, send_item_id, receive_item.owner.clone(), |_, _| {
 			Ok(())
 		})?;
@@ -181,4 +228,148 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Ok(())
 	}
+
+	/// Give the escrow held for the pending swap of `item` back to the account that paid it.
+	pub(crate) fn release_swap_escrow(collection: &T::CollectionId, item: &T::ItemId) {
+		if let Some((payer, amount)) = SwapEscrowOf::<T, I>::take(collection, item) {
+			T::Currency::unreserve(&payer, amount);
+		}
+	}
+
+	pub(crate) fn do_create_bundle_swap(
+		caller: T::AccountId,
+		offered: BundleOf<T, I>,
+		desired: BundleOf<T, I>,
+		maybe_price: Option<PriceWithDirection<ItemPrice<T, I>>>,
+		duration: <T as SystemConfig>::BlockNumber,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Swaps),
+			Error::<T, I>::MethodDisabled
+		);
+		ensure!(duration <= T::MaxDeadlineDuration::get(), Error::<T, I>::WrongDuration);
+		ensure!(!offered.is_empty() && !desired.is_empty(), Error::<T, I>::EmptyBundle);
+
+		let mut seen = Vec::with_capacity(offered.len() + desired.len());
+		for entry in offered.iter().chain(desired.iter()) {
+			ensure!(!seen.contains(&entry), Error::<T, I>::DuplicateBundleItem);
+			seen.push(entry);
+		}
+
+		for (collection, item) in offered.iter() {
+			let details = Item::<T, I>::get(collection, item).ok_or(Error::<T, I>::UnknownItem)?;
+			ensure!(details.owner == caller, Error::<T, I>::NoPermission);
+
+			let item_config = Self::get_item_config(collection, item)?;
+			ensure!(
+				item_config.is_setting_enabled(ItemSetting::OwnerTransferable),
+				Error::<T, I>::ItemLockedByOwner
+			);
+		}
+		for (collection, item) in desired.iter() {
+			ensure!(Item::<T, I>::contains_key(collection, item), Error::<T, I>::UnknownItem);
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let deadline = duration.saturating_add(now);
+
+		let swap_id = NextBundleSwapId::<T, I>::get();
+		NextBundleSwapId::<T, I>::put(swap_id.saturating_add(1));
+		PendingBundleSwapOf::<T, I>::insert(
+			swap_id,
+			PendingBundleSwap {
+				creator: caller.clone(),
+				offered: offered.clone(),
+				desired: desired.clone(),
+				price: maybe_price.clone(),
+				deadline,
+			},
+		);
+
+		Self::deposit_event(Event::BundleSwapCreated {
+			swap_id,
+			creator: caller,
+			offered,
+			desired,
+			price: maybe_price,
+			deadline,
+		});
+
+		Ok(())
+	}
+
+	pub(crate) fn do_cancel_bundle_swap(caller: T::AccountId, swap_id: u32) -> DispatchResult {
+		let swap = PendingBundleSwapOf::<T, I>::get(swap_id).ok_or(Error::<T, I>::UnknownSwap)?;
+
+		// Just like single item swaps, anyone can clean up an expired bundle swap.
+		let now = frame_system::Pallet::<T>::block_number();
+		if swap.deadline > now {
+			ensure!(swap.creator == caller, Error::<T, I>::SwapNotExpired);
+		}
+
+		PendingBundleSwapOf::<T, I>::remove(swap_id);
+
+		Self::deposit_event(Event::BundleSwapCancelled { swap_id });
+
+		Ok(())
+	}
+
+	/// Exchange the items of the bundle swap `swap_id` between its creator and the `caller`.
+	///
+	/// The ownership of every item is checked before anything is moved, and the whole call is
+	/// reverted if a later transfer fails. Returns the number of exchanged items.
+	pub(crate) fn do_claim_bundle_swap(
+		caller: T::AccountId,
+		swap_id: u32,
+		witness_price: Option<PriceWithDirection<ItemPrice<T, I>>>,
+	) -> Result<u32, DispatchError> {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Swaps),
+			Error::<T, I>::MethodDisabled
+		);
+
+		let swap = PendingBundleSwapOf::<T, I>::get(swap_id).ok_or(Error::<T, I>::UnknownSwap)?;
+		ensure!(swap.price == witness_price, Error::<T, I>::UnknownSwap);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now <= swap.deadline, Error::<T, I>::DeadlineExpired);
+
+		for (collection, item) in swap.offered.iter() {
+			let details = Item::<T, I>::get(collection, item).ok_or(Error::<T, I>::UnknownItem)?;
+			ensure!(details.owner == swap.creator, Error::<T, I>::WrongOwner);
+			Self::ensure_allowed_buyer(collection, &caller)?;
+		}
+		for (collection, item) in swap.desired.iter() {
+			let details = Item::<T, I>::get(collection, item).ok_or(Error::<T, I>::UnknownItem)?;
+			ensure!(details.owner == caller, Error::<T, I>::NoPermission);
+			Self::ensure_allowed_buyer(collection, &swap.creator)?;
+		}
+
+		if let Some(ref price) = swap.price {
+			match price.direction {
+				PriceDirection::Send =>
+					T::Currency::transfer(&swap.creator, &caller, price.amount, KeepAlive)?,
+				PriceDirection::Receive =>
+					T::Currency::transfer(&caller, &swap.creator, price.amount, KeepAlive)?,
+			};
+		}
+
+		for (collection, item) in swap.offered.iter() {
+			Self::do_transfer(*collection, *item, caller.clone(), |_, _| Ok(()))?;
+		}
+		for (collection, item) in swap.desired.iter() {
+			Self::do_transfer(*collection, *item, swap.creator.clone(), |_, _| Ok(()))?;
+		}
+
+		PendingBundleSwapOf::<T, I>::remove(swap_id);
+
+		Self::deposit_event(Event::BundleSwapClaimed {
+			swap_id,
+			creator: swap.creator,
+			claimer: caller,
+			price: swap.price,
+		});
+
+		Ok((swap.offered.len() + swap.desired.len()) as u32)
+	}
 }
@@ -70,13 +70,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			attribute.map_or(AttributeDeposit { account: None, amount: Zero::zero() }, |m| m.1);
 
 		let mut deposit = Zero::zero();
-		// disabled DepositRequired setting only affects the CollectionOwner namespace
+		// disabled DepositRequired setting only affects the CollectionOwner namespace,
+		// unless that's a system collection
 		if collection_config.is_setting_enabled(CollectionSetting::DepositRequired) ||
-			namespace != AttributeNamespace::CollectionOwner
+			(namespace != AttributeNamespace::CollectionOwner &&
+				!SystemCollection::<T, I>::contains_key(&collection))
 		{
-			deposit = T::DepositPerByte::get()
+			let deposits = Self::collection_deposits(&collection);
+			deposit = deposits
+				.per_byte
 				.saturating_mul(((key.len() + value.len()) as u32).into())
-				.saturating_add(T::AttributeDepositBase::get());
+				.saturating_add(deposits.attribute_base);
 		}
 
 		let is_collection_owner_namespace = namespace == AttributeNamespace::CollectionOwner;
@@ -128,6 +132,27 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	pub(crate) fn do_set_attributes(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		maybe_item: Option<T::ItemId>,
+		namespace: AttributeNamespace<T::AccountId>,
+		attributes: AttributesOf<T, I>,
+	) -> DispatchResult {
+		for (key, value) in attributes {
+			Self::do_set_attribute(
+				origin.clone(),
+				collection,
+				maybe_item,
+				namespace.clone(),
+				key,
+				value,
+				origin.clone(),
+			)?;
+		}
+		Ok(())
+	}
+
 	pub(crate) fn do_force_set_attribute(
 		set_as: Option<T::AccountId>,
 		collection: T::CollectionId,
@@ -136,6 +161,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		key: BoundedVec<u8, T::KeyLimit>,
 		value: BoundedVec<u8, T::ValueLimit>,
 	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Attributes),
+			Error::<T, I>::MethodDisabled
+		);
+
 		let mut collection_details =
 			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 
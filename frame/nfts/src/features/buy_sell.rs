@@ -27,32 +27,39 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		tips: BoundedVec<ItemTipOf<T, I>, T::MaxTips>,
 	) -> DispatchResult {
 		for tip in tips {
-			let ItemTip { collection, item, receiver, amount } = tip;
-			T::Currency::transfer(&sender, &receiver, amount, KeepAlive)?;
-			Self::deposit_event(Event::TipSent {
-				collection,
-				item,
-				sender: sender.clone(),
-				receiver,
-				amount,
-			});
+			Self::do_pay_tip(&sender, tip)?;
 		}
 		Ok(())
 	}
 
+	fn do_pay_tip(sender: &T::AccountId, tip: ItemTipOf<T, I>) -> DispatchResult {
+		let ItemTip { collection, item, receiver, amount } = tip;
+		ensure!(Item::<T, I>::contains_key(collection, item), Error::<T, I>::UnknownItem);
+		T::Currency::transfer(sender, &receiver, amount, KeepAlive)?;
+		Self::deposit_event(Event::TipSent {
+			collection,
+			item,
+			sender: sender.clone(),
+			receiver,
+			amount,
+		});
+		Ok(())
+	}
+
 	pub(crate) fn do_set_price(
 		collection: T::CollectionId,
 		item: T::ItemId,
 		sender: T::AccountId,
 		price: Option<ItemPrice<T, I>>,
 		whitelisted_buyer: Option<T::AccountId>,
+		maybe_deadline: Option<<T as SystemConfig>::BlockNumber>,
 	) -> DispatchResult {
 		ensure!(
 			Self::is_pallet_feature_enabled(PalletFeature::Trading),
 			Error::<T, I>::MethodDisabled
 		);
 
-		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		let details = Self::item_details(&collection, &item)?;
 		ensure!(details.owner == sender, Error::<T, I>::NoPermission);
 
 		let collection_config = Self::get_collection_config(&collection)?;
@@ -66,14 +73,35 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			item_config.is_setting_enabled(ItemSetting::Transferable),
 			Error::<T, I>::ItemLocked
 		);
+		ensure!(
+			item_config.is_setting_enabled(ItemSetting::OwnerTransferable),
+			Error::<T, I>::ItemLockedByOwner
+		);
 
 		if let Some(ref price) = price {
-			ItemPriceOf::<T, I>::insert(&collection, &item, (price, whitelisted_buyer.clone()));
+			if let Some(deadline) = maybe_deadline {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(deadline >= now, Error::<T, I>::DeadlineExpired);
+			}
+			// An item can't be sold while it's offered in a swap, the swap must be cancelled
+			// first.
+			ensure!(
+				!PendingSwapOf::<T, I>::contains_key(collection, item),
+				Error::<T, I>::ItemInSwap
+			);
+			let previous = ItemPriceOf::<T, I>::mutate(&collection, &item, |listing| {
+				listing.replace((*price, whitelisted_buyer.clone(), maybe_deadline))
+			});
+			// Make a change of the whitelisted buyer stand out by withdrawing the old listing.
+			if previous.is_some_and(|(_, old_buyer, _)| old_buyer != whitelisted_buyer) {
+				Self::deposit_event(Event::ItemPriceRemoved { collection, item });
+			}
 			Self::deposit_event(Event::ItemPriceSet {
 				collection,
 				item,
 				price: *price,
 				whitelisted_buyer,
+				deadline: maybe_deadline,
 			});
 		} else {
 			ItemPriceOf::<T, I>::remove(&collection, &item);
@@ -94,17 +122,22 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Error::<T, I>::MethodDisabled
 		);
 
-		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		let details = Self::item_details(&collection, &item)?;
 		ensure!(details.owner != buyer, Error::<T, I>::NoPermission);
 
 		let price_info =
 			ItemPriceOf::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::NotForSale)?;
 
+		if let Some(deadline) = price_info.2 {
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(deadline >= now, Error::<T, I>::ListingExpired);
+		}
 		ensure!(bid_price >= price_info.0, Error::<T, I>::BidTooLow);
 
 		if let Some(only_buyer) = price_info.1 {
 			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
 		}
+		Self::ensure_allowed_buyer(&collection, &buyer)?;
 
 		T::Currency::transfer(
 			&buyer,
@@ -115,6 +148,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		let old_owner = details.owner.clone();
 
+		ItemPriceOf::<T, I>::remove(collection, item);
 		Self::do_transfer(collection, item, buyer.clone(), |_, _| Ok(()))?;
 
 		Self::deposit_event(Event::ItemBought {
@@ -127,4 +161,91 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Ok(())
 	}
+
+	pub(crate) fn do_clear_expired_price(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DispatchResult {
+		let (_, _, maybe_deadline) =
+			ItemPriceOf::<T, I>::get(collection, item).ok_or(Error::<T, I>::NotForSale)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(
+			matches!(maybe_deadline, Some(deadline) if deadline < now),
+			Error::<T, I>::ListingNotExpired
+		);
+
+		ItemPriceOf::<T, I>::remove(collection, item);
+		Self::deposit_event(Event::ItemPriceRemoved { collection, item });
+		Ok(())
+	}
+
+	pub(crate) fn do_buy_item_with_tip(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		buyer: T::AccountId,
+		bid_price: ItemPrice<T, I>,
+		tip: Option<BalanceOf<T, I>>,
+	) -> DispatchResult {
+		Self::do_buy_item(collection, item, buyer.clone(), bid_price)?;
+
+		if let Some(amount) = tip {
+			let receiver =
+				Self::collection_owner(collection).ok_or(Error::<T, I>::UnknownCollection)?;
+			Self::do_pay_tip(&buyer, ItemTip { collection, item, receiver, amount })?;
+		}
+
+		Ok(())
+	}
+
+	pub(crate) fn do_add_allowed_buyer(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		buyer: T::AccountId,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, check_origin, CollectionRole::Admin),
+				Error::<T, I>::NoPermission
+			);
+		}
+		ensure!(Collection::<T, I>::contains_key(collection), Error::<T, I>::UnknownCollection);
+
+		CollectionAllowlist::<T, I>::insert(collection, &buyer, ());
+		Self::deposit_event(Event::AllowedBuyerAdded { collection, buyer });
+		Ok(())
+	}
+
+	pub(crate) fn do_remove_allowed_buyer(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		buyer: T::AccountId,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, check_origin, CollectionRole::Admin),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		CollectionAllowlist::<T, I>::take(collection, &buyer)
+			.ok_or(Error::<T, I>::BuyerNotAllowed)?;
+		Self::deposit_event(Event::AllowedBuyerRemoved { collection, buyer });
+		Ok(())
+	}
+
+	/// Ensure the `buyer` may acquire the items of the `collection`, i.e. either the collection
+	/// doesn't restrict its buyers or the `buyer` is in its allowlist.
+	pub(crate) fn ensure_allowed_buyer(
+		collection: &T::CollectionId,
+		buyer: &T::AccountId,
+	) -> DispatchResult {
+		let config = Self::get_collection_config(collection)?;
+		if !config.is_setting_enabled(CollectionSetting::UnrestrictedBuyers) {
+			ensure!(
+				CollectionAllowlist::<T, I>::contains_key(collection, buyer),
+				Error::<T, I>::BuyerNotAllowed
+			);
+		}
+		Ok(())
+	}
 }
@@ -29,6 +29,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> DispatchResult {
 		ensure!(!Collection::<T, I>::contains_key(collection), Error::<T, I>::CollectionIdInUse);
 
+		T::OnCollectionCreated::on_collection_created(&collection, &owner)
+			.map_err(|_| Error::<T, I>::CreationVetoed)?;
 		T::Currency::reserve(&owner, deposit)?;
 
 		Collection::<T, I>::insert(
@@ -50,68 +52,251 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			),
 		);
 
-		let next_id = collection.increment();
-
 		CollectionConfigOf::<T, I>::insert(&collection, config);
 		CollectionAccount::<T, I>::insert(&owner, &collection, ());
-		NextCollectionId::<T, I>::set(Some(next_id));
 
-		Self::deposit_event(Event::NextCollectionIdIncremented { next_id });
+		// Only the sequential path moves the counter forward, past any ids that were already
+		// claimed through `create_with_id`. Once the id space is exhausted the counter stays on
+		// the last id, so any further `create` fails with `CollectionIdInUse`.
+		if collection == NextCollectionId::<T, I>::get().unwrap_or(T::CollectionId::initial_value())
+		{
+			let mut next_id = collection.increment();
+			while let Some(id) = next_id.filter(|id| Collection::<T, I>::contains_key(id)) {
+				next_id = id.increment();
+			}
+			if let Some(next_id) = next_id {
+				NextCollectionId::<T, I>::set(Some(next_id));
+				Self::deposit_event(Event::NextCollectionIdIncremented { next_id });
+			}
+		}
+
 		Self::deposit_event(event);
 		Ok(())
 	}
 
+	/// Remove up to `witness` item metadata, item configs and attributes of the `collection`.
+	///
+	/// Once nothing is left, the collection itself is destroyed. A witness matching the
+	/// collection's counters therefore destroys it in a single call, while a smaller one allows
+	/// large collections to be torn down across several calls.
 	pub fn do_destroy_collection(
 		collection: T::CollectionId,
 		witness: DestroyWitness,
 		maybe_check_owner: Option<T::AccountId>,
-	) -> Result<DestroyWitness, DispatchError> {
+	) -> Result<DestroyProgress, DispatchError> {
 		Collection::<T, I>::try_mutate_exists(collection, |maybe_details| {
 			let collection_details =
-				maybe_details.take().ok_or(Error::<T, I>::UnknownCollection)?;
+				maybe_details.as_mut().ok_or(Error::<T, I>::UnknownCollection)?;
 			if let Some(check_owner) = maybe_check_owner {
 				ensure!(collection_details.owner == check_owner, Error::<T, I>::NoPermission);
 			}
 			ensure!(collection_details.items == 0, Error::<T, I>::CollectionNotEmpty);
-			ensure!(collection_details.attributes == witness.attributes, Error::<T, I>::BadWitness);
 			ensure!(
-				collection_details.item_metadatas == witness.item_metadatas,
+				CollectionAllowlist::<T, I>::iter_key_prefix(collection).next().is_none(),
+				Error::<T, I>::AllowlistNotEmpty
+			);
+			ensure!(collection_details.attributes >= witness.attributes, Error::<T, I>::BadWitness);
+			ensure!(
+				collection_details.item_metadatas >= witness.item_metadatas,
 				Error::<T, I>::BadWitness
 			);
 			ensure!(
-				collection_details.item_configs == witness.item_configs,
+				collection_details.item_configs >= witness.item_configs,
 				Error::<T, I>::BadWitness
 			);
 
-			for (_, metadata) in ItemMetadataOf::<T, I>::drain_prefix(&collection) {
+			let mut removed = DestroyWitness { item_metadatas: 0, item_configs: 0, attributes: 0 };
+
+			for (_, metadata) in ItemMetadataOf::<T, I>::drain_prefix(&collection)
+				.take(witness.item_metadatas as usize)
+			{
 				if let Some(depositor) = metadata.deposit.account {
 					T::Currency::unreserve(&depositor, metadata.deposit.amount);
 				}
+				removed.item_metadatas.saturating_inc();
 			}
 
-			CollectionMetadataOf::<T, I>::remove(&collection);
-			Self::clear_roles(&collection)?;
-
-			for (_, (_, deposit)) in Attribute::<T, I>::drain_prefix((&collection,)) {
+			for (_, (_, deposit)) in
+				Attribute::<T, I>::drain_prefix((&collection,)).take(witness.attributes as usize)
+			{
 				if !deposit.amount.is_zero() {
 					if let Some(account) = deposit.account {
 						T::Currency::unreserve(&account, deposit.amount);
 					}
 				}
+				removed.attributes.saturating_inc();
 			}
 
+			for _ in
+				ItemConfigOf::<T, I>::drain_prefix(&collection).take(witness.item_configs as usize)
+			{
+				removed.item_configs.saturating_inc();
+			}
+
+			// NOTE: running out of records before the witness is exhausted means there are none
+			// left, whatever the counter says.
+			let remaining = |counter: u32, removed: u32, witnessed: u32| {
+				if removed < witnessed {
+					0
+				} else {
+					counter.saturating_sub(removed)
+				}
+			};
+			collection_details.item_metadatas = remaining(
+				collection_details.item_metadatas,
+				removed.item_metadatas,
+				witness.item_metadatas,
+			);
+			collection_details.attributes =
+				remaining(collection_details.attributes, removed.attributes, witness.attributes);
+			collection_details.item_configs = remaining(
+				collection_details.item_configs,
+				removed.item_configs,
+				witness.item_configs,
+			);
+			let progress =
+				DestroyProgress { removed, remaining: collection_details.destroy_witness() };
+
+			if !progress.is_finished() {
+				Self::deposit_event(Event::PartiallyDestroyed {
+					collection,
+					remaining: progress.remaining,
+				});
+				return Ok(progress)
+			}
+
+			CollectionMetadataOf::<T, I>::remove(&collection);
+			DefaultItemMetadataOf::<T, I>::remove(&collection);
+			Self::clear_roles(&collection)?;
 			CollectionAccount::<T, I>::remove(&collection_details.owner, &collection);
 			T::Currency::unreserve(&collection_details.owner, collection_details.owner_deposit);
 			CollectionConfigOf::<T, I>::remove(&collection);
-			let _ = ItemConfigOf::<T, I>::clear_prefix(&collection, witness.item_configs, None);
+			SystemCollection::<T, I>::remove(&collection);
+			MintPaused::<T, I>::remove(&collection);
+			CollectionDepositOverride::<T, I>::remove(collection);
+			T::OnCollectionDestroyed::on_collection_destroyed(
+				&collection,
+				&collection_details.owner,
+			);
+			*maybe_details = None;
 
 			Self::deposit_event(Event::Destroyed { collection });
 
-			Ok(DestroyWitness {
-				item_metadatas: collection_details.item_metadatas,
-				item_configs: collection_details.item_configs,
-				attributes: collection_details.attributes,
-			})
+			Ok(progress)
+		})
+	}
+
+	/// Remove up to `max_items` items, item metadata, item configs, attributes and allowed buyers
+	/// of the `collection` without relying on its cached counters. Once a call removes fewer than
+	/// `max_items` records, nothing is left and the collection itself is destroyed.
+	///
+	/// Returns the number of removed records.
+	pub fn do_force_destroy_collection(
+		collection: T::CollectionId,
+		max_items: u32,
+	) -> Result<u32, DispatchError> {
+		let collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let mut removed: u32 = 0;
+
+		for (item, details) in Item::<T, I>::drain_prefix(&collection).take(max_items as usize) {
+			T::Currency::unreserve(&details.deposit.account, details.deposit.amount);
+			Account::<T, I>::remove((&details.owner, &collection, &item));
+			ItemPriceOf::<T, I>::remove(&collection, &item);
+			PendingSwapOf::<T, I>::remove(&collection, &item);
+			Self::release_swap_escrow(&collection, &item);
+			ItemAttributesApprovalsOf::<T, I>::remove(&collection, &item);
+			removed.saturating_inc();
+		}
+
+		for (_, metadata) in ItemMetadataOf::<T, I>::drain_prefix(&collection)
+			.take(max_items.saturating_sub(removed) as usize)
+		{
+			if let Some(depositor) = metadata.deposit.account {
+				T::Currency::unreserve(&depositor, metadata.deposit.amount);
+			}
+			removed.saturating_inc();
+		}
+
+		for (_, (_, deposit)) in Attribute::<T, I>::drain_prefix((&collection,))
+			.take(max_items.saturating_sub(removed) as usize)
+		{
+			if !deposit.amount.is_zero() {
+				if let Some(account) = deposit.account {
+					T::Currency::unreserve(&account, deposit.amount);
+				}
+			}
+			removed.saturating_inc();
+		}
+
+		for _ in ItemConfigOf::<T, I>::drain_prefix(&collection)
+			.take(max_items.saturating_sub(removed) as usize)
+		{
+			removed.saturating_inc();
+		}
+
+		for _ in CollectionAllowlist::<T, I>::drain_prefix(&collection)
+			.take(max_items.saturating_sub(removed) as usize)
+		{
+			removed.saturating_inc();
+		}
+
+		if removed < max_items {
+			CollectionMetadataOf::<T, I>::remove(&collection);
+			DefaultItemMetadataOf::<T, I>::remove(&collection);
+			Self::clear_roles(&collection)?;
+			CollectionAccount::<T, I>::remove(&collection_details.owner, &collection);
+			T::Currency::unreserve(&collection_details.owner, collection_details.owner_deposit);
+			CollectionConfigOf::<T, I>::remove(&collection);
+			SystemCollection::<T, I>::remove(&collection);
+			MintPaused::<T, I>::remove(&collection);
+			CollectionDepositOverride::<T, I>::remove(collection);
+			Collection::<T, I>::remove(&collection);
+
+			T::OnCollectionDestroyed::on_collection_destroyed(
+				&collection,
+				&collection_details.owner,
+			);
+			Self::deposit_event(Event::Destroyed { collection });
+		}
+
+		Ok(removed)
+	}
+
+	/// Set the cached counters of the `collection` to the number of its items, item metadata,
+	/// item configs and attributes in storage, reading at most `max_items` records.
+	///
+	/// Returns the number of records read.
+	pub fn do_recompute_collection_status(
+		collection: T::CollectionId,
+		max_items: u32,
+	) -> Result<u32, DispatchError> {
+		Collection::<T, I>::try_mutate(collection, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownCollection)?;
+			let mut budget = max_items as usize;
+			let mut count = |records: &mut dyn Iterator<Item = ()>| -> Result<u32, DispatchError> {
+				let counted = records.take(budget.saturating_add(1)).count();
+				budget = budget.checked_sub(counted).ok_or(Error::<T, I>::BadWitness)?;
+				Ok(counted as u32)
+			};
+
+			details.items = count(&mut Item::<T, I>::iter_key_prefix(collection).map(|_| ()))?;
+			details.item_metadatas =
+				count(&mut ItemMetadataOf::<T, I>::iter_key_prefix(collection).map(|_| ()))?;
+			details.item_configs =
+				count(&mut ItemConfigOf::<T, I>::iter_key_prefix(collection).map(|_| ()))?;
+			details.attributes =
+				count(&mut Attribute::<T, I>::iter_key_prefix((&collection,)).map(|_| ()))?;
+
+			Self::deposit_event(Event::CollectionStatusRecomputed {
+				collection,
+				items: details.items,
+				item_metadatas: details.item_metadatas,
+				item_configs: details.item_configs,
+				attributes: details.attributes,
+			});
+
+			Ok(max_items.saturating_sub(budget as u32))
 		})
 	}
 }
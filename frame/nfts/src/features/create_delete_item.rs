@@ -19,6 +19,12 @@ use crate::*;
 use frame_support::pallet_prelude::*;
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Mint an `item` of a `collection` into the `mint_to` account.
+	///
+	/// No origin is checked here, `with_details_and_config` could be used to check the
+	/// permissions of the caller and charge the mint price, returning the price paid if any. The
+	/// deposit is taken from `maybe_depositor`, or from the collection's owner if it's `None`. The
+	/// max supply is still enforced.
 	pub fn do_mint(
 		collection: T::CollectionId,
 		item: T::ItemId,
@@ -28,22 +34,33 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		with_details_and_config: impl FnOnce(
 			&CollectionDetailsFor<T, I>,
 			&CollectionConfigFor<T, I>,
-		) -> DispatchResult,
+		) -> Result<Option<ItemPrice<T, I>>, DispatchError>,
 	) -> DispatchResult {
 		ensure!(!Item::<T, I>::contains_key(collection, item), Error::<T, I>::AlreadyExists);
 
-		Collection::<T, I>::try_mutate(
+		// The config of a burnt item may have been preserved, e.g. because its properties were
+		// locked. The new item must then come with the very same config, whichever way it's
+		// minted. This is checked before any write, as not every caller is transactional.
+		let existing_config = ItemConfigOf::<T, I>::get(collection, item);
+		if let Some(existing_config) = existing_config {
+			ensure!(existing_config == item_config, Error::<T, I>::InconsistentItemConfig);
+		}
+
+		let price = Collection::<T, I>::try_mutate(
 			&collection,
-			|maybe_collection_details| -> DispatchResult {
+			|maybe_collection_details| -> Result<_, DispatchError> {
 				let collection_details =
 					maybe_collection_details.as_mut().ok_or(Error::<T, I>::UnknownCollection)?;
 
 				let collection_config = Self::get_collection_config(&collection)?;
-				with_details_and_config(collection_details, &collection_config)?;
+				let price = with_details_and_config(collection_details, &collection_config)?;
 
 				if let Some(max_supply) = collection_config.max_supply {
 					ensure!(collection_details.items < max_supply, Error::<T, I>::MaxSupplyReached);
 				}
+				if let Some((first, last)) = collection_config.item_id_range {
+					ensure!(first <= item && item <= last, Error::<T, I>::ItemIdOutOfRange);
+				}
 
 				collection_details.items.saturating_inc();
 
@@ -51,7 +68,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				let deposit_amount = match collection_config
 					.is_setting_enabled(CollectionSetting::DepositRequired)
 				{
-					true => T::ItemDeposit::get(),
+					true => Self::collection_deposits(&collection).item,
 					false => Zero::zero(),
 				};
 				let deposit_account = match maybe_depositor {
@@ -62,9 +79,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				let item_owner = mint_to.clone();
 				Account::<T, I>::insert((&item_owner, &collection, &item), ());
 
-				if let Ok(existing_config) = ItemConfigOf::<T, I>::try_get(&collection, &item) {
-					ensure!(existing_config == item_config, Error::<T, I>::InconsistentItemConfig);
-				} else {
+				if existing_config.is_none() {
 					ItemConfigOf::<T, I>::insert(&collection, &item, item_config);
 					collection_details.item_configs.saturating_inc();
 				}
@@ -76,13 +91,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					owner: item_owner,
 					approvals: ApprovalsOf::<T, I>::default(),
 					deposit,
+					mint_block: frame_system::Pallet::<T>::block_number(),
 				};
 				Item::<T, I>::insert(&collection, &item, details);
-				Ok(())
+				Ok(price)
 			},
 		)?;
 
-		Self::deposit_event(Event::Issued { collection, item, owner: mint_to });
+		Self::deposit_event(Event::Issued { collection, item, owner: mint_to, price });
 		Ok(())
 	}
 
@@ -118,7 +134,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Some(mint_to.clone()),
 			mint_to.clone(),
 			item_config,
-			|_, _| Ok(()),
+			|_, _| Ok(None),
 		)?;
 		let admin_account = Self::find_account_by_role(&collection, CollectionRole::Admin);
 		if let Some(admin_account) = admin_account {
@@ -146,13 +162,52 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Burn each of the `items` of a `collection` owned by `maybe_check_origin` and
+	/// `maybe_check_owner`, when those are set.
+	///
+	/// If `atomic` is `false`, the items which can't be burned are skipped rather than failing
+	/// the whole batch.
+	pub(crate) fn do_burn_many(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		items: BoundedVec<T::ItemId, T::MaxBatch>,
+		maybe_check_owner: Option<T::AccountId>,
+		atomic: bool,
+	) -> DispatchResult {
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+
+		for item in items {
+			// NOTE: `do_burn` checks everything before its first write, so a skipped item leaves
+			// no trace.
+			let result = Self::do_burn(collection, item, |details| {
+				if let Some(check_origin) = &maybe_check_origin {
+					ensure!(details.owner == *check_origin, Error::<T, I>::NoPermission);
+				}
+				if let Some(check_owner) = &maybe_check_owner {
+					ensure!(details.owner == *check_owner, Error::<T, I>::WrongOwner);
+				}
+				Ok(())
+			});
+			if atomic {
+				result?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Burn an `item` of a `collection`.
+	///
+	/// No origin is checked here, `with_details` could be used to check the permissions of the
+	/// caller. The locks are still enforced.
 	pub fn do_burn(
 		collection: T::CollectionId,
 		item: T::ItemId,
 		with_details: impl FnOnce(&ItemDetailsFor<T, I>) -> DispatchResult,
 	) -> DispatchResult {
 		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::ItemLocked);
-		let item_config = Self::get_item_config(&collection, &item)?;
+		let mut item_config = Self::get_item_config(&collection, &item)?;
+		// NOTE: the owner's transfer lock doesn't outlive the item
+		item_config.enable_setting(ItemSetting::OwnerTransferable);
 		// NOTE: if item's settings are not empty (e.g. item's metadata is locked)
 		// then we keep the config record and don't remove it
 		let remove_config = !item_config.has_disabled_settings();
@@ -198,10 +253,13 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Account::<T, I>::remove((&owner, &collection, &item));
 		ItemPriceOf::<T, I>::remove(&collection, &item);
 		PendingSwapOf::<T, I>::remove(&collection, &item);
+		Self::release_swap_escrow(&collection, &item);
 		ItemAttributesApprovalsOf::<T, I>::remove(&collection, &item);
 
 		if remove_config {
 			ItemConfigOf::<T, I>::remove(&collection, &item);
+		} else {
+			ItemConfigOf::<T, I>::insert(&collection, &item, item_config);
 		}
 
 		Self::deposit_event(Event::Burned { collection, item, owner });
@@ -36,7 +36,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				config.disable_setting(setting);
 			}
 
-			Self::deposit_event(Event::<T, I>::CollectionLocked { collection });
+			Self::deposit_event(Event::<T, I>::CollectionLocked {
+				collection,
+				settings: config.settings,
+			});
 			Ok(())
 		})
 	}
@@ -81,6 +84,70 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Unlock the transfer of up to `max_items` items of a `collection` locked by its Freezer,
+	/// returning how many were unlocked.
+	pub(crate) fn do_thaw_all(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		max_items: u32,
+	) -> Result<u32, DispatchError> {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, check_origin, CollectionRole::Freezer),
+				Error::<T, I>::NoPermission
+			);
+		}
+		ensure!(Collection::<T, I>::contains_key(collection), Error::<T, I>::UnknownCollection);
+
+		let frozen = ItemConfigOf::<T, I>::iter_prefix(collection)
+			.filter(|(_, config)| config.has_disabled_setting(ItemSetting::Transferable))
+			.take(max_items as usize)
+			.collect::<Vec<_>>();
+		let thawed = frozen.len() as u32;
+
+		for (item, mut config) in frozen {
+			config.enable_setting(ItemSetting::Transferable);
+			ItemConfigOf::<T, I>::insert(collection, item, config);
+			Self::deposit_event(Event::<T, I>::ItemTransferUnlocked { collection, item });
+		}
+
+		Ok(thawed)
+	}
+
+	pub(crate) fn do_lock_item_transfer_by_owner(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DispatchResult {
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details.owner == origin, Error::<T, I>::NoPermission);
+
+		let mut config = Self::get_item_config(&collection, &item)?;
+		config.disable_setting(ItemSetting::OwnerTransferable);
+		ItemConfigOf::<T, I>::insert(&collection, &item, config);
+
+		Self::deposit_event(Event::<T, I>::ItemTransferLockedByOwner { collection, item });
+		Ok(())
+	}
+
+	pub(crate) fn do_unlock_item_transfer_by_owner(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DispatchResult {
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		if let Some(check_owner) = maybe_check_owner {
+			ensure!(details.owner == check_owner, Error::<T, I>::NoPermission);
+		}
+
+		let mut config = Self::get_item_config(&collection, &item)?;
+		config.enable_setting(ItemSetting::OwnerTransferable);
+		ItemConfigOf::<T, I>::insert(&collection, &item, config);
+
+		Self::deposit_event(Event::<T, I>::ItemTransferUnlockedByOwner { collection, item });
+		Ok(())
+	}
+
 	pub(crate) fn do_lock_item_properties(
 		maybe_check_origin: Option<T::AccountId>,
 		collection: T::CollectionId,
@@ -108,8 +175,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Self::deposit_event(Event::<T, I>::ItemPropertiesLocked {
 				collection,
 				item,
-				lock_metadata,
-				lock_attributes,
+				lock_metadata: config.has_disabled_setting(ItemSetting::UnlockedMetadata),
+				lock_attributes: config.has_disabled_setting(ItemSetting::UnlockedAttributes),
 			});
 			Ok(())
 		})
@@ -27,6 +27,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		data: BoundedVec<u8, T::StringLimit>,
 		maybe_depositor: Option<T::AccountId>,
 	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Metadata),
+			Error::<T, I>::MethodDisabled
+		);
+
 		if let Some(check_origin) = &maybe_check_origin {
 			ensure!(
 				Self::has_role(&collection, &check_origin, CollectionRole::Admin),
@@ -44,6 +49,80 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Error::<T, I>::LockedItemMetadata
 		);
 
+		Self::write_item_metadata(
+			collection,
+			item,
+			data,
+			&mut collection_details,
+			maybe_depositor,
+			!is_root,
+		)
+	}
+
+	/// Set the metadata of each of the `items` of a `collection`, failing on the first item
+	/// whose metadata can't be set.
+	///
+	/// The changes made for the previous items are only reverted if this runs in a storage
+	/// layer, as dispatchables do.
+	pub(crate) fn do_set_item_metadata_many(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		items: ItemMetadataBatchOf<T, I>,
+	) -> DispatchResult {
+		for (item, data) in items {
+			Self::do_set_item_metadata(maybe_check_origin.clone(), collection, item, data, None)?;
+		}
+		Ok(())
+	}
+
+	/// Set the collection's default item metadata on a freshly minted `item`, if the collection
+	/// has one.
+	///
+	/// The permission and lock checks of `do_set_item_metadata` are skipped, as the metadata is
+	/// chosen by the collection's admin rather than by the minter. The deposit is taken from
+	/// `maybe_minter`; if it is `None` (a `ForceOrigin` mint) no deposit is taken.
+	pub(crate) fn do_apply_default_item_metadata(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		maybe_minter: Option<T::AccountId>,
+	) -> DispatchResult {
+		if !Self::is_pallet_feature_enabled(PalletFeature::Metadata) {
+			return Ok(())
+		}
+		let data = match DefaultItemMetadataOf::<T, I>::get(&collection) {
+			Some(data) => data,
+			None => return Ok(()),
+		};
+		if ItemMetadataOf::<T, I>::contains_key(&collection, &item) {
+			return Ok(())
+		}
+
+		let mut collection_details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		let with_deposit = maybe_minter.is_some();
+		let maybe_depositor = maybe_minter.filter(|minter| *minter != collection_details.owner);
+
+		Self::write_item_metadata(
+			collection,
+			item,
+			data,
+			&mut collection_details,
+			maybe_depositor,
+			with_deposit,
+		)
+	}
+
+	/// Store the item's metadata and settle the deposit difference with the previous one.
+	///
+	/// Note: if `maybe_depositor` is None, that means the depositor will be a collection's owner
+	fn write_item_metadata(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		data: BoundedVec<u8, T::StringLimit>,
+		collection_details: &mut CollectionDetailsFor<T, I>,
+		maybe_depositor: Option<T::AccountId>,
+		with_deposit: bool,
+	) -> DispatchResult {
 		let collection_config = Self::get_collection_config(&collection)?;
 
 		ItemMetadataOf::<T, I>::try_mutate_exists(collection, item, |metadata| {
@@ -56,11 +135,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				.map_or(ItemMetadataDeposit { account: None, amount: Zero::zero() }, |m| m.deposit);
 
 			let mut deposit = Zero::zero();
-			if collection_config.is_setting_enabled(CollectionSetting::DepositRequired) && !is_root
+			if collection_config.is_setting_enabled(CollectionSetting::DepositRequired) &&
+				with_deposit
 			{
-				deposit = T::DepositPerByte::get()
+				let deposits = Self::collection_deposits(&collection);
+				deposit = deposits
+					.per_byte
 					.saturating_mul(((data.len()) as u32).into())
-					.saturating_add(T::MetadataDepositBase::get());
+					.saturating_add(deposits.metadata_base);
 			}
 
 			let depositor = maybe_depositor.clone().unwrap_or(collection_details.owner.clone());
@@ -85,12 +167,37 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				data: data.clone(),
 			});
 
-			Collection::<T, I>::insert(&collection, &collection_details);
+			Collection::<T, I>::insert(&collection, &*collection_details);
 			Self::deposit_event(Event::ItemMetadataSet { collection, item, data });
 			Ok(())
 		})
 	}
 
+	/// Set or clear the metadata applied to items of the `collection` minted without explicit
+	/// metadata.
+	pub(crate) fn do_set_default_item_metadata(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		data: Option<BoundedVec<u8, T::StringLimit>>,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Metadata),
+			Error::<T, I>::MethodDisabled
+		);
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, &check_origin, CollectionRole::Admin),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		DefaultItemMetadataOf::<T, I>::set(&collection, data.clone());
+		Self::deposit_event(Event::DefaultItemMetadataSet { collection, data });
+		Ok(())
+	}
+
 	pub(crate) fn do_clear_item_metadata(
 		maybe_check_origin: Option<T::AccountId>,
 		collection: T::CollectionId,
@@ -136,6 +243,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		collection: T::CollectionId,
 		data: BoundedVec<u8, T::StringLimit>,
 	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Metadata),
+			Error::<T, I>::MethodDisabled
+		);
+
 		if let Some(check_origin) = &maybe_check_origin {
 			ensure!(
 				Self::has_role(&collection, &check_origin, CollectionRole::Admin),
@@ -159,9 +271,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			let mut deposit = Zero::zero();
 			if !is_root && collection_config.is_setting_enabled(CollectionSetting::DepositRequired)
 			{
-				deposit = T::DepositPerByte::get()
+				let deposits = Self::collection_deposits(&collection);
+				deposit = deposits
+					.per_byte
 					.saturating_mul(((data.len()) as u32).into())
-					.saturating_add(T::MetadataDepositBase::get());
+					.saturating_add(deposits.metadata_base);
 			}
 			if deposit > old_deposit {
 				T::Currency::reserve(&details.owner, deposit - old_deposit)?;
@@ -190,7 +304,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			);
 		}
 
-		let details =
+		let mut details =
 			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 		let collection_config = Self::get_collection_config(&collection)?;
 
@@ -203,6 +317,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		CollectionMetadataOf::<T, I>::try_mutate_exists(collection, |metadata| {
 			let deposit = metadata.take().ok_or(Error::<T, I>::UnknownCollection)?.deposit;
 			T::Currency::unreserve(&details.owner, deposit);
+			details.owner_deposit.saturating_reduce(deposit);
+			Collection::<T, I>::insert(collection, &details);
 			Self::deposit_event(Event::CollectionMetadataCleared { collection });
 			Ok(())
 		})
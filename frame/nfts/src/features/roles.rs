@@ -72,6 +72,23 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// Like `do_set_team`, but a role given as `None` keeps its current account.
+	pub(crate) fn do_set_team_roles(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		issuer: Option<T::AccountId>,
+		admin: Option<T::AccountId>,
+		freezer: Option<T::AccountId>,
+	) -> DispatchResult {
+		let issuer =
+			issuer.or_else(|| Self::find_account_by_role(&collection, CollectionRole::Issuer));
+		let admin =
+			admin.or_else(|| Self::find_account_by_role(&collection, CollectionRole::Admin));
+		let freezer =
+			freezer.or_else(|| Self::find_account_by_role(&collection, CollectionRole::Freezer));
+		Self::do_set_team(maybe_check_owner, collection, issuer, admin, freezer)
+	}
+
 	/// Clears all the roles in a specified collection.
 	///
 	/// - `collection_id`: A collection to clear the roles in.
@@ -24,6 +24,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		config: CollectionConfigFor<T, I>,
 	) -> DispatchResult {
 		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+		// deposits can't be re-enabled for a system collection
+		ensure!(
+			config.has_disabled_setting(CollectionSetting::DepositRequired) ||
+				!SystemCollection::<T, I>::contains_key(&collection),
+			Error::<T, I>::WrongSetting
+		);
 		CollectionConfigOf::<T, I>::insert(&collection, config);
 		Self::deposit_event(Event::CollectionConfigChanged { collection });
 		Ok(())
@@ -75,11 +81,61 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		CollectionConfigOf::<T, I>::try_mutate(collection, |maybe_config| {
 			let config = maybe_config.as_mut().ok_or(Error::<T, I>::NoConfig)?;
 			config.mint_settings = mint_settings;
-			Self::deposit_event(Event::CollectionMintSettingsUpdated { collection });
+			Self::deposit_event(Event::CollectionMintSettingsUpdated {
+				collection,
+				mint_type: mint_settings.mint_type,
+				price: mint_settings.price,
+			});
 			Ok(())
 		})
 	}
 
+	pub(crate) fn do_set_mint_paused(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		paused: bool,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, &check_origin, CollectionRole::Issuer),
+				Error::<T, I>::NoPermission
+			);
+		}
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+
+		if paused {
+			MintPaused::<T, I>::insert(&collection, ());
+		} else {
+			MintPaused::<T, I>::remove(&collection);
+		}
+		Self::deposit_event(Event::CollectionMintPaused { collection, paused });
+		Ok(())
+	}
+
+	pub(crate) fn do_set_collection_deposit_override(
+		collection: T::CollectionId,
+		maybe_deposits: Option<CollectionDepositsOf<T, I>>,
+	) -> DispatchResult {
+		ensure!(Collection::<T, I>::contains_key(collection), Error::<T, I>::UnknownCollection);
+
+		CollectionDepositOverride::<T, I>::set(collection, maybe_deposits);
+		Self::deposit_event(Event::CollectionDepositOverrideSet {
+			collection,
+			deposits: maybe_deposits,
+		});
+		Ok(())
+	}
+
+	/// The deposits to take for the items of the `collection`, honoring its override if any.
+	pub(crate) fn collection_deposits(collection: &T::CollectionId) -> CollectionDepositsOf<T, I> {
+		CollectionDepositOverride::<T, I>::get(collection).unwrap_or_else(|| CollectionDeposits {
+			item: T::ItemDeposit::get(),
+			metadata_base: T::MetadataDepositBase::get(),
+			attribute_base: T::AttributeDepositBase::get(),
+			per_byte: T::DepositPerByte::get(),
+		})
+	}
+
 	pub(crate) fn get_collection_config(
 		collection_id: &T::CollectionId,
 	) -> Result<CollectionConfigFor<T, I>, DispatchError> {
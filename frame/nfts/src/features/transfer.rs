@@ -19,6 +19,11 @@ use crate::*;
 use frame_support::pallet_prelude::*;
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Transfer an `item` of a `collection` to `dest`.
+	///
+	/// No origin is checked here, `with_details` could be used to check the permissions of the
+	/// caller. The collection and item settings as well as the locks are still enforced. Any
+	/// listing or pending swap of the item is removed.
 	pub fn do_transfer(
 		collection: T::CollectionId,
 		item: T::ItemId,
@@ -43,6 +48,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			item_config.is_setting_enabled(ItemSetting::Transferable),
 			Error::<T, I>::ItemLocked
 		);
+		ensure!(
+			item_config.is_setting_enabled(ItemSetting::OwnerTransferable),
+			Error::<T, I>::ItemLockedByOwner
+		);
 
 		let mut details =
 			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
@@ -59,8 +68,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		details.approvals.clear();
 
 		Item::<T, I>::insert(&collection, &item, &details);
-		ItemPriceOf::<T, I>::remove(&collection, &item);
-		PendingSwapOf::<T, I>::remove(&collection, &item);
+		Self::clear_item_offers(collection, item);
 
 		Self::deposit_event(Event::Transferred {
 			collection,
@@ -71,6 +79,25 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Remove the listing and the pending swap of an `item` which changes hands, so that they
+	/// can't be taken up from its new owner.
+	fn clear_item_offers(collection: T::CollectionId, item: T::ItemId) {
+		if ItemPriceOf::<T, I>::take(collection, item).is_some() {
+			Self::deposit_event(Event::ItemPriceRemoved { collection, item });
+		}
+		if let Some(swap) = PendingSwapOf::<T, I>::take(collection, item) {
+			Self::release_swap_escrow(&collection, &item);
+			Self::deposit_event(Event::SwapCancelled {
+				offered_collection: collection,
+				offered_item: item,
+				desired_collection: swap.desired_collection,
+				desired_item: swap.desired_item,
+				price: swap.price,
+				deadline: swap.deadline,
+			});
+		}
+	}
+
 	pub(crate) fn do_transfer_ownership(
 		origin: T::AccountId,
 		collection: T::CollectionId,
@@ -83,6 +110,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownCollection)?;
 			ensure!(origin == details.owner, Error::<T, I>::NoPermission);
 			if details.owner == owner {
+				Self::clear_ownership_acceptance(&owner, &collection);
 				return Ok(())
 			}
 
@@ -97,7 +125,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			CollectionAccount::<T, I>::insert(&owner, &collection, ());
 
 			details.owner = owner.clone();
-			OwnershipAcceptance::<T, I>::remove(&owner);
+			Self::clear_ownership_acceptance(&owner, &collection);
 
 			Self::deposit_event(Event::OwnerChanged { collection, new_owner: owner });
 			Ok(())
@@ -127,6 +155,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Clear the acceptance of `who` to become the owner of `collection`, now that it owns it, so
+	/// that it can't be used for a later transfer. An acceptance of another collection is kept.
+	fn clear_ownership_acceptance(who: &T::AccountId, collection: &T::CollectionId) {
+		if OwnershipAcceptance::<T, I>::get(who).as_ref() == Some(collection) {
+			OwnershipAcceptance::<T, I>::remove(who);
+			frame_system::Pallet::<T>::dec_consumers(who);
+		}
+	}
+
 	pub(crate) fn do_force_collection_owner(
 		collection: T::CollectionId,
 		owner: T::AccountId,
@@ -134,23 +171,71 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Collection::<T, I>::try_mutate(collection, |maybe_details| {
 			let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownCollection)?;
 			if details.owner == owner {
+				Self::clear_ownership_acceptance(&owner, &collection);
 				return Ok(())
 			}
 
-			// Move the deposit to the new owner.
-			T::Currency::repatriate_reserved(
+			// Move the deposit to the new owner. An account which doesn't exist yet can't hold a
+			// reserve, so the deposit is slashed instead and the new owner starts without one.
+			if T::Currency::repatriate_reserved(
 				&details.owner,
 				&owner,
 				details.owner_deposit,
 				Reserved,
-			)?;
+			)
+			.is_err()
+			{
+				let _ = T::Currency::slash_reserved(&details.owner, details.owner_deposit);
+				details.owner_deposit = Zero::zero();
+			}
 
 			CollectionAccount::<T, I>::remove(&details.owner, &collection);
 			CollectionAccount::<T, I>::insert(&owner, &collection, ());
 			details.owner = owner.clone();
+			Self::clear_ownership_acceptance(&owner, &collection);
 
 			Self::deposit_event(Event::OwnerChanged { collection, new_owner: owner });
 			Ok(())
 		})
 	}
+
+	/// Transfer up to `max_items` items of a `collection` to `new_owner`, returning how many were
+	/// transferred.
+	///
+	/// The collection and item settings aren't enforced, but the items locked by `T::Locker` and
+	/// those already owned by `new_owner` are skipped.
+	pub(crate) fn do_force_transfer_all(
+		collection: T::CollectionId,
+		new_owner: T::AccountId,
+		max_items: u32,
+	) -> Result<u32, DispatchError> {
+		ensure!(Collection::<T, I>::contains_key(collection), Error::<T, I>::UnknownCollection);
+
+		let items = Item::<T, I>::iter_prefix(collection)
+			.filter(|(item, details)| {
+				details.owner != new_owner && !T::Locker::is_locked(collection, *item)
+			})
+			.take(max_items as usize)
+			.collect::<Vec<_>>();
+		let transferred = items.len() as u32;
+
+		for (item, mut details) in items {
+			Account::<T, I>::remove((&details.owner, &collection, &item));
+			Account::<T, I>::insert((&new_owner, &collection, &item), ());
+			let from = sp_std::mem::replace(&mut details.owner, new_owner.clone());
+			details.approvals.clear();
+
+			Item::<T, I>::insert(collection, item, &details);
+			Self::clear_item_offers(collection, item);
+
+			Self::deposit_event(Event::Transferred {
+				collection,
+				item,
+				from,
+				to: new_owner.clone(),
+			});
+		}
+
+		Ok(transferred)
+	}
 }
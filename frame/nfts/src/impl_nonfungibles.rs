@@ -172,7 +172,12 @@ impl<T: Config<I>, I: 'static> Destroy<<T as SystemConfig>::AccountId> for Palle
 		witness: Self::DestroyWitness,
 		maybe_check_owner: Option<T::AccountId>,
 	) -> Result<Self::DestroyWitness, DispatchError> {
+		// The collection must be destroyed at once here.
+		if let Some(current) = Self::get_destroy_witness(&collection) {
+			ensure!(current == witness, Error::<T, I>::BadWitness);
+		}
 		Self::do_destroy_collection(collection, witness, maybe_check_owner)
+			.map(|progress| progress.removed)
 	}
 }
 
@@ -193,7 +198,7 @@ impl<T: Config<I>, I: 'static> Mutate<<T as SystemConfig>::AccountId, ItemConfig
 			},
 			who.clone(),
 			*item_config,
-			|_, _| Ok(()),
+			|_, _| Ok(None),
 		)
 	}
 
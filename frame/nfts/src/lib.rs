@@ -24,7 +24,7 @@
 //! * [`System`](../frame_system/index.html)
 //! * [`Support`](../frame_support/index.html)
 
-#![recursion_limit = "256"]
+#![recursion_limit = "512"]
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -71,7 +71,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -103,7 +103,7 @@ pub mod pallet {
 		type CollectionId: Member + Parameter + MaxEncodedLen + Copy + Incrementable;
 
 		/// The type used to identify a unique item within a collection.
-		type ItemId: Member + Parameter + MaxEncodedLen + Copy;
+		type ItemId: Member + Parameter + MaxEncodedLen + Copy + PartialOrd;
 
 		/// The currency mechanism, used for paying for reserves.
 		type Currency: ReservableCurrency<Self::AccountId>;
@@ -123,6 +123,12 @@ pub mod pallet {
 		/// Locker trait to enable Locking mechanism downstream.
 		type Locker: Locker<Self::CollectionId, Self::ItemId>;
 
+		/// Called when a collection is created, which it may veto.
+		type OnCollectionCreated: OnCollectionCreated<Self::CollectionId, Self::AccountId>;
+
+		/// Called when a collection is destroyed.
+		type OnCollectionDestroyed: OnCollectionDestroyed<Self::CollectionId, Self::AccountId>;
+
 		/// The basic amount of funds that must be reserved for collection.
 		#[pallet::constant]
 		type CollectionDeposit: Get<DepositBalanceOf<Self, I>>;
@@ -157,6 +163,9 @@ pub mod pallet {
 		type ValueLimit: Get<u32>;
 
 		/// The maximum approvals an item could have.
+		///
+		/// NOTE: The approvals are stored along with the item, so lowering the limit below the
+		/// number of approvals an existing item has makes that item undecodable.
 		#[pallet::constant]
 		type ApprovalsLimit: Get<u32>;
 
@@ -176,6 +185,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAttributesPerCall: Get<u32>;
 
+		/// The max number of items on each side of a bundle swap.
+		#[pallet::constant]
+		type MaxBundle: Get<u32>;
+
+		/// The max number of items that can be burned in one call.
+		#[pallet::constant]
+		type MaxBatch: Get<u32>;
+
 		/// Disables some of pallet's features.
 		#[pallet::constant]
 		type Features: Get<PalletFeatures>;
@@ -260,7 +277,7 @@ pub mod pallet {
 		T::CollectionId,
 		Blake2_128Concat,
 		T::ItemId,
-		ItemDetails<T::AccountId, ItemDepositOf<T, I>, ApprovalsOf<T, I>>,
+		ItemDetailsFor<T, I>,
 		OptionQuery,
 	>;
 
@@ -274,6 +291,16 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Metadata given to items of a collection that are minted without metadata of their own.
+	#[pallet::storage]
+	pub type DefaultItemMetadataOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		BoundedVec<u8, T::StringLimit>,
+		OptionQuery,
+	>;
+
 	/// Metadata of an item.
 	#[pallet::storage]
 	pub type ItemMetadataOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
@@ -300,7 +327,7 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
-	/// A price of an item.
+	/// A price of an item, its whitelisted buyer and the block after which the listing expires.
 	#[pallet::storage]
 	pub type ItemPriceOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
 		_,
@@ -308,7 +335,7 @@ pub mod pallet {
 		T::CollectionId,
 		Blake2_128Concat,
 		T::ItemId,
-		(ItemPrice<T, I>, Option<T::AccountId>),
+		(ItemPrice<T, I>, Option<T::AccountId>, Option<<T as SystemConfig>::BlockNumber>),
 		OptionQuery,
 	>;
 
@@ -347,6 +374,27 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The account and amount reserved for the `Send` price of an escrowed pending swap.
+	#[pallet::storage]
+	pub type SwapEscrowOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		(T::AccountId, ItemPrice<T, I>),
+		OptionQuery,
+	>;
+
+	/// Handles all the pending swaps of a bundle of items for another one.
+	#[pallet::storage]
+	pub type PendingBundleSwapOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, u32, PendingBundleSwapFor<T, I>, OptionQuery>;
+
+	/// Stores the id that is going to be used for the next bundle swap.
+	#[pallet::storage]
+	pub type NextBundleSwapId<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
 	/// Config of a collection.
 	#[pallet::storage]
 	pub type CollectionConfigOf<T: Config<I>, I: 'static = ()> =
@@ -364,6 +412,34 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Collections owned by the system. No deposits are ever taken for their items, metadata and
+	/// attributes.
+	#[pallet::storage]
+	pub type SystemCollection<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, (), OptionQuery>;
+
+	/// Collections whose public minting is paused.
+	#[pallet::storage]
+	pub type MintPaused<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, (), OptionQuery>;
+
+	/// The accounts allowed to buy or swap for the items of a collection with restricted buyers.
+	#[pallet::storage]
+	pub type CollectionAllowlist<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// The deposits taken for the items of a collection, if they differ from the defaults.
+	#[pallet::storage]
+	pub type CollectionDepositOverride<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, CollectionDepositsOf<T, I>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -373,8 +449,29 @@ pub mod pallet {
 		ForceCreated { collection: T::CollectionId, owner: T::AccountId },
 		/// A `collection` was destroyed.
 		Destroyed { collection: T::CollectionId },
-		/// An `item` was issued.
-		Issued { collection: T::CollectionId, item: T::ItemId, owner: T::AccountId },
+		/// Part of a `collection` was removed, `remaining` records are left to be destroyed.
+		PartiallyDestroyed { collection: T::CollectionId, remaining: DestroyWitness },
+		/// The deposits taken for the items of a `collection` were overridden, or reset to the
+		/// defaults if `deposits` is `None`.
+		CollectionDepositOverrideSet {
+			collection: T::CollectionId,
+			deposits: Option<CollectionDepositsOf<T, I>>,
+		},
+		/// The cached counters of a `collection` were recalculated from storage.
+		CollectionStatusRecomputed {
+			collection: T::CollectionId,
+			items: u32,
+			item_metadatas: u32,
+			item_configs: u32,
+			attributes: u32,
+		},
+		/// An `item` was issued, for the `price` paid by the minter if any.
+		Issued {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			owner: T::AccountId,
+			price: Option<ItemPrice<T, I>>,
+		},
 		/// An `item` was transferred.
 		Transferred {
 			collection: T::CollectionId,
@@ -388,15 +485,20 @@ pub mod pallet {
 		ItemTransferLocked { collection: T::CollectionId, item: T::ItemId },
 		/// An `item` became transferable.
 		ItemTransferUnlocked { collection: T::CollectionId, item: T::ItemId },
-		/// `item` metadata or attributes were locked.
+		/// An `item` was made non-transferable by its owner.
+		ItemTransferLockedByOwner { collection: T::CollectionId, item: T::ItemId },
+		/// An `item` locked by its owner became transferable.
+		ItemTransferUnlockedByOwner { collection: T::CollectionId, item: T::ItemId },
+		/// `item` metadata or attributes were locked. The flags tell whether each is locked now,
+		/// including by an earlier call.
 		ItemPropertiesLocked {
 			collection: T::CollectionId,
 			item: T::ItemId,
 			lock_metadata: bool,
 			lock_attributes: bool,
 		},
-		/// Some `collection` was locked.
-		CollectionLocked { collection: T::CollectionId },
+		/// Some `collection` was locked, resulting in the given combined `settings`.
+		CollectionLocked { collection: T::CollectionId, settings: CollectionSettings },
 		/// The owner changed.
 		OwnerChanged { collection: T::CollectionId, new_owner: T::AccountId },
 		/// The management team changed.
@@ -431,6 +533,11 @@ pub mod pallet {
 		CollectionMetadataSet { collection: T::CollectionId, data: BoundedVec<u8, T::StringLimit> },
 		/// Metadata has been cleared for a `collection`.
 		CollectionMetadataCleared { collection: T::CollectionId },
+		/// The default item metadata of a `collection` has been set or cleared.
+		DefaultItemMetadataSet {
+			collection: T::CollectionId,
+			data: Option<BoundedVec<u8, T::StringLimit>>,
+		},
 		/// New metadata has been set for an item.
 		ItemMetadataSet {
 			collection: T::CollectionId,
@@ -472,8 +579,18 @@ pub mod pallet {
 		OwnershipAcceptanceChanged { who: T::AccountId, maybe_collection: Option<T::CollectionId> },
 		/// Max supply has been set for a collection.
 		CollectionMaxSupplySet { collection: T::CollectionId, max_supply: u32 },
-		/// Mint settings for a collection had changed.
-		CollectionMintSettingsUpdated { collection: T::CollectionId },
+		/// Public minting of a `collection` was paused or resumed.
+		CollectionMintPaused { collection: T::CollectionId, paused: bool },
+		/// The `buyer` was allowed to acquire the items of a `collection` with restricted buyers.
+		AllowedBuyerAdded { collection: T::CollectionId, buyer: T::AccountId },
+		/// The `buyer` was removed from the allowlist of a `collection`.
+		AllowedBuyerRemoved { collection: T::CollectionId, buyer: T::AccountId },
+		/// Mint settings for a collection had changed, now minting with `mint_type` at `price`.
+		CollectionMintSettingsUpdated {
+			collection: T::CollectionId,
+			mint_type: MintType<T::CollectionId>,
+			price: Option<ItemPrice<T, I>>,
+		},
 		/// Event gets emitted when the `NextCollectionId` gets incremented.
 		NextCollectionIdIncremented { next_id: T::CollectionId },
 		/// The price was set for the item.
@@ -482,6 +599,7 @@ pub mod pallet {
 			item: T::ItemId,
 			price: ItemPrice<T, I>,
 			whitelisted_buyer: Option<T::AccountId>,
+			deadline: Option<<T as SystemConfig>::BlockNumber>,
 		},
 		/// The price for the item was removed.
 		ItemPriceRemoved { collection: T::CollectionId, item: T::ItemId },
@@ -530,6 +648,24 @@ pub mod pallet {
 			price: Option<PriceWithDirection<ItemPrice<T, I>>>,
 			deadline: <T as SystemConfig>::BlockNumber,
 		},
+		/// A swap of the `offered` items for the `desired` ones was created.
+		BundleSwapCreated {
+			swap_id: u32,
+			creator: T::AccountId,
+			offered: BundleOf<T, I>,
+			desired: BundleOf<T, I>,
+			price: Option<PriceWithDirection<ItemPrice<T, I>>>,
+			deadline: <T as SystemConfig>::BlockNumber,
+		},
+		/// The bundle swap was cancelled.
+		BundleSwapCancelled { swap_id: u32 },
+		/// The bundle swap has been claimed.
+		BundleSwapClaimed {
+			swap_id: u32,
+			creator: T::AccountId,
+			claimer: T::AccountId,
+			price: Option<PriceWithDirection<ItemPrice<T, I>>>,
+		},
 		/// New attributes have been set for an `item` of the `collection`.
 		PreSignedAttributesSet {
 			collection: T::CollectionId,
@@ -598,6 +734,10 @@ pub mod pallet {
 		AttributeNotFound,
 		/// Item is not for sale.
 		NotForSale,
+		/// The listing of the item has expired.
+		ListingExpired,
+		/// The listing of the item hasn't expired yet.
+		ListingNotExpired,
 		/// The provided bid is too low.
 		BidTooLow,
 		/// The item has reached its approval limit.
@@ -620,6 +760,8 @@ pub mod pallet {
 		MintNotStarted,
 		/// Mint has already ended.
 		MintEnded,
+		/// Minting of this collection is paused.
+		MintPaused,
 		/// The provided Item was already used for claiming.
 		AlreadyClaimed,
 		/// The provided data is incorrect.
@@ -636,6 +778,28 @@ pub mod pallet {
 		WrongNamespace,
 		/// Can't delete non-empty collections.
 		CollectionNotEmpty,
+		/// The item was locked (non-transferable) by its owner.
+		ItemLockedByOwner,
+		/// Only the item owner can cancel a swap before its deadline.
+		SwapNotExpired,
+		/// The item ID is outside of the range allowed by the collection.
+		ItemIdOutOfRange,
+		/// The buyer isn't allowed to acquire the items of this collection.
+		BuyerNotAllowed,
+		/// The collection's allowlist must be emptied before the collection is destroyed.
+		AllowlistNotEmpty,
+		/// Both sides of a bundle swap must hold at least one item.
+		EmptyBundle,
+		/// An item appears more than once in a bundle swap.
+		DuplicateBundleItem,
+		/// The item is offered in a pending swap, so it can't be listed for sale.
+		ItemInSwap,
+		/// The item is listed for sale, so it can't be offered in a swap.
+		ItemListed,
+		/// The creation of the collection was vetoed by `OnCollectionCreated`.
+		CreationVetoed,
+		/// The offerer can't cover the escrowed price of a swap.
+		InsufficientEscrow,
 	}
 
 	#[pallet::call]
@@ -722,18 +886,146 @@ pub mod pallet {
 			)
 		}
 
+		/// Issue a new system-owned collection of non-fungible items from a privileged origin.
+		///
+		/// This new collection has no items initially.
+		///
+		/// The origin must conform to `ForceOrigin`.
+		///
+		/// Unlike `force_create`, no deposits are ever taken for the items, metadata and
+		/// attributes of this collection, whatever the namespace. `DepositRequired` is
+		/// disabled in the given `config`.
+		///
+		/// - `owner`: The owner of this collection of items.
+		/// - `config`: The config of this collection.
+		///
+		/// Emits `ForceCreated` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::force_create_system())]
+		pub fn force_create_system(
+			origin: OriginFor<T>,
+			owner: AccountIdLookupOf<T>,
+			mut config: CollectionConfigFor<T, I>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+
+			let collection =
+				NextCollectionId::<T, I>::get().unwrap_or(T::CollectionId::initial_value());
+
+			config.disable_setting(CollectionSetting::DepositRequired);
+			Self::do_create_collection(
+				collection,
+				owner.clone(),
+				owner.clone(),
+				config,
+				Zero::zero(),
+				Event::ForceCreated { collection, owner },
+			)?;
+			SystemCollection::<T, I>::insert(&collection, ());
+			Ok(())
+		}
+
+		/// Issue a new collection of non-fungible items from a privileged origin, reserving a
+		/// custom deposit from the owner.
+		///
+		/// This new collection has no items initially.
+		///
+		/// The origin must conform to `ForceOrigin`.
+		///
+		/// Unlike `create`, the reserved `deposit` is chosen by the origin and may be zero. It is
+		/// refunded to the owner once the collection is destroyed.
+		///
+		/// - `owner`: The owner of this collection of items.
+		/// - `config`: The config of this collection.
+		/// - `deposit`: The amount reserved from the `owner`.
+		///
+		/// Emits `ForceCreated` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::create_with_deposit())]
+		pub fn create_with_deposit(
+			origin: OriginFor<T>,
+			owner: AccountIdLookupOf<T>,
+			config: CollectionConfigFor<T, I>,
+			deposit: DepositBalanceOf<T, I>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+
+			let collection =
+				NextCollectionId::<T, I>::get().unwrap_or(T::CollectionId::initial_value());
+
+			Self::do_create_collection(
+				collection,
+				owner.clone(),
+				owner.clone(),
+				config,
+				deposit,
+				Event::ForceCreated { collection, owner },
+			)
+		}
+
+		/// Issue a new collection of non-fungible items under a caller-chosen id.
+		///
+		/// Same as `create`, except that the collection gets the given `collection` id instead of
+		/// the next sequential one. Sequential creation later skips the ids claimed this way.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// `CollectionDeposit` funds of sender are reserved.
+		///
+		/// Parameters:
+		/// - `collection`: The id of the new collection. Must not be in use.
+		/// - `admin`: The admin of this collection. The admin is the initial address of each
+		/// member of the collection's admin team.
+		///
+		/// Emits `Created` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(54)]
+		#[pallet::weight(T::WeightInfo::create_with_id())]
+		pub fn create_with_id(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			admin: AccountIdLookupOf<T>,
+			config: CollectionConfigFor<T, I>,
+		) -> DispatchResult {
+			let owner = T::CreateOrigin::ensure_origin(origin, &collection)?;
+			let admin = T::Lookup::lookup(admin)?;
+
+			// DepositRequired can be disabled by calling the force_create() only
+			ensure!(
+				!config.has_disabled_setting(CollectionSetting::DepositRequired),
+				Error::<T, I>::WrongSetting
+			);
+
+			Self::do_create_collection(
+				collection,
+				owner.clone(),
+				admin.clone(),
+				config,
+				T::CollectionDeposit::get(),
+				Event::Created { collection, creator: owner, owner: admin },
+			)
+		}
+
 		/// Destroy a collection of fungible items.
 		///
 		/// The origin must conform to `ForceOrigin` or must be `Signed` and the sender must be the
 		/// owner of the `collection`.
 		///
-		/// NOTE: The collection must have 0 items to be destroyed.
+		/// NOTE: The collection must have 0 items and no allowed buyers to be destroyed.
 		///
 		/// - `collection`: The identifier of the collection to be destroyed.
-		/// - `witness`: Information on the items minted in the collection. This must be
-		/// correct.
+		/// - `witness`: The number of records to remove in this call. Passing the collection's
+		/// current counters destroys it at once, smaller numbers spread the work over several
+		/// calls. It can't exceed the counters.
 		///
-		/// Emits `Destroyed` event when successful.
+		/// Emits `Destroyed` event once the collection is gone, `PartiallyDestroyed` otherwise.
 		///
 		/// Weight: `O(m + c + a)` where:
 		/// - `m = witness.item_metadatas`
@@ -753,12 +1045,12 @@ pub mod pallet {
 			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
 				.map(|_| None)
 				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
-			let details = Self::do_destroy_collection(collection, witness, maybe_check_owner)?;
+			let progress = Self::do_destroy_collection(collection, witness, maybe_check_owner)?;
 
 			Ok(Some(T::WeightInfo::destroy(
-				details.item_metadatas,
-				details.item_configs,
-				details.attributes,
+				progress.removed.item_metadatas,
+				progress.removed.item_configs,
+				progress.removed.attributes,
 			))
 			.into())
 		}
@@ -771,9 +1063,12 @@ pub mod pallet {
 		/// - `item`: An identifier of the new item.
 		/// - `mint_to`: Account into which the item will be minted.
 		/// - `witness_data`: When the mint type is `HolderOf(collection_id)`, then the owned
-		///   item_id from that collection needs to be provided within the witness data object.
+		///   item_id from that collection needs to be provided within the witness data object. The
+		///   item must be owned by the sender, not by `mint_to`.
 		///
-		/// Note: the deposit will be taken from the `origin` and not the `owner` of the `item`.
+		/// Note: the deposit and the mint price will be taken from the `origin` and not the `owner`
+		/// of the `item`. The same goes for the deposit of the collection's default item metadata,
+		/// if one is set.
 		///
 		/// Emits `Issued` event when successful.
 		///
@@ -808,6 +1103,10 @@ pub mod pallet {
 					if let Some(end_block) = mint_settings.end_block {
 						ensure!(end_block >= now, Error::<T, I>::MintEnded);
 					}
+					ensure!(
+						!MintPaused::<T, I>::contains_key(&collection),
+						Error::<T, I>::MintPaused
+					);
 
 					match mint_settings.mint_type {
 						MintType::Issuer => {
@@ -866,9 +1165,10 @@ pub mod pallet {
 						)?;
 					}
 
-					Ok(())
+					Ok(mint_settings.price)
 				},
-			)
+			)?;
+			Self::do_apply_default_item_metadata(collection, item, Some(caller))
 		}
 
 		/// Mint an item of a particular collection from a privileged origin.
@@ -881,6 +1181,9 @@ pub mod pallet {
 		/// - `mint_to`: Account into which the item will be minted.
 		/// - `item_config`: A config of the new item.
 		///
+		/// If the collection has default item metadata, it is set on the new item with the deposit
+		/// taken from the signer; no deposit is taken from `ForceOrigin`.
+		///
 		/// Emits `Issued` event when successful.
 		///
 		/// Weight: `O(1)`
@@ -898,13 +1201,14 @@ pub mod pallet {
 				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
 			let mint_to = T::Lookup::lookup(mint_to)?;
 
-			if let Some(check_origin) = maybe_check_origin {
+			if let Some(check_origin) = &maybe_check_origin {
 				ensure!(
-					Self::has_role(&collection, &check_origin, CollectionRole::Issuer),
+					Self::has_role(&collection, check_origin, CollectionRole::Issuer),
 					Error::<T, I>::NoPermission
 				);
 			}
-			Self::do_mint(collection, item, None, mint_to, item_config, |_, _| Ok(()))
+			Self::do_mint(collection, item, None, mint_to, item_config, |_, _| Ok(None))?;
+			Self::do_apply_default_item_metadata(collection, item, maybe_check_origin)
 		}
 
 		/// Destroy a single item.
@@ -937,6 +1241,37 @@ pub mod pallet {
 			})
 		}
 
+		/// Destroy several items of a collection at once.
+		///
+		/// The origin must conform to `ForceOrigin` or must be Signed and the signing account must
+		/// be the owner of each of the `items`.
+		///
+		/// - `collection`: The collection of the items to be burned.
+		/// - `items`: The items to be burned.
+		/// - `check_owner`: If `Some`, each item must be owned by this account.
+		/// - `atomic`: If `true`, the call fails if any of the `items` can't be burned. Otherwise
+		///   the items which are unknown or can't be burned are skipped.
+		///
+		/// Emits `Burned` for each burned item.
+		///
+		/// Weight: `O(items.len())`
+		#[pallet::call_index(60)]
+		#[pallet::weight(T::WeightInfo::burn_many(items.len() as u32))]
+		pub fn burn_many(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			items: BoundedVec<T::ItemId, T::MaxBatch>,
+			check_owner: Option<AccountIdLookupOf<T>>,
+			atomic: bool,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			let maybe_check_owner = check_owner.map(T::Lookup::lookup).transpose()?;
+
+			Self::do_burn_many(maybe_check_origin, collection, items, maybe_check_owner, atomic)
+		}
+
 		/// Move an item from the sender account to another.
 		///
 		/// Origin must be Signed and the signing account must be either:
@@ -948,7 +1283,11 @@ pub mod pallet {
 		/// - `item`: The item to be transferred.
 		/// - `dest`: The account to receive ownership of the item.
 		///
-		/// Emits `Transferred`.
+		/// Any listing or pending swap of the item is removed, as it was made by the previous
+		/// owner.
+		///
+		/// Emits `Transferred`, and `ItemPriceRemoved` or `SwapCancelled` if such an offer was
+		/// removed.
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(6)]
@@ -1007,7 +1346,7 @@ pub mod pallet {
 
 			let config = Self::get_collection_config(&collection)?;
 			let deposit = match config.is_setting_enabled(CollectionSetting::DepositRequired) {
-				true => T::ItemDeposit::get(),
+				true => Self::collection_deposits(&collection).item,
 				false => Zero::zero(),
 			};
 
@@ -1093,7 +1432,8 @@ pub mod pallet {
 		///
 		/// Note: it's possible to only lock(set) the setting, but not to unset it.
 		///
-		/// Emits `CollectionLocked`.
+		/// Emits `CollectionLocked` with the settings resulting from the previous and the new
+		/// locks.
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(10)]
@@ -1164,9 +1504,44 @@ pub mod pallet {
 			Self::do_set_team(maybe_check_owner, collection, issuer, admin, freezer)
 		}
 
+		/// Change some of the Issuer, Admin and Freezer of a collection, keeping the others.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`.
+		///
+		/// Unlike `set_team`, a role given as `None` is left unchanged rather than removed.
+		///
+		/// - `collection`: The collection whose team should be changed.
+		/// - `issuer`: The new Issuer of this collection, if it should change.
+		/// - `admin`: The new Admin of this collection, if it should change.
+		/// - `freezer`: The new Freezer of this collection, if it should change.
+		///
+		/// Emits `TeamChanged` with the resulting team.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::set_team_roles())]
+		pub fn set_team_roles(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			issuer: Option<AccountIdLookupOf<T>>,
+			admin: Option<AccountIdLookupOf<T>>,
+			freezer: Option<AccountIdLookupOf<T>>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			let issuer = issuer.map(T::Lookup::lookup).transpose()?;
+			let admin = admin.map(T::Lookup::lookup).transpose()?;
+			let freezer = freezer.map(T::Lookup::lookup).transpose()?;
+			Self::do_set_team_roles(maybe_check_owner, collection, issuer, admin, freezer)
+		}
+
 		/// Change the Owner of a collection.
 		///
-		/// Origin must be `ForceOrigin`.
+		/// Origin must be `ForceOrigin`. The new owner doesn't need to accept the collection, so
+		/// this can recover a collection whose owner lost access to their account. The owner's
+		/// deposit is moved to the new owner, or slashed if the new owner's account doesn't exist.
 		///
 		/// - `collection`: The identifier of the collection.
 		/// - `owner`: The new Owner of this collection.
@@ -1216,7 +1591,7 @@ pub mod pallet {
 		/// - `item`: The item to be approved for delegated transfer.
 		/// - `delegate`: The account to delegate permission to transfer the item.
 		/// - `maybe_deadline`: Optional deadline for the approval. Specified by providing the
-		/// 	number of blocks after which the approval will expire
+		///   number of blocks after which the approval will expire, at most `MaxDeadlineDuration`.
 		///
 		/// Emits `TransferApproved` on success.
 		///
@@ -1312,7 +1687,7 @@ pub mod pallet {
 		/// Note: `lock_attributes` affects the attributes in the `CollectionOwner` namespace only.
 		/// When the metadata or attributes are locked, it won't be possible the unlock them.
 		///
-		/// Emits `ItemPropertiesLocked`.
+		/// Emits `ItemPropertiesLocked` with the resulting locks of the item.
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(18)]
@@ -1342,8 +1717,9 @@ pub mod pallet {
 		/// - `CollectionOwner` namespace could be modified by the `collection` Admin only;
 		/// - `ItemOwner` namespace could be modified by the `maybe_item` owner only. `maybe_item`
 		///   should be set in that case;
-		/// - `Account(AccountId)` namespace could be modified only when the `origin` was given a
-		///   permission to do so;
+		/// - `Account(AccountId)` namespace could be modified only by that account, once the item
+		///   owner approved it with `approve_item_attributes`. Its keys can't clash with those of
+		///   the other namespaces;
 		///
 		/// The funds of `origin` are reserved according to the formula:
 		/// `AttributeDepositBase + DepositPerByte * (key.len + value.len)` taking into
@@ -1517,6 +1893,34 @@ pub mod pallet {
 			Self::do_set_item_metadata(maybe_check_origin, collection, item, data, None)
 		}
 
+		/// Set the metadata of several items of a collection at once.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Admin of the
+		/// `collection`.
+		///
+		/// Each item is handled as by `set_metadata`, with its deposit reserved from the signer.
+		/// The call fails as a whole if the metadata of any of the items can't be set, e.g. because
+		/// it is locked or the signer can't pay for the deposits.
+		///
+		/// - `collection`: The identifier of the collection whose items' metadata to set.
+		/// - `items`: The items along with their metadata. Limited in length by `MaxBatch`.
+		///
+		/// Emits `ItemMetadataSet` for each item.
+		///
+		/// Weight: `O(items.len())`
+		#[pallet::call_index(61)]
+		#[pallet::weight(T::WeightInfo::set_metadata_many(items.len() as u32))]
+		pub fn set_metadata_many(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			items: ItemMetadataBatchOf<T, I>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_item_metadata_many(maybe_check_origin, collection, items)
+		}
+
 		/// Clear the metadata for an item.
 		///
 		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Admin of the
@@ -1604,6 +2008,9 @@ pub mod pallet {
 		///   willing to accept, or if `None`, an indication that the signer is willing to accept no
 		///   ownership transferal.
 		///
+		/// The acceptance is cleared once the signer becomes the owner of the collection, so it
+		/// has to be set again before the collection can be transferred to the signer later on.
+		///
 		/// Emits `OwnershipAcceptanceChanged`.
 		#[pallet::call_index(28)]
 		#[pallet::weight(T::WeightInfo::set_accept_ownership())]
@@ -1665,14 +2072,17 @@ pub mod pallet {
 
 		/// Set (or reset) the price for an item.
 		///
-		/// Origin must be Signed and must be the owner of the `item`.
+		/// Origin must be Signed and must be the owner of the `item`. An item offered in a pending
+		/// swap can't be listed until the swap is cancelled.
 		///
 		/// - `collection`: The collection of the item.
 		/// - `item`: The item to set the price for.
 		/// - `price`: The price for the item. Pass `None`, to reset the price.
 		/// - `buyer`: Restricts the buy operation to a specific account.
+		/// - `maybe_deadline`: The last block at which the item can be bought at this price.
 		///
-		/// Emits `ItemPriceSet` on success if the price is not `None`.
+		/// Emits `ItemPriceSet` on success if the price is not `None`, preceded by
+		/// `ItemPriceRemoved` if an existing listing had a different whitelisted buyer.
 		/// Emits `ItemPriceRemoved` on success if the price is `None`.
 		#[pallet::call_index(31)]
 		#[pallet::weight(T::WeightInfo::set_price())]
@@ -1682,10 +2092,11 @@ pub mod pallet {
 			item: T::ItemId,
 			price: Option<ItemPrice<T, I>>,
 			whitelisted_buyer: Option<AccountIdLookupOf<T>>,
+			maybe_deadline: Option<<T as SystemConfig>::BlockNumber>,
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
 			let whitelisted_buyer = whitelisted_buyer.map(T::Lookup::lookup).transpose()?;
-			Self::do_set_price(collection, item, origin, price, whitelisted_buyer)
+			Self::do_set_price(collection, item, origin, price, whitelisted_buyer, maybe_deadline)
 		}
 
 		/// Allows to buy an item if it's up for sale.
@@ -1713,7 +2124,7 @@ pub mod pallet {
 		///
 		/// Origin must be Signed.
 		///
-		/// - `tips`: Tips array.
+		/// - `tips`: Tips array, up to `MaxTips` of them. Every tipped item must exist.
 		///
 		/// Emits `TipSent` on every tip transfer.
 		#[pallet::call_index(33)]
@@ -1731,7 +2142,8 @@ pub mod pallet {
 		/// The target can execute the swap during the specified `duration` of blocks (if set).
 		/// Additionally, the price could be set for the desired `item`.
 		///
-		/// Origin must be Signed and must be an owner of the `item`.
+		/// Origin must be Signed and must be an owner of the `item`. A listed item can't be
+		/// offered until its price is removed.
 		///
 		/// - `collection`: The collection of the item.
 		/// - `item`: The item an owner wants to give.
@@ -1740,6 +2152,8 @@ pub mod pallet {
 		/// - `maybe_price`: The price an owner is willing to pay or receive for the desired `item`.
 		/// - `duration`: A deadline for the swap. Specified by providing the number of blocks
 		/// 	after which the swap will expire.
+		/// - `escrow`: Whether to reserve the price up front. Only allowed for a `Send` price,
+		/// 	which is then paid out of the reserve on claim and released if the swap is removed.
 		///
 		/// Emits `SwapCreated` on success.
 		#[pallet::call_index(34)]
@@ -1752,6 +2166,7 @@ pub mod pallet {
 			maybe_desired_item: Option<T::ItemId>,
 			maybe_price: Option<PriceWithDirection<ItemPrice<T, I>>>,
 			duration: <T as SystemConfig>::BlockNumber,
+			escrow: bool,
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
 			Self::do_create_swap(
@@ -1762,6 +2177,7 @@ pub mod pallet {
 				maybe_desired_item,
 				maybe_price,
 				duration,
+				escrow,
 			)
 		}
 
@@ -1869,6 +2285,464 @@ pub mod pallet {
 			Self::validate_signature(&Encode::encode(&data), &signature, &signer)?;
 			Self::do_set_attributes_pre_signed(origin, data, signer)
 		}
+
+		/// Disallow the transfer of an item until its owner unlocks it.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the `item`.
+		///
+		/// Unlike `lock_item_transfer`, this lock can't be lifted by the collection's team, only by
+		/// the owner or with `force_unlock_item_transfer_by_owner`.
+		///
+		/// - `collection`: The collection of the item to be changed.
+		/// - `item`: The item to become non-transferable.
+		///
+		/// Emits `ItemTransferLockedByOwner`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::lock_item_transfer_by_owner())]
+		pub fn lock_item_transfer_by_owner(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_lock_item_transfer_by_owner(origin, collection, item)
+		}
+
+		/// Revert the effects of a previous `lock_item_transfer_by_owner`.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the `item`.
+		///
+		/// - `collection`: The collection of the item to be changed.
+		/// - `item`: The item to become transferable.
+		///
+		/// Emits `ItemTransferUnlockedByOwner`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::unlock_item_transfer_by_owner())]
+		pub fn unlock_item_transfer_by_owner(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_unlock_item_transfer_by_owner(Some(origin), collection, item)
+		}
+
+		/// Lift the lock put on an item by its owner with `lock_item_transfer_by_owner`, without
+		/// the owner.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// - `collection`: The collection of the item to be changed.
+		/// - `item`: The item to become transferable.
+		///
+		/// Emits `ItemTransferUnlockedByOwner`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(62)]
+		#[pallet::weight(T::WeightInfo::unlock_item_transfer_by_owner())]
+		pub fn force_unlock_item_transfer_by_owner(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_unlock_item_transfer_by_owner(None, collection, item)
+		}
+
+		/// Set several attributes for a collection or item at once.
+		///
+		/// Origin must be Signed and must conform to the namespace ruleset, see `set_attribute`.
+		///
+		/// The funds of `origin` are reserved for each attribute according to the formula:
+		/// `AttributeDepositBase + DepositPerByte * (key.len + value.len)` taking into
+		/// account any already reserved funds.
+		///
+		/// If any of the attributes can't be set, none of them are.
+		///
+		/// - `collection`: The identifier of the collection whose item's attributes to set.
+		/// - `maybe_item`: The identifier of the item whose attributes to set.
+		/// - `namespace`: Attributes' namespace.
+		/// - `attributes`: The key-value pairs of the attributes to set.
+		///
+		/// Emits `AttributeSet` for each provided attribute.
+		///
+		/// Weight: `O(A)` where `A = attributes.len()`
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::set_attributes(attributes.len() as u32))]
+		pub fn set_attributes(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_item: Option<T::ItemId>,
+			namespace: AttributeNamespace<T::AccountId>,
+			attributes: AttributesOf<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_set_attributes(origin, collection, maybe_item, namespace, attributes)
+		}
+
+		/// Allows to buy an item if it's up for sale and to tip the collection's owner at once.
+		///
+		/// Origin must be Signed and must not be the owner of the `item`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item the sender wants to buy.
+		/// - `bid_price`: The price the sender is willing to pay.
+		/// - `tip`: An optional amount to send to the owner of the `collection`, e.g. to support
+		///   the creator.
+		///
+		/// Emits `ItemBought` on success, followed by `TipSent` if the `tip` was provided.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::buy_item().saturating_add(T::WeightInfo::pay_tips(1)))]
+		pub fn buy_item_with_tip(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			bid_price: ItemPrice<T, I>,
+			tip: Option<BalanceOf<T, I>>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_buy_item_with_tip(collection, item, origin, bid_price, tip)
+		}
+
+		/// Allow an account to buy or swap for the items of a collection with restricted buyers.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Admin
+		/// of the `collection`.
+		///
+		/// The allowlist is only enforced once the collection's `UnrestrictedBuyers` setting is
+		/// disabled.
+		///
+		/// - `collection`: The collection whose allowlist should be extended.
+		/// - `buyer`: The account to be allowed.
+		///
+		/// Emits `AllowedBuyerAdded` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::add_allowed_buyer())]
+		pub fn add_allowed_buyer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			buyer: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			let buyer = T::Lookup::lookup(buyer)?;
+			Self::do_add_allowed_buyer(maybe_check_origin, collection, buyer)
+		}
+
+		/// Remove an account from the allowlist of a collection.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Admin
+		/// of the `collection`.
+		///
+		/// - `collection`: The collection whose allowlist should be changed.
+		/// - `buyer`: The account to be removed.
+		///
+		/// Emits `AllowedBuyerRemoved` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::remove_allowed_buyer())]
+		pub fn remove_allowed_buyer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			buyer: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			let buyer = T::Lookup::lookup(buyer)?;
+			Self::do_remove_allowed_buyer(maybe_check_origin, collection, buyer)
+		}
+
+		/// Pause or resume the public minting of a collection.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Issuer
+		/// of the `collection`.
+		///
+		/// Unlike changing the `mint_settings`, pausing keeps the minting window as it is.
+		///
+		/// - `collection`: The identifier of the collection to change.
+		/// - `paused`: Whether `mint` should be rejected for this collection.
+		///
+		/// Emits `CollectionMintPaused` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::set_mint_paused())]
+		pub fn set_mint_paused(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			paused: bool,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_mint_paused(maybe_check_origin, collection, paused)
+		}
+
+		/// Destroy a collection without a witness, in as many calls as needed.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// Meant for collections whose cached counters no longer match their content, making
+		/// them impossible to `destroy`. Every call removes up to `max_items` items, item
+		/// metadata, item configs, attributes and allowed buyers, returning their deposits. The
+		/// collection itself is destroyed by the first call which removes fewer than `max_items`
+		/// records.
+		///
+		/// - `collection`: The identifier of the collection to be destroyed.
+		/// - `max_items`: The maximum number of records to remove in this call.
+		///
+		/// Emits `Destroyed` event once the collection is gone.
+		///
+		/// Weight: `O(max_items)`
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::force_destroy(*max_items))]
+		pub fn force_destroy(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			max_items: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let removed = Self::do_force_destroy_collection(collection, max_items)?;
+			Ok(Some(T::WeightInfo::force_destroy(removed)).into())
+		}
+
+		/// Recalculate the cached counters of a collection from what is actually in storage.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// Meant for collections whose `items`, `item_metadatas`, `item_configs` or `attributes`
+		/// counters no longer match their content.
+		///
+		/// - `collection`: The identifier of the collection to fix.
+		/// - `max_items`: An upper bound on the number of items, item metadata, item configs and
+		///   attributes of the collection, all together. Fails with `BadWitness` if exceeded.
+		///
+		/// Emits `CollectionStatusRecomputed` event when successful.
+		///
+		/// Weight: `O(max_items)`
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::recompute_collection_status(*max_items))]
+		pub fn recompute_collection_status(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			max_items: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let counted = Self::do_recompute_collection_status(collection, max_items)?;
+			Ok(Some(T::WeightInfo::recompute_collection_status(counted)).into())
+		}
+
+		/// Set or remove the deposits taken for the items of a collection, in place of the
+		/// `ItemDeposit`, `MetadataDepositBase`, `AttributeDepositBase` and `DepositPerByte`.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// Deposits which were already taken are unaffected, they are returned in full once
+		/// released.
+		///
+		/// - `collection`: The identifier of the collection to change.
+		/// - `maybe_deposits`: The deposits to take, or `None` to go back to the defaults.
+		///
+		/// Emits `CollectionDepositOverrideSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::set_collection_deposit_override())]
+		pub fn set_collection_deposit_override(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			maybe_deposits: Option<CollectionDepositsOf<T, I>>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::do_set_collection_deposit_override(collection, maybe_deposits)
+		}
+
+		/// Remove the listing of an item once its deadline has passed.
+		///
+		/// Origin must be Signed. Any account can clear an expired listing, no deposit is
+		/// returned as none was taken.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item whose listing is to be cleared.
+		///
+		/// Emits `ItemPriceRemoved` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::clear_expired_price())]
+		pub fn clear_expired_price(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_clear_expired_price(collection, item)
+		}
+
+		/// Register a swap of a bundle of items for another one, with an optional price.
+		///
+		/// Origin must be Signed and must own all the `offered` items.
+		///
+		/// Unlike `create_swap`, the `desired` items are named individually. Anyone owning all of
+		/// them can claim the swap until the deadline.
+		///
+		/// - `offered`: The items to be sent.
+		/// - `desired`: The items to be received.
+		/// - `maybe_price`: The price an owner of the desired items is willing to pay or receive.
+		/// - `duration`: A deadline for the swap. Specified by providing the number of blocks after
+		///   which the swap will expire.
+		///
+		/// Emits `BundleSwapCreated` on success.
+		///
+		/// Weight: `O(o + d)` where `o = offered.len()` and `d = desired.len()`
+		#[pallet::call_index(55)]
+		#[pallet::weight(T::WeightInfo::create_bundle_swap(
+			offered.len().saturating_add(desired.len()) as u32
+		))]
+		pub fn create_bundle_swap(
+			origin: OriginFor<T>,
+			offered: BundleOf<T, I>,
+			desired: BundleOf<T, I>,
+			maybe_price: Option<PriceWithDirection<ItemPrice<T, I>>>,
+			duration: <T as SystemConfig>::BlockNumber,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_create_bundle_swap(origin, offered, desired, maybe_price, duration)
+		}
+
+		/// Cancel a bundle swap.
+		///
+		/// Origin must be Signed. Before the deadline, only the creator of the swap can cancel
+		/// it, anyone can afterwards.
+		///
+		/// - `swap_id`: The id of the swap.
+		///
+		/// Emits `BundleSwapCancelled` on success.
+		#[pallet::call_index(56)]
+		#[pallet::weight(T::WeightInfo::cancel_bundle_swap())]
+		pub fn cancel_bundle_swap(origin: OriginFor<T>, swap_id: u32) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_cancel_bundle_swap(origin, swap_id)
+		}
+
+		/// Claim a bundle swap, exchanging all the items of both sides at once.
+		///
+		/// Origin must be Signed and must own all the desired items of the swap, while its
+		/// creator must still own all the offered ones. Nothing is exchanged if any transfer
+		/// fails.
+		///
+		/// - `swap_id`: The id of the swap.
+		/// - `witness_price`: A price that was previously agreed on.
+		///
+		/// Emits `BundleSwapClaimed` on success.
+		///
+		/// Weight: `O(o + d)` where `o` and `d` are the numbers of offered and desired items
+		#[pallet::call_index(57)]
+		#[pallet::weight(T::WeightInfo::claim_bundle_swap(T::MaxBundle::get().saturating_mul(2)))]
+		pub fn claim_bundle_swap(
+			origin: OriginFor<T>,
+			swap_id: u32,
+			witness_price: Option<PriceWithDirection<ItemPrice<T, I>>>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let items = Self::do_claim_bundle_swap(origin, swap_id, witness_price)?;
+			Ok(Some(T::WeightInfo::claim_bundle_swap(items)).into())
+		}
+
+		/// Transfer the items of a collection to a single account, a bounded number at a time.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// Unlike `transfer_ownership`, this moves the items rather than the collection. Items
+		/// locked by `Config::Locker` or already owned by `new_owner` are left untouched, and
+		/// the transfer settings of the collection and its items are ignored.
+		///
+		/// - `collection`: The collection whose items are to be transferred.
+		/// - `new_owner`: The account to receive the items.
+		/// - `max_items`: The maximum number of items to transfer in this call.
+		///
+		/// Emits `Transferred` for each transferred item.
+		///
+		/// Weight: `O(max_items)`
+		#[pallet::call_index(53)]
+		#[pallet::weight(T::WeightInfo::force_transfer_all(*max_items))]
+		pub fn force_transfer_all(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			new_owner: AccountIdLookupOf<T>,
+			max_items: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let new_owner = T::Lookup::lookup(new_owner)?;
+			let transferred = Self::do_force_transfer_all(collection, new_owner, max_items)?;
+			Ok(Some(T::WeightInfo::force_transfer_all(transferred)).into())
+		}
+
+		/// Re-allow the transfer of the items of a collection locked by its Freezer, a bounded
+		/// number at a time.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Freezer of
+		/// the `collection`.
+		///
+		/// Only the locks of `lock_item_transfer` are lifted, not those of the collection, of
+		/// the item owners or of `Config::Locker`.
+		///
+		/// - `collection`: The collection whose items are to be thawed.
+		/// - `max_items`: The maximum number of items to thaw in this call.
+		///
+		/// Emits `ItemTransferUnlocked` for each thawed item.
+		///
+		/// Weight: `O(max_items)`
+		#[pallet::call_index(58)]
+		#[pallet::weight(T::WeightInfo::thaw_all(*max_items))]
+		pub fn thaw_all(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			max_items: u32,
+		) -> DispatchResultWithPostInfo {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			let thawed = Self::do_thaw_all(maybe_check_origin, collection, max_items)?;
+			Ok(Some(T::WeightInfo::thaw_all(thawed)).into())
+		}
+
+		/// Set or clear the metadata given to items of a collection that are minted without
+		/// metadata of their own.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Admin of
+		/// the `collection`.
+		///
+		/// The default is applied by `mint` and `force_mint`, with the metadata deposit taken from
+		/// the minter. Metadata set explicitly on an item afterwards replaces it.
+		///
+		/// - `collection`: The identifier of the collection.
+		/// - `data`: The default item metadata, or `None` to stop applying one.
+		///
+		/// Emits `DefaultItemMetadataSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(59)]
+		#[pallet::weight(T::WeightInfo::set_default_item_metadata())]
+		pub fn set_default_item_metadata(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			data: Option<BoundedVec<u8, T::StringLimit>>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_default_item_metadata(maybe_check_origin, collection, data)
+		}
 	}
 }
 
@@ -19,10 +19,8 @@ macro_rules! impl_incrementable {
 	($($type:ty),+) => {
 		$(
 			impl Incrementable for $type {
-				fn increment(&self) -> Self {
-					let mut val = self.clone();
-					val.saturating_inc();
-					val
+				fn increment(&self) -> Option<Self> {
+					self.checked_add(1)
 				}
 
 				fn initial_value() -> Self {
@@ -23,7 +23,7 @@ pub mod v1 {
 
 	use super::*;
 
-	#[derive(Decode)]
+	#[derive(Encode, Decode)]
 	pub struct OldCollectionDetails<AccountId, DepositBalance> {
 		pub owner: AccountId,
 		pub owner_deposit: DepositBalance,
@@ -45,6 +45,7 @@ pub mod v1 {
 		}
 	}
 
+	/// Migrate the NFTs pallet from V0 to V1, counting the item configs of every collection.
 	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
 	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
 		fn on_runtime_upgrade() -> Weight {
@@ -58,7 +59,7 @@ pub mod v1 {
 				onchain_version
 			);
 
-			if onchain_version == 0 && current_version == 1 {
+			if onchain_version == 0 {
 				let mut translated = 0u64;
 				let mut configs_iterated = 0u64;
 				Collection::<T>::translate::<
@@ -71,13 +72,12 @@ pub mod v1 {
 					Some(old_value.migrate_to_v1(item_configs))
 				});
 
-				current_version.put::<Pallet<T>>();
+				StorageVersion::new(1).put::<Pallet<T>>();
 
 				log::info!(
 					target: LOG_TARGET,
-					"Upgraded {} records, storage to version {:?}",
-					translated,
-					current_version
+					"Upgraded {} records, storage to version 1",
+					translated
 				);
 				T::DbWeight::get().reads_writes(translated + configs_iterated + 1, translated + 1)
 			} else {
@@ -91,10 +91,9 @@ pub mod v1 {
 
 		#[cfg(feature = "try-runtime")]
 		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
-			let current_version = Pallet::<T>::current_storage_version();
 			let onchain_version = Pallet::<T>::on_chain_storage_version();
-			ensure!(onchain_version == 0 && current_version == 1, "migration from version 0 to 1.");
-			let prev_count = Collection::<T>::iter().count();
+			ensure!(onchain_version == 0, "migration from version 0 to 1.");
+			let prev_count = Collection::<T>::iter_keys().count();
 			Ok((prev_count as u32).encode())
 		}
 
@@ -115,3 +114,350 @@ pub mod v1 {
 		}
 	}
 }
+
+pub mod v2 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	#[derive(Encode, Decode)]
+	pub struct OldCollectionConfig<Price, BlockNumber, CollectionId> {
+		pub settings: CollectionSettings,
+		pub max_supply: Option<u32>,
+		pub mint_settings: MintSettings<Price, BlockNumber, CollectionId>,
+	}
+
+	impl<Price, BlockNumber, CollectionId> OldCollectionConfig<Price, BlockNumber, CollectionId> {
+		fn migrate_to_v2<ItemId>(
+			self,
+		) -> CollectionConfig<Price, BlockNumber, CollectionId, ItemId> {
+			CollectionConfig {
+				settings: self.settings,
+				max_supply: self.max_supply,
+				mint_settings: self.mint_settings,
+				item_id_range: None,
+			}
+		}
+	}
+
+	/// Migrate the NFTs pallet from V1 to V2, giving every collection no item id range.
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T>::current_storage_version();
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Running migration with current storage version {:?} / onchain {:?}",
+				current_version,
+				onchain_version
+			);
+
+			if onchain_version == 1 {
+				let mut translated = 0u64;
+				CollectionConfigOf::<T>::translate::<
+					OldCollectionConfig<BalanceOf<T>, T::BlockNumber, T::CollectionId>,
+					_,
+				>(|_, old_value| {
+					translated.saturating_inc();
+					Some(old_value.migrate_to_v2())
+				});
+
+				StorageVersion::new(2).put::<Pallet<T>>();
+
+				log::info!(
+					target: LOG_TARGET,
+					"Upgraded {} records, storage to version 2",
+					translated
+				);
+				T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			ensure!(onchain_version == 1, "migration from version 1 to 2.");
+			let prev_count = CollectionConfigOf::<T>::iter_keys().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), &'static str> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = CollectionConfigOf::<T>::iter().count() as u32;
+			assert_eq!(
+				prev_count, post_count,
+				"the records count before and after the migration should be the same"
+			);
+
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "wrong storage version");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	#[derive(Encode, Decode)]
+	pub struct OldItemDetails<AccountId, Deposit, Approvals> {
+		pub owner: AccountId,
+		pub approvals: Approvals,
+		pub deposit: Deposit,
+	}
+
+	impl<AccountId, Deposit, Approvals> OldItemDetails<AccountId, Deposit, Approvals> {
+		fn migrate_to_v3<BlockNumber>(
+			self,
+			mint_block: BlockNumber,
+		) -> ItemDetails<AccountId, Deposit, Approvals, BlockNumber> {
+			ItemDetails {
+				owner: self.owner,
+				approvals: self.approvals,
+				deposit: self.deposit,
+				mint_block,
+			}
+		}
+	}
+
+	/// Migrate the NFTs pallet from V2 to V3, recording the mint block of the existing items.
+	///
+	/// The block in which an existing item was minted isn't known, so it is set to the block of
+	/// the upgrade. It is thus only a bound: such an item was minted at or before that block.
+	pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T>::current_storage_version();
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Running migration with current storage version {:?} / onchain {:?}",
+				current_version,
+				onchain_version
+			);
+
+			if onchain_version == 2 {
+				let now = frame_system::Pallet::<T>::block_number();
+				let mut translated = 0u64;
+				Item::<T>::translate::<
+					OldItemDetails<T::AccountId, ItemDepositOf<T, ()>, ApprovalsOf<T, ()>>,
+					_,
+				>(|_, _, old_value| {
+					translated.saturating_inc();
+					Some(old_value.migrate_to_v3(now))
+				});
+
+				StorageVersion::new(3).put::<Pallet<T>>();
+
+				log::info!(
+					target: LOG_TARGET,
+					"Upgraded {} records, storage to version 3",
+					translated
+				);
+				T::DbWeight::get().reads_writes(translated + 2, translated + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			ensure!(onchain_version == 2, "migration from version 2 to 3.");
+			let prev_count = Item::<T>::iter_keys().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), &'static str> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = Item::<T>::iter().count() as u32;
+			assert_eq!(
+				prev_count, post_count,
+				"the records count before and after the migration should be the same"
+			);
+
+			ensure!(Pallet::<T>::on_chain_storage_version() == 3, "wrong storage version");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v4 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+
+	/// Migrate the NFTs pallet from V3 to V4, adding an empty deadline to the existing listings,
+	/// which thus never expire.
+	pub struct MigrateToV4<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let current_version = Pallet::<T>::current_storage_version();
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Running migration with current storage version {:?} / onchain {:?}",
+				current_version,
+				onchain_version
+			);
+
+			if onchain_version == 3 {
+				let mut translated = 0u64;
+				ItemPriceOf::<T>::translate::<(ItemPrice<T, ()>, Option<T::AccountId>), _>(
+					|_, _, (price, whitelisted_buyer)| {
+						translated.saturating_inc();
+						Some((price, whitelisted_buyer, None))
+					},
+				);
+
+				StorageVersion::new(4).put::<Pallet<T>>();
+
+				log::info!(
+					target: LOG_TARGET,
+					"Upgraded {} records, storage to version 4",
+					translated
+				);
+				T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+			} else {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			ensure!(onchain_version == 3, "migration from version 3 to 4.");
+			let prev_count = ItemPriceOf::<T>::iter_keys().count();
+			Ok((prev_count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(prev_count: Vec<u8>) -> Result<(), &'static str> {
+			let prev_count: u32 = Decode::decode(&mut prev_count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			let post_count = ItemPriceOf::<T>::iter().count() as u32;
+			assert_eq!(
+				prev_count, post_count,
+				"the records count before and after the migration should be the same"
+			);
+
+			ensure!(Pallet::<T>::on_chain_storage_version() == 4, "wrong storage version");
+
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "try-runtime")]
+mod test {
+	use super::*;
+	use crate::mock::*;
+	use frame_support::{pallet_prelude::*, storage::unhashed};
+
+	fn account(id: u8) -> AccountId {
+		[id; 32].into()
+	}
+
+	fn upgrade<M: OnRuntimeUpgrade>() {
+		let state = M::pre_upgrade().unwrap();
+		let _w = M::on_runtime_upgrade();
+		M::post_upgrade(state).unwrap();
+	}
+
+	#[test]
+	fn migration_v0_to_v4_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Nfts>();
+			System::set_block_number(5);
+
+			let details = v1::OldCollectionDetails {
+				owner: account(1),
+				owner_deposit: 2u64,
+				items: 1,
+				item_metadatas: 0,
+				attributes: 0,
+			};
+			unhashed::put(&Collection::<Test>::hashed_key_for(0), &details);
+			let config = v2::OldCollectionConfig::<u64, u64, u32> {
+				settings: CollectionSettings::all_enabled(),
+				max_supply: Some(10),
+				mint_settings: MintSettings::default(),
+			};
+			unhashed::put(&CollectionConfigOf::<Test>::hashed_key_for(0), &config);
+			ItemConfigOf::<Test>::insert(0, 42, ItemConfig::default());
+			let item = v3::OldItemDetails {
+				owner: account(1),
+				approvals: ApprovalsOf::<Test>::default(),
+				deposit: ItemDeposit { account: account(1), amount: 1u64 },
+			};
+			unhashed::put(&Item::<Test>::hashed_key_for(0, 42), &item);
+			unhashed::put(&ItemPriceOf::<Test>::hashed_key_for(0, 42), &(7u64, Some(account(2))));
+
+			// Each migration starts from the version left by the previous one.
+			upgrade::<v1::MigrateToV1<Test>>();
+			assert_eq!(StorageVersion::get::<Nfts>(), 1);
+			upgrade::<v2::MigrateToV2<Test>>();
+			assert_eq!(StorageVersion::get::<Nfts>(), 2);
+			upgrade::<v3::MigrateToV3<Test>>();
+			assert_eq!(StorageVersion::get::<Nfts>(), 3);
+			upgrade::<v4::MigrateToV4<Test>>();
+			assert_eq!(StorageVersion::get::<Nfts>(), 4);
+
+			let details = Collection::<Test>::get(0).unwrap();
+			assert_eq!((details.owner, details.items, details.item_configs), (account(1), 1, 1));
+			let config = CollectionConfigOf::<Test>::get(0).unwrap();
+			assert_eq!((config.max_supply, config.item_id_range), (Some(10), None));
+			let item = Item::<Test>::get(0, 42).unwrap();
+			assert_eq!((item.owner, item.deposit.amount), (account(1), 1));
+			// The mint block of an existing item is the block of the upgrade.
+			assert_eq!(Nfts::item_mint_block(0, 42), Some(5));
+			assert_eq!(ItemPriceOf::<Test>::get(0, 42), Some((7, Some(account(2)), None)));
+		});
+	}
+
+	#[test]
+	fn migrations_only_run_from_their_previous_version() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(2).put::<Nfts>();
+			let listing = (7u64, Some(account(2)));
+			unhashed::put(&ItemPriceOf::<Test>::hashed_key_for(0, 42), &listing);
+
+			// Neither the migrations already applied nor those which come later do anything.
+			let _w = v1::MigrateToV1::<Test>::on_runtime_upgrade();
+			let _w = v2::MigrateToV2::<Test>::on_runtime_upgrade();
+			let _w = v4::MigrateToV4::<Test>::on_runtime_upgrade();
+			assert_eq!(StorageVersion::get::<Nfts>(), 2);
+			assert_eq!(unhashed::get(&ItemPriceOf::<Test>::hashed_key_for(0, 42)), Some(listing));
+			assert!(v4::MigrateToV4::<Test>::pre_upgrade().is_err());
+		});
+	}
+}
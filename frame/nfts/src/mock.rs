@@ -21,7 +21,10 @@ use super::*;
 use crate as pallet_nfts;
 
 use frame_support::{
-	construct_runtime, parameter_types,
+	construct_runtime,
+	dispatch::{DispatchError, DispatchResult},
+	instances::Instance2,
+	parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
 };
 use sp_core::H256;
@@ -44,6 +47,7 @@ construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Nfts: pallet_nfts::{Pallet, Call, Storage, Event<T>},
+		Nfts2: pallet_nfts::<Instance2>::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -96,6 +100,30 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub storage Features: PalletFeatures = PalletFeatures::all_enabled();
+	pub storage ApprovalsLimit: u32 = 10;
+}
+
+parameter_types! {
+	pub static CreatedCollections: Vec<(u32, AccountId)> = vec![];
+	pub static DestroyedCollections: Vec<(u32, AccountId)> = vec![];
+	pub static VetoCreation: bool = false;
+}
+
+/// Records the collections created and destroyed, vetoing creations while `VetoCreation` is set.
+pub struct CollectionHooks;
+impl OnCollectionCreated<u32, AccountId> for CollectionHooks {
+	fn on_collection_created(collection: &u32, owner: &AccountId) -> DispatchResult {
+		if VetoCreation::get() {
+			return Err(DispatchError::Other("vetoed"))
+		}
+		CreatedCollections::mutate(|c| c.push((*collection, owner.clone())));
+		Ok(())
+	}
+}
+impl OnCollectionDestroyed<u32, AccountId> for CollectionHooks {
+	fn on_collection_destroyed(collection: &u32, owner: &AccountId) {
+		DestroyedCollections::mutate(|c| c.push((*collection, owner.clone())));
+	}
 }
 
 impl Config for Test {
@@ -106,6 +134,8 @@ impl Config for Test {
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<Self::AccountId>>;
 	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type Locker = ();
+	type OnCollectionCreated = CollectionHooks;
+	type OnCollectionDestroyed = CollectionHooks;
 	type CollectionDeposit = ConstU64<2>;
 	type ItemDeposit = ConstU64<1>;
 	type MetadataDepositBase = ConstU64<1>;
@@ -114,11 +144,13 @@ impl Config for Test {
 	type StringLimit = ConstU32<50>;
 	type KeyLimit = ConstU32<50>;
 	type ValueLimit = ConstU32<50>;
-	type ApprovalsLimit = ConstU32<10>;
+	type ApprovalsLimit = ApprovalsLimit;
 	type ItemAttributesApprovalsLimit = ConstU32<2>;
 	type MaxTips = ConstU32<10>;
 	type MaxDeadlineDuration = ConstU64<10000>;
 	type MaxAttributesPerCall = ConstU32<2>;
+	type MaxBundle = ConstU32<3>;
+	type MaxBatch = ConstU32<5>;
 	type Features = Features;
 	/// Off-chain = signature On-chain - therefore no conversion needed.
 	/// It needs to be From<MultiSignature> for benchmarking.
@@ -130,6 +162,40 @@ impl Config for Test {
 	type Helper = ();
 }
 
+/// A second instance, taking different deposits and `u128` item ids.
+impl Config<Instance2> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u128;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<Self::AccountId>>;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type Locker = ();
+	type OnCollectionCreated = ();
+	type OnCollectionDestroyed = ();
+	type CollectionDeposit = ConstU64<5>;
+	type ItemDeposit = ConstU64<3>;
+	type MetadataDepositBase = ConstU64<2>;
+	type AttributeDepositBase = ConstU64<4>;
+	type DepositPerByte = ConstU64<2>;
+	type StringLimit = ConstU32<50>;
+	type KeyLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
+	type ApprovalsLimit = ApprovalsLimit;
+	type ItemAttributesApprovalsLimit = ConstU32<2>;
+	type MaxTips = ConstU32<10>;
+	type MaxDeadlineDuration = ConstU64<10000>;
+	type MaxAttributesPerCall = ConstU32<2>;
+	type MaxBundle = ConstU32<3>;
+	type MaxBatch = ConstU32<5>;
+	type Features = Features;
+	type OffchainSignature = Signature;
+	type OffchainPublic = AccountPublic;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
 
@@ -22,14 +22,15 @@ use enumflags2::BitFlags;
 use frame_support::{
 	assert_noop, assert_ok,
 	dispatch::Dispatchable,
+	instances::Instance2,
 	traits::{
-		tokens::nonfungibles_v2::{Destroy, Mutate},
+		tokens::nonfungibles_v2::{Create, Destroy, InspectEnumerable, Mutate},
 		Currency, Get,
 	},
 };
 use pallet_balances::Error as BalancesError;
 use sp_core::{bounded::BoundedVec, Pair};
-use sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner};
+use sp_runtime::{traits::IdentifyAccount, DispatchError, MultiSignature, MultiSigner};
 use sp_std::prelude::*;
 
 type AccountIdOf<Test> = <Test as frame_system::Config>::AccountId;
@@ -122,6 +123,7 @@ fn collection_config_from_disabled_settings(
 		settings: CollectionSettings::from_disabled(settings),
 		max_supply: None,
 		mint_settings: MintSettings::default(),
+		item_id_range: None,
 	}
 }
 
@@ -130,6 +132,17 @@ fn collection_config_with_all_settings_enabled() -> CollectionConfigFor<Test> {
 		settings: CollectionSettings::all_enabled(),
 		max_supply: None,
 		mint_settings: MintSettings::default(),
+		item_id_range: None,
+	}
+}
+
+/// The same config for the second instance, whose item ids are `u128`.
+fn collection_config_for_instance2() -> CollectionConfigFor<Test, Instance2> {
+	CollectionConfig {
+		settings: CollectionSettings::all_enabled(),
+		max_supply: None,
+		mint_settings: MintSettings::default(),
+		item_id_range: None,
 	}
 }
 
@@ -175,6 +188,89 @@ fn basic_minting_should_work() {
 	});
 }
 
+#[test]
+fn create_with_id_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		// claim an id ahead of the counter, plus the one right after it
+		assert_ok!(Nfts::create_with_id(
+			RuntimeOrigin::signed(account(1)),
+			1,
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), 2);
+		assert!(events().contains(&Event::<Test>::Created {
+			collection: 1,
+			creator: account(1),
+			owner: account(1),
+		}));
+		assert_eq!(Nfts::get_next_id(), 0);
+		assert_ok!(Nfts::create_with_id(
+			RuntimeOrigin::signed(account(2)),
+			2,
+			account(2),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_noop!(
+			Nfts::create_with_id(
+				RuntimeOrigin::signed(account(2)),
+				1,
+				account(2),
+				collection_config_with_all_settings_enabled()
+			),
+			Error::<Test>::CollectionIdInUse
+		);
+		assert_noop!(
+			Nfts::create_with_id(
+				RuntimeOrigin::signed(account(2)),
+				5,
+				account(2),
+				default_collection_config()
+			),
+			Error::<Test>::WrongSetting
+		);
+
+		// sequential creation takes the free id, then skips over the claimed ones
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(account(1)),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert!(Collection::<Test>::contains_key(0));
+		assert_eq!(Nfts::get_next_id(), 3);
+		assert!(events().contains(&Event::<Test>::NextCollectionIdIncremented { next_id: 3 }));
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(2),
+			default_collection_config()
+		));
+		assert!(Collection::<Test>::contains_key(3));
+		assert_eq!(Nfts::get_next_id(), 4);
+
+		// claiming the next id directly moves the counter as well
+		assert_ok!(Nfts::create_with_id(
+			RuntimeOrigin::signed(account(2)),
+			4,
+			account(2),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_eq!(Nfts::get_next_id(), 5);
+		assert_eq!(
+			collections(),
+			vec![
+				(account(1), 0),
+				(account(1), 1),
+				(account(2), 2),
+				(account(2), 3),
+				(account(2), 4)
+			]
+		);
+	});
+}
+
 #[test]
 fn lifecycle_should_work() {
 	new_test_ext().execute_with(|| {
@@ -327,1991 +423,5250 @@ fn destroy_should_work() {
 }
 
 #[test]
-fn mint_should_work() {
+fn destroy_in_batches_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Nfts::force_create(
-			RuntimeOrigin::root(),
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(account(1)),
 			account(1),
-			default_collection_config()
+			collection_config_with_all_settings_enabled()
 		));
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
-		assert_eq!(Nfts::owner(0, 42).unwrap(), account(1));
-		assert_eq!(collections(), vec![(account(1), 0)]);
-		assert_eq!(items(), vec![(account(1), 0, 42)]);
+		for i in 0..5 {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				None,
+				AttributeNamespace::CollectionOwner,
+				bvec![i],
+				bvec![i],
+			));
+		}
+		for item in 0..3 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(1), None));
+			assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(1)), 0, item));
+			assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, item));
+		}
+		assert_eq!(
+			Nfts::get_destroy_witness(&0).unwrap(),
+			DestroyWitness { item_metadatas: 0, item_configs: 3, attributes: 5 }
+		);
 
-		// validate minting start and end settings
-		assert_ok!(Nfts::update_mint_settings(
-			RuntimeOrigin::signed(account(1)),
-			0,
-			MintSettings {
-				start_block: Some(2),
-				end_block: Some(3),
-				mint_type: MintType::Public,
-				..Default::default()
-			}
-		));
+		// the first call removes only part of the collection
+		let budget = DestroyWitness { item_metadatas: 0, item_configs: 2, attributes: 2 };
+		let post_info = Nfts::destroy(RuntimeOrigin::signed(account(1)), 0, budget).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<Test as Config>::WeightInfo::destroy(0, 2, 2)));
+		let remaining = DestroyWitness { item_metadatas: 0, item_configs: 1, attributes: 3 };
+		assert!(events().contains(&Event::<Test>::PartiallyDestroyed { collection: 0, remaining }));
+		assert_eq!(Nfts::get_destroy_witness(&0), Some(remaining));
+		assert_eq!(attributes(0).len(), 3);
+		assert_eq!(ItemConfigOf::<Test>::iter_prefix(0).count(), 1);
 
-		System::set_block_number(1);
-		assert_noop!(
-			Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 43, account(1), None),
-			Error::<Test>::MintNotStarted
-		);
-		System::set_block_number(4);
+		// the witness can't exceed what's left
 		assert_noop!(
-			Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 43, account(1), None),
-			Error::<Test>::MintEnded
+			Nfts::destroy(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				DestroyWitness { attributes: 4, ..remaining }
+			),
+			Error::<Test>::BadWitness
 		);
-
-		// validate price
-		assert_ok!(Nfts::update_mint_settings(
+		assert_ok!(Nfts::destroy(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			MintSettings { mint_type: MintType::Public, price: Some(1), ..Default::default() }
+			DestroyWitness { item_metadatas: 0, item_configs: 0, attributes: 2 }
 		));
-		Balances::make_free_balance_be(&account(2), 100);
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 43, account(2), None));
-		assert_eq!(Balances::total_balance(&account(2)), 99);
+		assert!(Collection::<Test>::contains_key(0));
 
-		// validate types
-		assert_ok!(Nfts::force_create(
-			RuntimeOrigin::root(),
-			account(1),
-			default_collection_config()
-		));
-		assert_ok!(Nfts::update_mint_settings(
+		// the rest goes at once
+		assert_ok!(Nfts::destroy(
 			RuntimeOrigin::signed(account(1)),
-			1,
-			MintSettings { mint_type: MintType::HolderOf(0), ..Default::default() }
+			0,
+			Nfts::get_destroy_witness(&0).unwrap()
 		));
+		assert!(events().contains(&Event::<Test>::Destroyed { collection: 0 }));
+		assert!(!Collection::<Test>::contains_key(0));
+		assert!(attributes(0).is_empty());
+		assert_eq!(ItemConfigOf::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(Balances::reserved_balance(&account(1)), 0);
+	});
+}
+
+#[test]
+fn create_and_destroy_collection_via_traits_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		// the deposit can only be waived through `force_create`
 		assert_noop!(
-			Nfts::mint(RuntimeOrigin::signed(account(3)), 1, 42, account(3), None),
-			Error::<Test>::BadWitness
-		);
-		assert_noop!(
-			Nfts::mint(RuntimeOrigin::signed(account(2)), 1, 42, account(2), None),
-			Error::<Test>::BadWitness
+			<Nfts as Create<_, _>>::create_collection(
+				&account(1),
+				&account(2),
+				&collection_config_from_disabled_settings(
+					CollectionSetting::DepositRequired.into()
+				),
+			),
+			Error::<Test>::WrongSetting
 		);
+
+		let collection = <Nfts as Create<_, _>>::create_collection(
+			&account(1),
+			&account(2),
+			&collection_config_with_all_settings_enabled(),
+		)
+		.unwrap();
+		assert_eq!(collection, 0);
+		assert_eq!(NextCollectionId::<Test>::get(), Some(1));
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner, account(1));
+		assert_eq!(Balances::reserved_balance(&account(1)), 2);
+		assert!(Nfts::has_role(&0, &account(2), CollectionRole::Admin));
+		assert_eq!(collections(), vec![(account(1), 0)]);
+
+		let witness = <Nfts as Destroy<_>>::get_destroy_witness(&0).unwrap();
 		assert_noop!(
-			Nfts::mint(
-				RuntimeOrigin::signed(account(2)),
-				1,
-				42,
-				account(2),
-				Some(MintWitness { owned_item: 42 })
+			<Nfts as Destroy<_>>::destroy(
+				0,
+				DestroyWitness { item_metadatas: 1, ..witness },
+				Some(account(1))
 			),
 			Error::<Test>::BadWitness
 		);
-		assert_ok!(Nfts::mint(
-			RuntimeOrigin::signed(account(2)),
-			1,
-			42,
-			account(2),
-			Some(MintWitness { owned_item: 43 })
-		));
-
-		// can't mint twice
 		assert_noop!(
-			Nfts::mint(
-				RuntimeOrigin::signed(account(2)),
-				1,
-				46,
-				account(2),
-				Some(MintWitness { owned_item: 43 })
-			),
-			Error::<Test>::AlreadyClaimed
+			<Nfts as Destroy<_>>::destroy(0, witness, Some(account(2))),
+			Error::<Test>::NoPermission
 		);
+		assert_eq!(<Nfts as Destroy<_>>::destroy(0, witness, Some(account(1))), Ok(witness));
+		assert!(!Collection::<Test>::contains_key(0));
+		assert_eq!(<Nfts as Destroy<_>>::get_destroy_witness(&0), None);
+		assert_eq!(Balances::reserved_balance(&account(1)), 0);
+		assert!(collections().is_empty());
 	});
 }
 
 #[test]
-fn transfer_should_work() {
+fn collection_hooks_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Nfts::force_create(
-			RuntimeOrigin::root(),
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(account(1)),
 			account(1),
-			default_collection_config()
+			collection_config_with_all_settings_enabled()
 		));
-		assert_ok!(Nfts::force_mint(
-			RuntimeOrigin::signed(account(1)),
-			0,
-			42,
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
 			account(2),
-			default_item_config()
+			collection_config_with_all_settings_enabled()
 		));
+		assert_eq!(CreatedCollections::get(), vec![(0, account(1)), (1, account(2))]);
+		assert!(DestroyedCollections::get().is_empty());
 
-		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
-		assert_eq!(items(), vec![(account(3), 0, 42)]);
+		let w = Nfts::get_destroy_witness(&0).unwrap();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(account(1)), 0, w));
+		let w = Nfts::get_destroy_witness(&1).unwrap();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::root(), 1, w));
+		assert_eq!(DestroyedCollections::get(), vec![(0, account(1)), (1, account(2))]);
+
+		// a vetoed creation fails and reserves nothing
+		VetoCreation::set(true);
 		assert_noop!(
-			Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(4)),
-			Error::<Test>::NoPermission
+			Nfts::create(
+				RuntimeOrigin::signed(account(1)),
+				account(1),
+				collection_config_with_all_settings_enabled()
+			),
+			Error::<Test>::CreationVetoed
 		);
+		assert_eq!(Balances::reserved_balance(&account(1)), 0);
+		assert_eq!(CreatedCollections::get().len(), 2);
+	});
+}
 
-		assert_ok!(Nfts::approve_transfer(
-			RuntimeOrigin::signed(account(3)),
-			0,
-			42,
-			account(2),
-			None
-		));
-		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(4)));
+#[test]
+fn create_with_deposit_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_noop!(
+			Nfts::create_with_deposit(
+				RuntimeOrigin::signed(account(1)),
+				account(1),
+				default_collection_config(),
+				10
+			),
+			DispatchError::BadOrigin
+		);
 
-		// validate we can't transfer non-transferable items
-		let collection_id = 1;
-		assert_ok!(Nfts::force_create(
+		// a custom deposit is reserved and refunded on destroy
+		assert_ok!(Nfts::create_with_deposit(
 			RuntimeOrigin::root(),
 			account(1),
-			collection_config_from_disabled_settings(
-				CollectionSetting::TransferableItems | CollectionSetting::DepositRequired
-			)
+			default_collection_config(),
+			10
 		));
+		assert_eq!(
+			events().last(),
+			Some(&Event::ForceCreated { collection: 0, owner: account(1) })
+		);
+		assert_eq!(Balances::reserved_balance(&account(1)), 10);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 10);
+		let w = Nfts::get_destroy_witness(&0).unwrap();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(account(1)), 0, w));
+		assert_eq!(Balances::reserved_balance(&account(1)), 0);
+		assert_eq!(Balances::free_balance(&account(1)), 100);
 
-		assert_ok!(Nfts::force_mint(
-			RuntimeOrigin::signed(account(1)),
-			1,
-			1,
-			account(42),
-			default_item_config()
+		// a zero deposit reserves nothing
+		assert_ok!(Nfts::create_with_deposit(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config(),
+			0
 		));
+		assert_eq!(Balances::reserved_balance(&account(1)), 0);
+		let w = Nfts::get_destroy_witness(&1).unwrap();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(account(1)), 1, w));
+		assert_eq!(Balances::free_balance(&account(1)), 100);
 
+		// the deposit must be affordable
 		assert_noop!(
-			Nfts::transfer(RuntimeOrigin::signed(account(1)), collection_id, 42, account(3)),
-			Error::<Test>::ItemsNonTransferable
+			Nfts::create_with_deposit(
+				RuntimeOrigin::root(),
+				account(1),
+				default_collection_config(),
+				101
+			),
+			BalancesError::<Test, _>::InsufficientBalance
 		);
 	});
 }
 
 #[test]
-fn locking_transfer_should_work() {
+fn force_destroy_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Nfts::force_create(
-			RuntimeOrigin::root(),
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(account(1)),
 			account(1),
-			default_collection_config()
+			collection_config_with_all_settings_enabled()
 		));
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
-		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(1)), 0, 42));
-		assert_noop!(
-			Nfts::transfer(RuntimeOrigin::signed(account(1)), 0, 42, account(2)),
-			Error::<Test>::ItemLocked
-		);
-
-		assert_ok!(Nfts::unlock_item_transfer(RuntimeOrigin::signed(account(1)), 0, 42));
-		assert_ok!(Nfts::lock_collection(
+		for item in 0..3 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(2), None));
+		}
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 1, bvec![1, 1]));
+		assert_ok!(Nfts::set_attribute(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			CollectionSettings::from_disabled(CollectionSetting::TransferableItems.into())
+			Some(2),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
 		));
-		assert_noop!(
-			Nfts::transfer(RuntimeOrigin::signed(account(1)), 0, 42, account(2)),
-			Error::<Test>::ItemsNonTransferable
-		);
-
-		assert_ok!(Nfts::force_collection_config(
-			RuntimeOrigin::root(),
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
 			0,
-			collection_config_with_all_settings_enabled(),
-		));
-		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(1)), 0, 42, account(2)));
-	});
-}
-
-#[test]
-fn origin_guards_should_work() {
-	new_test_ext().execute_with(|| {
-		assert_ok!(Nfts::force_create(
-			RuntimeOrigin::root(),
-			account(1),
-			default_collection_config()
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0],
 		));
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+		assert_ok!(Nfts::set_price(RuntimeOrigin::signed(account(2)), 0, 0, Some(1), None, None));
+		assert!(Balances::reserved_balance(&account(1)) > 0);
+		assert!(Balances::reserved_balance(&account(2)) > 0);
 
-		Balances::make_free_balance_be(&account(2), 100);
-		assert_ok!(Nfts::set_accept_ownership(RuntimeOrigin::signed(account(2)), Some(0)));
-		assert_noop!(
-			Nfts::transfer_ownership(RuntimeOrigin::signed(account(2)), 0, account(2)),
-			Error::<Test>::NoPermission
-		);
+		// desync the cached counters so the collection can't be destroyed
+		Collection::<Test>::mutate(0, |maybe_details| {
+			let details = maybe_details.as_mut().unwrap();
+			details.items = 5;
+			details.attributes = 0;
+		});
 		assert_noop!(
-			Nfts::set_team(
-				RuntimeOrigin::signed(account(2)),
+			Nfts::destroy(
+				RuntimeOrigin::signed(account(1)),
 				0,
-				Some(account(2)),
-				Some(account(2)),
-				Some(account(2)),
+				Nfts::get_destroy_witness(&0).unwrap()
 			),
-			Error::<Test>::NoPermission
-		);
-		assert_noop!(
-			Nfts::lock_item_transfer(RuntimeOrigin::signed(account(2)), 0, 42),
-			Error::<Test>::NoPermission
-		);
-		assert_noop!(
-			Nfts::unlock_item_transfer(RuntimeOrigin::signed(account(2)), 0, 42),
-			Error::<Test>::NoPermission
-		);
-		assert_noop!(
-			Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 69, account(2), None),
-			Error::<Test>::NoPermission
+			Error::<Test>::CollectionNotEmpty
 		);
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 43, account(2), None));
+
 		assert_noop!(
-			Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 43),
-			Error::<Test>::NoPermission
+			Nfts::force_destroy(RuntimeOrigin::signed(account(1)), 0, 4),
+			DispatchError::BadOrigin
 		);
-		let w = Nfts::get_destroy_witness(&0).unwrap();
 		assert_noop!(
-			Nfts::destroy(RuntimeOrigin::signed(account(2)), 0, w),
-			Error::<Test>::NoPermission
+			Nfts::force_destroy(RuntimeOrigin::root(), 1, 4),
+			Error::<Test>::UnknownCollection
 		);
+
+		// 3 items, 1 metadata, 2 attributes and 3 item configs are removed in batches
+		let post_info = Nfts::force_destroy(RuntimeOrigin::root(), 0, 4).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<Test as Config>::WeightInfo::force_destroy(4)));
+		assert!(items().is_empty());
+		assert!(!ItemMetadataOf::<Test>::contains_key(0, 1));
+		assert!(!ItemPriceOf::<Test>::contains_key(0, 0));
+		assert!(Collection::<Test>::contains_key(0));
+
+		assert_ok!(Nfts::force_destroy(RuntimeOrigin::root(), 0, 4));
+		assert!(attributes(0).is_empty());
+		assert!(Collection::<Test>::contains_key(0));
+
+		let post_info = Nfts::force_destroy(RuntimeOrigin::root(), 0, 4).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<Test as Config>::WeightInfo::force_destroy(1)));
+		assert!(events().contains(&Event::<Test>::Destroyed { collection: 0 }));
+		assert!(!Collection::<Test>::contains_key(0));
+		assert!(!CollectionConfigOf::<Test>::contains_key(0));
+		assert_eq!(ItemConfigOf::<Test>::iter_prefix(0).count(), 0);
+		assert!(!CollectionAccount::<Test>::contains_key(account(1), 0));
+		assert_eq!(Balances::reserved_balance(&account(1)), 0);
+		assert_eq!(Balances::reserved_balance(&account(2)), 0);
 	});
 }
 
 #[test]
-fn transfer_owner_should_work() {
+fn recompute_collection_status_should_work() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&account(1), 100);
 		Balances::make_free_balance_be(&account(2), 100);
-		Balances::make_free_balance_be(&account(3), 100);
 		assert_ok!(Nfts::create(
 			RuntimeOrigin::signed(account(1)),
 			account(1),
 			collection_config_with_all_settings_enabled()
 		));
-		assert_eq!(collections(), vec![(account(1), 0)]);
-		assert_noop!(
-			Nfts::transfer_ownership(RuntimeOrigin::signed(account(1)), 0, account(2)),
-			Error::<Test>::Unaccepted
-		);
-		assert_ok!(Nfts::set_accept_ownership(RuntimeOrigin::signed(account(2)), Some(0)));
-		assert_ok!(Nfts::transfer_ownership(RuntimeOrigin::signed(account(1)), 0, account(2)));
-
-		assert_eq!(collections(), vec![(account(2), 0)]);
-		assert_eq!(Balances::total_balance(&account(1)), 98);
-		assert_eq!(Balances::total_balance(&account(2)), 102);
-		assert_eq!(Balances::reserved_balance(&account(1)), 0);
-		assert_eq!(Balances::reserved_balance(&account(2)), 2);
-
-		assert_ok!(Nfts::set_accept_ownership(RuntimeOrigin::signed(account(1)), Some(0)));
-		assert_noop!(
-			Nfts::transfer_ownership(RuntimeOrigin::signed(account(1)), 0, account(1)),
-			Error::<Test>::NoPermission
-		);
-
-		// Mint and set metadata now and make sure that deposit gets transferred back.
-		assert_ok!(Nfts::set_collection_metadata(
+		for item in 0..3 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(2), None));
+		}
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 1, bvec![1, 1]));
+		assert_ok!(Nfts::set_attribute(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			bvec![0u8; 20],
+			Some(2),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
 		));
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
-		assert_eq!(Balances::reserved_balance(&account(1)), 1);
-		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 20]));
-		assert_ok!(Nfts::set_accept_ownership(RuntimeOrigin::signed(account(3)), Some(0)));
-		assert_ok!(Nfts::transfer_ownership(RuntimeOrigin::signed(account(2)), 0, account(3)));
-		assert_eq!(collections(), vec![(account(3), 0)]);
-		assert_eq!(Balances::total_balance(&account(2)), 58);
-		assert_eq!(Balances::total_balance(&account(3)), 144);
-		assert_eq!(Balances::reserved_balance(&account(2)), 0);
-		assert_eq!(Balances::reserved_balance(&account(3)), 44);
+		let witness = Nfts::get_destroy_witness(&0).unwrap();
 
-		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(1)), 0, 42, account(2)));
-		// reserved_balance of accounts 1 & 2 should be unchanged:
-		assert_eq!(Balances::reserved_balance(&account(1)), 1);
-		assert_eq!(Balances::reserved_balance(&account(2)), 0);
+		// corrupt the cached counters
+		Collection::<Test>::mutate(0, |maybe_details| {
+			let details = maybe_details.as_mut().unwrap();
+			details.items = 5;
+			details.item_metadatas = 0;
+			details.attributes = 7;
+		});
+		assert_ne!(Nfts::get_destroy_witness(&0).unwrap(), witness);
 
-		// 2's acceptance from before is reset when it became an owner, so it cannot be transferred
-		// without a fresh acceptance.
 		assert_noop!(
-			Nfts::transfer_ownership(RuntimeOrigin::signed(account(3)), 0, account(2)),
-			Error::<Test>::Unaccepted
+			Nfts::recompute_collection_status(RuntimeOrigin::signed(account(1)), 0, 8),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Nfts::recompute_collection_status(RuntimeOrigin::root(), 1, 8),
+			Error::<Test>::UnknownCollection
+		);
+		// 3 items, 1 metadata, 3 item configs and 1 attribute don't fit into 7 reads
+		assert_noop!(
+			Nfts::recompute_collection_status(RuntimeOrigin::root(), 0, 7),
+			Error::<Test>::BadWitness
+		);
+
+		let post_info = Nfts::recompute_collection_status(RuntimeOrigin::root(), 0, 10).unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<Test as Config>::WeightInfo::recompute_collection_status(8))
 		);
+		assert!(events().contains(&Event::<Test>::CollectionStatusRecomputed {
+			collection: 0,
+			items: 3,
+			item_metadatas: 1,
+			item_configs: 3,
+			attributes: 1,
+		}));
+		assert_eq!(Nfts::get_destroy_witness(&0).unwrap(), witness);
+		assert_eq!(Collection::<Test>::get(0).unwrap().items, 3);
 	});
 }
 
 #[test]
-fn set_team_should_work() {
+fn update_mint_settings_should_emit_event() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
-			default_collection_config(),
+			default_collection_config()
 		));
-		assert_ok!(Nfts::set_team(
+		assert_ok!(Nfts::update_mint_settings(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			Some(account(2)),
-			Some(account(3)),
-			Some(account(4)),
+			MintSettings { mint_type: MintType::Issuer, ..Default::default() }
 		));
+		assert!(events().contains(&Event::<Test>::CollectionMintSettingsUpdated {
+			collection: 0,
+			mint_type: MintType::Issuer,
+			price: None,
+		}));
 
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 42, account(2), None));
-
-		// admin can't transfer/burn items he doesn't own
-		assert_noop!(
-			Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(3)),
-			Error::<Test>::NoPermission
-		);
-		assert_noop!(
-			Nfts::burn(RuntimeOrigin::signed(account(3)), 0, 42),
-			Error::<Test>::NoPermission
-		);
-
-		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(4)), 0, 42));
-		assert_ok!(Nfts::unlock_item_transfer(RuntimeOrigin::signed(account(4)), 0, 42));
-
-		// validate we can set any role to None
-		assert_ok!(Nfts::set_team(
+		// switching from free to public priced minting
+		assert_ok!(Nfts::update_mint_settings(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			Some(account(2)),
-			Some(account(3)),
-			None,
+			MintSettings { mint_type: MintType::Public, price: Some(10), ..Default::default() }
 		));
-		assert_noop!(
-			Nfts::lock_item_transfer(RuntimeOrigin::signed(account(4)), 0, 42),
-			Error::<Test>::NoPermission
-		);
-
-		// set all the roles to None
-		assert_ok!(Nfts::set_team(RuntimeOrigin::signed(account(1)), 0, None, None, None,));
-
-		// validate we can't set the roles back
-		assert_noop!(
-			Nfts::set_team(
-				RuntimeOrigin::signed(account(1)),
-				0,
-				Some(account(2)),
-				Some(account(3)),
-				None,
-			),
-			Error::<Test>::NoPermission
+		assert_eq!(
+			events(),
+			vec![Event::<Test>::CollectionMintSettingsUpdated {
+				collection: 0,
+				mint_type: MintType::Public,
+				price: Some(10),
+			}]
 		);
-
-		// only the root account can change the roles from None to Some()
-		assert_ok!(Nfts::set_team(
-			RuntimeOrigin::root(),
-			0,
-			Some(account(2)),
-			Some(account(3)),
-			None,
-		));
 	});
 }
 
 #[test]
-fn set_collection_metadata_should_work() {
+fn mint_should_work() {
 	new_test_ext().execute_with(|| {
-		// Cannot add metadata to unknown item
-		assert_noop!(
-			Nfts::set_collection_metadata(RuntimeOrigin::signed(account(1)), 0, bvec![0u8; 20]),
-			Error::<Test>::NoPermission,
-		);
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
-			collection_config_with_all_settings_enabled()
+			default_collection_config()
 		));
-		// Cannot add metadata to unowned item
-		assert_noop!(
-			Nfts::set_collection_metadata(RuntimeOrigin::signed(account(2)), 0, bvec![0u8; 20]),
-			Error::<Test>::NoPermission,
-		);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+		assert!(events().contains(&Event::<Test>::Issued {
+			collection: 0,
+			item: 42,
+			owner: account(1),
+			price: None,
+		}));
+		assert_eq!(Nfts::owner(0, 42).unwrap(), account(1));
+		assert_eq!(collections(), vec![(account(1), 0)]);
+		assert_eq!(items(), vec![(account(1), 0, 42)]);
 
-		// Successfully add metadata and take deposit
-		Balances::make_free_balance_be(&account(1), 30);
-		assert_ok!(Nfts::set_collection_metadata(
+		// validate minting start and end settings
+		assert_ok!(Nfts::update_mint_settings(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			bvec![0u8; 20]
+			MintSettings {
+				start_block: Some(2),
+				end_block: Some(3),
+				mint_type: MintType::Public,
+				..Default::default()
+			}
 		));
-		assert_eq!(Balances::free_balance(&account(1)), 9);
-		assert!(CollectionMetadataOf::<Test>::contains_key(0));
 
-		// Force origin works, too.
-		assert_ok!(Nfts::set_collection_metadata(RuntimeOrigin::root(), 0, bvec![0u8; 18]));
+		System::set_block_number(1);
+		assert_noop!(
+			Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 43, account(1), None),
+			Error::<Test>::MintNotStarted
+		);
+		System::set_block_number(4);
+		assert_noop!(
+			Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 43, account(1), None),
+			Error::<Test>::MintEnded
+		);
 
-		// Update deposit
-		assert_ok!(Nfts::set_collection_metadata(
+		// validate price
+		assert_ok!(Nfts::update_mint_settings(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			bvec![0u8; 15]
+			MintSettings { mint_type: MintType::Public, price: Some(1), ..Default::default() }
 		));
-		assert_eq!(Balances::free_balance(&account(1)), 14);
-		assert_ok!(Nfts::set_collection_metadata(
-			RuntimeOrigin::signed(account(1)),
-			0,
-			bvec![0u8; 25]
-		));
-		assert_eq!(Balances::free_balance(&account(1)), 4);
-
-		// Cannot over-reserve
-		assert_noop!(
-			Nfts::set_collection_metadata(RuntimeOrigin::signed(account(1)), 0, bvec![0u8; 40]),
-			BalancesError::<Test, _>::InsufficientBalance,
-		);
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 43, account(2), None));
+		assert_eq!(Balances::total_balance(&account(2)), 99);
+		assert!(events().contains(&Event::<Test>::Issued {
+			collection: 0,
+			item: 43,
+			owner: account(2),
+			price: Some(1),
+		}));
 
-		// Can't set or clear metadata once frozen
-		assert_ok!(Nfts::set_collection_metadata(
-			RuntimeOrigin::signed(account(1)),
-			0,
-			bvec![0u8; 15]
+		// validate types
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
 		));
-		assert_ok!(Nfts::lock_collection(
+		assert_ok!(Nfts::update_mint_settings(
 			RuntimeOrigin::signed(account(1)),
-			0,
-			CollectionSettings::from_disabled(CollectionSetting::UnlockedMetadata.into())
+			1,
+			MintSettings { mint_type: MintType::HolderOf(0), ..Default::default() }
 		));
 		assert_noop!(
-			Nfts::set_collection_metadata(RuntimeOrigin::signed(account(1)), 0, bvec![0u8; 15]),
-			Error::<Test, _>::LockedCollectionMetadata,
-		);
-		assert_noop!(
-			Nfts::clear_collection_metadata(RuntimeOrigin::signed(account(1)), 0),
-			Error::<Test>::LockedCollectionMetadata
+			Nfts::mint(RuntimeOrigin::signed(account(3)), 1, 42, account(3), None),
+			Error::<Test>::BadWitness
 		);
-
-		// Clear Metadata
-		assert_ok!(Nfts::set_collection_metadata(RuntimeOrigin::root(), 0, bvec![0u8; 15]));
 		assert_noop!(
-			Nfts::clear_collection_metadata(RuntimeOrigin::signed(account(2)), 0),
-			Error::<Test>::NoPermission
+			Nfts::mint(RuntimeOrigin::signed(account(2)), 1, 42, account(2), None),
+			Error::<Test>::BadWitness
 		);
 		assert_noop!(
-			Nfts::clear_collection_metadata(RuntimeOrigin::signed(account(1)), 1),
-			Error::<Test>::NoPermission
+			Nfts::mint(
+				RuntimeOrigin::signed(account(2)),
+				1,
+				42,
+				account(2),
+				Some(MintWitness { owned_item: 42 })
+			),
+			Error::<Test>::BadWitness
 		);
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(account(2)),
+			1,
+			42,
+			account(2),
+			Some(MintWitness { owned_item: 43 })
+		));
+
+		// can't mint twice
 		assert_noop!(
-			Nfts::clear_collection_metadata(RuntimeOrigin::signed(account(1)), 0),
-			Error::<Test>::LockedCollectionMetadata
+			Nfts::mint(
+				RuntimeOrigin::signed(account(2)),
+				1,
+				46,
+				account(2),
+				Some(MintWitness { owned_item: 43 })
+			),
+			Error::<Test>::AlreadyClaimed
 		);
-		assert_ok!(Nfts::clear_collection_metadata(RuntimeOrigin::root(), 0));
-		assert!(!CollectionMetadataOf::<Test>::contains_key(0));
 	});
 }
 
 #[test]
-fn set_item_metadata_should_work() {
+fn mint_to_another_account_should_work() {
 	new_test_ext().execute_with(|| {
-		Balances::make_free_balance_be(&account(1), 30);
-
-		// Cannot add metadata to unknown item
+		Balances::make_free_balance_be(&account(2), 100);
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
-			collection_config_with_all_settings_enabled()
+			default_collection_config()
+		));
+		assert_ok!(Nfts::update_mint_settings(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			MintSettings { mint_type: MintType::Public, ..Default::default() }
 		));
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
-		// Cannot add metadata to unowned item
-		assert_noop!(
-			Nfts::set_metadata(RuntimeOrigin::signed(account(2)), 0, 42, bvec![0u8; 20]),
-			Error::<Test>::NoPermission,
-		);
-
-		// Successfully add metadata and take deposit
-		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 20]));
-		assert_eq!(Balances::free_balance(&account(1)), 8);
-		assert!(ItemMetadataOf::<Test>::contains_key(0, 42));
 
-		// Force origin works, too.
-		assert_ok!(Nfts::set_metadata(RuntimeOrigin::root(), 0, 42, bvec![0u8; 18]));
+		// without a price, nothing is paid
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 42, account(3), None));
+		assert_eq!(Nfts::owner(0, 42), Some(account(3)));
+		assert_eq!(Balances::total_balance(&account(2)), 100);
+		assert!(events().contains(&Event::<Test>::Issued {
+			collection: 0,
+			item: 42,
+			owner: account(3),
+			price: None,
+		}));
 
-		// Update deposit
-		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 15]));
-		assert_eq!(Balances::free_balance(&account(1)), 13);
-		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 25]));
-		assert_eq!(Balances::free_balance(&account(1)), 3);
+		// with a price, the sender pays it
+		assert_ok!(Nfts::update_mint_settings(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			MintSettings { mint_type: MintType::Public, price: Some(10), ..Default::default() }
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 43, account(3), None));
+		assert_eq!(Nfts::owner(0, 43), Some(account(3)));
+		assert_eq!(Balances::total_balance(&account(2)), 90);
+		assert_eq!(Balances::total_balance(&account(1)), 10);
+		assert_eq!(Balances::total_balance(&account(3)), 0);
+		assert!(events().contains(&Event::<Test>::Issued {
+			collection: 0,
+			item: 43,
+			owner: account(3),
+			price: Some(10),
+		}));
 
-		// Cannot over-reserve
+		// the witness of `HolderOf` is checked against the sender
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::update_mint_settings(
+			RuntimeOrigin::signed(account(1)),
+			1,
+			MintSettings { mint_type: MintType::HolderOf(0), ..Default::default() }
+		));
 		assert_noop!(
-			Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 40]),
-			BalancesError::<Test, _>::InsufficientBalance,
+			Nfts::mint(
+				RuntimeOrigin::signed(account(2)),
+				1,
+				42,
+				account(3),
+				Some(MintWitness { owned_item: 42 })
+			),
+			Error::<Test>::BadWitness
 		);
-
-		// Can't set or clear metadata once frozen
-		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 15]));
-		assert_ok!(Nfts::lock_item_properties(
-			RuntimeOrigin::signed(account(1)),
-			0,
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(account(3)),
+			1,
 			42,
-			true,
-			false
+			account(2),
+			Some(MintWitness { owned_item: 42 })
+		));
+		assert_eq!(Nfts::owner(1, 42), Some(account(2)));
+	});
+}
+
+#[test]
+fn mint_pause_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
 		));
+		let mint_settings = MintSettings {
+			start_block: Some(2),
+			end_block: Some(10),
+			mint_type: MintType::Public,
+			..Default::default()
+		};
+		assert_ok!(Nfts::update_mint_settings(RuntimeOrigin::signed(account(1)), 0, mint_settings));
+
+		System::set_block_number(3);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 42, account(2), None));
+
+		// only the issuer or the force origin can pause the minting
 		assert_noop!(
-			Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 15]),
-			Error::<Test, _>::LockedItemMetadata,
+			Nfts::set_mint_paused(RuntimeOrigin::signed(account(2)), 0, true),
+			Error::<Test>::NoPermission
 		);
 		assert_noop!(
-			Nfts::clear_metadata(RuntimeOrigin::signed(account(1)), 0, 42),
-			Error::<Test>::LockedItemMetadata,
+			Nfts::set_mint_paused(RuntimeOrigin::root(), 1, true),
+			Error::<Test>::UnknownCollection
+		);
+		assert_ok!(Nfts::set_mint_paused(RuntimeOrigin::signed(account(1)), 0, true));
+		assert!(
+			events().contains(&Event::<Test>::CollectionMintPaused { collection: 0, paused: true })
 		);
 
-		// Clear Metadata
-		assert_ok!(Nfts::set_metadata(RuntimeOrigin::root(), 0, 42, bvec![0u8; 15]));
+		System::set_block_number(4);
 		assert_noop!(
-			Nfts::clear_metadata(RuntimeOrigin::signed(account(2)), 0, 42),
-			Error::<Test>::NoPermission,
+			Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 43, account(2), None),
+			Error::<Test>::MintPaused
 		);
+		// the minting window is kept
+		assert_eq!(CollectionConfigOf::<Test>::get(0).unwrap().mint_settings, mint_settings);
+
+		assert_ok!(Nfts::set_mint_paused(RuntimeOrigin::root(), 0, false));
+		assert!(events()
+			.contains(&Event::<Test>::CollectionMintPaused { collection: 0, paused: false }));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 43, account(2), None));
+
+		// the window still applies after resuming
+		System::set_block_number(11);
 		assert_noop!(
-			Nfts::clear_metadata(RuntimeOrigin::signed(account(1)), 1, 42),
-			Error::<Test>::NoPermission,
+			Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 44, account(2), None),
+			Error::<Test>::MintEnded
 		);
-		assert_ok!(Nfts::clear_metadata(RuntimeOrigin::root(), 0, 42));
-		assert!(!ItemMetadataOf::<Test>::contains_key(0, 42));
 	});
 }
 
 #[test]
-fn set_collection_owner_attributes_should_work() {
+fn item_mint_block_should_work() {
 	new_test_ext().execute_with(|| {
-		Balances::make_free_balance_be(&account(1), 100);
-
+		System::set_block_number(5);
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
-			collection_config_with_all_settings_enabled()
+			default_collection_config()
 		));
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		assert_eq!(Nfts::item_mint_block(0, 42), None);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_eq!(Nfts::item_mint_block(0, 42), Some(5));
 
-		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(1)),
-			0,
-			None,
-			AttributeNamespace::CollectionOwner,
-			bvec![0],
-			bvec![0],
+		// the mint block is kept across transfers
+		System::set_block_number(8);
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
+		assert_eq!(Nfts::item_mint_block(0, 42), Some(5));
+
+		// and cleared on burn
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(3)), 0, 42));
+		assert_eq!(Nfts::item_mint_block(0, 42), None);
+
+		// a minted again item gets the current block
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_eq!(Nfts::item_mint_block(0, 42), Some(8));
+	});
+}
+
+#[test]
+fn collection_stats_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(Nfts::collection_stats(0), None);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			CollectionConfig {
+				max_supply: Some(2),
+				mint_settings: MintSettings {
+					mint_type: MintType::Public,
+					start_block: Some(2),
+					end_block: Some(10),
+					..Default::default()
+				},
+				..default_collection_config()
+			}
 		));
+		let stats = |items, item_metadatas, attributes, mint_open| CollectionStats {
+			owner: account(1),
+			items,
+			item_metadatas,
+			attributes,
+			mint_open,
+		};
+		// the mint window hasn't opened yet
+		assert_eq!(Nfts::collection_stats(0), Some(stats(0, 0, 0, false)));
+
+		// mid-mint
+		System::set_block_number(2);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 42, account(2), None));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![42]));
 		assert_ok!(Nfts::set_attribute(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			Some(0),
+			Some(42),
 			AttributeNamespace::CollectionOwner,
 			bvec![0],
 			bvec![0],
 		));
-		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(1)),
-			0,
-			Some(0),
-			AttributeNamespace::CollectionOwner,
-			bvec![1],
-			bvec![0],
+		assert_eq!(Nfts::collection_stats(0), Some(stats(1, 1, 1, true)));
+
+		assert_ok!(Nfts::set_mint_paused(RuntimeOrigin::signed(account(1)), 0, true));
+		assert_eq!(Nfts::collection_stats(0), Some(stats(1, 1, 1, false)));
+		assert_ok!(Nfts::set_mint_paused(RuntimeOrigin::signed(account(1)), 0, false));
+
+		// the max supply is hit
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(3)), 0, 43, account(3), None));
+		assert_eq!(Nfts::collection_stats(0), Some(stats(2, 1, 1, false)));
+
+		// the mint window has closed
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(3)), 0, 43));
+		assert_eq!(Nfts::collection_stats(0), Some(stats(1, 1, 1, true)));
+		System::set_block_number(11);
+		assert_eq!(Nfts::collection_stats(0), Some(stats(1, 1, 1, false)));
+	});
+}
+
+#[test]
+fn collections_should_page_through_all_collections() {
+	new_test_ext().execute_with(|| {
+		assert!(Nfts::collections(None, 10).is_empty());
+
+		for owner in 1..=5 {
+			assert_ok!(Nfts::force_create(
+				RuntimeOrigin::root(),
+				account(owner),
+				default_collection_config()
+			));
+		}
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::root(),
+			2,
+			42,
+			account(1),
+			default_item_config()
 		));
-		assert_eq!(
-			attributes(0),
-			vec![
-				(None, AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
-				(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
-				(Some(0), AttributeNamespace::CollectionOwner, bvec![1], bvec![0]),
-			]
-		);
-		assert_eq!(Balances::reserved_balance(account(1)), 10);
-		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 9);
 
-		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(1)),
+		let mut pages = vec![];
+		let mut start = None;
+		loop {
+			let page = Nfts::collections(start, 2);
+			assert!(page.len() <= 2);
+			start = page.last().map(|(collection, _)| *collection);
+			let last_page = page.len() < 2;
+			pages.push(page);
+			if last_page {
+				break
+			}
+		}
+		assert_eq!(pages.iter().map(|page| page.len()).collect::<Vec<_>>(), vec![2, 2, 1]);
+
+		let listed = pages.into_iter().flatten().collect::<Vec<_>>();
+		let mut ids = listed.iter().map(|(collection, _)| *collection).collect::<Vec<_>>();
+		ids.sort();
+		assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+		for (collection, stats) in listed {
+			assert_eq!(Some(stats), Nfts::collection_stats(collection));
+		}
+		assert_eq!(Nfts::collection_stats(2).unwrap().items, 1);
+
+		// A page starting after the last collection is empty.
+		let all = Nfts::collections(None, 10);
+		assert_eq!(all.len(), 5);
+		assert!(Nfts::collections(Some(all[4].0), 10).is_empty());
+		assert_eq!(Nfts::collections(Some(all[1].0), 10), all[2..].to_vec());
+		assert!(Nfts::collections(None, 0).is_empty());
+
+		// The cursor still advances if its collection has since been destroyed.
+		let destroyed = all[1].0;
+		assert_ok!(Nfts::force_destroy(RuntimeOrigin::root(), destroyed, 10));
+		assert_eq!(Nfts::collections(Some(destroyed), 10), all[2..].to_vec());
+	});
+}
+
+#[test]
+fn item_price_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 43, account(2), None));
+		assert_eq!(Nfts::item_price(0, 42), None);
+		assert_eq!(Nfts::item_price(0, 44), None);
+
+		assert_ok!(Nfts::set_price(RuntimeOrigin::signed(account(2)), 0, 42, Some(5), None, None));
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(account(2)),
 			0,
-			None,
-			AttributeNamespace::CollectionOwner,
-			bvec![0],
-			bvec![0; 10],
+			43,
+			Some(7),
+			Some(account(3)),
+			Some(3),
 		));
-		assert_eq!(
-			attributes(0),
-			vec![
-				(None, AttributeNamespace::CollectionOwner, bvec![0], bvec![0; 10]),
-				(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
-				(Some(0), AttributeNamespace::CollectionOwner, bvec![1], bvec![0]),
-			]
-		);
-		assert_eq!(Balances::reserved_balance(account(1)), 19);
-		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 18);
+		assert_eq!(Nfts::item_price(0, 42), Some((5, None)));
+		assert_eq!(Nfts::item_price(0, 43), Some((7, Some(account(3)))));
 
-		assert_ok!(Nfts::clear_attribute(
+		// an expired listing can't be bought
+		System::set_block_number(4);
+		assert_eq!(Nfts::item_price(0, 43), None);
+
+		assert_ok!(Nfts::set_price(RuntimeOrigin::signed(account(2)), 0, 42, None, None, None));
+		assert_eq!(Nfts::item_price(0, 42), None);
+	});
+}
+
+#[test]
+fn metadata_getters_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_eq!(Nfts::collection_metadata(0), None);
+		assert_eq!(Nfts::item_metadata(0, 42), None);
+		assert_eq!(Nfts::collection_metadata(1), None);
+		assert_eq!(Nfts::item_metadata(0, 43), None);
+
+		assert_ok!(Nfts::set_collection_metadata(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			Some(0),
-			AttributeNamespace::CollectionOwner,
-			bvec![1],
+			bvec![1, 2]
 		));
-		assert_eq!(
-			attributes(0),
-			vec![
-				(None, AttributeNamespace::CollectionOwner, bvec![0], bvec![0; 10]),
-				(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
-			]
-		);
-		assert_eq!(Balances::reserved_balance(account(1)), 16);
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![3, 4, 5]));
+		assert_eq!(Nfts::collection_metadata(0), Some(vec![1, 2]));
+		assert_eq!(Nfts::item_metadata(0, 42), Some(vec![3, 4, 5]));
 
-		assert_ok!(Nfts::burn(RuntimeOrigin::root(), 0, 0));
-		let w = Nfts::get_destroy_witness(&0).unwrap();
-		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(account(1)), 0, w));
-		assert_eq!(attributes(0), vec![]);
-		assert_eq!(Balances::reserved_balance(account(1)), 0);
+		assert_ok!(Nfts::clear_collection_metadata(RuntimeOrigin::signed(account(1)), 0));
+		assert_ok!(Nfts::clear_metadata(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert_eq!(Nfts::collection_metadata(0), None);
+		assert_eq!(Nfts::item_metadata(0, 42), None);
 	});
 }
 
 #[test]
-fn set_item_owner_attributes_should_work() {
+fn transfer_should_work() {
 	new_test_ext().execute_with(|| {
-		Balances::make_free_balance_be(&account(1), 100);
-		Balances::make_free_balance_be(&account(2), 100);
-		Balances::make_free_balance_be(&account(3), 100);
-
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
-			collection_config_with_all_settings_enabled()
+			default_collection_config()
 		));
 		assert_ok!(Nfts::force_mint(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			0,
+			42,
 			account(2),
 			default_item_config()
 		));
 
-		// can't set for the collection
-		assert_noop!(
-			Nfts::set_attribute(
-				RuntimeOrigin::signed(account(2)),
-				0,
-				None,
-				AttributeNamespace::ItemOwner,
-				bvec![0],
-				bvec![0],
-			),
-			Error::<Test>::NoPermission,
-		);
-		// can't set for the non-owned item
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
+		assert_eq!(items(), vec![(account(3), 0, 42)]);
 		assert_noop!(
-			Nfts::set_attribute(
-				RuntimeOrigin::signed(account(1)),
-				0,
-				Some(0),
-				AttributeNamespace::ItemOwner,
-				bvec![0],
-				bvec![0],
-			),
-			Error::<Test>::NoPermission,
+			Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(4)),
+			Error::<Test>::NoPermission
 		);
-		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(2)),
-			0,
-			Some(0),
-			AttributeNamespace::ItemOwner,
-			bvec![0],
-			bvec![0],
-		));
-		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(2)),
+
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(3)),
 			0,
-			Some(0),
-			AttributeNamespace::ItemOwner,
-			bvec![1],
-			bvec![0],
+			42,
+			account(2),
+			None
 		));
-		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(2)),
-			0,
-			Some(0),
-			AttributeNamespace::ItemOwner,
-			bvec![2],
-			bvec![0],
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(4)));
+
+		// validate we can't transfer non-transferable items
+		let collection_id = 1;
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_from_disabled_settings(
+				CollectionSetting::TransferableItems | CollectionSetting::DepositRequired
+			)
 		));
-		assert_eq!(
-			attributes(0),
-			vec![
-				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![1], bvec![0]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0]),
-			]
-		);
-		assert_eq!(Balances::reserved_balance(account(2)), 9);
 
-		// validate an attribute can be updated
-		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(2)),
-			0,
-			Some(0),
-			AttributeNamespace::ItemOwner,
-			bvec![0],
-			bvec![0; 10],
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			1,
+			1,
+			account(42),
+			default_item_config()
 		));
-		assert_eq!(
-			attributes(0),
-			vec![
-				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 10]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![1], bvec![0]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0]),
-			]
-		);
-		assert_eq!(Balances::reserved_balance(account(2)), 18);
 
-		// validate only item's owner (or the root) can remove an attribute
 		assert_noop!(
-			Nfts::clear_attribute(
+			Nfts::transfer(RuntimeOrigin::signed(account(1)), collection_id, 42, account(3)),
+			Error::<Test>::ItemsNonTransferable
+		);
+	});
+}
+
+#[test]
+fn force_transfer_all_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		for (item, owner) in [(1, 2), (2, 2), (3, 3), (4, 3), (5, 9)] {
+			assert_ok!(Nfts::force_mint(
 				RuntimeOrigin::signed(account(1)),
 				0,
-				Some(0),
-				AttributeNamespace::ItemOwner,
-				bvec![1],
-			),
-			Error::<Test>::NoPermission,
+				item,
+				account(owner),
+				default_item_config()
+			));
+		}
+		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(1)), 0, 3));
+		assert_ok!(Nfts::set_price(RuntimeOrigin::signed(account(2)), 0, 1, Some(1), None, None));
+
+		assert_noop!(
+			Nfts::force_transfer_all(RuntimeOrigin::signed(account(1)), 0, account(9), 10),
+			DispatchError::BadOrigin
 		);
-		assert_ok!(Nfts::clear_attribute(
-			RuntimeOrigin::signed(account(2)),
-			0,
-			Some(0),
-			AttributeNamespace::ItemOwner,
-			bvec![1],
-		));
-		assert_eq!(
-			attributes(0),
-			vec![
-				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 10]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0])
-			]
+		assert_noop!(
+			Nfts::force_transfer_all(RuntimeOrigin::root(), 1, account(9), 10),
+			Error::<Test>::UnknownCollection
 		);
-		assert_eq!(Balances::reserved_balance(account(2)), 15);
+		events();
 
-		// transfer item
-		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 0, account(3)));
+		let transferred = |events: &Vec<Event<Test>>| {
+			events
+				.iter()
+				.filter(|e| matches!(e, Event::Transferred { to, .. } if *to == account(9)))
+				.count()
+		};
+		assert_ok!(Nfts::force_transfer_all(RuntimeOrigin::root(), 0, account(9), 2));
+		let mut all_events = events();
+		assert_eq!(transferred(&all_events), 2);
+		let owned_by_target = || items().into_iter().filter(|i| i.0 == account(9)).count();
+		assert_eq!(owned_by_target(), 3);
+
+		assert_ok!(Nfts::force_transfer_all(RuntimeOrigin::root(), 0, account(9), 2));
+		let second_events = events();
+		assert_eq!(transferred(&second_events), 2);
+		assert_eq!(owned_by_target(), 5);
+
+		// the listing of the previous owner was withdrawn along the way
+		all_events.extend(second_events);
+		assert_eq!(all_events.len(), 5);
+		assert!(all_events.contains(&Event::ItemPriceRemoved { collection: 0, item: 1 }));
+		assert!(!ItemPriceOf::<Test>::contains_key(0, 1));
+
+		// nothing is left to transfer
+		assert_ok!(Nfts::force_transfer_all(RuntimeOrigin::root(), 0, account(9), 2));
+		assert!(events().is_empty());
+		assert_eq!(items(), (1..=5).map(|item| (account(9), 0, item)).collect::<Vec<_>>());
+	});
+}
 
-		// validate the attribute are still here & the deposit belongs to the previous owner
-		assert_eq!(
-			attributes(0),
-			vec![
-				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 10]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0])
-			]
+#[test]
+fn origin_free_helpers_should_enforce_settings() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::set_collection_max_supply(RuntimeOrigin::signed(account(1)), 0, 2));
+
+		// mint
+		assert_ok!(Nfts::do_mint(0, 42, None, account(2), default_item_config(), |_, _| Ok(None)));
+		assert_eq!(items(), vec![(account(2), 0, 42)]);
+		assert_noop!(
+			Nfts::do_mint(0, 42, None, account(2), default_item_config(), |_, _| Ok(None)),
+			Error::<Test>::AlreadyExists
+		);
+		assert_ok!(Nfts::do_mint(0, 43, None, account(2), default_item_config(), |_, _| Ok(None)));
+		assert_noop!(
+			Nfts::do_mint(0, 44, None, account(2), default_item_config(), |_, _| Ok(None)),
+			Error::<Test>::MaxSupplyReached
 		);
-		let key: BoundedVec<_, _> = bvec![0];
-		let (_, deposit) =
-			Attribute::<Test>::get((0, Some(0), AttributeNamespace::ItemOwner, &key)).unwrap();
-		assert_eq!(deposit.account, Some(account(2)));
-		assert_eq!(deposit.amount, 12);
 
-		// on attribute update the deposit should be returned to the previous owner
-		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(3)),
+		// transfer
+		assert_ok!(Nfts::do_transfer(0, 42, account(3), |_, _| Ok(())));
+		assert_eq!(items(), vec![(account(2), 0, 43), (account(3), 0, 42)]);
+		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert_noop!(
+			Nfts::do_transfer(0, 42, account(2), |_, _| Ok(())),
+			Error::<Test>::ItemLocked
+		);
+		assert_ok!(Nfts::unlock_item_transfer(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert_ok!(Nfts::lock_item_transfer_by_owner(RuntimeOrigin::signed(account(3)), 0, 42));
+		assert_noop!(
+			Nfts::do_transfer(0, 42, account(2), |_, _| Ok(())),
+			Error::<Test>::ItemLockedByOwner
+		);
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(account(1)),
 			0,
-			Some(0),
-			AttributeNamespace::ItemOwner,
-			bvec![0],
-			bvec![0; 11],
+			CollectionSettings::from_disabled(CollectionSetting::TransferableItems.into())
 		));
-		let (_, deposit) =
-			Attribute::<Test>::get((0, Some(0), AttributeNamespace::ItemOwner, &key)).unwrap();
-		assert_eq!(deposit.account, Some(account(3)));
-		assert_eq!(deposit.amount, 13);
-		assert_eq!(Balances::reserved_balance(account(2)), 3);
-		assert_eq!(Balances::reserved_balance(account(3)), 13);
-
-		// validate attributes on item deletion
-		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(3)), 0, 0));
-		assert_eq!(
-			attributes(0),
-			vec![
-				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 11]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0])
-			]
+		assert_noop!(
+			Nfts::do_transfer(0, 43, account(3), |_, _| Ok(())),
+			Error::<Test>::ItemsNonTransferable
 		);
-		assert_ok!(Nfts::clear_attribute(
-			RuntimeOrigin::signed(account(3)),
-			0,
-			Some(0),
-			AttributeNamespace::ItemOwner,
-			bvec![0],
-		));
-		assert_ok!(Nfts::clear_attribute(
-			RuntimeOrigin::signed(account(2)),
-			0,
-			Some(0),
-			AttributeNamespace::ItemOwner,
-			bvec![2],
-		));
-		assert_eq!(Balances::reserved_balance(account(2)), 0);
-		assert_eq!(Balances::reserved_balance(account(3)), 0);
+
+		// burn
+		assert_ok!(Nfts::do_burn(0, 42, |_| Ok(())));
+		assert_ok!(Nfts::do_burn(0, 43, |_| Ok(())));
+		assert!(items().is_empty());
+		assert_noop!(Nfts::do_burn(0, 43, |_| Ok(())), Error::<Test>::UnknownItem);
 	});
 }
 
 #[test]
-fn set_external_account_attributes_should_work() {
+fn settings_helpers_should_work() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&account(1), 100);
-		Balances::make_free_balance_be(&account(2), 100);
+		assert!(Nfts::collection_config(0).is_none());
+		assert!(Nfts::item_config(0, 42).is_none());
 
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
 			collection_config_with_all_settings_enabled()
 		));
-		assert_ok!(Nfts::force_mint(
+		assert_eq!(Nfts::collection_config(0), Some(default_collection_config()));
+		assert_eq!(
+			CollectionSettingsInfo::from(Nfts::collection_config(0).unwrap().settings),
+			CollectionSettingsInfo {
+				transferable_items: true,
+				metadata_locked: false,
+				attributes_locked: false,
+				max_supply_locked: false,
+				deposit_required: false,
+				restricted_buyers: false,
+			}
+		);
+
+		assert_ok!(Nfts::lock_collection(
 			RuntimeOrigin::signed(account(1)),
-			0,
-			0,
-			account(1),
-			default_item_config()
+			1,
+			CollectionSettings::from_disabled(
+				CollectionSetting::TransferableItems | CollectionSetting::UnlockedMetadata
+			)
 		));
-		assert_ok!(Nfts::approve_item_attributes(
+		assert_eq!(
+			CollectionSettingsInfo::from(Nfts::collection_config(1).unwrap().settings),
+			CollectionSettingsInfo {
+				transferable_items: false,
+				metadata_locked: true,
+				attributes_locked: false,
+				max_supply_locked: false,
+				deposit_required: true,
+				restricted_buyers: false,
+			}
+		);
+		assert_ok!(Nfts::lock_collection(
 			RuntimeOrigin::signed(account(1)),
-			0,
-			0,
-			account(2)
+			1,
+			CollectionSettings::from_disabled(
+				CollectionSetting::UnlockedAttributes | CollectionSetting::UnlockedMaxSupply
+			)
 		));
+		assert_eq!(
+			CollectionSettingsInfo::from(Nfts::collection_config(1).unwrap().settings),
+			CollectionSettingsInfo {
+				transferable_items: false,
+				metadata_locked: true,
+				attributes_locked: true,
+				max_supply_locked: true,
+				deposit_required: true,
+				restricted_buyers: false,
+			}
+		);
 
-		assert_noop!(
-			Nfts::set_attribute(
-				RuntimeOrigin::signed(account(2)),
-				0,
-				Some(0),
-				AttributeNamespace::Account(account(1)),
-				bvec![0],
-				bvec![0],
-			),
-			Error::<Test>::NoPermission,
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_eq!(Nfts::item_config(0, 42), Some(default_item_config()));
+		assert_eq!(
+			ItemSettingsInfo::from(Nfts::item_config(0, 42).unwrap().settings),
+			ItemSettingsInfo {
+				transferable: true,
+				metadata_locked: false,
+				attributes_locked: false,
+				locked_by_owner: false,
+			}
 		);
-		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(2)),
-			0,
-			Some(0),
-			AttributeNamespace::Account(account(2)),
-			bvec![0],
-			bvec![0],
-		));
-		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(2)),
+
+		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert_ok!(Nfts::lock_item_properties(
+			RuntimeOrigin::signed(account(1)),
 			0,
-			Some(0),
-			AttributeNamespace::Account(account(2)),
-			bvec![1],
-			bvec![0],
+			42,
+			true,
+			false
 		));
 		assert_eq!(
-			attributes(0),
-			vec![
-				(Some(0), AttributeNamespace::Account(account(2)), bvec![0], bvec![0]),
-				(Some(0), AttributeNamespace::Account(account(2)), bvec![1], bvec![0]),
-			]
+			ItemSettingsInfo::from(Nfts::item_config(0, 42).unwrap().settings),
+			ItemSettingsInfo {
+				transferable: false,
+				metadata_locked: true,
+				attributes_locked: false,
+				locked_by_owner: false,
+			}
 		);
-		assert_eq!(Balances::reserved_balance(account(2)), 6);
 
-		// remove permission to set attributes
-		assert_ok!(Nfts::cancel_item_attributes_approval(
+		assert_ok!(Nfts::unlock_item_transfer(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert_ok!(Nfts::lock_item_transfer_by_owner(RuntimeOrigin::signed(account(2)), 0, 42));
+		assert_ok!(Nfts::lock_item_properties(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			0,
-			account(2),
-			CancelAttributesApprovalWitness { account_attributes: 2 },
+			42,
+			false,
+			true
 		));
-		assert_eq!(attributes(0), vec![]);
-		assert_eq!(Balances::reserved_balance(account(2)), 0);
-		assert_noop!(
-			Nfts::set_attribute(
-				RuntimeOrigin::signed(account(2)),
-				0,
-				Some(0),
-				AttributeNamespace::Account(account(2)),
-				bvec![0],
-				bvec![0],
-			),
-			Error::<Test>::NoPermission,
+		assert_eq!(
+			ItemSettingsInfo::from(Nfts::item_config(0, 42).unwrap().settings),
+			ItemSettingsInfo {
+				transferable: true,
+				metadata_locked: true,
+				attributes_locked: true,
+				locked_by_owner: true,
+			}
 		);
 	});
 }
 
 #[test]
-fn validate_deposit_required_setting() {
+fn lock_item_properties_should_emit_the_resulting_locks() {
 	new_test_ext().execute_with(|| {
-		Balances::make_free_balance_be(&account(1), 100);
-		Balances::make_free_balance_be(&account(2), 100);
-		Balances::make_free_balance_be(&account(3), 100);
-
-		// with the disabled DepositRequired setting, only the collection's owner can set the
-		// attributes for free.
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
 			default_collection_config()
 		));
-		assert_ok!(Nfts::force_mint(
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 43, account(2), None));
+
+		assert_ok!(Nfts::lock_item_properties(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			0,
-			account(2),
-			default_item_config()
-		));
-		assert_ok!(Nfts::approve_item_attributes(
-			RuntimeOrigin::signed(account(2)),
-			0,
-			0,
-			account(3)
+			42,
+			false,
+			true
 		));
+		assert!(events().contains(&Event::<Test>::ItemPropertiesLocked {
+			collection: 0,
+			item: 42,
+			lock_metadata: false,
+			lock_attributes: true,
+		}));
 
-		assert_ok!(Nfts::set_attribute(
+		// locks taken earlier are reported as well
+		assert_ok!(Nfts::lock_item_properties(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			Some(0),
-			AttributeNamespace::CollectionOwner,
-			bvec![0],
-			bvec![0],
-		));
-		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(2)),
-			0,
-			Some(0),
-			AttributeNamespace::ItemOwner,
-			bvec![1],
-			bvec![0],
+			43,
+			true,
+			false
 		));
-		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(3)),
+		assert_ok!(Nfts::lock_item_properties(
+			RuntimeOrigin::signed(account(1)),
 			0,
-			Some(0),
-			AttributeNamespace::Account(account(3)),
-			bvec![2],
-			bvec![0],
-		));
-		assert_ok!(<Nfts as Mutate<<Test as SystemConfig>::AccountId, ItemConfig>>::set_attribute(
-			&0,
-			&0,
-			&[3],
-			&[0],
+			43,
+			false,
+			true
 		));
 		assert_eq!(
-			attributes(0),
-			vec![
-				(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![1], bvec![0]),
-				(Some(0), AttributeNamespace::Account(account(3)), bvec![2], bvec![0]),
-				(Some(0), AttributeNamespace::Pallet, bvec![3], bvec![0]),
-			]
-		);
-		assert_eq!(Balances::reserved_balance(account(1)), 0);
-		assert_eq!(Balances::reserved_balance(account(2)), 3);
-		assert_eq!(Balances::reserved_balance(account(3)), 3);
-
-		assert_ok!(
-			<Nfts as Mutate<<Test as SystemConfig>::AccountId, ItemConfig>>::clear_attribute(
-				&0,
-				&0,
-				&[3],
-			)
-		);
-		assert_eq!(
-			attributes(0),
-			vec![
-				(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
-				(Some(0), AttributeNamespace::ItemOwner, bvec![1], bvec![0]),
-				(Some(0), AttributeNamespace::Account(account(3)), bvec![2], bvec![0]),
-			]
+			events().last(),
+			Some(&Event::<Test>::ItemPropertiesLocked {
+				collection: 0,
+				item: 43,
+				lock_metadata: true,
+				lock_attributes: true,
+			})
 		);
 	});
 }
 
 #[test]
-fn set_attribute_should_respect_lock() {
+fn attribute_helpers_should_work() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&account(1), 100);
-
+		Balances::make_free_balance_be(&account(2), 100);
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
-			collection_config_with_all_settings_enabled(),
+			default_collection_config()
 		));
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 1, account(1), None));
-
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
 		assert_ok!(Nfts::set_attribute(
 			RuntimeOrigin::signed(account(1)),
 			0,
 			None,
 			AttributeNamespace::CollectionOwner,
 			bvec![0],
-			bvec![0],
+			bvec![10],
 		));
 		assert_ok!(Nfts::set_attribute(
 			RuntimeOrigin::signed(account(1)),
 			0,
 			Some(0),
 			AttributeNamespace::CollectionOwner,
-			bvec![0],
-			bvec![0],
+			bvec![1],
+			bvec![11],
 		));
+		// other namespaces aren't looked up
 		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(1)),
-			0,
-			Some(1),
-			AttributeNamespace::CollectionOwner,
-			bvec![0],
-			bvec![0],
-		));
-		assert_eq!(
-			attributes(0),
-			vec![
-				(None, AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
-				(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
-				(Some(1), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
-			]
-		);
-		assert_eq!(Balances::reserved_balance(account(1)), 11);
-
-		assert_ok!(Nfts::set_collection_metadata(RuntimeOrigin::signed(account(1)), 0, bvec![]));
-		assert_ok!(Nfts::lock_collection(
-			RuntimeOrigin::signed(account(1)),
+			RuntimeOrigin::signed(account(2)),
 			0,
-			CollectionSettings::from_disabled(CollectionSetting::UnlockedAttributes.into())
-		));
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![2],
+			bvec![12],
+		));
+
+		assert_eq!(Nfts::attribute(0, None, &[0]), Some(vec![10]));
+		assert!(Nfts::has_attribute(0, None, &[0]));
+		assert_eq!(Nfts::attribute(0, Some(0), &[1]), Some(vec![11]));
+		assert!(Nfts::has_attribute(0, Some(0), &[1]));
+
+		// the scope must match
+		assert_eq!(Nfts::attribute(0, Some(0), &[0]), None);
+		assert!(!Nfts::has_attribute(0, Some(0), &[0]));
+		assert_eq!(Nfts::attribute(0, None, &[1]), None);
+		assert!(!Nfts::has_attribute(0, None, &[1]));
+
+		assert_eq!(Nfts::attribute(0, Some(0), &[2]), None);
+		assert!(!Nfts::has_attribute(0, Some(0), &[2]));
+		assert_eq!(Nfts::attribute(1, None, &[0]), None);
+		assert!(!Nfts::has_attribute(0, Some(1), &[1]));
+		let too_long = [0u8; 51];
+		assert_eq!(Nfts::attribute(0, None, &too_long), None);
+		assert!(!Nfts::has_attribute(0, None, &too_long));
+	});
+}
 
-		let e = Error::<Test>::LockedCollectionAttributes;
+#[test]
+fn locking_transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(1)), 0, 42));
 		assert_noop!(
-			Nfts::set_attribute(
-				RuntimeOrigin::signed(account(1)),
-				0,
-				None,
-				AttributeNamespace::CollectionOwner,
-				bvec![0],
-				bvec![0],
-			),
-			e
+			Nfts::transfer(RuntimeOrigin::signed(account(1)), 0, 42, account(2)),
+			Error::<Test>::ItemLocked
 		);
-		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(1)),
-			0,
-			Some(0),
-			AttributeNamespace::CollectionOwner,
-			bvec![0],
-			bvec![1],
-		));
 
-		assert_ok!(Nfts::lock_item_properties(
+		assert_ok!(Nfts::unlock_item_transfer(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert_ok!(Nfts::lock_collection(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			0,
-			false,
-			true
+			CollectionSettings::from_disabled(CollectionSetting::TransferableItems.into())
 		));
-		let e = Error::<Test>::LockedItemAttributes;
 		assert_noop!(
-			Nfts::set_attribute(
-				RuntimeOrigin::signed(account(1)),
-				0,
-				Some(0),
-				AttributeNamespace::CollectionOwner,
-				bvec![0],
-				bvec![1],
-			),
-			e
+			Nfts::transfer(RuntimeOrigin::signed(account(1)), 0, 42, account(2)),
+			Error::<Test>::ItemsNonTransferable
 		);
-		assert_ok!(Nfts::set_attribute(
-			RuntimeOrigin::signed(account(1)),
+
+		assert_ok!(Nfts::force_collection_config(
+			RuntimeOrigin::root(),
 			0,
-			Some(1),
-			AttributeNamespace::CollectionOwner,
-			bvec![0],
-			bvec![1],
+			collection_config_with_all_settings_enabled(),
 		));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(1)), 0, 42, account(2)));
 	});
 }
 
 #[test]
-fn preserve_config_for_frozen_items() {
+fn locking_transfer_by_owner_should_work() {
 	new_test_ext().execute_with(|| {
-		Balances::make_free_balance_be(&account(1), 100);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let collection_id = 0;
+		let item_id = 42;
+
+		Balances::make_free_balance_be(&user_1, 100);
+		Balances::make_free_balance_be(&user_2, 100);
 
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
-			account(1),
-			collection_config_with_all_settings_enabled()
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_2.clone(),
+			None
 		));
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 1, account(1), None));
 
-		// if the item is not locked/frozen then the config gets deleted on item burn
-		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 1));
-		assert!(!ItemConfigOf::<Test>::contains_key(0, 1));
+		// only the owner can lock the item
+		assert_noop!(
+			Nfts::lock_item_transfer_by_owner(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_id
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::lock_item_transfer_by_owner(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id
+		));
+		assert!(events().contains(&Event::<Test>::ItemTransferLockedByOwner {
+			collection: collection_id,
+			item: item_id,
+		}));
 
-		// lock the item and ensure the config stays unchanged
-		assert_ok!(Nfts::lock_item_properties(RuntimeOrigin::signed(account(1)), 0, 0, true, true));
+		assert_noop!(
+			Nfts::transfer(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_id,
+				user_1.clone()
+			),
+			Error::<Test>::ItemLockedByOwner
+		);
+		assert_noop!(
+			Nfts::set_price(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_id,
+				Some(1),
+				None,
+				None
+			),
+			Error::<Test>::ItemLockedByOwner
+		);
+		assert_noop!(
+			Nfts::create_swap(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_id,
+				collection_id,
+				None,
+				None,
+				1,
+				false
+			),
+			Error::<Test>::ItemLockedByOwner
+		);
 
-		let expect_config = item_config_from_disabled_settings(
-			ItemSetting::UnlockedAttributes | ItemSetting::UnlockedMetadata,
+		// the team can't lift the owner's lock
+		assert_ok!(Nfts::unlock_item_transfer(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id
+		));
+		assert_noop!(
+			Nfts::unlock_item_transfer_by_owner(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_id
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::transfer(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_id,
+				user_1.clone()
+			),
+			Error::<Test>::ItemLockedByOwner
 		);
-		let config = ItemConfigOf::<Test>::get(0, 0).unwrap();
-		assert_eq!(config, expect_config);
 
-		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 0));
-		let config = ItemConfigOf::<Test>::get(0, 0).unwrap();
-		assert_eq!(config, expect_config);
+		assert_ok!(Nfts::unlock_item_transfer_by_owner(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id
+		));
+		assert!(events().contains(&Event::<Test>::ItemTransferUnlockedByOwner {
+			collection: collection_id,
+			item: item_id,
+		}));
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			Some(1),
+			None,
+			None
+		));
 
-		// can't mint with the different config
+		// a listing created before the lock can't be bought while the item is locked
+		assert_ok!(Nfts::lock_item_transfer_by_owner(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id
+		));
 		assert_noop!(
-			Nfts::force_mint(
-				RuntimeOrigin::signed(account(1)),
-				0,
-				0,
-				account(2),
-				default_item_config()
+			Nfts::buy_item(RuntimeOrigin::signed(user_1.clone()), collection_id, item_id, 1),
+			Error::<Test>::ItemLockedByOwner
+		);
+
+		// only the owner is able to unlock the item, bar the force origin
+		assert_noop!(
+			Nfts::unlock_item_transfer_by_owner(RuntimeOrigin::root(), collection_id, item_id),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Nfts::force_unlock_item_transfer_by_owner(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_id
 			),
-			Error::<Test>::InconsistentItemConfig
+			DispatchError::BadOrigin
 		);
+		assert_ok!(Nfts::unlock_item_transfer_by_owner(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id
+		));
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			1
+		));
+		assert_eq!(items(), vec![(user_1.clone(), collection_id, item_id)]);
 
-		assert_ok!(Nfts::update_mint_settings(
-			RuntimeOrigin::signed(account(1)),
-			0,
-			MintSettings {
-				default_item_settings: ItemSettings::from_disabled(
-					ItemSetting::UnlockedAttributes | ItemSetting::UnlockedMetadata
-				),
-				..Default::default()
-			}
+		// the force origin can lift the owner's lock without them
+		assert_ok!(Nfts::lock_item_transfer_by_owner(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id
 		));
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		assert_ok!(Nfts::force_unlock_item_transfer_by_owner(
+			RuntimeOrigin::root(),
+			collection_id,
+			item_id
+		));
+		assert!(events().contains(&Event::<Test>::ItemTransferUnlockedByOwner {
+			collection: collection_id,
+			item: item_id
+		}));
+		assert!(Nfts::is_transferable(collection_id, item_id));
+
+		// the lock doesn't outlive the item
+		assert_ok!(Nfts::lock_item_transfer_by_owner(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id
+		));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(user_1.clone()), collection_id, item_id));
+		assert!(!ItemConfigOf::<Test>::contains_key(collection_id, item_id));
 	});
 }
 
 #[test]
-fn force_update_collection_should_work() {
+fn is_transferable_should_reflect_each_lock() {
 	new_test_ext().execute_with(|| {
-		Balances::make_free_balance_be(&account(1), 100);
-
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
-			collection_config_with_all_settings_enabled()
-		));
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
-		assert_ok!(Nfts::force_mint(
-			RuntimeOrigin::signed(account(1)),
-			0,
-			69,
-			account(2),
-			default_item_config(),
+			default_collection_config()
 		));
-		assert_ok!(Nfts::set_collection_metadata(
+		assert!(!Nfts::is_transferable(0, 42));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(2), None));
+		assert!(Nfts::is_transferable(0, 42));
+
+		// locked by the freezer
+		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert!(!Nfts::is_transferable(0, 42));
+		assert_ok!(Nfts::unlock_item_transfer(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert!(Nfts::is_transferable(0, 42));
+
+		// locked by the owner
+		assert_ok!(Nfts::lock_item_transfer_by_owner(RuntimeOrigin::signed(account(2)), 0, 42));
+		assert!(!Nfts::is_transferable(0, 42));
+		assert_ok!(Nfts::unlock_item_transfer_by_owner(RuntimeOrigin::signed(account(2)), 0, 42));
+		assert!(Nfts::is_transferable(0, 42));
+
+		// locked for the whole collection
+		assert_ok!(Nfts::lock_collection(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			bvec![0; 20]
+			CollectionSettings::from_disabled(CollectionSetting::TransferableItems.into())
 		));
-		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0; 20]));
-		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 69, bvec![0; 20]));
-		assert_eq!(Balances::reserved_balance(account(1)), 65);
+		assert!(!Nfts::is_transferable(0, 42));
 
-		// force item status to be free holding
+		// gone
 		assert_ok!(Nfts::force_collection_config(
 			RuntimeOrigin::root(),
 			0,
-			collection_config_from_disabled_settings(CollectionSetting::DepositRequired.into()),
-		));
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 142, account(1), None));
-		assert_ok!(Nfts::force_mint(
-			RuntimeOrigin::signed(account(1)),
-			0,
-			169,
-			account(2),
-			default_item_config(),
+			collection_config_with_all_settings_enabled(),
 		));
-		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 142, bvec![0; 20]));
-		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 169, bvec![0; 20]));
+		assert!(Nfts::is_transferable(0, 42));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(2)), 0, 42));
+		assert!(!Nfts::is_transferable(0, 42));
+	});
+}
 
-		Balances::make_free_balance_be(&account(5), 100);
-		assert_ok!(Nfts::force_collection_owner(RuntimeOrigin::root(), 0, account(5)));
-		assert_ok!(Nfts::set_team(
+#[test]
+fn thaw_all_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
-			0,
-			Some(account(2)),
-			Some(account(5)),
-			Some(account(4)),
+			account(1),
+			default_collection_config()
 		));
-		assert_eq!(collections(), vec![(account(5), 0)]);
-		assert_eq!(Balances::reserved_balance(account(1)), 2);
-		assert_eq!(Balances::reserved_balance(account(5)), 63);
+		for item in 0..3 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(2), None));
+			assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(1)), 0, item));
+		}
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 3, account(2), None));
+		assert_ok!(Nfts::lock_item_transfer_by_owner(RuntimeOrigin::signed(account(2)), 0, 3));
+		events();
 
-		assert_ok!(Nfts::redeposit(
-			RuntimeOrigin::signed(account(5)),
-			0,
-			bvec![0, 42, 50, 69, 100]
-		));
-		assert_eq!(Balances::reserved_balance(account(1)), 0);
+		// only the freezer or the force origin may thaw the items
+		assert_noop!(
+			Nfts::thaw_all(RuntimeOrigin::signed(account(2)), 0, 10),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::thaw_all(RuntimeOrigin::root(), 1, 10),
+			Error::<Test>::UnknownCollection
+		);
 
-		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(5)), 0, 42, bvec![0; 20]));
-		assert_eq!(Balances::reserved_balance(account(5)), 42);
+		assert_ok!(Nfts::thaw_all(RuntimeOrigin::signed(account(1)), 0, 2));
+		let thawed = (0..3).filter(|item| Nfts::is_transferable(0, *item)).count();
+		assert_eq!(thawed, 2);
+		assert_eq!(
+			events()
+				.into_iter()
+				.filter(|e| matches!(e, Event::<Test>::ItemTransferUnlocked { .. }))
+				.count(),
+			2
+		);
 
-		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(5)), 0, 69, bvec![0; 20]));
-		assert_eq!(Balances::reserved_balance(account(5)), 21);
+		assert_ok!(Nfts::thaw_all(RuntimeOrigin::root(), 0, 10));
+		assert!((0..3).all(|item| Nfts::is_transferable(0, item)));
+		assert_eq!(
+			events()
+				.into_iter()
+				.filter(|e| matches!(e, Event::<Test>::ItemTransferUnlocked { .. }))
+				.count(),
+			1
+		);
 
-		assert_ok!(Nfts::set_collection_metadata(
-			RuntimeOrigin::signed(account(5)),
-			0,
-			bvec![0; 20]
-		));
-		assert_eq!(Balances::reserved_balance(account(5)), 0);
+		// the lock of the owner is left in place
+		assert!(!Nfts::is_transferable(0, 3));
+	});
+}
 
-		// validate new roles
-		assert_ok!(Nfts::set_team(
+#[test]
+fn origin_guards_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
-			0,
-			Some(account(2)),
-			Some(account(3)),
-			Some(account(4)),
+			account(1),
+			default_collection_config()
 		));
-		assert_eq!(
-			CollectionRoleOf::<Test>::get(0, account(2)).unwrap(),
-			CollectionRoles(CollectionRole::Issuer.into())
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_ok!(Nfts::set_accept_ownership(RuntimeOrigin::signed(account(2)), Some(0)));
+		assert_noop!(
+			Nfts::transfer_ownership(RuntimeOrigin::signed(account(2)), 0, account(2)),
+			Error::<Test>::NoPermission
 		);
-		assert_eq!(
-			CollectionRoleOf::<Test>::get(0, account(3)).unwrap(),
-			CollectionRoles(CollectionRole::Admin.into())
+		assert_noop!(
+			Nfts::set_team(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				Some(account(2)),
+				Some(account(2)),
+				Some(account(2)),
+			),
+			Error::<Test>::NoPermission
 		);
-		assert_eq!(
-			CollectionRoleOf::<Test>::get(0, account(4)).unwrap(),
-			CollectionRoles(CollectionRole::Freezer.into())
+		assert_noop!(
+			Nfts::lock_item_transfer(RuntimeOrigin::signed(account(2)), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::unlock_item_transfer(RuntimeOrigin::signed(account(2)), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 69, account(2), None),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 43, account(2), None));
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 43),
+			Error::<Test>::NoPermission
+		);
+		let w = Nfts::get_destroy_witness(&0).unwrap();
+		assert_noop!(
+			Nfts::destroy(RuntimeOrigin::signed(account(2)), 0, w),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn transfer_owner_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		Balances::make_free_balance_be(&account(3), 100);
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(account(1)),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_eq!(collections(), vec![(account(1), 0)]);
+		assert_noop!(
+			Nfts::transfer_ownership(RuntimeOrigin::signed(account(1)), 0, account(2)),
+			Error::<Test>::Unaccepted
 		);
+		assert_ok!(Nfts::set_accept_ownership(RuntimeOrigin::signed(account(2)), Some(0)));
+		assert_ok!(Nfts::transfer_ownership(RuntimeOrigin::signed(account(1)), 0, account(2)));
 
-		assert_ok!(Nfts::set_team(
-			RuntimeOrigin::root(),
+		assert_eq!(collections(), vec![(account(2), 0)]);
+		assert_eq!(Balances::total_balance(&account(1)), 98);
+		assert_eq!(Balances::total_balance(&account(2)), 102);
+		assert_eq!(Balances::reserved_balance(&account(1)), 0);
+		assert_eq!(Balances::reserved_balance(&account(2)), 2);
+
+		assert_ok!(Nfts::set_accept_ownership(RuntimeOrigin::signed(account(1)), Some(0)));
+		assert_noop!(
+			Nfts::transfer_ownership(RuntimeOrigin::signed(account(1)), 0, account(1)),
+			Error::<Test>::NoPermission
+		);
+
+		// Mint and set metadata now and make sure that deposit gets transferred back.
+		assert_ok!(Nfts::set_collection_metadata(
+			RuntimeOrigin::signed(account(1)),
 			0,
-			Some(account(3)),
-			Some(account(2)),
-			Some(account(3)),
+			bvec![0u8; 20],
 		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+		assert_eq!(Balances::reserved_balance(&account(1)), 1);
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 20]));
+		assert_ok!(Nfts::set_accept_ownership(RuntimeOrigin::signed(account(3)), Some(0)));
+		assert_ok!(Nfts::transfer_ownership(RuntimeOrigin::signed(account(2)), 0, account(3)));
+		assert_eq!(collections(), vec![(account(3), 0)]);
+		assert_eq!(Balances::total_balance(&account(2)), 58);
+		assert_eq!(Balances::total_balance(&account(3)), 144);
+		assert_eq!(Balances::reserved_balance(&account(2)), 0);
+		assert_eq!(Balances::reserved_balance(&account(3)), 44);
 
-		assert_eq!(
-			CollectionRoleOf::<Test>::get(0, account(2)).unwrap(),
-			CollectionRoles(CollectionRole::Admin.into())
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(1)), 0, 42, account(2)));
+		// reserved_balance of accounts 1 & 2 should be unchanged:
+		assert_eq!(Balances::reserved_balance(&account(1)), 1);
+		assert_eq!(Balances::reserved_balance(&account(2)), 0);
+
+		// 2's acceptance from before is reset when it became an owner, so it cannot be transferred
+		// without a fresh acceptance.
+		assert_noop!(
+			Nfts::transfer_ownership(RuntimeOrigin::signed(account(3)), 0, account(2)),
+			Error::<Test>::Unaccepted
 		);
-		assert_eq!(
-			CollectionRoleOf::<Test>::get(0, account(3)).unwrap(),
-			CollectionRoles(CollectionRole::Issuer | CollectionRole::Freezer)
+	});
+}
+
+#[test]
+fn ownership_acceptance_is_cleared_for_the_new_owner() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		Balances::make_free_balance_be(&account(3), 100);
+		for _ in 0..2 {
+			assert_ok!(Nfts::force_create(
+				RuntimeOrigin::root(),
+				account(1),
+				default_collection_config()
+			));
+		}
+		let consumers = System::consumers(&account(2));
+
+		assert_ok!(Nfts::set_accept_ownership(RuntimeOrigin::signed(account(2)), Some(0)));
+		assert_eq!(OwnershipAcceptance::<Test>::get(account(2)), Some(0));
+		assert_eq!(System::consumers(&account(2)), consumers + 1);
+		assert_ok!(Nfts::transfer_ownership(RuntimeOrigin::signed(account(1)), 0, account(2)));
+		assert!(!OwnershipAcceptance::<Test>::contains_key(account(2)));
+		assert_eq!(System::consumers(&account(2)), consumers);
+
+		// the recipient has to accept again before it can be given the collection back
+		assert_ok!(Nfts::set_accept_ownership(RuntimeOrigin::signed(account(3)), Some(0)));
+		assert_ok!(Nfts::transfer_ownership(RuntimeOrigin::signed(account(2)), 0, account(3)));
+		assert_noop!(
+			Nfts::transfer_ownership(RuntimeOrigin::signed(account(3)), 0, account(2)),
+			Error::<Test>::Unaccepted
 		);
+		assert_ok!(Nfts::set_accept_ownership(RuntimeOrigin::signed(account(2)), Some(0)));
+		assert_ok!(Nfts::transfer_ownership(RuntimeOrigin::signed(account(3)), 0, account(2)));
+		assert!(!OwnershipAcceptance::<Test>::contains_key(account(2)));
+
+		// a forced change of owner clears the acceptance of that collection only
+		assert_ok!(Nfts::set_accept_ownership(RuntimeOrigin::signed(account(3)), Some(0)));
+		assert_ok!(Nfts::force_collection_owner(RuntimeOrigin::root(), 1, account(3)));
+		assert_eq!(OwnershipAcceptance::<Test>::get(account(3)), Some(0));
+		assert_ok!(Nfts::force_collection_owner(RuntimeOrigin::root(), 0, account(3)));
+		assert!(!OwnershipAcceptance::<Test>::contains_key(account(3)));
+
+		// as does transferring a collection to its owner
+		assert_ok!(Nfts::set_accept_ownership(RuntimeOrigin::signed(account(3)), Some(0)));
+		assert_ok!(Nfts::transfer_ownership(RuntimeOrigin::signed(account(3)), 0, account(3)));
+		assert!(!OwnershipAcceptance::<Test>::contains_key(account(3)));
 	});
 }
 
 #[test]
-fn burn_works() {
+fn force_collection_owner_recovers_a_collection() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&account(1), 100);
-		assert_ok!(Nfts::force_create(
-			RuntimeOrigin::root(),
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(account(1)),
 			account(1),
 			collection_config_with_all_settings_enabled()
 		));
-		assert_ok!(Nfts::set_team(
+		assert_ok!(Nfts::set_collection_metadata(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			Some(account(2)),
-			Some(account(3)),
-			Some(account(4)),
+			bvec![0, 0]
 		));
+		let deposit = Collection::<Test>::get(0).unwrap().owner_deposit;
+		assert_eq!(Balances::reserved_balance(&account(1)), deposit);
 
-		assert_noop!(
-			Nfts::burn(RuntimeOrigin::signed(account(5)), 0, 42),
-			Error::<Test>::UnknownItem
+		// the deposit follows the collection to its new owner, who needn't accept it
+		assert_ok!(Nfts::force_collection_owner(RuntimeOrigin::root(), 0, account(2)));
+		assert_eq!(
+			events().last(),
+			Some(&Event::OwnerChanged { collection: 0, new_owner: account(2) })
 		);
+		assert_eq!(Balances::reserved_balance(&account(1)), 0);
+		assert_eq!(Balances::reserved_balance(&account(2)), deposit);
+		assert_eq!(collections(), vec![(account(2), 0)]);
 
-		assert_ok!(Nfts::force_mint(
+		// the new owner takes over the team and edits the metadata
+		assert_ok!(Nfts::set_team(
 			RuntimeOrigin::signed(account(2)),
 			0,
-			42,
-			account(5),
-			default_item_config()
+			Some(account(2)),
+			Some(account(2)),
+			Some(account(2)),
 		));
-		assert_ok!(Nfts::force_mint(
+		assert_ok!(Nfts::set_collection_metadata(
 			RuntimeOrigin::signed(account(2)),
 			0,
-			69,
-			account(5),
-			default_item_config()
+			bvec![0, 0, 0]
 		));
-		assert_eq!(Balances::reserved_balance(account(1)), 2);
-
-		assert_noop!(
-			Nfts::burn(RuntimeOrigin::signed(account(0)), 0, 42),
-			Error::<Test>::NoPermission
+		assert_eq!(CollectionMetadataOf::<Test>::get(0).unwrap().data.into_inner(), vec![0, 0, 0]);
+		assert_eq!(
+			Balances::reserved_balance(&account(2)),
+			Collection::<Test>::get(0).unwrap().owner_deposit
 		);
 
-		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(5)), 0, 42));
-		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(5)), 0, 69));
-		assert_eq!(Balances::reserved_balance(account(1)), 0);
+		// an account which doesn't exist can't hold the deposit, so it's slashed
+		let issuance = Balances::total_issuance();
+		let deposit = Collection::<Test>::get(0).unwrap().owner_deposit;
+		assert_ok!(Nfts::force_collection_owner(RuntimeOrigin::root(), 0, account(3)));
+		assert_eq!(Balances::reserved_balance(&account(2)), 0);
+		assert_eq!(Balances::total_issuance(), issuance - deposit);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner, account(3));
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 0);
 	});
 }
 
 #[test]
-fn approval_lifecycle_works() {
+fn set_team_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
-			default_collection_config()
+			default_collection_config(),
 		));
-		assert_ok!(Nfts::force_mint(
+		assert_ok!(Nfts::set_team(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			42,
-			account(2),
-			default_item_config()
-		));
-		assert_ok!(Nfts::approve_transfer(
-			RuntimeOrigin::signed(account(2)),
-			0,
-			42,
-			account(3),
-			None
+			Some(account(2)),
+			Some(account(3)),
+			Some(account(4)),
 		));
-		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(4)));
+
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 42, account(2), None));
+
+		// admin can't transfer/burn items he doesn't own
 		assert_noop!(
 			Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(3)),
 			Error::<Test>::NoPermission
 		);
-		assert!(Item::<Test>::get(0, 42).unwrap().approvals.is_empty());
-
-		assert_ok!(Nfts::approve_transfer(
-			RuntimeOrigin::signed(account(4)),
-			0,
-			42,
-			account(2),
-			None
-		));
-		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(2)));
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(account(3)), 0, 42),
+			Error::<Test>::NoPermission
+		);
 
-		// ensure we can't buy an item when the collection has a NonTransferableItems flag
-		let collection_id = 1;
-		assert_ok!(Nfts::force_create(
-			RuntimeOrigin::root(),
-			account(1),
-			collection_config_from_disabled_settings(
-				CollectionSetting::TransferableItems | CollectionSetting::DepositRequired
-			)
-		));
+		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(account(4)), 0, 42));
+		assert_ok!(Nfts::unlock_item_transfer(RuntimeOrigin::signed(account(4)), 0, 42));
 
-		assert_ok!(Nfts::mint(
+		// validate we can set any role to None
+		assert_ok!(Nfts::set_team(
 			RuntimeOrigin::signed(account(1)),
-			1,
-			collection_id,
-			account(1),
+			0,
+			Some(account(2)),
+			Some(account(3)),
 			None,
 		));
+		assert_noop!(
+			Nfts::lock_item_transfer(RuntimeOrigin::signed(account(4)), 0, 42),
+			Error::<Test>::NoPermission
+		);
+
+		// set all the roles to None
+		assert_ok!(Nfts::set_team(RuntimeOrigin::signed(account(1)), 0, None, None, None,));
 
+		// validate we can't set the roles back
 		assert_noop!(
-			Nfts::approve_transfer(
+			Nfts::set_team(
 				RuntimeOrigin::signed(account(1)),
-				collection_id,
-				1,
-				account(2),
-				None
+				0,
+				Some(account(2)),
+				Some(account(3)),
+				None,
 			),
-			Error::<Test>::ItemsNonTransferable
+			Error::<Test>::NoPermission
 		);
+
+		// only the root account can change the roles from None to Some()
+		assert_ok!(Nfts::set_team(
+			RuntimeOrigin::root(),
+			0,
+			Some(account(2)),
+			Some(account(3)),
+			None,
+		));
 	});
 }
 
 #[test]
-fn cancel_approval_works() {
+fn set_team_roles_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
-			default_collection_config()
+			default_collection_config(),
 		));
-		assert_ok!(Nfts::force_mint(
+		assert_ok!(Nfts::set_team(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			42,
-			account(2),
-			default_item_config()
+			Some(account(2)),
+			Some(account(3)),
+			Some(account(4)),
 		));
 
-		assert_ok!(Nfts::approve_transfer(
-			RuntimeOrigin::signed(account(2)),
-			0,
-			42,
-			account(3),
-			None
-		));
-		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 1, 42, account(3)),
-			Error::<Test>::UnknownItem
-		);
-		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 43, account(3)),
-			Error::<Test>::UnknownItem
-		);
+		// only the owner or the force origin can change the team
 		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(account(3)), 0, 42, account(3)),
+			Nfts::set_team_roles(RuntimeOrigin::signed(account(2)), 0, None, None, None),
 			Error::<Test>::NoPermission
 		);
-		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 42, account(4)),
-			Error::<Test>::NotDelegate
-		);
-
-		assert_ok!(Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
-		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 42, account(3)),
-			Error::<Test>::NotDelegate
-		);
 
-		let current_block = 1;
-		System::set_block_number(current_block);
-		assert_ok!(Nfts::force_mint(
+		// change only the freezer, the other roles are preserved
+		assert_ok!(Nfts::set_team_roles(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			69,
-			account(2),
-			default_item_config()
+			None,
+			None,
+			Some(account(5)),
 		));
-		// approval expires after 2 blocks.
-		assert_ok!(Nfts::approve_transfer(
-			RuntimeOrigin::signed(account(2)),
+		assert!(events().contains(&Event::<Test>::TeamChanged {
+			collection: 0,
+			issuer: Some(account(2)),
+			admin: Some(account(3)),
+			freezer: Some(account(5)),
+		}));
+		assert!(Nfts::has_role(&0, &account(2), CollectionRole::Issuer));
+		assert!(Nfts::has_role(&0, &account(3), CollectionRole::Admin));
+		assert!(Nfts::has_role(&0, &account(5), CollectionRole::Freezer));
+		assert!(!Nfts::has_role(&0, &account(4), CollectionRole::Freezer));
+
+		// nothing changes without any new role
+		assert_ok!(Nfts::set_team_roles(RuntimeOrigin::signed(account(1)), 0, None, None, None));
+		assert!(Nfts::has_role(&0, &account(2), CollectionRole::Issuer));
+		assert!(Nfts::has_role(&0, &account(3), CollectionRole::Admin));
+		assert!(Nfts::has_role(&0, &account(5), CollectionRole::Freezer));
+
+		// only the root account can fill a role which is unset
+		assert_ok!(Nfts::set_team(
+			RuntimeOrigin::signed(account(1)),
 			0,
-			42,
-			account(3),
-			Some(2)
+			Some(account(2)),
+			Some(account(3)),
+			None,
 		));
 		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(account(5)), 0, 42, account(3)),
+			Nfts::set_team_roles(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				None,
+				None,
+				Some(account(4))
+			),
 			Error::<Test>::NoPermission
 		);
-
-		System::set_block_number(current_block + 3);
-		// 5 can cancel the approval since the deadline has passed.
-		assert_ok!(Nfts::cancel_approval(RuntimeOrigin::signed(account(5)), 0, 42, account(3)));
-		assert_eq!(approvals(0, 69), vec![]);
+		assert_ok!(Nfts::set_team_roles(RuntimeOrigin::root(), 0, None, None, Some(account(4))));
+		assert!(Nfts::has_role(&0, &account(2), CollectionRole::Issuer));
+		assert!(Nfts::has_role(&0, &account(4), CollectionRole::Freezer));
 	});
 }
 
 #[test]
-fn approving_multiple_accounts_works() {
+fn set_collection_metadata_should_work() {
 	new_test_ext().execute_with(|| {
+		// Cannot add metadata to unknown item
+		assert_noop!(
+			Nfts::set_collection_metadata(RuntimeOrigin::signed(account(1)), 0, bvec![0u8; 20]),
+			Error::<Test>::NoPermission,
+		);
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
-			default_collection_config()
+			collection_config_with_all_settings_enabled()
 		));
-		assert_ok!(Nfts::force_mint(
+		// Cannot add metadata to unowned item
+		assert_noop!(
+			Nfts::set_collection_metadata(RuntimeOrigin::signed(account(2)), 0, bvec![0u8; 20]),
+			Error::<Test>::NoPermission,
+		);
+
+		// Successfully add metadata and take deposit
+		Balances::make_free_balance_be(&account(1), 30);
+		assert_ok!(Nfts::set_collection_metadata(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			42,
-			account(2),
-			default_item_config()
+			bvec![0u8; 20]
 		));
+		assert_eq!(Balances::free_balance(&account(1)), 9);
+		assert!(CollectionMetadataOf::<Test>::contains_key(0));
 
-		let current_block = 1;
-		System::set_block_number(current_block);
-		assert_ok!(Nfts::approve_transfer(
-			RuntimeOrigin::signed(account(2)),
+		// Force origin works, too.
+		assert_ok!(Nfts::set_collection_metadata(RuntimeOrigin::root(), 0, bvec![0u8; 18]));
+
+		// Update deposit
+		assert_ok!(Nfts::set_collection_metadata(
+			RuntimeOrigin::signed(account(1)),
 			0,
-			42,
-			account(3),
-			None
+			bvec![0u8; 15]
 		));
-		assert_ok!(Nfts::approve_transfer(
-			RuntimeOrigin::signed(account(2)),
+		assert_eq!(Balances::free_balance(&account(1)), 14);
+		assert_ok!(Nfts::set_collection_metadata(
+			RuntimeOrigin::signed(account(1)),
 			0,
-			42,
-			account(4),
-			None
+			bvec![0u8; 25]
 		));
-		assert_ok!(Nfts::approve_transfer(
-			RuntimeOrigin::signed(account(2)),
+		assert_eq!(Balances::free_balance(&account(1)), 4);
+
+		// Cannot over-reserve
+		assert_noop!(
+			Nfts::set_collection_metadata(RuntimeOrigin::signed(account(1)), 0, bvec![0u8; 40]),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+
+		// Can't set or clear metadata once frozen
+		assert_ok!(Nfts::set_collection_metadata(
+			RuntimeOrigin::signed(account(1)),
 			0,
-			42,
-			account(5),
-			Some(2)
+			bvec![0u8; 15]
 		));
-		assert_eq!(
-			approvals(0, 42),
-			vec![(account(3), None), (account(4), None), (account(5), Some(current_block + 2))]
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedMetadata.into())
+		));
+		assert_noop!(
+			Nfts::set_collection_metadata(RuntimeOrigin::signed(account(1)), 0, bvec![0u8; 15]),
+			Error::<Test>::LockedCollectionMetadata,
+		);
+		assert_noop!(
+			Nfts::clear_collection_metadata(RuntimeOrigin::signed(account(1)), 0),
+			Error::<Test>::LockedCollectionMetadata
 		);
 
-		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(4)), 0, 42, account(6)));
+		// Clear Metadata
+		assert_ok!(Nfts::set_collection_metadata(RuntimeOrigin::root(), 0, bvec![0u8; 15]));
 		assert_noop!(
-			Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(7)),
+			Nfts::clear_collection_metadata(RuntimeOrigin::signed(account(2)), 0),
 			Error::<Test>::NoPermission
 		);
 		assert_noop!(
-			Nfts::transfer(RuntimeOrigin::signed(account(5)), 0, 42, account(8)),
+			Nfts::clear_collection_metadata(RuntimeOrigin::signed(account(1)), 1),
 			Error::<Test>::NoPermission
 		);
+		assert_noop!(
+			Nfts::clear_collection_metadata(RuntimeOrigin::signed(account(1)), 0),
+			Error::<Test>::LockedCollectionMetadata
+		);
+		assert_ok!(Nfts::clear_collection_metadata(RuntimeOrigin::root(), 0));
+		assert!(!CollectionMetadataOf::<Test>::contains_key(0));
 	});
 }
 
 #[test]
-fn approvals_limit_works() {
+fn clear_collection_metadata_should_refund_deposit() {
 	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(account(1)),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		let reserved = Balances::reserved_balance(account(1));
+		let owner_deposit = Collection::<Test>::get(0).unwrap().owner_deposit;
+
+		// cleared by the admin
+		assert_ok!(Nfts::set_collection_metadata(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			bvec![0u8; 20]
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), reserved + 21);
+		assert_ok!(Nfts::clear_collection_metadata(RuntimeOrigin::signed(account(1)), 0));
+		assert!(events().contains(&Event::<Test>::CollectionMetadataCleared { collection: 0 }));
+		assert_eq!(Balances::reserved_balance(account(1)), reserved);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, owner_deposit);
+
+		// cleared by the force origin, the owner gets the deposit back
+		assert_ok!(Nfts::set_collection_metadata(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			bvec![0u8; 20]
+		));
+		assert_ok!(Nfts::clear_collection_metadata(RuntimeOrigin::root(), 0));
+		assert_eq!(Balances::reserved_balance(account(1)), reserved);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, owner_deposit);
+
+		// no deposit is taken for a collection without the deposit requirement
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
-			default_collection_config()
+			collection_config_from_disabled_settings(CollectionSetting::DepositRequired.into())
 		));
-		assert_ok!(Nfts::force_mint(
+		let reserved = Balances::reserved_balance(account(1));
+		let owner_deposit = Collection::<Test>::get(1).unwrap().owner_deposit;
+		assert_ok!(Nfts::set_collection_metadata(
 			RuntimeOrigin::signed(account(1)),
-			0,
-			42,
-			account(2),
-			default_item_config()
+			1,
+			bvec![0u8; 20]
 		));
+		assert_eq!(Balances::reserved_balance(account(1)), reserved);
+		assert_ok!(Nfts::clear_collection_metadata(RuntimeOrigin::signed(account(1)), 1));
+		assert!(events().contains(&Event::<Test>::CollectionMetadataCleared { collection: 1 }));
+		assert_eq!(Balances::reserved_balance(account(1)), reserved);
+		assert_eq!(Collection::<Test>::get(1).unwrap().owner_deposit, owner_deposit);
+	});
+}
 
-		for i in 3..13 {
-			assert_ok!(Nfts::approve_transfer(
-				RuntimeOrigin::signed(account(2)),
-				0,
-				42,
-				account(i),
-				None
-			));
-		}
-		// the limit is 10
+#[test]
+fn set_item_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 30);
+
+		// Cannot add metadata to unknown item
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+		// Cannot add metadata to unowned item
 		assert_noop!(
-			Nfts::approve_transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(14), None),
-			Error::<Test>::ReachedApprovalLimit
+			Nfts::set_metadata(RuntimeOrigin::signed(account(2)), 0, 42, bvec![0u8; 20]),
+			Error::<Test>::NoPermission,
+		);
+
+		// Successfully add metadata and take deposit
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 20]));
+		assert_eq!(Balances::free_balance(&account(1)), 8);
+		assert!(ItemMetadataOf::<Test>::contains_key(0, 42));
+
+		// Force origin works, too.
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::root(), 0, 42, bvec![0u8; 18]));
+
+		// Update deposit
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 15]));
+		assert_eq!(Balances::free_balance(&account(1)), 13);
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 25]));
+		assert_eq!(Balances::free_balance(&account(1)), 3);
+
+		// Cannot over-reserve
+		assert_noop!(
+			Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 40]),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+
+		// Can't set or clear metadata once frozen
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 15]));
+		assert_ok!(Nfts::lock_item_properties(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			true,
+			false
+		));
+		assert_noop!(
+			Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 15]),
+			Error::<Test>::LockedItemMetadata,
+		);
+		assert_noop!(
+			Nfts::clear_metadata(RuntimeOrigin::signed(account(1)), 0, 42),
+			Error::<Test>::LockedItemMetadata,
 		);
+
+		// Clear Metadata
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::root(), 0, 42, bvec![0u8; 15]));
+		assert_noop!(
+			Nfts::clear_metadata(RuntimeOrigin::signed(account(2)), 0, 42),
+			Error::<Test>::NoPermission,
+		);
+		assert_noop!(
+			Nfts::clear_metadata(RuntimeOrigin::signed(account(1)), 1, 42),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Nfts::clear_metadata(RuntimeOrigin::root(), 0, 42));
+		assert!(!ItemMetadataOf::<Test>::contains_key(0, 42));
 	});
 }
 
 #[test]
-fn approval_deadline_works() {
+fn set_metadata_many_should_work() {
 	new_test_ext().execute_with(|| {
-		System::set_block_number(0);
-		assert!(System::block_number().is_zero());
-
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&account(1), 30);
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
-			collection_config_from_disabled_settings(CollectionSetting::DepositRequired.into())
+			collection_config_with_all_settings_enabled()
 		));
-		assert_ok!(Nfts::force_mint(
+		for item in 1..=3 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(1), None));
+		}
+		let free = Balances::free_balance(account(1));
+		events();
+
+		assert_noop!(
+			Nfts::set_metadata_many(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				bvec![(1, bvec![0u8; 3])]
+			),
+			Error::<Test>::NoPermission,
+		);
+
+		assert_ok!(Nfts::set_metadata_many(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			42,
-			account(2),
-			default_item_config()
+			bvec![(1, bvec![1u8; 3]), (2, bvec![2u8; 4])],
 		));
+		// the deposits of both items are reserved
+		assert_eq!(Balances::free_balance(account(1)), free - 4 - 5);
+		assert_eq!(
+			events(),
+			vec![
+				Event::ItemMetadataSet { collection: 0, item: 1, data: bvec![1u8; 3] },
+				Event::ItemMetadataSet { collection: 0, item: 2, data: bvec![2u8; 4] },
+			]
+		);
+		assert_eq!(Nfts::item_metadata(0, 2), Some(vec![2u8; 4]));
+		assert_eq!(Collection::<Test>::get(0).unwrap().item_metadatas, 2);
 
-		// the approval expires after the 2nd block.
-		assert_ok!(Nfts::approve_transfer(
-			RuntimeOrigin::signed(account(2)),
+		// nothing is set if one of the items has its metadata locked
+		assert_ok!(Nfts::lock_item_properties(
+			RuntimeOrigin::signed(account(1)),
 			0,
-			42,
-			account(3),
-			Some(2)
+			2,
+			true,
+			false
 		));
-
-		System::set_block_number(3);
 		assert_noop!(
-			Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(4)),
-			Error::<Test>::ApprovalExpired
+			Nfts::set_metadata_many(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				bvec![(3, bvec![3u8; 3]), (2, bvec![3u8; 3])]
+			),
+			Error::<Test>::LockedItemMetadata,
 		);
-		System::set_block_number(1);
-		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(4)));
-
-		assert_eq!(System::block_number(), 1);
-		// make a new approval with a deadline after 4 blocks, so it will expire after the 5th
-		// block.
-		assert_ok!(Nfts::approve_transfer(
-			RuntimeOrigin::signed(account(4)),
+		assert_ok!(Nfts::set_metadata_many(
+			RuntimeOrigin::root(),
 			0,
-			42,
-			account(6),
-			Some(4)
+			bvec![(3, bvec![3u8; 3]), (2, bvec![3u8; 3])]
 		));
-		// this should still work.
-		System::set_block_number(5);
-		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(6)), 0, 42, account(5)));
+		assert_eq!(Nfts::item_metadata(0, 2), Some(vec![3u8; 3]));
 	});
 }
 
 #[test]
-fn cancel_approval_works_with_admin() {
+fn set_metadata_many_fails_as_a_whole_without_funds() {
 	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 30);
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
-			default_collection_config()
+			collection_config_with_all_settings_enabled()
 		));
-		assert_ok!(Nfts::force_mint(
+		for item in 1..=2 {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(1), None));
+		}
+		let free = Balances::free_balance(account(1));
+
+		// the first item's deposit of `1 + len` can be paid, the second one's can't
+		let len = (free - 2) as usize;
+		assert_noop!(
+			Nfts::set_metadata_many(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				bvec![(1, vec![0u8; len].try_into().unwrap()), (2, bvec![0u8; 3])]
+			),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+		assert!(!ItemMetadataOf::<Test>::contains_key(0, 1));
+		assert_eq!(Balances::free_balance(account(1)), free);
+	});
+}
+
+#[test]
+fn default_item_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::set_team(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			42,
-			account(2),
-			default_item_config()
+			Some(account(2)),
+			Some(account(1)),
+			Some(account(1)),
 		));
 
-		assert_ok!(Nfts::approve_transfer(
-			RuntimeOrigin::signed(account(2)),
-			0,
-			42,
-			account(3),
-			None
-		));
-		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 1, 42, account(1)),
-			Error::<Test>::UnknownItem
-		);
+		// Only the admin or the force origin can set the default
 		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 43, account(1)),
-			Error::<Test>::UnknownItem
+			Nfts::set_default_item_metadata(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				Some(bvec![0u8; 10])
+			),
+			Error::<Test>::NoPermission,
 		);
 		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 42, account(4)),
-			Error::<Test>::NotDelegate
+			Nfts::set_default_item_metadata(RuntimeOrigin::root(), 1, Some(bvec![0u8; 10])),
+			Error::<Test>::UnknownCollection,
 		);
+		assert_ok!(Nfts::set_default_item_metadata(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(bvec![0u8; 10])
+		));
+		assert!(events().contains(&Event::<Test>::DefaultItemMetadataSet {
+			collection: 0,
+			data: Some(bvec![0u8; 10]),
+		}));
+		assert_eq!(Balances::reserved_balance(&account(1)), 0);
 
-		assert_ok!(Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
-		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 42, account(1)),
-			Error::<Test>::NotDelegate
-		);
+		// Minting without metadata applies the default and charges the minter
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 42, account(3), None));
+		let metadata = ItemMetadataOf::<Test>::get(0, 42).unwrap();
+		assert_eq!(metadata.data.into_inner(), vec![0u8; 10]);
+		assert_eq!(metadata.deposit, ItemMetadataDeposit { account: Some(account(2)), amount: 11 });
+		assert_eq!(Balances::reserved_balance(&account(2)), 1 + 11);
+		assert_eq!(Collection::<Test>::get(0).unwrap().item_metadatas, 1);
+
+		// Explicit metadata replaces the default and moves the deposit to the owner
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![1u8; 5]));
+		assert_eq!(ItemMetadataOf::<Test>::get(0, 42).unwrap().data.into_inner(), vec![1u8; 5]);
+		assert_eq!(Balances::reserved_balance(&account(2)), 1);
+		assert_eq!(Balances::reserved_balance(&account(1)), 6);
+		assert_eq!(Collection::<Test>::get(0).unwrap().item_metadatas, 1);
+
+		// The force origin takes no metadata deposit, only the owner's item deposit
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::root(),
+			0,
+			43,
+			account(3),
+			default_item_config()
+		));
+		let metadata = ItemMetadataOf::<Test>::get(0, 43).unwrap();
+		assert_eq!(metadata.data.into_inner(), vec![0u8; 10]);
+		assert_eq!(metadata.deposit, ItemMetadataDeposit { account: None, amount: 0 });
+		assert_eq!(Balances::reserved_balance(&account(1)), 6 + 1);
+
+		// Clearing the default stops it being applied
+		assert_ok!(Nfts::set_default_item_metadata(RuntimeOrigin::root(), 0, None));
+		assert!(!DefaultItemMetadataOf::<Test>::contains_key(0));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(2)), 0, 44, account(3), None));
+		assert!(!ItemMetadataOf::<Test>::contains_key(0, 44));
+		assert_eq!(Balances::reserved_balance(&account(2)), 2);
+		assert_eq!(Collection::<Test>::get(0).unwrap().item_metadatas, 2);
+
+		// The default is removed together with the collection
+		assert_ok!(Nfts::set_default_item_metadata(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(bvec![0u8; 10])
+		));
+		assert_ok!(Nfts::force_destroy(RuntimeOrigin::root(), 0, 10));
+		assert!(!DefaultItemMetadataOf::<Test>::contains_key(0));
 	});
 }
 
 #[test]
-fn cancel_approval_works_with_force() {
+fn effective_attribute_falls_back_to_the_collection() {
 	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			account(1),
 			default_collection_config()
 		));
-		assert_ok!(Nfts::force_mint(
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 1, account(1), None));
+		for (key, value) in [(0, 10), (1, 11)] {
+			assert_ok!(Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				None,
+				AttributeNamespace::CollectionOwner,
+				bvec![key],
+				bvec![value],
+			));
+		}
+		assert_ok!(Nfts::set_attribute(
 			RuntimeOrigin::signed(account(1)),
 			0,
-			42,
-			account(2),
-			default_item_config()
+			Some(1),
+			AttributeNamespace::CollectionOwner,
+			bvec![1],
+			bvec![21],
 		));
 
-		assert_ok!(Nfts::approve_transfer(
-			RuntimeOrigin::signed(account(2)),
+		// items inherit the attributes of their collection
+		assert_eq!(Nfts::effective_attribute(0, 0, &[0]), Some(vec![10]));
+		assert_eq!(Nfts::effective_attribute(0, 0, &[1]), Some(vec![11]));
+		assert_eq!(Nfts::effective_attribute(0, 1, &[0]), Some(vec![10]));
+		// while the exact-scope lookup doesn't
+		assert_eq!(Nfts::attribute(0, Some(0), &[0]), None);
+
+		// an item attribute overrides the collection one
+		assert_eq!(Nfts::effective_attribute(0, 1, &[1]), Some(vec![21]));
+
+		assert_eq!(Nfts::effective_attribute(0, 0, &[2]), None);
+		assert_eq!(Nfts::effective_attribute(1, 0, &[0]), None);
+	});
+}
+
+#[test]
+fn set_collection_owner_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
 			0,
-			42,
-			account(3),
-			None
+			None,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![1],
+			bvec![0],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(None, AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
+				(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
+				(Some(0), AttributeNamespace::CollectionOwner, bvec![1], bvec![0]),
+			]
+		);
+		assert_eq!(Balances::reserved_balance(account(1)), 10);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 9);
+
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			None,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0; 10],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(None, AttributeNamespace::CollectionOwner, bvec![0], bvec![0; 10]),
+				(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
+				(Some(0), AttributeNamespace::CollectionOwner, bvec![1], bvec![0]),
+			]
+		);
+		assert_eq!(Balances::reserved_balance(account(1)), 19);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, 18);
+
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![1],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(None, AttributeNamespace::CollectionOwner, bvec![0], bvec![0; 10]),
+				(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
+			]
+		);
+		assert_eq!(Balances::reserved_balance(account(1)), 16);
+
+		assert_ok!(Nfts::burn(RuntimeOrigin::root(), 0, 0));
+		let w = Nfts::get_destroy_witness(&0).unwrap();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(account(1)), 0, w));
+		assert_eq!(attributes(0), vec![]);
+		assert_eq!(Balances::reserved_balance(account(1)), 0);
+	});
+}
+
+#[test]
+fn set_item_owner_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		Balances::make_free_balance_be(&account(3), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(2),
+			default_item_config()
+		));
+
+		// can't set for the collection
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				None,
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::NoPermission,
+		);
+		// can't set for the non-owned item
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![1],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![2],
+			bvec![0],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![1], bvec![0]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0]),
+			]
+		);
+		assert_eq!(Balances::reserved_balance(account(2)), 9);
+
+		// validate an attribute can be updated
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0; 10],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 10]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![1], bvec![0]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0]),
+			]
+		);
+		assert_eq!(Balances::reserved_balance(account(2)), 18);
+
+		// validate only item's owner (or the root) can remove an attribute
+		assert_noop!(
+			Nfts::clear_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![1],
+			),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![1],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 10]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0])
+			]
+		);
+		assert_eq!(Balances::reserved_balance(account(2)), 15);
+
+		// transfer item
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 0, account(3)));
+
+		// validate the attribute are still here & the deposit belongs to the previous owner
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 10]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0])
+			]
+		);
+		let key: BoundedVec<_, _> = bvec![0];
+		let (_, deposit) =
+			Attribute::<Test>::get((0, Some(0), AttributeNamespace::ItemOwner, &key)).unwrap();
+		assert_eq!(deposit.account, Some(account(2)));
+		assert_eq!(deposit.amount, 12);
+
+		// on attribute update the deposit should be returned to the previous owner
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(3)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0; 11],
+		));
+		let (_, deposit) =
+			Attribute::<Test>::get((0, Some(0), AttributeNamespace::ItemOwner, &key)).unwrap();
+		assert_eq!(deposit.account, Some(account(3)));
+		assert_eq!(deposit.amount, 13);
+		assert_eq!(Balances::reserved_balance(account(2)), 3);
+		assert_eq!(Balances::reserved_balance(account(3)), 13);
+
+		// validate attributes on item deletion
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(3)), 0, 0));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::ItemOwner, bvec![0], bvec![0; 11]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![2], bvec![0])
+			]
+		);
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(3)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+		));
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![2],
+		));
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+		assert_eq!(Balances::reserved_balance(account(3)), 0);
+	});
+}
+
+#[test]
+fn system_collection_takes_no_deposits() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		Balances::make_free_balance_be(&account(3), 100);
+
+		assert_noop!(
+			Nfts::force_create_system(
+				RuntimeOrigin::signed(account(1)),
+				account(1),
+				collection_config_with_all_settings_enabled()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Nfts::force_create_system(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert!(SystemCollection::<Test>::contains_key(0));
+		assert!(!CollectionConfigOf::<Test>::get(0)
+			.unwrap()
+			.is_setting_enabled(CollectionSetting::DepositRequired));
+
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+		assert_ok!(Nfts::set_collection_metadata(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			bvec![0u8; 20]
+		));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 0, bvec![0u8; 20]));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::approve_item_attributes(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			0,
+			account(3)
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(3)),
+			0,
+			Some(0),
+			AttributeNamespace::Account(account(3)),
+			bvec![0],
+			bvec![0],
+		));
+		assert_eq!(Balances::reserved_balance(&account(1)), 0);
+		assert_eq!(Balances::reserved_balance(&account(2)), 0);
+		assert_eq!(Balances::reserved_balance(&account(3)), 0);
+
+		// deposits can't be re-enabled
+		assert_noop!(
+			Nfts::force_collection_config(
+				RuntimeOrigin::root(),
+				0,
+				collection_config_with_all_settings_enabled()
+			),
+			Error::<Test>::WrongSetting
+		);
+
+		// the mark is removed together with the collection
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(2)), 0, 0));
+		let w = Nfts::get_destroy_witness(&0).unwrap();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(account(1)), 0, w));
+		assert!(!SystemCollection::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn set_external_account_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(1),
+			default_item_config()
+		));
+		assert_ok!(Nfts::approve_item_attributes(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(2)
+		));
+
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				Some(0),
+				AttributeNamespace::Account(account(1)),
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::NoPermission,
+		);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::Account(account(2)),
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::Account(account(2)),
+			bvec![1],
+			bvec![0],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::Account(account(2)), bvec![0], bvec![0]),
+				(Some(0), AttributeNamespace::Account(account(2)), bvec![1], bvec![0]),
+			]
+		);
+		assert_eq!(Balances::reserved_balance(account(2)), 6);
+
+		// remove permission to set attributes
+		assert_ok!(Nfts::cancel_item_attributes_approval(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(2),
+			CancelAttributesApprovalWitness { account_attributes: 2 },
+		));
+		assert_eq!(attributes(0), vec![]);
+		assert_eq!(Balances::reserved_balance(account(2)), 0);
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				Some(0),
+				AttributeNamespace::Account(account(2)),
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::NoPermission,
+		);
+	});
+}
+
+#[test]
+fn approved_attribute_writer_can_write_its_namespace() {
+	new_test_ext().execute_with(|| {
+		let owner = account(1);
+		let writer = account(2);
+		Balances::make_free_balance_be(&owner, 100);
+		Balances::make_free_balance_be(&writer, 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			owner.clone(),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			0,
+			owner.clone(),
+			default_item_config()
+		));
+
+		// The item owner delegates its attributes to e.g. a game server.
+		assert_ok!(Nfts::approve_item_attributes(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			0,
+			writer.clone()
+		));
+		let namespace = AttributeNamespace::Account(writer.clone());
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(writer.clone()),
+			0,
+			Some(0),
+			namespace.clone(),
+			bvec![0],
+			bvec![10],
+		));
+		// It may overwrite and clear what it wrote.
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(writer.clone()),
+			0,
+			Some(0),
+			namespace.clone(),
+			bvec![0],
+			bvec![20],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(writer.clone()),
+			0,
+			Some(0),
+			namespace.clone(),
+			bvec![1],
+			bvec![30],
+		));
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(writer.clone()),
+			0,
+			Some(0),
+			namespace.clone(),
+			bvec![1],
+		));
+		assert_eq!(attributes(0), vec![(Some(0), namespace.clone(), bvec![0], bvec![20])]);
+		assert_eq!(Balances::reserved_balance(&writer), 3);
+
+		// The delegation doesn't reach into the other namespaces.
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(writer.clone()),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::NoPermission,
+		);
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(writer),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::NoPermission,
+		);
+	});
+}
+
+#[test]
+fn unapproved_attribute_writer_cannot_write() {
+	new_test_ext().execute_with(|| {
+		let owner = account(1);
+		let writer = account(2);
+		let intruder = account(3);
+		Balances::make_free_balance_be(&owner, 100);
+		Balances::make_free_balance_be(&writer, 100);
+		Balances::make_free_balance_be(&intruder, 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			owner.clone(),
+			collection_config_with_all_settings_enabled()
+		));
+		for item in 0..2 {
+			assert_ok!(Nfts::force_mint(
+				RuntimeOrigin::signed(owner.clone()),
+				0,
+				item,
+				owner.clone(),
+				default_item_config()
+			));
+		}
+		assert_ok!(Nfts::approve_item_attributes(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			0,
+			writer.clone()
+		));
+
+		// An account never approved can write neither under its own name nor under the writer's.
+		for namespace in [
+			AttributeNamespace::Account(intruder.clone()),
+			AttributeNamespace::Account(writer.clone()),
+		] {
+			assert_noop!(
+				Nfts::set_attribute(
+					RuntimeOrigin::signed(intruder.clone()),
+					0,
+					Some(0),
+					namespace,
+					bvec![0],
+					bvec![0],
+				),
+				Error::<Test>::NoPermission,
+			);
+		}
+		// Not even the item owner can write under the writer's name.
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(owner.clone()),
+				0,
+				Some(0),
+				AttributeNamespace::Account(writer.clone()),
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::NoPermission,
+		);
+		// An approval covers a single item.
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(writer.clone()),
+				0,
+				Some(1),
+				AttributeNamespace::Account(writer.clone()),
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::NoPermission,
+		);
+
+		// Once the approval is cancelled, the writer is just another account.
+		assert_ok!(Nfts::cancel_item_attributes_approval(
+			RuntimeOrigin::signed(owner),
+			0,
+			0,
+			writer.clone(),
+			CancelAttributesApprovalWitness { account_attributes: 0 },
+		));
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(writer.clone()),
+				0,
+				Some(0),
+				AttributeNamespace::Account(writer),
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::NoPermission,
+		);
+		assert_eq!(attributes(0), vec![]);
+	});
+}
+#[test]
+fn validate_deposit_required_setting() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		Balances::make_free_balance_be(&account(3), 100);
+
+		// with the disabled DepositRequired setting, only the collection's owner can set the
+		// attributes for free.
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(2),
+			default_item_config()
+		));
+		assert_ok!(Nfts::approve_item_attributes(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			0,
+			account(3)
+		));
+
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![1],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(3)),
+			0,
+			Some(0),
+			AttributeNamespace::Account(account(3)),
+			bvec![2],
+			bvec![0],
+		));
+		assert_ok!(<Nfts as Mutate<AccountIdOf<Test>, ItemConfig>>::set_attribute(
+			&0,
+			&0,
+			&[3],
+			&[0],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![1], bvec![0]),
+				(Some(0), AttributeNamespace::Account(account(3)), bvec![2], bvec![0]),
+				(Some(0), AttributeNamespace::Pallet, bvec![3], bvec![0]),
+			]
+		);
+		assert_eq!(Balances::reserved_balance(account(1)), 0);
+		assert_eq!(Balances::reserved_balance(account(2)), 3);
+		assert_eq!(Balances::reserved_balance(account(3)), 3);
+
+		assert_ok!(<Nfts as Mutate<AccountIdOf<Test>, ItemConfig>>::clear_attribute(&0, &0, &[3],));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
+				(Some(0), AttributeNamespace::ItemOwner, bvec![1], bvec![0]),
+				(Some(0), AttributeNamespace::Account(account(3)), bvec![2], bvec![0]),
+			]
+		);
+	});
+}
+
+#[test]
+fn set_attribute_should_respect_lock() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled(),
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 1, account(1), None));
+
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			None,
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(1),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(None, AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
+				(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
+				(Some(1), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
+			]
+		);
+		assert_eq!(Balances::reserved_balance(account(1)), 11);
+
+		assert_ok!(Nfts::set_collection_metadata(RuntimeOrigin::signed(account(1)), 0, bvec![]));
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedAttributes.into())
+		));
+
+		let e = Error::<Test>::LockedCollectionAttributes;
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				None,
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+				bvec![0],
+			),
+			e
+		);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![1],
+		));
+
+		assert_ok!(Nfts::lock_item_properties(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			false,
+			true
+		));
+		let e = Error::<Test>::LockedItemAttributes;
+		assert_noop!(
+			Nfts::set_attribute(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+				bvec![1],
+			),
+			e
+		);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(1),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![1],
+		));
+	});
+}
+
+#[test]
+fn set_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 5);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled(),
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None));
+
+		assert_ok!(Nfts::set_attributes(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(0),
+			AttributeNamespace::CollectionOwner,
+			bvec![(bvec![0], bvec![0]), (bvec![1], bvec![0, 1])],
+		));
+		assert_eq!(
+			attributes(0),
+			vec![
+				(Some(0), AttributeNamespace::CollectionOwner, bvec![0], bvec![0]),
+				(Some(0), AttributeNamespace::CollectionOwner, bvec![1], bvec![0, 1]),
+			]
+		);
+		let events = events();
+		for (key, value) in [(bvec![0], bvec![0]), (bvec![1], bvec![0, 1])] {
+			assert!(events.contains(&Event::<Test>::AttributeSet {
+				collection: 0,
+				maybe_item: Some(0),
+				key,
+				value,
+				namespace: AttributeNamespace::CollectionOwner,
+			}));
+		}
+		// item deposit + (1 + 2) + (1 + 3)
+		assert_eq!(Balances::reserved_balance(&account(1)), 8);
+		assert_eq!(Collection::<Test>::get(0).unwrap().attributes, 2);
+
+		// nothing is set if the owner can't afford all of the deposits
+		assert_noop!(
+			Nfts::set_attributes(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				Some(0),
+				AttributeNamespace::ItemOwner,
+				bvec![(bvec![0], bvec![0]), (bvec![1], bvec![0])],
+			),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+
+		// nothing is set if one of the attributes is locked
+		assert_ok!(Nfts::lock_item_properties(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			false,
+			true
+		));
+		assert_noop!(
+			Nfts::set_attributes(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(0),
+				AttributeNamespace::CollectionOwner,
+				bvec![(bvec![2], bvec![0])],
+			),
+			Error::<Test>::LockedItemAttributes,
+		);
+
+		// a batch the item owner can afford goes through
+		assert_ok!(Nfts::set_attributes(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			Some(0),
+			AttributeNamespace::ItemOwner,
+			bvec![(bvec![0], bvec![0])],
+		));
+		assert_eq!(Balances::reserved_balance(&account(2)), 3);
+	});
+}
+
+#[test]
+fn preserve_config_for_frozen_items() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 1, account(1), None));
+
+		// if the item is not locked/frozen then the config gets deleted on item burn
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 1));
+		assert!(!ItemConfigOf::<Test>::contains_key(0, 1));
+
+		// lock the item and ensure the config stays unchanged
+		assert_ok!(Nfts::lock_item_properties(RuntimeOrigin::signed(account(1)), 0, 0, true, true));
+
+		let expect_config = item_config_from_disabled_settings(
+			ItemSetting::UnlockedAttributes | ItemSetting::UnlockedMetadata,
+		);
+		let config = ItemConfigOf::<Test>::get(0, 0).unwrap();
+		assert_eq!(config, expect_config);
+
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 0));
+		let config = ItemConfigOf::<Test>::get(0, 0).unwrap();
+		assert_eq!(config, expect_config);
+
+		// can't mint with the different config
+		assert_noop!(
+			Nfts::force_mint(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				0,
+				account(2),
+				default_item_config()
+			),
+			Error::<Test>::InconsistentItemConfig
+		);
+
+		assert_ok!(Nfts::update_mint_settings(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			MintSettings {
+				default_item_settings: ItemSettings::from_disabled(
+					ItemSetting::UnlockedAttributes | ItemSetting::UnlockedMetadata
+				),
+				..Default::default()
+			}
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+	});
+}
+
+#[test]
+fn force_mint_should_respect_preserved_item_config() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(1), None));
+		assert_ok!(Nfts::lock_item_properties(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			true,
+			false
+		));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 0));
+
+		let preserved_config =
+			item_config_from_disabled_settings(ItemSetting::UnlockedMetadata.into());
+		assert_eq!(ItemConfigOf::<Test>::get(0, 0), Some(preserved_config));
+
+		// The collection's default item settings changed since, which has no effect on the
+		// config the item has to be minted with.
+		assert_ok!(Nfts::update_mint_settings(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			MintSettings {
+				default_item_settings: ItemSettings::from_disabled(
+					ItemSetting::Transferable.into()
+				),
+				..Default::default()
+			}
+		));
+
+		assert_noop!(
+			Nfts::force_mint(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				0,
+				account(2),
+				default_item_config()
+			),
+			Error::<Test>::InconsistentItemConfig
+		);
+		assert_noop!(
+			Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 0, account(2), None),
+			Error::<Test>::InconsistentItemConfig
+		);
+		// A mismatch is caught before anything is written, even outside of a dispatch.
+		assert_noop!(
+			<Nfts as Mutate<AccountIdOf<Test>, ItemConfig>>::mint_into(
+				&0,
+				&0,
+				&account(2),
+				&default_item_config(),
+				true
+			),
+			Error::<Test>::InconsistentItemConfig
+		);
+
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			0,
+			account(2),
+			preserved_config
+		));
+		assert_eq!(items(), vec![(account(2), 0, 0)]);
+		assert_eq!(ItemConfigOf::<Test>::get(0, 0), Some(preserved_config));
+		assert_eq!(Collection::<Test>::get(0).unwrap().item_configs, 1);
+	});
+}
+
+#[test]
+fn force_update_collection_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			69,
+			account(2),
+			default_item_config(),
+		));
+		assert_ok!(Nfts::set_collection_metadata(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			bvec![0; 20]
+		));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0; 20]));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 69, bvec![0; 20]));
+		assert_eq!(Balances::reserved_balance(account(1)), 65);
+
+		// force item status to be free holding
+		assert_ok!(Nfts::force_collection_config(
+			RuntimeOrigin::root(),
+			0,
+			collection_config_from_disabled_settings(CollectionSetting::DepositRequired.into()),
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 142, account(1), None));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			169,
+			account(2),
+			default_item_config(),
+		));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 142, bvec![0; 20]));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 169, bvec![0; 20]));
+
+		Balances::make_free_balance_be(&account(5), 100);
+		assert_ok!(Nfts::force_collection_owner(RuntimeOrigin::root(), 0, account(5)));
+		assert_ok!(Nfts::set_team(
+			RuntimeOrigin::root(),
+			0,
+			Some(account(2)),
+			Some(account(5)),
+			Some(account(4)),
+		));
+		assert_eq!(collections(), vec![(account(5), 0)]);
+		assert_eq!(Balances::reserved_balance(account(1)), 2);
+		assert_eq!(Balances::reserved_balance(account(5)), 63);
+
+		assert_ok!(Nfts::redeposit(
+			RuntimeOrigin::signed(account(5)),
+			0,
+			bvec![0, 42, 50, 69, 100]
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), 0);
+
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(5)), 0, 42, bvec![0; 20]));
+		assert_eq!(Balances::reserved_balance(account(5)), 42);
+
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(5)), 0, 69, bvec![0; 20]));
+		assert_eq!(Balances::reserved_balance(account(5)), 21);
+
+		assert_ok!(Nfts::set_collection_metadata(
+			RuntimeOrigin::signed(account(5)),
+			0,
+			bvec![0; 20]
+		));
+		assert_eq!(Balances::reserved_balance(account(5)), 0);
+
+		// validate new roles
+		assert_ok!(Nfts::set_team(
+			RuntimeOrigin::root(),
+			0,
+			Some(account(2)),
+			Some(account(3)),
+			Some(account(4)),
+		));
+		assert_eq!(
+			CollectionRoleOf::<Test>::get(0, account(2)).unwrap(),
+			CollectionRoles(CollectionRole::Issuer.into())
+		);
+		assert_eq!(
+			CollectionRoleOf::<Test>::get(0, account(3)).unwrap(),
+			CollectionRoles(CollectionRole::Admin.into())
+		);
+		assert_eq!(
+			CollectionRoleOf::<Test>::get(0, account(4)).unwrap(),
+			CollectionRoles(CollectionRole::Freezer.into())
+		);
+
+		assert_ok!(Nfts::set_team(
+			RuntimeOrigin::root(),
+			0,
+			Some(account(3)),
+			Some(account(2)),
+			Some(account(3)),
+		));
+
+		assert_eq!(
+			CollectionRoleOf::<Test>::get(0, account(2)).unwrap(),
+			CollectionRoles(CollectionRole::Admin.into())
+		);
+		assert_eq!(
+			CollectionRoleOf::<Test>::get(0, account(3)).unwrap(),
+			CollectionRoles(CollectionRole::Issuer | CollectionRole::Freezer)
+		);
+	});
+}
+
+#[test]
+fn burn_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::set_team(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(account(2)),
+			Some(account(3)),
+			Some(account(4)),
+		));
+
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(account(5)), 0, 42),
+			Error::<Test>::UnknownItem
+		);
+
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			account(5),
+			default_item_config()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			69,
+			account(5),
+			default_item_config()
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), 2);
+
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(account(0)), 0, 42),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(5)), 0, 42));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(5)), 0, 69));
+		assert_eq!(Balances::reserved_balance(account(1)), 0);
+	});
+}
+
+#[test]
+fn burn_many_atomic_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(2), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		for item in [42, 43, 44] {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(2), None));
+		}
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 45, account(3), None));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0u8; 10]));
+		assert_eq!(Balances::reserved_balance(account(1)), 4 + 11);
+
+		assert_noop!(
+			Nfts::burn_many(RuntimeOrigin::signed(account(2)), 1, bvec![42], None, true),
+			Error::<Test>::UnknownCollection
+		);
+		// One unknown or foreign item fails the whole batch
+		assert_noop!(
+			Nfts::burn_many(RuntimeOrigin::signed(account(2)), 0, bvec![42, 43, 46], None, true),
+			Error::<Test>::UnknownItem
+		);
+		assert_noop!(
+			Nfts::burn_many(RuntimeOrigin::signed(account(2)), 0, bvec![42, 45], None, true),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::burn_many(RuntimeOrigin::root(), 0, bvec![42, 45], Some(account(2)), true),
+			Error::<Test>::WrongOwner
+		);
+
+		assert_ok!(Nfts::burn_many(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			bvec![42, 43],
+			Some(account(2)),
+			true
+		));
+		let events = events();
+		assert!(events.contains(&Event::<Test>::Burned {
+			collection: 0,
+			item: 42,
+			owner: account(2)
+		}));
+		assert!(events.contains(&Event::<Test>::Burned {
+			collection: 0,
+			item: 43,
+			owner: account(2)
+		}));
+		assert_eq!(items(), vec![(account(2), 0, 44), (account(3), 0, 45)]);
+		assert!(!ItemMetadataOf::<Test>::contains_key(0, 42));
+		// The item and metadata deposits are refunded
+		assert_eq!(Balances::reserved_balance(account(1)), 2);
+		assert_eq!(Collection::<Test>::get(0).unwrap().items, 2);
+
+		// The force origin may burn anyone's items
+		assert_ok!(Nfts::burn_many(RuntimeOrigin::root(), 0, bvec![44, 45], None, true));
+		assert!(items().is_empty());
+		assert_eq!(Balances::reserved_balance(account(1)), 0);
+	});
+}
+
+#[test]
+fn burn_many_best_effort_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		for item in [42, 43, 44] {
+			assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, item, account(2), None));
+		}
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 45, account(3), None));
+
+		// Unknown and foreign items are skipped
+		assert_ok!(Nfts::burn_many(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			bvec![42, 45, 46, 43],
+			None,
+			false
+		));
+		assert_eq!(items(), vec![(account(2), 0, 44), (account(3), 0, 45)]);
+		assert_eq!(Balances::reserved_balance(account(1)), 2);
+		assert_eq!(Collection::<Test>::get(0).unwrap().items, 2);
+
+		assert_ok!(Nfts::burn_many(
+			RuntimeOrigin::root(),
+			0,
+			bvec![44, 45],
+			Some(account(3)),
+			false
+		));
+		assert_eq!(items(), vec![(account(2), 0, 44)]);
+		assert_eq!(events().into_iter().filter(|e| matches!(e, Event::Burned { .. })).count(), 3);
+	});
+}
+
+#[test]
+fn approval_lifecycle_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			account(2),
+			default_item_config()
+		));
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			account(3),
+			None
+		));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(4)));
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(3)),
+			Error::<Test>::NoPermission
+		);
+		assert!(Item::<Test>::get(0, 42).unwrap().approvals.is_empty());
+
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(4)),
+			0,
+			42,
+			account(2),
+			None
+		));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(2)));
+
+		// ensure we can't buy an item when the collection has a NonTransferableItems flag
+		let collection_id = 1;
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_from_disabled_settings(
+				CollectionSetting::TransferableItems | CollectionSetting::DepositRequired
+			)
+		));
+
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(account(1)),
+			1,
+			collection_id,
+			account(1),
+			None,
+		));
+
+		assert_noop!(
+			Nfts::approve_transfer(
+				RuntimeOrigin::signed(account(1)),
+				collection_id,
+				1,
+				account(2),
+				None
+			),
+			Error::<Test>::ItemsNonTransferable
+		);
+	});
+}
+
+#[test]
+fn cancel_approval_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			account(2),
+			default_item_config()
+		));
+
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			account(3),
+			None
+		));
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 1, 42, account(3)),
+			Error::<Test>::UnknownCollection
+		);
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 43, account(3)),
+			Error::<Test>::UnknownItem
+		);
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(account(3)), 0, 42, account(3)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 42, account(4)),
+			Error::<Test>::NotDelegate
+		);
+
+		assert_ok!(Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 42, account(3)),
+			Error::<Test>::NotDelegate
+		);
+
+		let current_block = 1;
+		System::set_block_number(current_block);
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			69,
+			account(2),
+			default_item_config()
+		));
+		// approval expires after 2 blocks.
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			account(3),
+			Some(2)
+		));
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(account(5)), 0, 42, account(3)),
+			Error::<Test>::NoPermission
+		);
+
+		System::set_block_number(current_block + 3);
+		// 5 can cancel the approval since the deadline has passed.
+		assert_ok!(Nfts::cancel_approval(RuntimeOrigin::signed(account(5)), 0, 42, account(3)));
+		assert_eq!(approvals(0, 69), vec![]);
+	});
+}
+
+#[test]
+fn approving_multiple_accounts_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			account(2),
+			default_item_config()
+		));
+
+		let current_block = 1;
+		System::set_block_number(current_block);
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			account(3),
+			None
+		));
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			account(4),
+			None
+		));
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			account(5),
+			Some(2)
+		));
+		assert_eq!(
+			approvals(0, 42),
+			vec![(account(3), None), (account(4), None), (account(5), Some(current_block + 2))]
+		);
+
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(4)), 0, 42, account(6)));
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(7)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(account(5)), 0, 42, account(8)),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn approvals_limit_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			account(2),
+			default_item_config()
+		));
+
+		for i in 3..13 {
+			assert_ok!(Nfts::approve_transfer(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				42,
+				account(i),
+				None
+			));
+		}
+		// the limit is 10
+		assert_noop!(
+			Nfts::approve_transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(14), None),
+			Error::<Test>::ReachedApprovalLimit
+		);
+	});
+}
+
+#[test]
+fn approvals_limit_is_configurable() {
+	new_test_ext().execute_with(|| {
+		ApprovalsLimit::set(&2);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			account(2),
+			default_item_config()
+		));
+
+		for i in 3..5 {
+			assert_ok!(Nfts::approve_transfer(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				42,
+				account(i),
+				None
+			));
+		}
+		assert_noop!(
+			Nfts::approve_transfer(RuntimeOrigin::signed(account(2)), 0, 42, account(5), None),
+			Error::<Test>::ReachedApprovalLimit
+		);
+
+		// clearing the approvals makes room again
+		assert_ok!(Nfts::clear_all_transfer_approvals(RuntimeOrigin::signed(account(2)), 0, 42));
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			account(5),
+			None
+		));
+	});
+}
+
+#[test]
+fn approval_deadline_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(0);
+		assert!(System::block_number().is_zero());
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_from_disabled_settings(CollectionSetting::DepositRequired.into())
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			account(2),
+			default_item_config()
+		));
+
+		// the approval expires after the 2nd block.
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			account(3),
+			Some(2)
+		));
+
+		System::set_block_number(3);
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(4)),
+			Error::<Test>::ApprovalExpired
+		);
+		System::set_block_number(1);
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(4)));
+
+		assert_eq!(System::block_number(), 1);
+		// make a new approval with a deadline after 4 blocks, so it will expire after the 5th
+		// block.
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(4)),
+			0,
+			42,
+			account(6),
+			Some(4)
+		));
+		// this should still work.
+		System::set_block_number(5);
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(6)), 0, 42, account(5)));
+	});
+}
+
+#[test]
+fn approval_deadline_is_bounded_by_max_duration() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_from_disabled_settings(CollectionSetting::DepositRequired.into())
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			account(2),
+			default_item_config()
+		));
+
+		let max_duration: u64 = <Test as Config>::MaxDeadlineDuration::get();
+		assert_noop!(
+			Nfts::approve_transfer(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				42,
+				account(3),
+				Some(max_duration + 1)
+			),
+			Error::<Test>::WrongDuration
+		);
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			account(3),
+			Some(max_duration)
+		));
+		assert_eq!(
+			approvals(0, 42),
+			vec![(account(3), Some(System::block_number() + max_duration))]
+		);
+	});
+}
+
+#[test]
+fn cancel_approval_works_with_admin() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			account(2),
+			default_item_config()
+		));
+
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			account(3),
+			None
+		));
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 1, 42, account(1)),
+			Error::<Test>::UnknownCollection
+		);
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 43, account(1)),
+			Error::<Test>::UnknownItem
+		);
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 42, account(4)),
+			Error::<Test>::NotDelegate
+		);
+
+		assert_ok!(Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::signed(account(2)), 0, 42, account(1)),
+			Error::<Test>::NotDelegate
+		);
+	});
+}
+
+#[test]
+fn cancel_approval_works_with_force() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			account(2),
+			default_item_config()
+		));
+
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			account(3),
+			None
+		));
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::root(), 1, 42, account(1)),
+			Error::<Test>::UnknownCollection
+		);
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::root(), 0, 43, account(1)),
+			Error::<Test>::UnknownItem
+		);
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::root(), 0, 42, account(4)),
+			Error::<Test>::NotDelegate
+		);
+
+		assert_ok!(Nfts::cancel_approval(RuntimeOrigin::root(), 0, 42, account(3)));
+		assert_noop!(
+			Nfts::cancel_approval(RuntimeOrigin::root(), 0, 42, account(1)),
+			Error::<Test>::NotDelegate
+		);
+	});
+}
+
+#[test]
+fn clear_all_transfer_approvals_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			42,
+			account(2),
+			default_item_config()
+		));
+
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			account(3),
+			None
+		));
+		assert_ok!(Nfts::approve_transfer(
+			RuntimeOrigin::signed(account(2)),
+			0,
+			42,
+			account(4),
+			None
+		));
+
+		assert_noop!(
+			Nfts::clear_all_transfer_approvals(RuntimeOrigin::signed(account(3)), 0, 42),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Nfts::clear_all_transfer_approvals(RuntimeOrigin::signed(account(2)), 0, 42));
+
+		assert!(events().contains(&Event::<Test>::AllApprovalsCancelled {
+			collection: 0,
+			item: 42,
+			owner: account(2),
+		}));
+		assert_eq!(approvals(0, 42), vec![]);
+
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(5)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(account(4)), 0, 42, account(5)),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn max_supply_should_work() {
+	new_test_ext().execute_with(|| {
+		let collection_id = 0;
+		let user_id = account(1);
+		let max_supply = 1;
+
+		// validate set_collection_max_supply
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_id.clone(),
+			default_collection_config()
+		));
+		assert_eq!(CollectionConfigOf::<Test>::get(collection_id).unwrap().max_supply, None);
+
+		assert_ok!(Nfts::set_collection_max_supply(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			max_supply
+		));
+		assert_eq!(
+			CollectionConfigOf::<Test>::get(collection_id).unwrap().max_supply,
+			Some(max_supply)
+		);
+
+		assert!(events().contains(&Event::<Test>::CollectionMaxSupplySet {
+			collection: collection_id,
+			max_supply,
+		}));
+
+		assert_ok!(Nfts::set_collection_max_supply(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			max_supply + 1
+		));
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedMaxSupply.into())
+		));
+		assert_noop!(
+			Nfts::set_collection_max_supply(
+				RuntimeOrigin::signed(user_id.clone()),
+				collection_id,
+				max_supply + 2
+			),
+			Error::<Test>::MaxSupplyLocked
+		);
+
+		// validate we can't mint more to max supply
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			0,
+			user_id.clone(),
+			None
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			1,
+			user_id.clone(),
+			None
+		));
+		assert_noop!(
+			Nfts::mint(RuntimeOrigin::signed(user_id.clone()), collection_id, 2, user_id, None),
+			Error::<Test>::MaxSupplyReached
+		);
+	});
+}
+
+#[test]
+fn item_id_range_should_work() {
+	new_test_ext().execute_with(|| {
+		let collection_id = 0;
+		let user_id = account(1);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_id.clone(),
+			CollectionConfig { item_id_range: Some((10, 20)), ..default_collection_config() }
+		));
+
+		// the bounds of the range are allowed
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			10,
+			user_id.clone(),
+			None
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			20,
+			user_id.clone(),
+			default_item_config()
+		));
+
+		// ids outside of the range are rejected
+		assert_noop!(
+			Nfts::mint(
+				RuntimeOrigin::signed(user_id.clone()),
+				collection_id,
+				9,
+				user_id.clone(),
+				None
+			),
+			Error::<Test>::ItemIdOutOfRange
+		);
+		assert_noop!(
+			Nfts::force_mint(
+				RuntimeOrigin::root(),
+				collection_id,
+				21,
+				user_id.clone(),
+				default_item_config()
+			),
+			Error::<Test>::ItemIdOutOfRange
+		);
+
+		// without a range any id can be used
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_id.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_id.clone()),
+			1,
+			u32::MAX,
+			user_id.clone(),
+			None
+		));
+	});
+}
+
+#[test]
+fn mint_settings_should_work() {
+	new_test_ext().execute_with(|| {
+		let collection_id = 0;
+		let user_id = account(1);
+		let item_id = 0;
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_id.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_id,
+			user_id.clone(),
+			None,
+		));
+		assert_eq!(
+			ItemConfigOf::<Test>::get(collection_id, item_id)
+				.unwrap()
+				.settings
+				.get_disabled(),
+			ItemSettings::all_enabled().get_disabled()
+		);
+
+		let collection_id = 1;
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_id.clone(),
+			CollectionConfig {
+				mint_settings: MintSettings {
+					default_item_settings: ItemSettings::from_disabled(
+						ItemSetting::Transferable | ItemSetting::UnlockedMetadata
+					),
+					..Default::default()
+				},
+				..default_collection_config()
+			}
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_id,
+			user_id.clone(),
+			None,
+		));
+		assert_eq!(
+			ItemConfigOf::<Test>::get(collection_id, item_id)
+				.unwrap()
+				.settings
+				.get_disabled(),
+			ItemSettings::from_disabled(ItemSetting::Transferable | ItemSetting::UnlockedMetadata)
+				.get_disabled()
+		);
+	});
+}
+
+#[test]
+fn unknown_collection_and_unknown_item_are_told_apart() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+
+		for (collection, item, error) in
+			[(1, 42, Error::<Test>::UnknownCollection), (0, 43, Error::<Test>::UnknownItem)]
+		{
+			let error = DispatchError::from(error);
+			assert_noop!(
+				Nfts::approve_transfer(
+					RuntimeOrigin::signed(account(1)),
+					collection,
+					item,
+					account(2),
+					None
+				),
+				error
+			);
+			assert_noop!(
+				Nfts::cancel_approval(
+					RuntimeOrigin::signed(account(1)),
+					collection,
+					item,
+					account(2)
+				),
+				error
+			);
+			assert_noop!(
+				Nfts::clear_all_transfer_approvals(
+					RuntimeOrigin::signed(account(1)),
+					collection,
+					item
+				),
+				error
+			);
+			assert_noop!(
+				Nfts::set_price(
+					RuntimeOrigin::signed(account(1)),
+					collection,
+					item,
+					Some(1),
+					None,
+					None
+				),
+				error
+			);
+			assert_noop!(
+				Nfts::buy_item(RuntimeOrigin::signed(account(2)), collection, item, 1),
+				error
+			);
+		}
+	});
+}
+
+#[test]
+fn set_price_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_id = account(1);
+		let collection_id = 0;
+		let item_1 = 1;
+		let item_2 = 2;
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_id.clone(),
+			default_collection_config()
+		));
+
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_1,
+			user_id.clone(),
+			None,
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_2,
+			user_id.clone(),
+			None,
+		));
+
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_1,
+			Some(1),
+			None,
+			None,
+		));
+
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_2,
+			Some(2),
+			Some(account(3)),
+			None,
+		));
+
+		let item = ItemPriceOf::<Test>::get(collection_id, item_1).unwrap();
+		assert_eq!(item.0, 1);
+		assert_eq!(item.1, None);
+
+		let item = ItemPriceOf::<Test>::get(collection_id, item_2).unwrap();
+		assert_eq!(item.0, 2);
+		assert_eq!(item.1, Some(account(3)));
+
+		assert!(events().contains(&Event::<Test>::ItemPriceSet {
+			collection: collection_id,
+			item: item_1,
+			price: 1,
+			whitelisted_buyer: None,
+			deadline: None,
+		}));
+
+		// changing the whitelisted buyer withdraws the previous listing
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_2,
+			Some(3),
+			Some(account(4)),
+			None,
+		));
+		assert_eq!(
+			events(),
+			vec![
+				Event::<Test>::ItemPriceRemoved { collection: collection_id, item: item_2 },
+				Event::<Test>::ItemPriceSet {
+					collection: collection_id,
+					item: item_2,
+					price: 3,
+					whitelisted_buyer: Some(account(4)),
+					deadline: None,
+				},
+			]
+		);
+		assert_eq!(
+			ItemPriceOf::<Test>::get(collection_id, item_2),
+			Some((3, Some(account(4)), None))
+		);
+
+		// only the price changes with the same buyer
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_2,
+			Some(4),
+			Some(account(4)),
+			None,
+		));
+		assert_eq!(
+			events(),
+			vec![Event::<Test>::ItemPriceSet {
+				collection: collection_id,
+				item: item_2,
+				price: 4,
+				whitelisted_buyer: Some(account(4)),
+				deadline: None,
+			}]
+		);
+
+		// validate we can unset the price
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_2,
+			None,
+			None,
+			None
+		));
+		assert!(events().contains(&Event::<Test>::ItemPriceRemoved {
+			collection: collection_id,
+			item: item_2
+		}));
+		assert!(!ItemPriceOf::<Test>::contains_key(collection_id, item_2));
+
+		// ensure we can't set price when the items are non-transferable
+		let collection_id = 1;
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_id.clone(),
+			collection_config_from_disabled_settings(
+				CollectionSetting::TransferableItems | CollectionSetting::DepositRequired
+			)
+		));
+
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_1,
+			user_id.clone(),
+			None,
+		));
+
+		assert_noop!(
+			Nfts::set_price(
+				RuntimeOrigin::signed(user_id.clone()),
+				collection_id,
+				item_1,
+				Some(2),
+				None,
+				None
+			),
+			Error::<Test>::ItemsNonTransferable
+		);
+	});
+}
+
+#[test]
+fn buy_item_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let item_1 = 1;
+		let item_2 = 2;
+		let item_3 = 3;
+		let price_1 = 20;
+		let price_2 = 30;
+		let initial_balance = 100;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_1,
+			user_1.clone(),
+			None
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_2,
+			user_1.clone(),
+			None
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_3,
+			user_1.clone(),
+			None
+		));
+
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_1,
+			Some(price_1),
+			None,
+			None,
+		));
+
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_2,
+			Some(price_2),
+			Some(user_3.clone()),
+			None,
+		));
+
+		// can't buy for less
+		assert_noop!(
+			Nfts::buy_item(RuntimeOrigin::signed(user_2.clone()), collection_id, item_1, 1),
+			Error::<Test>::BidTooLow
+		);
+
+		// pass the higher price to validate it will still deduct correctly
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_1,
+			price_1 + 1,
 		));
+
+		// validate the new owner & balances
+		let item = Item::<Test>::get(collection_id, item_1).unwrap();
+		assert_eq!(item.owner, user_2.clone());
+		assert_eq!(Balances::total_balance(&user_1.clone()), initial_balance + price_1);
+		assert_eq!(Balances::total_balance(&user_2.clone()), initial_balance - price_1);
+
+		// can't buy from yourself
 		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::root(), 1, 42, account(1)),
-			Error::<Test>::UnknownItem
-		);
-		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::root(), 0, 43, account(1)),
-			Error::<Test>::UnknownItem
+			Nfts::buy_item(RuntimeOrigin::signed(user_1.clone()), collection_id, item_2, price_2),
+			Error::<Test>::NoPermission
 		);
+
+		// can't buy when the item is listed for a specific buyer
 		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::root(), 0, 42, account(4)),
-			Error::<Test>::NotDelegate
+			Nfts::buy_item(RuntimeOrigin::signed(user_2.clone()), collection_id, item_2, price_2),
+			Error::<Test>::NoPermission
 		);
 
-		assert_ok!(Nfts::cancel_approval(RuntimeOrigin::root(), 0, 42, account(3)));
+		// can buy when I'm a whitelisted buyer
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_3.clone()),
+			collection_id,
+			item_2,
+			price_2
+		));
+
+		assert!(events().contains(&Event::<Test>::ItemBought {
+			collection: collection_id,
+			item: item_2,
+			price: price_2,
+			seller: user_1.clone(),
+			buyer: user_3.clone(),
+		}));
+
+		// ensure we reset the buyer field
+		assert!(!ItemPriceOf::<Test>::contains_key(collection_id, item_2));
+
+		// can't buy when item is not for sale
 		assert_noop!(
-			Nfts::cancel_approval(RuntimeOrigin::root(), 0, 42, account(1)),
-			Error::<Test>::NotDelegate
+			Nfts::buy_item(RuntimeOrigin::signed(user_2.clone()), collection_id, item_3, price_2),
+			Error::<Test>::NotForSale
 		);
+
+		// ensure we can't buy an item when the collection or an item are frozen
+		{
+			assert_ok!(Nfts::set_price(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_3,
+				Some(price_1),
+				None,
+				None,
+			));
+
+			// lock the collection
+			assert_ok!(Nfts::lock_collection(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				CollectionSettings::from_disabled(CollectionSetting::TransferableItems.into())
+			));
+
+			let buy_item_call = mock::RuntimeCall::Nfts(crate::Call::<Test>::buy_item {
+				collection: collection_id,
+				item: item_3,
+				bid_price: price_1,
+			});
+			assert_noop!(
+				buy_item_call.dispatch(RuntimeOrigin::signed(user_2.clone())),
+				Error::<Test>::ItemsNonTransferable
+			);
+
+			// unlock the collection
+			assert_ok!(Nfts::force_collection_config(
+				RuntimeOrigin::root(),
+				collection_id,
+				collection_config_with_all_settings_enabled(),
+			));
+
+			// lock the transfer
+			assert_ok!(Nfts::lock_item_transfer(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_3,
+			));
+
+			let buy_item_call = mock::RuntimeCall::Nfts(crate::Call::<Test>::buy_item {
+				collection: collection_id,
+				item: item_3,
+				bid_price: price_1,
+			});
+			assert_noop!(
+				buy_item_call.dispatch(RuntimeOrigin::signed(user_2)),
+				Error::<Test>::ItemLocked
+			);
+		}
 	});
 }
 
 #[test]
-fn clear_all_transfer_approvals_works() {
+fn buy_item_should_respect_the_listing_deadline() {
 	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let collection_id = 0;
+		let item_1 = 1;
+		let item_2 = 2;
+		let price = 20;
+
+		Balances::make_free_balance_be(&user_1, 100);
+		Balances::make_free_balance_be(&user_2, 100);
+
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
-			account(1),
+			user_1.clone(),
 			default_collection_config()
 		));
-		assert_ok!(Nfts::force_mint(
-			RuntimeOrigin::signed(account(1)),
-			0,
-			42,
-			account(2),
-			default_item_config()
-		));
-
-		assert_ok!(Nfts::approve_transfer(
-			RuntimeOrigin::signed(account(2)),
-			0,
-			42,
-			account(3),
-			None
-		));
-		assert_ok!(Nfts::approve_transfer(
-			RuntimeOrigin::signed(account(2)),
-			0,
-			42,
-			account(4),
-			None
-		));
+		for item in [item_1, item_2] {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item,
+				user_1.clone(),
+				None
+			));
+		}
 
+		System::set_block_number(5);
 		assert_noop!(
-			Nfts::clear_all_transfer_approvals(RuntimeOrigin::signed(account(3)), 0, 42),
-			Error::<Test>::NoPermission
+			Nfts::set_price(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_1,
+				Some(price),
+				None,
+				Some(4)
+			),
+			Error::<Test>::DeadlineExpired
 		);
-
-		assert_ok!(Nfts::clear_all_transfer_approvals(RuntimeOrigin::signed(account(2)), 0, 42));
-
-		assert!(events().contains(&Event::<Test>::AllApprovalsCancelled {
-			collection: 0,
-			item: 42,
-			owner: account(2),
+		for item in [item_1, item_2] {
+			assert_ok!(Nfts::set_price(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item,
+				Some(price),
+				None,
+				Some(10),
+			));
+		}
+		assert_eq!(ItemPriceOf::<Test>::get(collection_id, item_1), Some((price, None, Some(10))));
+		assert!(events().contains(&Event::<Test>::ItemPriceSet {
+			collection: collection_id,
+			item: item_1,
+			price,
+			whitelisted_buyer: None,
+			deadline: Some(10),
 		}));
-		assert_eq!(approvals(0, 42), vec![]);
 
+		// the item can be bought up to and including the deadline
+		System::set_block_number(10);
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_1,
+			price
+		));
+		assert_eq!(Nfts::owner(collection_id, item_1), Some(user_2.clone()));
+
+		System::set_block_number(11);
 		assert_noop!(
-			Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(5)),
-			Error::<Test>::NoPermission
-		);
-		assert_noop!(
-			Nfts::transfer(RuntimeOrigin::signed(account(4)), 0, 42, account(5)),
-			Error::<Test>::NoPermission
+			Nfts::buy_item(RuntimeOrigin::signed(user_2.clone()), collection_id, item_2, price),
+			Error::<Test>::ListingExpired
 		);
+		assert_eq!(Nfts::owner(collection_id, item_2), Some(user_1));
 	});
 }
 
 #[test]
-fn max_supply_should_work() {
+fn clear_expired_price_should_work() {
 	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
 		let collection_id = 0;
-		let user_id = account(1);
-		let max_supply = 1;
+		let item_1 = 1;
+		let item_2 = 2;
 
-		// validate set_collection_max_supply
+		Balances::make_free_balance_be(&user_1, 100);
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
-			user_id.clone(),
+			user_1.clone(),
 			default_collection_config()
 		));
-		assert_eq!(CollectionConfigOf::<Test>::get(collection_id).unwrap().max_supply, None);
-
-		assert_ok!(Nfts::set_collection_max_supply(
-			RuntimeOrigin::signed(user_id.clone()),
-			collection_id,
-			max_supply
-		));
-		assert_eq!(
-			CollectionConfigOf::<Test>::get(collection_id).unwrap().max_supply,
-			Some(max_supply)
-		);
-
-		assert!(events().contains(&Event::<Test>::CollectionMaxSupplySet {
-			collection: collection_id,
-			max_supply,
-		}));
-
-		assert_ok!(Nfts::set_collection_max_supply(
-			RuntimeOrigin::signed(user_id.clone()),
+		for item in [item_1, item_2] {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item,
+				user_1.clone(),
+				None
+			));
+		}
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
 			collection_id,
-			max_supply + 1
+			item_1,
+			Some(1),
+			None,
+			Some(5),
 		));
-		assert_ok!(Nfts::lock_collection(
-			RuntimeOrigin::signed(user_id.clone()),
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
 			collection_id,
-			CollectionSettings::from_disabled(CollectionSetting::UnlockedMaxSupply.into())
+			item_2,
+			Some(1),
+			None,
+			None,
 		));
+
 		assert_noop!(
-			Nfts::set_collection_max_supply(
-				RuntimeOrigin::signed(user_id.clone()),
-				collection_id,
-				max_supply + 2
-			),
-			Error::<Test>::MaxSupplyLocked
+			Nfts::clear_expired_price(RuntimeOrigin::signed(account(3)), collection_id, 3),
+			Error::<Test>::NotForSale
+		);
+		assert_noop!(
+			Nfts::clear_expired_price(RuntimeOrigin::signed(account(3)), collection_id, item_1),
+			Error::<Test>::ListingNotExpired
 		);
 
-		// validate we can't mint more to max supply
-		assert_ok!(Nfts::mint(
-			RuntimeOrigin::signed(user_id.clone()),
-			collection_id,
-			0,
-			user_id.clone(),
-			None
-		));
-		assert_ok!(Nfts::mint(
-			RuntimeOrigin::signed(user_id.clone()),
-			collection_id,
-			1,
-			user_id.clone(),
-			None
-		));
+		System::set_block_number(6);
+		// listings without a deadline never expire
 		assert_noop!(
-			Nfts::mint(RuntimeOrigin::signed(user_id.clone()), collection_id, 2, user_id, None),
-			Error::<Test>::MaxSupplyReached
+			Nfts::clear_expired_price(RuntimeOrigin::signed(account(3)), collection_id, item_2),
+			Error::<Test>::ListingNotExpired
 		);
+		let balance = Balances::free_balance(account(3));
+		assert_ok!(Nfts::clear_expired_price(
+			RuntimeOrigin::signed(account(3)),
+			collection_id,
+			item_1
+		));
+		assert!(!ItemPriceOf::<Test>::contains_key(collection_id, item_1));
+		assert_eq!(Balances::free_balance(account(3)), balance);
+		assert!(events().contains(&Event::<Test>::ItemPriceRemoved {
+			collection: collection_id,
+			item: item_1,
+		}));
 	});
 }
 
 #[test]
-fn mint_settings_should_work() {
+fn pay_tips_should_work() {
 	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
 		let collection_id = 0;
-		let user_id = account(1);
-		let item_id = 0;
+		let item_id = 1;
+		let tip = 2;
+		let initial_balance = 100;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
 
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
-			user_id.clone(),
+			user_2.clone(),
 			default_collection_config()
 		));
 		assert_ok!(Nfts::mint(
-			RuntimeOrigin::signed(user_id.clone()),
+			RuntimeOrigin::signed(user_2.clone()),
 			collection_id,
 			item_id,
-			user_id.clone(),
-			None,
+			user_2.clone(),
+			None
 		));
-		assert_eq!(
-			ItemConfigOf::<Test>::get(collection_id, item_id)
-				.unwrap()
-				.settings
-				.get_disabled(),
-			ItemSettings::all_enabled().get_disabled()
-		);
 
-		let collection_id = 1;
+		assert_ok!(Nfts::pay_tips(
+			RuntimeOrigin::signed(user_1.clone()),
+			bvec![
+				ItemTip {
+					collection: collection_id,
+					item: item_id,
+					receiver: user_2.clone(),
+					amount: tip
+				},
+				ItemTip {
+					collection: collection_id,
+					item: item_id,
+					receiver: user_3.clone(),
+					amount: tip
+				},
+			]
+		));
+
+		assert_eq!(Balances::total_balance(&user_1), initial_balance - tip * 2);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance + tip);
+		assert_eq!(Balances::total_balance(&user_3), initial_balance + tip);
+
+		let events = events();
+		assert!(events.contains(&Event::<Test>::TipSent {
+			collection: collection_id,
+			item: item_id,
+			sender: user_1.clone(),
+			receiver: user_2.clone(),
+			amount: tip,
+		}));
+		assert!(events.contains(&Event::<Test>::TipSent {
+			collection: collection_id,
+			item: item_id,
+			sender: user_1.clone(),
+			receiver: user_3.clone(),
+			amount: tip,
+		}));
+	});
+}
+
+#[test]
+fn pay_tips_should_check_items() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		Balances::make_free_balance_be(&user_1, 100);
+		Balances::make_free_balance_be(&user_2, 100);
+
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
-			user_id.clone(),
-			CollectionConfig {
-				mint_settings: MintSettings {
-					default_item_settings: ItemSettings::from_disabled(
-						ItemSetting::Transferable | ItemSetting::UnlockedMetadata
-					),
-					..Default::default()
-				},
-				..default_collection_config()
-			}
+			user_2.clone(),
+			default_collection_config()
 		));
-		assert_ok!(Nfts::mint(
-			RuntimeOrigin::signed(user_id.clone()),
-			collection_id,
-			item_id,
-			user_id.clone(),
-			None,
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(user_2.clone()), 0, 1, user_2.clone(), None));
+		let tip =
+			|collection, item| ItemTip { collection, item, receiver: user_2.clone(), amount: 1 };
+
+		// tipping an unknown item or collection fails, even along with valid tips
+		assert_noop!(
+			Nfts::pay_tips(RuntimeOrigin::signed(user_1.clone()), bvec![tip(0, 2)]),
+			Error::<Test>::UnknownItem
+		);
+		assert_noop!(
+			Nfts::pay_tips(RuntimeOrigin::signed(user_1.clone()), bvec![tip(0, 1), tip(1, 1)]),
+			Error::<Test>::UnknownItem
+		);
+
+		// up to `MaxTips` tips are paid at once
+		let max_tips: u32 = <Test as Config>::MaxTips::get();
+		let tips: Vec<_> = (0..max_tips).map(|_| tip(0, 1)).collect();
+		assert_ok!(Nfts::pay_tips(
+			RuntimeOrigin::signed(user_1.clone()),
+			tips.clone().try_into().unwrap()
 		));
+		assert_eq!(Balances::total_balance(&user_1), 100 - max_tips as u64);
+		assert_eq!(Balances::total_balance(&user_2), 100 + max_tips as u64);
 		assert_eq!(
-			ItemConfigOf::<Test>::get(collection_id, item_id)
-				.unwrap()
-				.settings
-				.get_disabled(),
-			ItemSettings::from_disabled(ItemSetting::Transferable | ItemSetting::UnlockedMetadata)
-				.get_disabled()
+			events()
+				.into_iter()
+				.filter(|e| matches!(e, Event::<Test>::TipSent { .. }))
+				.count(),
+			max_tips as usize
 		);
+
+		// ... but no more
+		let too_many = [tips, vec![tip(0, 1)]].concat();
+		assert!(BoundedVec::<_, <Test as Config>::MaxTips>::try_from(too_many).is_err());
 	});
 }
 
 #[test]
-fn set_price_should_work() {
+fn buy_item_with_tip_should_work() {
 	new_test_ext().execute_with(|| {
-		let user_id = account(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
 		let collection_id = 0;
 		let item_1 = 1;
 		let item_2 = 2;
+		let price = 20;
+		let tip = 5;
+		let initial_balance = 100;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
 
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
-			user_id.clone(),
+			user_1.clone(),
 			default_collection_config()
 		));
+		for item in [item_1, item_2] {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item,
+				user_2.clone(),
+				None
+			));
+			assert_ok!(Nfts::set_price(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item,
+				Some(price),
+				None,
+				None,
+			));
+		}
 
-		assert_ok!(Nfts::mint(
-			RuntimeOrigin::signed(user_id.clone()),
-			collection_id,
-			item_1,
-			user_id.clone(),
-			None,
-		));
-		assert_ok!(Nfts::mint(
-			RuntimeOrigin::signed(user_id.clone()),
-			collection_id,
-			item_2,
-			user_id.clone(),
-			None,
-		));
-
-		assert_ok!(Nfts::set_price(
-			RuntimeOrigin::signed(user_id.clone()),
+		// the tip goes to the collection's owner
+		assert_ok!(Nfts::buy_item_with_tip(
+			RuntimeOrigin::signed(user_3.clone()),
 			collection_id,
 			item_1,
-			Some(1),
-			None,
-		));
-
-		assert_ok!(Nfts::set_price(
-			RuntimeOrigin::signed(user_id.clone()),
-			collection_id,
-			item_2,
-			Some(2),
-			Some(account(3)),
+			price,
+			Some(tip),
 		));
-
-		let item = ItemPriceOf::<Test>::get(collection_id, item_1).unwrap();
-		assert_eq!(item.0, 1);
-		assert_eq!(item.1, None);
-
-		let item = ItemPriceOf::<Test>::get(collection_id, item_2).unwrap();
-		assert_eq!(item.0, 2);
-		assert_eq!(item.1, Some(account(3)));
-
-		assert!(events().contains(&Event::<Test>::ItemPriceSet {
+		assert_eq!(Item::<Test>::get(collection_id, item_1).unwrap().owner, user_3);
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + tip);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance + price);
+		assert_eq!(Balances::total_balance(&user_3), initial_balance - price - tip);
+		let tip_events = events();
+		assert!(tip_events.contains(&Event::<Test>::ItemBought {
 			collection: collection_id,
 			item: item_1,
-			price: 1,
-			whitelisted_buyer: None,
+			price,
+			seller: user_2.clone(),
+			buyer: user_3.clone(),
+		}));
+		assert!(tip_events.contains(&Event::<Test>::TipSent {
+			collection: collection_id,
+			item: item_1,
+			sender: user_3.clone(),
+			receiver: user_1.clone(),
+			amount: tip,
 		}));
 
-		// validate we can unset the price
-		assert_ok!(Nfts::set_price(
-			RuntimeOrigin::signed(user_id.clone()),
+		// without a tip it's a regular purchase
+		assert_ok!(Nfts::buy_item_with_tip(
+			RuntimeOrigin::signed(user_3.clone()),
 			collection_id,
 			item_2,
+			price,
 			None,
-			None
 		));
-		assert!(events().contains(&Event::<Test>::ItemPriceRemoved {
+		assert_eq!(Item::<Test>::get(collection_id, item_2).unwrap().owner, user_3);
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + tip);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance + price * 2);
+		assert_eq!(Balances::total_balance(&user_3), initial_balance - price * 2 - tip);
+		let events = events();
+		assert!(events.contains(&Event::<Test>::ItemBought {
 			collection: collection_id,
-			item: item_2
+			item: item_2,
+			price,
+			seller: user_2.clone(),
+			buyer: user_3.clone(),
 		}));
-		assert!(!ItemPriceOf::<Test>::contains_key(collection_id, item_2));
+		assert!(!events.iter().any(|e| matches!(e, Event::<Test>::TipSent { .. })));
+	});
+}
+
+#[test]
+fn item_cannot_be_listed_and_offered_in_a_swap_at_once() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let collection_id = 0;
 
-		// ensure we can't set price when the items are non-transferable
-		let collection_id = 1;
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
-			user_id.clone(),
-			collection_config_from_disabled_settings(
-				CollectionSetting::TransferableItems | CollectionSetting::DepositRequired
-			)
+			user_1.clone(),
+			default_collection_config()
 		));
+		for item in [1, 2, 3] {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item,
+				user_1.clone(),
+				None
+			));
+		}
 
-		assert_ok!(Nfts::mint(
-			RuntimeOrigin::signed(user_id.clone()),
+		// listed first, then offered in a swap
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
 			collection_id,
-			item_1,
-			user_id.clone(),
+			1,
+			Some(10),
+			None,
+			None,
+		));
+		assert_noop!(
+			Nfts::create_swap(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				1,
+				collection_id,
+				Some(3),
+				None,
+				2,
+				false,
+			),
+			Error::<Test>::ItemListed
+		);
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			1,
+			None,
+			None,
+			None,
+		));
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			1,
+			collection_id,
+			Some(3),
 			None,
+			2,
+			false,
 		));
 
+		// offered in a swap first, then listed
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			2,
+			collection_id,
+			Some(3),
+			None,
+			2,
+			false,
+		));
 		assert_noop!(
 			Nfts::set_price(
-				RuntimeOrigin::signed(user_id.clone()),
+				RuntimeOrigin::signed(user_1.clone()),
 				collection_id,
-				item_1,
-				Some(2),
-				None
+				2,
+				Some(10),
+				None,
+				None,
 			),
-			Error::<Test>::ItemsNonTransferable
+			Error::<Test>::ItemInSwap
 		);
+		// removing a price is always possible
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			2,
+			None,
+			None,
+			None,
+		));
+		assert_ok!(Nfts::cancel_swap(RuntimeOrigin::signed(user_1.clone()), collection_id, 2));
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1),
+			collection_id,
+			2,
+			Some(10),
+			Some(user_2),
+			None,
+		));
 	});
 }
 
 #[test]
-fn buy_item_should_work() {
+fn restricted_buyers_should_work() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		let user_1 = account(1);
 		let user_2 = account(2);
 		let user_3 = account(3);
-		let collection_id = 0;
-		let item_1 = 1;
-		let item_2 = 2;
-		let item_3 = 3;
-		let price_1 = 20;
-		let price_2 = 30;
+		let restricted = 0;
+		let unrestricted = 1;
+		let price = 20;
 		let initial_balance = 100;
 
 		Balances::make_free_balance_be(&user_1, initial_balance);
@@ -2321,210 +5676,180 @@ fn buy_item_should_work() {
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			user_1.clone(),
-			default_collection_config()
-		));
-
-		assert_ok!(Nfts::mint(
-			RuntimeOrigin::signed(user_1.clone()),
-			collection_id,
-			item_1,
-			user_1.clone(),
-			None
-		));
-		assert_ok!(Nfts::mint(
-			RuntimeOrigin::signed(user_1.clone()),
-			collection_id,
-			item_2,
-			user_1.clone(),
-			None
+			collection_config_from_disabled_settings(
+				CollectionSetting::DepositRequired | CollectionSetting::UnrestrictedBuyers
+			)
 		));
-		assert_ok!(Nfts::mint(
-			RuntimeOrigin::signed(user_1.clone()),
-			collection_id,
-			item_3,
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
 			user_1.clone(),
-			None
-		));
-
-		assert_ok!(Nfts::set_price(
-			RuntimeOrigin::signed(user_1.clone()),
-			collection_id,
-			item_1,
-			Some(price_1),
-			None,
+			default_collection_config()
 		));
+		assert!(
+			CollectionSettingsInfo::from(Nfts::collection_config(restricted).unwrap().settings)
+				.restricted_buyers
+		);
+		for (collection, item) in [(restricted, 1), (restricted, 2), (unrestricted, 1)] {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection,
+				item,
+				user_2.clone(),
+				None
+			));
+			assert_ok!(Nfts::set_price(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection,
+				item,
+				Some(price),
+				None,
+				None,
+			));
+		}
 
-		assert_ok!(Nfts::set_price(
-			RuntimeOrigin::signed(user_1.clone()),
-			collection_id,
-			item_2,
-			Some(price_2),
-			Some(user_3.clone()),
-		));
+		// anyone can buy from a collection without the restriction
+		assert_ok!(Nfts::buy_item(RuntimeOrigin::signed(user_3.clone()), unrestricted, 1, price));
+		assert_eq!(Item::<Test>::get(unrestricted, 1).unwrap().owner, user_3);
 
-		// can't buy for less
 		assert_noop!(
-			Nfts::buy_item(RuntimeOrigin::signed(user_2.clone()), collection_id, item_1, 1),
-			Error::<Test>::BidTooLow
+			Nfts::buy_item(RuntimeOrigin::signed(user_3.clone()), restricted, 1, price),
+			Error::<Test>::BuyerNotAllowed
 		);
 
-		// pass the higher price to validate it will still deduct correctly
-		assert_ok!(Nfts::buy_item(
-			RuntimeOrigin::signed(user_2.clone()),
-			collection_id,
-			item_1,
-			price_1 + 1,
-		));
-
-		// validate the new owner & balances
-		let item = Item::<Test>::get(collection_id, item_1).unwrap();
-		assert_eq!(item.owner, user_2.clone());
-		assert_eq!(Balances::total_balance(&user_1.clone()), initial_balance + price_1);
-		assert_eq!(Balances::total_balance(&user_2.clone()), initial_balance - price_1);
-
-		// can't buy from yourself
+		// only the admin can change the allowlist
 		assert_noop!(
-			Nfts::buy_item(RuntimeOrigin::signed(user_1.clone()), collection_id, item_2, price_2),
+			Nfts::add_allowed_buyer(
+				RuntimeOrigin::signed(user_2.clone()),
+				restricted,
+				user_3.clone()
+			),
 			Error::<Test>::NoPermission
 		);
-
-		// can't buy when the item is listed for a specific buyer
 		assert_noop!(
-			Nfts::buy_item(RuntimeOrigin::signed(user_2.clone()), collection_id, item_2, price_2),
+			Nfts::add_allowed_buyer(RuntimeOrigin::signed(user_1.clone()), 2, user_3.clone()),
 			Error::<Test>::NoPermission
 		);
-
-		// can buy when I'm a whitelisted buyer
-		assert_ok!(Nfts::buy_item(
-			RuntimeOrigin::signed(user_3.clone()),
-			collection_id,
-			item_2,
-			price_2
+		assert_noop!(
+			Nfts::add_allowed_buyer(RuntimeOrigin::root(), 2, user_3.clone()),
+			Error::<Test>::UnknownCollection
+		);
+		assert_ok!(Nfts::add_allowed_buyer(
+			RuntimeOrigin::signed(user_1.clone()),
+			restricted,
+			user_3.clone()
 		));
-
-		assert!(events().contains(&Event::<Test>::ItemBought {
-			collection: collection_id,
-			item: item_2,
-			price: price_2,
-			seller: user_1.clone(),
+		assert!(events().contains(&Event::<Test>::AllowedBuyerAdded {
+			collection: restricted,
 			buyer: user_3.clone(),
 		}));
+		assert!(CollectionAllowlist::<Test>::contains_key(restricted, &user_3));
 
-		// ensure we reset the buyer field
-		assert!(!ItemPriceOf::<Test>::contains_key(collection_id, item_2));
+		assert_ok!(Nfts::buy_item(RuntimeOrigin::signed(user_3.clone()), restricted, 1, price));
+		assert_eq!(Item::<Test>::get(restricted, 1).unwrap().owner, user_3);
+
+		// the allowlist isn't capped, and each buyer is checked on its own
+		for i in 4..10 {
+			assert_ok!(Nfts::add_allowed_buyer(RuntimeOrigin::root(), restricted, account(i)));
+		}
+		assert_eq!(CollectionAllowlist::<Test>::iter_key_prefix(restricted).count(), 7);
 
-		// can't buy when item is not for sale
 		assert_noop!(
-			Nfts::buy_item(RuntimeOrigin::signed(user_2.clone()), collection_id, item_3, price_2),
-			Error::<Test>::NotForSale
+			Nfts::remove_allowed_buyer(
+				RuntimeOrigin::signed(user_2.clone()),
+				restricted,
+				user_3.clone()
+			),
+			Error::<Test>::NoPermission
 		);
-
-		// ensure we can't buy an item when the collection or an item are frozen
-		{
-			assert_ok!(Nfts::set_price(
-				RuntimeOrigin::signed(user_1.clone()),
-				collection_id,
-				item_3,
-				Some(price_1),
-				None,
-			));
-
-			// lock the collection
-			assert_ok!(Nfts::lock_collection(
+		assert_ok!(Nfts::remove_allowed_buyer(
+			RuntimeOrigin::signed(user_1.clone()),
+			restricted,
+			user_3.clone()
+		));
+		assert!(events().contains(&Event::<Test>::AllowedBuyerRemoved {
+			collection: restricted,
+			buyer: user_3.clone(),
+		}));
+		assert_noop!(
+			Nfts::remove_allowed_buyer(
 				RuntimeOrigin::signed(user_1.clone()),
-				collection_id,
-				CollectionSettings::from_disabled(CollectionSetting::TransferableItems.into())
-			));
-
-			let buy_item_call = mock::RuntimeCall::Nfts(crate::Call::<Test>::buy_item {
-				collection: collection_id,
-				item: item_3,
-				bid_price: price_1,
-			});
-			assert_noop!(
-				buy_item_call.dispatch(RuntimeOrigin::signed(user_2.clone())),
-				Error::<Test>::ItemsNonTransferable
-			);
-
-			// unlock the collection
-			assert_ok!(Nfts::force_collection_config(
-				RuntimeOrigin::root(),
-				collection_id,
-				collection_config_with_all_settings_enabled(),
-			));
+				restricted,
+				user_3.clone()
+			),
+			Error::<Test>::BuyerNotAllowed
+		);
 
-			// lock the transfer
-			assert_ok!(Nfts::lock_item_transfer(
-				RuntimeOrigin::signed(user_1.clone()),
-				collection_id,
-				item_3,
-			));
+		// swapping for an item of a restricted collection is subject to the allowlist too
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_2.clone()),
+			restricted,
+			2,
+			None,
+			None,
+			None,
+		));
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(user_2.clone()),
+			restricted,
+			2,
+			unrestricted,
+			Some(1),
+			None,
+			2,
+			false,
+		));
+		assert_noop!(
+			Nfts::claim_swap(
+				RuntimeOrigin::signed(user_3.clone()),
+				unrestricted,
+				1,
+				restricted,
+				2,
+				None,
+			),
+			Error::<Test>::BuyerNotAllowed
+		);
+		assert_ok!(Nfts::add_allowed_buyer(
+			RuntimeOrigin::signed(user_1.clone()),
+			restricted,
+			user_3.clone()
+		));
+		assert_ok!(Nfts::claim_swap(
+			RuntimeOrigin::signed(user_3.clone()),
+			unrestricted,
+			1,
+			restricted,
+			2,
+			None,
+		));
+		assert_eq!(Item::<Test>::get(restricted, 2).unwrap().owner, user_3);
+		assert_eq!(Item::<Test>::get(unrestricted, 1).unwrap().owner, user_2);
 
-			let buy_item_call = mock::RuntimeCall::Nfts(crate::Call::<Test>::buy_item {
-				collection: collection_id,
-				item: item_3,
-				bid_price: price_1,
-			});
-			assert_noop!(
-				buy_item_call.dispatch(RuntimeOrigin::signed(user_2)),
-				Error::<Test>::ItemLocked
-			);
-		}
+		// the allowlist goes away with the collection
+		assert_ok!(Nfts::force_destroy(RuntimeOrigin::root(), restricted, 20));
+		assert_eq!(CollectionAllowlist::<Test>::iter_key_prefix(restricted).count(), 0);
 	});
 }
 
 #[test]
-fn pay_tips_should_work() {
+fn destroy_requires_an_empty_allowlist() {
 	new_test_ext().execute_with(|| {
-		let user_1 = account(1);
-		let user_2 = account(2);
-		let user_3 = account(3);
-		let collection_id = 0;
-		let item_id = 1;
-		let tip = 2;
-		let initial_balance = 100;
-
-		Balances::make_free_balance_be(&user_1, initial_balance);
-		Balances::make_free_balance_be(&user_2, initial_balance);
-		Balances::make_free_balance_be(&user_3, initial_balance);
-
-		assert_ok!(Nfts::pay_tips(
-			RuntimeOrigin::signed(user_1.clone()),
-			bvec![
-				ItemTip {
-					collection: collection_id,
-					item: item_id,
-					receiver: user_2.clone(),
-					amount: tip
-				},
-				ItemTip {
-					collection: collection_id,
-					item: item_id,
-					receiver: user_3.clone(),
-					amount: tip
-				},
-			]
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
 		));
+		assert_ok!(Nfts::add_allowed_buyer(RuntimeOrigin::signed(account(1)), 0, account(2)));
 
-		assert_eq!(Balances::total_balance(&user_1), initial_balance - tip * 2);
-		assert_eq!(Balances::total_balance(&user_2), initial_balance + tip);
-		assert_eq!(Balances::total_balance(&user_3), initial_balance + tip);
+		let w = Nfts::get_destroy_witness(&0).unwrap();
+		assert_noop!(
+			Nfts::destroy(RuntimeOrigin::signed(account(1)), 0, w),
+			Error::<Test>::AllowlistNotEmpty
+		);
 
-		let events = events();
-		assert!(events.contains(&Event::<Test>::TipSent {
-			collection: collection_id,
-			item: item_id,
-			sender: user_1.clone(),
-			receiver: user_2.clone(),
-			amount: tip,
-		}));
-		assert!(events.contains(&Event::<Test>::TipSent {
-			collection: collection_id,
-			item: item_id,
-			sender: user_1.clone(),
-			receiver: user_3.clone(),
-			amount: tip,
-		}));
+		assert_ok!(Nfts::remove_allowed_buyer(RuntimeOrigin::signed(account(1)), 0, account(2)));
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(account(1)), 0, w));
+		assert!(!Collection::<Test>::contains_key(0));
 	});
 }
 
@@ -2573,6 +5898,7 @@ fn create_cancel_swap_should_work() {
 				Some(item_2 + 1),
 				Some(price_with_direction.clone()),
 				duration,
+				false,
 			),
 			Error::<Test>::UnknownItem
 		);
@@ -2585,6 +5911,7 @@ fn create_cancel_swap_should_work() {
 				None,
 				Some(price_with_direction.clone()),
 				duration,
+				false,
 			),
 			Error::<Test>::UnknownCollection
 		);
@@ -2599,6 +5926,7 @@ fn create_cancel_swap_should_work() {
 				Some(item_2),
 				Some(price_with_direction.clone()),
 				max_duration.saturating_add(1),
+				false,
 			),
 			Error::<Test>::WrongDuration
 		);
@@ -2611,6 +5939,7 @@ fn create_cancel_swap_should_work() {
 			Some(item_2),
 			Some(price_with_direction.clone()),
 			duration,
+			false,
 		));
 
 		let swap = PendingSwapOf::<Test>::get(collection_id, item_1).unwrap();
@@ -2653,13 +5982,34 @@ fn create_cancel_swap_should_work() {
 			Some(item_2),
 			Some(price_with_direction.clone()),
 			duration,
+			false,
 		));
 		assert_noop!(
 			Nfts::cancel_swap(RuntimeOrigin::signed(account(2)), collection_id, item_1),
-			Error::<Test>::NoPermission
+			Error::<Test>::SwapNotExpired
 		);
 		System::set_block_number(expect_deadline + 1);
+		let balances_before =
+			(Balances::total_balance(&user_id), Balances::total_balance(&account(2)));
 		assert_ok!(Nfts::cancel_swap(RuntimeOrigin::signed(account(2)), collection_id, item_1));
+		assert!(events().contains(&Event::<Test>::SwapCancelled {
+			offered_collection: collection_id,
+			offered_item: item_1,
+			desired_collection: collection_id,
+			desired_item: Some(item_2),
+			price: Some(price_with_direction.clone()),
+			deadline: expect_deadline,
+		}));
+		assert!(!PendingSwapOf::<Test>::contains_key(collection_id, item_1));
+		// cancelling doesn't move any funds
+		assert_eq!(
+			(Balances::total_balance(&user_id), Balances::total_balance(&account(2))),
+			balances_before
+		);
+		assert_noop!(
+			Nfts::cancel_swap(RuntimeOrigin::signed(account(2)), collection_id, item_1),
+			Error::<Test>::UnknownSwap
+		);
 
 		// validate optional desired_item param
 		assert_ok!(Nfts::create_swap(
@@ -2670,6 +6020,7 @@ fn create_cancel_swap_should_work() {
 			None,
 			Some(price_with_direction),
 			duration,
+			false,
 		));
 
 		let swap = PendingSwapOf::<Test>::get(collection_id, item_1).unwrap();
@@ -2678,7 +6029,223 @@ fn create_cancel_swap_should_work() {
 }
 
 #[test]
-fn claim_swap_should_work() {
+fn claim_swap_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let collection_id = 0;
+		let item_1 = 1;
+		let item_2 = 2;
+		let item_3 = 3;
+		let item_4 = 4;
+		let item_5 = 5;
+		let price = 100;
+		let price_direction = PriceDirection::Receive;
+		let price_with_direction =
+			PriceWithDirection { amount: price, direction: price_direction.clone() };
+		let duration = 2;
+		let initial_balance = 1000;
+		let deadline = 1 + duration;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_1,
+			user_1.clone(),
+			None,
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_2,
+			user_2.clone(),
+			default_item_config(),
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_3,
+			user_2.clone(),
+			default_item_config(),
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_4,
+			user_1.clone(),
+			None,
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_5,
+			user_2.clone(),
+			default_item_config(),
+		));
+
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_1,
+			collection_id,
+			Some(item_2),
+			Some(price_with_direction.clone()),
+			duration,
+			false,
+		));
+
+		// validate the deadline
+		System::set_block_number(5);
+		assert_noop!(
+			Nfts::claim_swap(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_2,
+				collection_id,
+				item_1,
+				Some(price_with_direction.clone()),
+			),
+			Error::<Test>::DeadlineExpired
+		);
+		System::set_block_number(1);
+
+		// validate edge cases
+		assert_noop!(
+			Nfts::claim_swap(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_2,
+				collection_id,
+				item_4, // no swap was created for that asset
+				Some(price_with_direction.clone()),
+			),
+			Error::<Test>::UnknownSwap
+		);
+		assert_noop!(
+			Nfts::claim_swap(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_4, // not my item
+				collection_id,
+				item_1,
+				Some(price_with_direction.clone()),
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::claim_swap(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_5, // my item, but not the one another part wants
+				collection_id,
+				item_1,
+				Some(price_with_direction.clone()),
+			),
+			Error::<Test>::UnknownSwap
+		);
+		assert_noop!(
+			Nfts::claim_swap(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_2,
+				collection_id,
+				item_1,
+				Some(PriceWithDirection { amount: price + 1, direction: price_direction.clone() }), // wrong price
+			),
+			Error::<Test>::UnknownSwap
+		);
+		assert_noop!(
+			Nfts::claim_swap(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_2,
+				collection_id,
+				item_1,
+				Some(PriceWithDirection { amount: price, direction: PriceDirection::Send }), // wrong direction
+			),
+			Error::<Test>::UnknownSwap
+		);
+
+		assert_ok!(Nfts::claim_swap(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_2,
+			collection_id,
+			item_1,
+			Some(price_with_direction.clone()),
+		));
+
+		// validate the new owner
+		let item = Item::<Test>::get(collection_id, item_1).unwrap();
+		assert_eq!(item.owner, user_2.clone());
+		let item = Item::<Test>::get(collection_id, item_2).unwrap();
+		assert_eq!(item.owner, user_1.clone());
+
+		// validate the balances
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + price);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - price);
+
+		// ensure we reset the swap
+		assert!(!PendingSwapOf::<Test>::contains_key(collection_id, item_1));
+
+		// validate the event
+		assert!(events().contains(&Event::<Test>::SwapClaimed {
+			sent_collection: collection_id,
+			sent_item: item_2,
+			sent_item_owner: user_2.clone(),
+			received_collection: collection_id,
+			received_item: item_1,
+			received_item_owner: user_1.clone(),
+			price: Some(price_with_direction.clone()),
+			deadline,
+		}));
+
+		// validate the optional desired_item param and another price direction
+		let price_direction = PriceDirection::Send;
+		let price_with_direction = PriceWithDirection { amount: price, direction: price_direction };
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_4,
+			collection_id,
+			None,
+			Some(price_with_direction.clone()),
+			duration,
+			false,
+		));
+		assert_ok!(Nfts::claim_swap(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_1,
+			collection_id,
+			item_4,
+			Some(price_with_direction),
+		));
+		let item = Item::<Test>::get(collection_id, item_1).unwrap();
+		assert_eq!(item.owner, user_1);
+		let item = Item::<Test>::get(collection_id, item_4).unwrap();
+		assert_eq!(item.owner, user_2);
+
+		assert_eq!(Balances::total_balance(&user_1), initial_balance - price);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance + price);
+	});
+}
+
+#[test]
+fn escrowed_swap_should_work() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		let user_1 = account(1);
@@ -2686,32 +6253,24 @@ fn claim_swap_should_work() {
 		let collection_id = 0;
 		let item_1 = 1;
 		let item_2 = 2;
-		let item_3 = 3;
-		let item_4 = 4;
-		let item_5 = 5;
 		let price = 100;
-		let price_direction = PriceDirection::Receive;
-		let price_with_direction =
-			PriceWithDirection { amount: price, direction: price_direction.clone() };
+		let send_price = PriceWithDirection { amount: price, direction: PriceDirection::Send };
 		let duration = 2;
 		let initial_balance = 1000;
-		let deadline = 1 + duration;
 
 		Balances::make_free_balance_be(&user_1, initial_balance);
 		Balances::make_free_balance_be(&user_2, initial_balance);
-
 		assert_ok!(Nfts::force_create(
 			RuntimeOrigin::root(),
 			user_1.clone(),
 			default_collection_config()
 		));
-
-		assert_ok!(Nfts::mint(
+		assert_ok!(Nfts::force_mint(
 			RuntimeOrigin::signed(user_1.clone()),
 			collection_id,
 			item_1,
 			user_1.clone(),
-			None,
+			default_item_config(),
 		));
 		assert_ok!(Nfts::force_mint(
 			RuntimeOrigin::signed(user_1.clone()),
@@ -2720,174 +6279,385 @@ fn claim_swap_should_work() {
 			user_2.clone(),
 			default_item_config(),
 		));
-		assert_ok!(Nfts::force_mint(
+		let reserved = Balances::reserved_balance(&user_1);
+
+		// only a price sent by the offerer can be escrowed
+		assert_noop!(
+			Nfts::create_swap(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_1,
+				collection_id,
+				Some(item_2),
+				Some(PriceWithDirection { amount: price, direction: PriceDirection::Receive }),
+				duration,
+				true,
+			),
+			Error::<Test>::IncorrectData
+		);
+		assert_noop!(
+			Nfts::create_swap(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_1,
+				collection_id,
+				Some(item_2),
+				Some(PriceWithDirection { amount: initial_balance + 1, ..send_price.clone() }),
+				duration,
+				true,
+			),
+			Error::<Test>::InsufficientEscrow
+		);
+
+		// cancelling refunds the escrow
+		assert_ok!(Nfts::create_swap(
 			RuntimeOrigin::signed(user_1.clone()),
 			collection_id,
-			item_3,
-			user_2.clone(),
-			default_item_config(),
+			item_1,
+			collection_id,
+			Some(item_2),
+			Some(send_price.clone()),
+			duration,
+			true,
 		));
-		assert_ok!(Nfts::mint(
+		assert_eq!(Balances::reserved_balance(&user_1), reserved + price);
+		assert_eq!(SwapEscrowOf::<Test>::get(collection_id, item_1), Some((user_1.clone(), price)));
+		assert_ok!(Nfts::cancel_swap(RuntimeOrigin::signed(user_1.clone()), collection_id, item_1));
+		assert_eq!(Balances::reserved_balance(&user_1), reserved);
+		assert!(!SwapEscrowOf::<Test>::contains_key(collection_id, item_1));
+
+		// claiming pays the counterparty out of the escrow
+		assert_ok!(Nfts::create_swap(
 			RuntimeOrigin::signed(user_1.clone()),
 			collection_id,
-			item_4,
-			user_1.clone(),
-			None,
+			item_1,
+			collection_id,
+			Some(item_2),
+			Some(send_price.clone()),
+			duration,
+			true,
 		));
-		assert_ok!(Nfts::force_mint(
-			RuntimeOrigin::signed(user_1.clone()),
+		assert_ok!(Nfts::claim_swap(
+			RuntimeOrigin::signed(user_2.clone()),
 			collection_id,
-			item_5,
-			user_2.clone(),
-			default_item_config(),
+			item_2,
+			collection_id,
+			item_1,
+			Some(send_price.clone()),
 		));
+		assert_eq!(Item::<Test>::get(collection_id, item_1).unwrap().owner, user_2);
+		assert_eq!(Item::<Test>::get(collection_id, item_2).unwrap().owner, user_1);
+		assert_eq!(Balances::reserved_balance(&user_1), reserved);
+		assert_eq!(Balances::total_balance(&user_1), initial_balance - price);
+		assert_eq!(Balances::free_balance(&user_2), initial_balance + price);
+		assert!(!SwapEscrowOf::<Test>::contains_key(collection_id, item_1));
 
+		// moving the offered item away drops the swap and refunds the escrow
 		assert_ok!(Nfts::create_swap(
 			RuntimeOrigin::signed(user_1.clone()),
 			collection_id,
-			item_1,
+			item_2,
 			collection_id,
-			Some(item_2),
-			Some(price_with_direction.clone()),
+			Some(item_1),
+			Some(send_price),
 			duration,
+			true,
+		));
+		assert_eq!(Balances::reserved_balance(&user_1), reserved + price);
+		assert_ok!(Nfts::transfer(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_2,
+			account(3)
+		));
+		assert_eq!(Balances::reserved_balance(&user_1), reserved);
+		assert!(!SwapEscrowOf::<Test>::contains_key(collection_id, item_2));
+	});
+}
+
+#[test]
+fn transfer_removes_the_listing_of_the_item() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&account(1), 100);
+		Balances::make_free_balance_be(&account(3), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
 		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 1, account(1), None));
+		assert_ok!(Nfts::set_price(RuntimeOrigin::signed(account(1)), 0, 1, Some(10), None, None));
+		events();
 
-		// validate the deadline
-		System::set_block_number(5);
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(1)), 0, 1, account(2)));
+		assert_eq!(
+			events(),
+			vec![
+				Event::ItemPriceRemoved { collection: 0, item: 1 },
+				Event::Transferred { collection: 0, item: 1, from: account(1), to: account(2) },
+			]
+		);
+		assert!(!ItemPriceOf::<Test>::contains_key(0, 1));
 		assert_noop!(
-			Nfts::claim_swap(
-				RuntimeOrigin::signed(user_2.clone()),
-				collection_id,
-				item_2,
-				collection_id,
-				item_1,
-				Some(price_with_direction.clone()),
-			),
-			Error::<Test>::DeadlineExpired
+			Nfts::buy_item(RuntimeOrigin::signed(account(3)), 0, 1, 10),
+			Error::<Test>::NotForSale
 		);
+
+		// a sale consumes the listing without reporting it as removed
+		assert_ok!(Nfts::set_price(RuntimeOrigin::signed(account(2)), 0, 1, Some(10), None, None));
+		events();
+		assert_ok!(Nfts::buy_item(RuntimeOrigin::signed(account(3)), 0, 1, 10));
+		assert!(!events().contains(&Event::ItemPriceRemoved { collection: 0, item: 1 }));
+	});
+}
+
+#[test]
+fn transfer_cancels_the_swap_of_the_item() {
+	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
+		let price = PriceWithDirection { amount: 50, direction: PriceDirection::Send };
+		Balances::make_free_balance_be(&account(1), 1000);
+		Balances::make_free_balance_be(&account(2), 1000);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 1, account(1), None));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 2, account(2), None));
+		let reserved = Balances::reserved_balance(account(1));
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			1,
+			0,
+			Some(2),
+			Some(price.clone()),
+			2,
+			true,
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), reserved + 50);
+		events();
 
-		// validate edge cases
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(1)), 0, 1, account(3)));
+		assert_eq!(
+			events(),
+			vec![
+				Event::SwapCancelled {
+					offered_collection: 0,
+					offered_item: 1,
+					desired_collection: 0,
+					desired_item: Some(2),
+					price: Some(price.clone()),
+					deadline: 3,
+				},
+				Event::Transferred { collection: 0, item: 1, from: account(1), to: account(3) },
+			]
+		);
+		assert!(!PendingSwapOf::<Test>::contains_key(0, 1));
+		// the escrow goes back to the previous owner who paid it
+		assert_eq!(Balances::reserved_balance(account(1)), reserved);
 		assert_noop!(
-			Nfts::claim_swap(
-				RuntimeOrigin::signed(user_2.clone()),
-				collection_id,
-				item_2,
-				collection_id,
-				item_4, // no swap was created for that asset
-				Some(price_with_direction.clone()),
-			),
+			Nfts::claim_swap(RuntimeOrigin::signed(account(2)), 0, 2, 0, 1, Some(price)),
 			Error::<Test>::UnknownSwap
 		);
-		assert_noop!(
-			Nfts::claim_swap(
+	});
+}
+
+#[test]
+fn claim_bundle_swap_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let price = 100;
+		let price_with_direction =
+			PriceWithDirection { amount: price, direction: PriceDirection::Receive };
+		let duration = 2;
+		let initial_balance = 1000;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+
+		for owner in [&user_1, &user_2] {
+			assert_ok!(Nfts::force_create(
+				RuntimeOrigin::root(),
+				owner.clone(),
+				default_collection_config()
+			));
+		}
+		for item in [1, 2] {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1.clone()),
+				0,
+				item,
+				user_1.clone(),
+				None
+			));
+		}
+		for item in [1, 2, 3] {
+			assert_ok!(Nfts::mint(
 				RuntimeOrigin::signed(user_2.clone()),
-				collection_id,
-				item_4, // not my item
-				collection_id,
-				item_1,
-				Some(price_with_direction.clone()),
+				1,
+				item,
+				user_2.clone(),
+				None
+			));
+		}
+
+		let offered: BundleOf<Test> = bvec![(0, 1), (0, 2)];
+		let desired: BundleOf<Test> = bvec![(1, 1), (1, 2), (1, 3)];
+
+		assert_noop!(
+			Nfts::create_bundle_swap(
+				RuntimeOrigin::signed(user_1.clone()),
+				offered.clone(),
+				bvec![],
+				None,
+				duration,
 			),
-			Error::<Test>::NoPermission
+			Error::<Test>::EmptyBundle
 		);
 		assert_noop!(
-			Nfts::claim_swap(
-				RuntimeOrigin::signed(user_2.clone()),
-				collection_id,
-				item_5, // my item, but not the one another part wants
-				collection_id,
-				item_1,
-				Some(price_with_direction.clone()),
+			Nfts::create_bundle_swap(
+				RuntimeOrigin::signed(user_1.clone()),
+				offered.clone(),
+				bvec![(1, 1), (0, 2)],
+				None,
+				duration,
 			),
-			Error::<Test>::UnknownSwap
+			Error::<Test>::DuplicateBundleItem
 		);
 		assert_noop!(
-			Nfts::claim_swap(
-				RuntimeOrigin::signed(user_2.clone()),
-				collection_id,
-				item_2,
-				collection_id,
-				item_1,
-				Some(PriceWithDirection { amount: price + 1, direction: price_direction.clone() }), // wrong price
+			Nfts::create_bundle_swap(
+				RuntimeOrigin::signed(user_1.clone()),
+				desired.clone(),
+				offered.clone(),
+				None,
+				duration,
 			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Nfts::create_bundle_swap(
+			RuntimeOrigin::signed(user_1.clone()),
+			offered.clone(),
+			desired.clone(),
+			Some(price_with_direction.clone()),
+			duration,
+		));
+		assert!(events().contains(&Event::<Test>::BundleSwapCreated {
+			swap_id: 0,
+			creator: user_1.clone(),
+			offered,
+			desired,
+			price: Some(price_with_direction.clone()),
+			deadline: 1 + duration,
+		}));
+
+		assert_noop!(
+			Nfts::claim_bundle_swap(RuntimeOrigin::signed(user_2.clone()), 0, None),
 			Error::<Test>::UnknownSwap
 		);
 		assert_noop!(
-			Nfts::claim_swap(
-				RuntimeOrigin::signed(user_2.clone()),
-				collection_id,
-				item_2,
-				collection_id,
-				item_1,
-				Some(PriceWithDirection { amount: price, direction: PriceDirection::Send }), // wrong direction
+			Nfts::claim_bundle_swap(
+				RuntimeOrigin::signed(user_1.clone()),
+				0,
+				Some(price_with_direction.clone())
 			),
-			Error::<Test>::UnknownSwap
+			Error::<Test>::NoPermission
 		);
 
-		assert_ok!(Nfts::claim_swap(
+		assert_ok!(Nfts::claim_bundle_swap(
 			RuntimeOrigin::signed(user_2.clone()),
-			collection_id,
-			item_2,
-			collection_id,
-			item_1,
-			Some(price_with_direction.clone()),
+			0,
+			Some(price_with_direction.clone())
 		));
-
-		// validate the new owner
-		let item = Item::<Test>::get(collection_id, item_1).unwrap();
-		assert_eq!(item.owner, user_2.clone());
-		let item = Item::<Test>::get(collection_id, item_2).unwrap();
-		assert_eq!(item.owner, user_1.clone());
-
-		// validate the balances
+		assert!(events().contains(&Event::<Test>::BundleSwapClaimed {
+			swap_id: 0,
+			creator: user_1.clone(),
+			claimer: user_2.clone(),
+			price: Some(price_with_direction),
+		}));
+		assert_eq!(
+			items(),
+			vec![
+				(user_1.clone(), 1, 1),
+				(user_1.clone(), 1, 2),
+				(user_1.clone(), 1, 3),
+				(user_2.clone(), 0, 1),
+				(user_2.clone(), 0, 2),
+			]
+		);
 		assert_eq!(Balances::total_balance(&user_1), initial_balance + price);
 		assert_eq!(Balances::total_balance(&user_2), initial_balance - price);
+		assert!(!PendingBundleSwapOf::<Test>::contains_key(0));
+	});
+}
 
-		// ensure we reset the swap
-		assert!(!PendingSwapOf::<Test>::contains_key(collection_id, item_1));
+#[test]
+fn claim_bundle_swap_should_fail_if_an_item_changed_hands() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
 
-		// validate the event
-		assert!(events().contains(&Event::<Test>::SwapClaimed {
-			sent_collection: collection_id,
-			sent_item: item_2,
-			sent_item_owner: user_2.clone(),
-			received_collection: collection_id,
-			received_item: item_1,
-			received_item_owner: user_1.clone(),
-			price: Some(price_with_direction.clone()),
-			deadline,
-		}));
+		Balances::make_free_balance_be(&user_1, 1000);
+		Balances::make_free_balance_be(&user_2, 1000);
 
-		// validate the optional desired_item param and another price direction
-		let price_direction = PriceDirection::Send;
-		let price_with_direction = PriceWithDirection { amount: price, direction: price_direction };
-		Balances::make_free_balance_be(&user_1, initial_balance);
-		Balances::make_free_balance_be(&user_2, initial_balance);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		for (item, owner) in [(1, &user_1), (2, &user_1), (3, &user_2)] {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(user_1.clone()),
+				0,
+				item,
+				owner.clone(),
+				None
+			));
+		}
 
-		assert_ok!(Nfts::create_swap(
+		assert_ok!(Nfts::create_bundle_swap(
 			RuntimeOrigin::signed(user_1.clone()),
-			collection_id,
-			item_4,
-			collection_id,
+			bvec![(0, 1), (0, 2)],
+			bvec![(0, 3)],
 			None,
-			Some(price_with_direction.clone()),
-			duration,
-		));
-		assert_ok!(Nfts::claim_swap(
-			RuntimeOrigin::signed(user_2.clone()),
-			collection_id,
-			item_1,
-			collection_id,
-			item_4,
-			Some(price_with_direction),
+			2,
 		));
-		let item = Item::<Test>::get(collection_id, item_1).unwrap();
-		assert_eq!(item.owner, user_1);
-		let item = Item::<Test>::get(collection_id, item_4).unwrap();
-		assert_eq!(item.owner, user_2);
+		// The creator no longer owns one of the offered items.
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(user_1.clone()), 0, 2, user_3.clone()));
 
-		assert_eq!(Balances::total_balance(&user_1), initial_balance - price);
-		assert_eq!(Balances::total_balance(&user_2), initial_balance + price);
+		assert_noop!(
+			Nfts::claim_bundle_swap(RuntimeOrigin::signed(user_2.clone()), 0, None),
+			Error::<Test>::WrongOwner
+		);
+		assert_eq!(
+			items(),
+			vec![(user_1.clone(), 0, 1), (user_2.clone(), 0, 3), (user_3.clone(), 0, 2)]
+		);
+
+		// A failing transfer rolls back the ones made before it.
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(user_3), 0, 2, user_1.clone()));
+		assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(user_1.clone()), 0, 3));
+		assert_noop!(
+			Nfts::claim_bundle_swap(RuntimeOrigin::signed(user_2.clone()), 0, None),
+			Error::<Test>::ItemLocked
+		);
+		assert!(PendingBundleSwapOf::<Test>::contains_key(0));
+
+		// Only the creator can cancel the swap before its deadline.
+		assert_noop!(
+			Nfts::cancel_bundle_swap(RuntimeOrigin::signed(user_2.clone()), 0),
+			Error::<Test>::SwapNotExpired
+		);
+		assert_ok!(Nfts::cancel_bundle_swap(RuntimeOrigin::signed(user_1), 0));
+		assert!(!PendingBundleSwapOf::<Test>::contains_key(0));
+		assert!(events().contains(&Event::<Test>::BundleSwapCancelled { swap_id: 0 }));
 	});
 }
 
@@ -2956,6 +6726,10 @@ fn collection_locking_should_work() {
 
 		let stored_config = CollectionConfigOf::<Test>::get(collection_id).unwrap();
 		assert_eq!(stored_config, lock_config);
+		assert!(events().contains(&Event::<Test>::CollectionLocked {
+			collection: collection_id,
+			settings: lock_config.settings,
+		}));
 
 		// validate full lock
 		assert_ok!(Nfts::lock_collection(
@@ -2971,6 +6745,11 @@ fn collection_locking_should_work() {
 				CollectionSetting::UnlockedAttributes,
 		);
 		assert_eq!(stored_config, full_lock_config);
+		// The event reflects the cumulative locks, not only the latest ones.
+		assert!(events().contains(&Event::<Test>::CollectionLocked {
+			collection: collection_id,
+			settings: full_lock_config.settings,
+		}));
 	});
 }
 
@@ -3006,6 +6785,7 @@ fn pallet_level_feature_flags_should_work() {
 				collection_id,
 				item_id,
 				Some(1),
+				None,
 				None
 			),
 			Error::<Test>::MethodDisabled
@@ -3039,6 +6819,75 @@ fn pallet_level_feature_flags_should_work() {
 			),
 			Error::<Test>::MethodDisabled
 		);
+		assert_noop!(
+			Nfts::force_set_attribute(
+				RuntimeOrigin::root(),
+				None,
+				collection_id,
+				None,
+				AttributeNamespace::CollectionOwner,
+				bvec![0],
+				bvec![0],
+			),
+			Error::<Test>::MethodDisabled
+		);
+	})
+}
+
+#[test]
+fn metadata_feature_flag_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_id = account(1);
+		let collection_id = 0;
+		let item_id = 1;
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_id.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_id,
+			user_id.clone(),
+			None,
+		));
+		assert_ok!(Nfts::set_metadata(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_id,
+			bvec![0]
+		));
+		assert_ok!(Nfts::set_collection_metadata(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			bvec![0]
+		));
+
+		Features::set(&PalletFeatures::from_disabled(PalletFeature::Metadata.into()));
+
+		// neither the item nor the collection metadata can be written, even by the force origin
+		for origin in [RuntimeOrigin::signed(user_id.clone()), RuntimeOrigin::root()] {
+			assert_noop!(
+				Nfts::set_metadata(origin.clone(), collection_id, item_id, bvec![1]),
+				Error::<Test>::MethodDisabled
+			);
+			assert_noop!(
+				Nfts::set_collection_metadata(origin, collection_id, bvec![1]),
+				Error::<Test>::MethodDisabled
+			);
+		}
+
+		// the existing metadata can still be cleared
+		assert_ok!(Nfts::clear_metadata(
+			RuntimeOrigin::signed(user_id.clone()),
+			collection_id,
+			item_id
+		));
+		assert_ok!(Nfts::clear_collection_metadata(RuntimeOrigin::signed(user_id), collection_id));
+		assert_eq!(Nfts::item_metadata(collection_id, item_id), None);
+		assert_eq!(Nfts::collection_metadata(collection_id), None);
 	})
 }
 
@@ -3643,3 +7492,285 @@ fn pre_signed_attributes_should_work() {
 		);
 	})
 }
+
+#[test]
+fn inspect_enumerable_should_work() {
+	new_test_ext().execute_with(|| {
+		fn sorted<T: Ord>(mut v: Vec<T>) -> Vec<T> {
+			v.sort();
+			v
+		}
+
+		for owner in [account(1), account(2)] {
+			assert_ok!(Nfts::force_create(
+				RuntimeOrigin::root(),
+				owner,
+				default_collection_config()
+			));
+		}
+		for (collection, item, owner) in
+			[(0, 1, account(1)), (0, 2, account(2)), (0, 3, account(1)), (1, 1, account(1))]
+		{
+			assert_ok!(Nfts::force_mint(
+				RuntimeOrigin::signed(Collection::<Test>::get(collection).unwrap().owner),
+				collection,
+				item,
+				owner,
+				default_item_config()
+			));
+		}
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 3));
+
+		assert_eq!(sorted(<Nfts as InspectEnumerable<_>>::collections().collect()), vec![0, 1]);
+		assert_eq!(
+			sorted(<Nfts as InspectEnumerable<_>>::collections().collect()),
+			sorted(Collection::<Test>::iter_keys().collect())
+		);
+
+		assert_eq!(sorted(<Nfts as InspectEnumerable<_>>::items(&0).collect()), vec![1, 2]);
+		assert_eq!(sorted(<Nfts as InspectEnumerable<_>>::items(&1).collect()), vec![1]);
+		assert_eq!(<Nfts as InspectEnumerable<_>>::items(&2).count(), 0);
+		for collection in 0..2 {
+			assert_eq!(
+				sorted(<Nfts as InspectEnumerable<_>>::items(&collection).collect()),
+				sorted(Item::<Test>::iter_key_prefix(collection).collect())
+			);
+		}
+
+		assert_eq!(
+			sorted(<Nfts as InspectEnumerable<_>>::owned(&account(1)).collect()),
+			vec![(0, 1), (1, 1)]
+		);
+		assert_eq!(
+			sorted(<Nfts as InspectEnumerable<_>>::owned(&account(2)).collect()),
+			vec![(0, 2)]
+		);
+		assert_eq!(<Nfts as InspectEnumerable<_>>::owned(&account(3)).count(), 0);
+		for owner in [account(1), account(2)] {
+			assert_eq!(
+				sorted(<Nfts as InspectEnumerable<_>>::owned(&owner).collect()),
+				sorted(
+					Item::<Test>::iter()
+						.filter(|(_, _, details)| details.owner == owner)
+						.map(|(collection, item, _)| (collection, item))
+						.collect()
+				)
+			);
+		}
+
+		assert_eq!(
+			sorted(<Nfts as InspectEnumerable<_>>::owned_in_collection(&0, &account(1)).collect()),
+			vec![1]
+		);
+		assert_eq!(
+			sorted(<Nfts as InspectEnumerable<_>>::owned_in_collection(&1, &account(1)).collect()),
+			vec![1]
+		);
+		assert_eq!(<Nfts as InspectEnumerable<_>>::owned_in_collection(&1, &account(2)).count(), 0);
+
+		// transfers are reflected
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(2)), 0, 2, account(1)));
+		assert_eq!(
+			sorted(<Nfts as InspectEnumerable<_>>::owned_in_collection(&0, &account(1)).collect()),
+			vec![1, 2]
+		);
+		assert_eq!(<Nfts as InspectEnumerable<_>>::owned(&account(2)).count(), 0);
+	});
+}
+
+#[test]
+fn deposits_follow_the_instance_config() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		let config = collection_config_with_all_settings_enabled();
+
+		// the default instance
+		assert_ok!(Nfts::create(RuntimeOrigin::signed(account(1)), account(1), config));
+		assert_eq!(Balances::reserved_balance(account(1)), 2);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+		assert_eq!(Balances::reserved_balance(account(1)), 3);
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0; 10]));
+		assert_eq!(Balances::reserved_balance(account(1)), 14);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(42),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), 17);
+
+		// the second instance takes its own deposits
+		assert_ok!(Nfts2::create(
+			RuntimeOrigin::signed(account(1)),
+			account(1),
+			collection_config_for_instance2()
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), 17 + 5);
+		assert_ok!(Nfts2::mint(RuntimeOrigin::signed(account(1)), 0, 42, account(1), None));
+		assert_eq!(Balances::reserved_balance(account(1)), 22 + 3);
+		assert_ok!(Nfts2::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0; 10]));
+		assert_eq!(Balances::reserved_balance(account(1)), 25 + 2 + 2 * 10);
+		assert_ok!(Nfts2::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(42),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), 47 + 4 + 2 * 2);
+
+		// and returns them
+		assert_ok!(Nfts2::burn(RuntimeOrigin::signed(account(1)), 0, 42));
+		assert_ok!(Nfts2::destroy(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Nfts2::get_destroy_witness(&0).unwrap()
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), 17);
+	});
+}
+
+#[test]
+fn wide_item_ids_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		let item = u32::MAX as u128 + 1;
+		assert_ok!(Nfts2::create(
+			RuntimeOrigin::signed(account(1)),
+			account(1),
+			collection_config_for_instance2()
+		));
+		assert_ok!(Nfts2::mint(RuntimeOrigin::signed(account(1)), 0, item, account(2), None));
+		assert_ok!(Nfts2::mint(RuntimeOrigin::signed(account(1)), 0, u128::MAX, account(2), None));
+		assert_eq!(Nfts2::owner(0, item), Some(account(2)));
+
+		assert_ok!(Nfts2::transfer(RuntimeOrigin::signed(account(2)), 0, item, account(3)));
+		assert_eq!(Nfts2::owner(0, item), Some(account(3)));
+		assert_eq!(Nfts2::owner(0, u128::MAX), Some(account(2)));
+		assert!(Account::<Test, Instance2>::contains_key((account(3), 0, item)));
+
+		assert_ok!(Nfts2::burn(RuntimeOrigin::signed(account(3)), 0, item));
+		assert_eq!(Nfts2::owner(0, item), None);
+		assert!(!Account::<Test, Instance2>::contains_key((account(3), 0, item)));
+		assert_eq!(Collection::<Test, Instance2>::get(0).unwrap().items, 1);
+	});
+}
+
+#[test]
+fn exhausted_collection_ids_should_not_be_reused() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		Nfts::set_next_id(u32::MAX);
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(account(1)),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert!(Collection::<Test>::contains_key(u32::MAX));
+		assert_eq!(Nfts::get_next_id(), u32::MAX);
+		assert!(!events()
+			.iter()
+			.any(|e| matches!(e, Event::<Test>::NextCollectionIdIncremented { .. })));
+
+		assert_noop!(
+			Nfts::create(
+				RuntimeOrigin::signed(account(1)),
+				account(1),
+				collection_config_with_all_settings_enabled()
+			),
+			Error::<Test>::CollectionIdInUse
+		);
+	});
+}
+
+#[test]
+fn collection_deposit_override_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(account(1)),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 1, account(1), None));
+		assert_eq!(Balances::reserved_balance(account(1)), 3);
+
+		let deposits =
+			CollectionDeposits { item: 4, metadata_base: 3, attribute_base: 2, per_byte: 0 };
+		assert_noop!(
+			Nfts::set_collection_deposit_override(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				Some(deposits)
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Nfts::set_collection_deposit_override(RuntimeOrigin::root(), 1, Some(deposits)),
+			Error::<Test>::UnknownCollection
+		);
+		assert_ok!(Nfts::set_collection_deposit_override(RuntimeOrigin::root(), 0, Some(deposits)));
+		assert!(events().contains(&Event::<Test>::CollectionDepositOverrideSet {
+			collection: 0,
+			deposits: Some(deposits),
+		}));
+
+		// the override is used for new deposits
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 2, account(1), None));
+		assert_eq!(Balances::reserved_balance(account(1)), 3 + 4);
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 2, bvec![0; 10]));
+		assert_eq!(Balances::reserved_balance(account(1)), 7 + 3);
+		assert_ok!(Nfts::set_collection_metadata(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			bvec![0; 10]
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), 10 + 3);
+		assert_ok!(Nfts::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(2),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+			bvec![0],
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), 13 + 2);
+
+		// deposits can be waived
+		assert_ok!(Nfts::set_collection_deposit_override(
+			RuntimeOrigin::root(),
+			0,
+			Some(CollectionDeposits::default())
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 3, account(1), None));
+		assert_ok!(Nfts::set_metadata(RuntimeOrigin::signed(account(1)), 0, 3, bvec![0; 10]));
+		assert_eq!(Balances::reserved_balance(account(1)), 15);
+
+		// going back to the defaults, the deposits taken so far are returned as they were
+		assert_ok!(Nfts::set_collection_deposit_override(RuntimeOrigin::root(), 0, None));
+		assert!(!CollectionDepositOverride::<Test>::contains_key(0));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 3));
+		assert_eq!(Balances::reserved_balance(account(1)), 15);
+		assert_ok!(Nfts::clear_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			Some(2),
+			AttributeNamespace::CollectionOwner,
+			bvec![0],
+		));
+		assert_eq!(Balances::reserved_balance(account(1)), 13);
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(1)), 0, 2));
+		assert_eq!(Balances::reserved_balance(account(1)), 13 - 3 - 4);
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 0, 4, account(1), None));
+		assert_eq!(Balances::reserved_balance(account(1)), 6 + 1);
+
+		// the override is removed along with the collection
+		assert_ok!(Nfts::set_collection_deposit_override(RuntimeOrigin::root(), 0, Some(deposits)));
+		assert_ok!(Nfts::force_destroy(RuntimeOrigin::root(), 0, 10));
+		assert!(!CollectionDepositOverride::<Test>::contains_key(0));
+		assert_eq!(Balances::reserved_balance(account(1)), 0);
+	});
+}
@@ -22,6 +22,7 @@ use crate::macros::*;
 use codec::EncodeLike;
 use enumflags2::{bitflags, BitFlags};
 use frame_support::{
+	dispatch::DispatchResult,
 	pallet_prelude::{BoundedVec, MaxEncodedLen},
 	traits::Get,
 	BoundedBTreeMap, BoundedBTreeSet,
@@ -39,17 +40,24 @@ pub(super) type ApprovalsOf<T, I = ()> = BoundedBTreeMap<
 >;
 pub(super) type ItemAttributesApprovals<T, I = ()> =
 	BoundedBTreeSet<<T as SystemConfig>::AccountId, <T as Config<I>>::ItemAttributesApprovalsLimit>;
+pub(super) type CollectionDepositsOf<T, I = ()> = CollectionDeposits<DepositBalanceOf<T, I>>;
 pub(super) type ItemDepositOf<T, I> =
 	ItemDeposit<DepositBalanceOf<T, I>, <T as SystemConfig>::AccountId>;
 pub(super) type AttributeDepositOf<T, I> =
 	AttributeDeposit<DepositBalanceOf<T, I>, <T as SystemConfig>::AccountId>;
 pub(super) type ItemMetadataDepositOf<T, I> =
 	ItemMetadataDeposit<DepositBalanceOf<T, I>, <T as SystemConfig>::AccountId>;
-pub(super) type ItemDetailsFor<T, I> =
-	ItemDetails<<T as SystemConfig>::AccountId, ItemDepositOf<T, I>, ApprovalsOf<T, I>>;
+pub(super) type ItemDetailsFor<T, I> = ItemDetails<
+	<T as SystemConfig>::AccountId,
+	ItemDepositOf<T, I>,
+	ApprovalsOf<T, I>,
+	<T as SystemConfig>::BlockNumber,
+>;
 pub(super) type BalanceOf<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
 pub(super) type ItemPrice<T, I = ()> = BalanceOf<T, I>;
+pub(super) type ItemPriceInfo<T, I = ()> =
+	(ItemPrice<T, I>, Option<<T as SystemConfig>::AccountId>);
 pub(super) type ItemTipOf<T, I = ()> = ItemTip<
 	<T as Config<I>>::CollectionId,
 	<T as Config<I>>::ItemId,
@@ -60,6 +68,7 @@ pub(super) type CollectionConfigFor<T, I = ()> = CollectionConfig<
 	BalanceOf<T, I>,
 	<T as SystemConfig>::BlockNumber,
 	<T as Config<I>>::CollectionId,
+	<T as Config<I>>::ItemId,
 >;
 pub(super) type PreSignedMintOf<T, I = ()> = PreSignedMint<
 	<T as Config<I>>::CollectionId,
@@ -73,13 +82,56 @@ pub(super) type PreSignedAttributesOf<T, I = ()> = PreSignedAttributes<
 	<T as SystemConfig>::AccountId,
 	<T as SystemConfig>::BlockNumber,
 >;
+pub(super) type AttributesOf<T, I = ()> = BoundedVec<
+	(BoundedVec<u8, <T as Config<I>>::KeyLimit>, BoundedVec<u8, <T as Config<I>>::ValueLimit>),
+	<T as Config<I>>::MaxAttributesPerCall,
+>;
+pub(super) type ItemMetadataBatchOf<T, I = ()> = BoundedVec<
+	(<T as Config<I>>::ItemId, BoundedVec<u8, <T as Config<I>>::StringLimit>),
+	<T as Config<I>>::MaxBatch,
+>;
 
-pub trait Incrementable {
-	fn increment(&self) -> Self;
+pub(super) type BundleOf<T, I = ()> = BoundedVec<
+	(<T as Config<I>>::CollectionId, <T as Config<I>>::ItemId),
+	<T as Config<I>>::MaxBundle,
+>;
+pub(super) type PendingBundleSwapFor<T, I = ()> = PendingBundleSwap<
+	<T as SystemConfig>::AccountId,
+	BundleOf<T, I>,
+	PriceWithDirection<ItemPrice<T, I>>,
+	<T as SystemConfig>::BlockNumber,
+>;
+
+/// An id that can be handed out sequentially.
+pub trait Incrementable: Sized {
+	/// The id following this one, or `None` once the id space is exhausted.
+	fn increment(&self) -> Option<Self>;
+	/// The first id to hand out.
 	fn initial_value() -> Self;
 }
 impl_incrementable!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
 
+/// Notified of the creation of collections, e.g. to register them with another pallet.
+pub trait OnCollectionCreated<CollectionId, AccountId> {
+	/// Called once the deposit of `owner` for the new `collection` is reserved, right before the
+	/// collection is stored. An error vetoes the creation.
+	fn on_collection_created(collection: &CollectionId, owner: &AccountId) -> DispatchResult;
+}
+impl<CollectionId, AccountId> OnCollectionCreated<CollectionId, AccountId> for () {
+	fn on_collection_created(_: &CollectionId, _: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Notified of the destruction of collections.
+pub trait OnCollectionDestroyed<CollectionId, AccountId> {
+	/// Called once the `collection` of `owner` is destroyed.
+	fn on_collection_destroyed(collection: &CollectionId, owner: &AccountId);
+}
+impl<CollectionId, AccountId> OnCollectionDestroyed<CollectionId, AccountId> for () {
+	fn on_collection_destroyed(_: &CollectionId, _: &AccountId) {}
+}
+
 /// Information about a collection.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct CollectionDetails<AccountId, DepositBalance> {
@@ -112,6 +164,22 @@ pub struct DestroyWitness {
 	pub attributes: u32,
 }
 
+/// The outcome of destroying a collection.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DestroyProgress {
+	/// The records removed by this call.
+	pub removed: DestroyWitness,
+	/// The records left to be removed before the collection is gone.
+	pub remaining: DestroyWitness,
+}
+
+impl DestroyProgress {
+	/// Whether the collection has been destroyed.
+	pub fn is_finished(&self) -> bool {
+		self.remaining == DestroyWitness { item_metadatas: 0, item_configs: 0, attributes: 0 }
+	}
+}
+
 impl<AccountId, DepositBalance> CollectionDetails<AccountId, DepositBalance> {
 	pub fn destroy_witness(&self) -> DestroyWitness {
 		DestroyWitness {
@@ -131,7 +199,7 @@ pub struct MintWitness<ItemId> {
 
 /// Information concerning the ownership of a single unique item.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
-pub struct ItemDetails<AccountId, Deposit, Approvals> {
+pub struct ItemDetails<AccountId, Deposit, Approvals, BlockNumber> {
 	/// The owner of this item.
 	pub(super) owner: AccountId,
 	/// The approved transferrer of this item, if one is set.
@@ -139,6 +207,11 @@ pub struct ItemDetails<AccountId, Deposit, Approvals> {
 	/// The amount held in the pallet's default account for this item. Free-hold items will have
 	/// this as zero.
 	pub(super) deposit: Deposit,
+	/// The block in which this item was minted.
+	///
+	/// For items minted before this was recorded, it is the block of the runtime upgrade which
+	/// started recording it.
+	pub(super) mint_block: BlockNumber,
 }
 
 /// Information about the reserved item deposit.
@@ -205,6 +278,21 @@ pub struct PendingSwap<CollectionId, ItemId, ItemPriceWithDirection, Deadline> {
 	pub(super) deadline: Deadline,
 }
 
+/// Information about the pending swap of a bundle of items for another one.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingBundleSwap<AccountId, Bundle, ItemPriceWithDirection, Deadline> {
+	/// The account that created the swap and owns the offered items.
+	pub(super) creator: AccountId,
+	/// The items the creator wants to send.
+	pub(super) offered: Bundle,
+	/// The items the creator wants to receive.
+	pub(super) desired: Bundle,
+	/// A price for the desired items with the direction.
+	pub(super) price: Option<ItemPriceWithDirection>,
+	/// A deadline for the swap.
+	pub(super) deadline: Deadline,
+}
+
 /// Information about the reserved attribute deposit.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct AttributeDeposit<DepositBalance, AccountId> {
@@ -256,6 +344,9 @@ pub enum CollectionSetting {
 	UnlockedMaxSupply,
 	/// When this isn't set then the deposit is required to hold the items of this collection.
 	DepositRequired,
+	/// Anyone can buy or swap for the items of this collection. When this isn't set, only the
+	/// accounts in the collection's allowlist can.
+	UnrestrictedBuyers,
 }
 
 /// Wrapper type for `BitFlags<CollectionSetting>` that implements `Codec`.
@@ -352,16 +443,20 @@ pub enum PalletAttributes<CollectionId> {
 #[derive(
 	Clone, Copy, Decode, Default, Encode, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo,
 )]
-pub struct CollectionConfig<Price, BlockNumber, CollectionId> {
+pub struct CollectionConfig<Price, BlockNumber, CollectionId, ItemId> {
 	/// Collection's settings.
 	pub settings: CollectionSettings,
 	/// Collection's max supply.
 	pub max_supply: Option<u32>,
 	/// Default settings each item will get during the mint.
 	pub mint_settings: MintSettings<Price, BlockNumber, CollectionId>,
+	/// The inclusive range of ids items can be minted with, if restricted.
+	pub item_id_range: Option<(ItemId, ItemId)>,
 }
 
-impl<Price, BlockNumber, CollectionId> CollectionConfig<Price, BlockNumber, CollectionId> {
+impl<Price, BlockNumber, CollectionId, ItemId>
+	CollectionConfig<Price, BlockNumber, CollectionId, ItemId>
+{
 	pub fn is_setting_enabled(&self, setting: CollectionSetting) -> bool {
 		!self.settings.is_disabled(setting)
 	}
@@ -376,6 +471,67 @@ impl<Price, BlockNumber, CollectionId> CollectionConfig<Price, BlockNumber, Coll
 	}
 }
 
+/// Collection's settings decoded into plain flags.
+#[derive(Clone, Copy, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct CollectionSettingsInfo {
+	/// Items in this collection are transferable.
+	pub transferable_items: bool,
+	/// The metadata of this collection is locked.
+	pub metadata_locked: bool,
+	/// Attributes of this collection are locked.
+	pub attributes_locked: bool,
+	/// The max supply of this collection is locked.
+	pub max_supply_locked: bool,
+	/// A deposit is required to hold the items of this collection.
+	pub deposit_required: bool,
+	/// Only the accounts in the collection's allowlist can acquire its items.
+	pub restricted_buyers: bool,
+}
+
+impl From<CollectionSettings> for CollectionSettingsInfo {
+	fn from(settings: CollectionSettings) -> Self {
+		Self {
+			transferable_items: !settings.is_disabled(CollectionSetting::TransferableItems),
+			metadata_locked: settings.is_disabled(CollectionSetting::UnlockedMetadata),
+			attributes_locked: settings.is_disabled(CollectionSetting::UnlockedAttributes),
+			max_supply_locked: settings.is_disabled(CollectionSetting::UnlockedMaxSupply),
+			deposit_required: !settings.is_disabled(CollectionSetting::DepositRequired),
+			restricted_buyers: settings.is_disabled(CollectionSetting::UnrestrictedBuyers),
+		}
+	}
+}
+
+/// Aggregate statistics of a collection, as cached in its details.
+#[derive(Clone, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct CollectionStats<AccountId> {
+	/// Collection's owner.
+	pub owner: AccountId,
+	/// The total number of outstanding items of this collection.
+	pub items: u32,
+	/// The total number of outstanding item metadata of this collection.
+	pub item_metadatas: u32,
+	/// The total number of attributes for this collection.
+	pub attributes: u32,
+	/// Whether minting is currently open, i.e. within the mint window, not paused and below
+	/// the max supply.
+	pub mint_open: bool,
+}
+
+/// The deposits taken for the items of a collection, in place of the pallet's defaults.
+#[derive(
+	Clone, Copy, Decode, Default, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct CollectionDeposits<DepositBalance> {
+	/// The deposit for an item, replacing `ItemDeposit`.
+	pub item: DepositBalance,
+	/// The base deposit for item and collection metadata, replacing `MetadataDepositBase`.
+	pub metadata_base: DepositBalance,
+	/// The base deposit for an attribute, replacing `AttributeDepositBase`.
+	pub attribute_base: DepositBalance,
+	/// The additional deposit per byte of metadata or attribute, replacing `DepositPerByte`.
+	pub per_byte: DepositBalance,
+}
+
 /// Support for up to 64 user-enabled features on an item.
 #[bitflags]
 #[repr(u64)]
@@ -387,6 +543,8 @@ pub enum ItemSetting {
 	UnlockedMetadata,
 	/// Attributes of this item can be modified.
 	UnlockedAttributes,
+	/// The owner of this item hasn't locked its transfer.
+	OwnerTransferable,
 }
 
 /// Wrapper type for `BitFlags<ItemSetting>` that implements `Codec`.
@@ -437,6 +595,30 @@ impl ItemConfig {
 	}
 }
 
+/// Item's settings decoded into plain flags.
+#[derive(Clone, Copy, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ItemSettingsInfo {
+	/// This item is transferable.
+	pub transferable: bool,
+	/// The metadata of this item is locked.
+	pub metadata_locked: bool,
+	/// Attributes of this item are locked.
+	pub attributes_locked: bool,
+	/// The owner of this item has locked its transfer.
+	pub locked_by_owner: bool,
+}
+
+impl From<ItemSettings> for ItemSettingsInfo {
+	fn from(settings: ItemSettings) -> Self {
+		Self {
+			transferable: !settings.is_disabled(ItemSetting::Transferable),
+			metadata_locked: settings.is_disabled(ItemSetting::UnlockedMetadata),
+			attributes_locked: settings.is_disabled(ItemSetting::UnlockedAttributes),
+			locked_by_owner: settings.is_disabled(ItemSetting::OwnerTransferable),
+		}
+	}
+}
+
 /// Support for up to 64 system-enabled features on a collection.
 #[bitflags]
 #[repr(u64)]
@@ -450,6 +632,8 @@ pub enum PalletFeature {
 	Approvals,
 	/// Allow/disallow atomic items swap.
 	Swaps,
+	/// Allow/disallow setting metadata.
+	Metadata,
 }
 
 /// Wrapper type for `BitFlags<PalletFeature>` that implements `Codec`.
@@ -87,6 +87,28 @@ pub trait WeightInfo {
 	fn claim_swap() -> Weight;
 	fn mint_pre_signed(n: u32, ) -> Weight;
 	fn set_attributes_pre_signed(n: u32, ) -> Weight;
+	fn lock_item_transfer_by_owner() -> Weight;
+	fn unlock_item_transfer_by_owner() -> Weight;
+	fn force_create_system() -> Weight;
+	fn set_attributes(n: u32, ) -> Weight;
+	fn set_mint_paused() -> Weight;
+	fn force_destroy(n: u32, ) -> Weight;
+	fn create_with_deposit() -> Weight;
+	fn set_team_roles() -> Weight;
+	fn add_allowed_buyer() -> Weight;
+	fn remove_allowed_buyer() -> Weight;
+	fn recompute_collection_status(n: u32, ) -> Weight;
+	fn set_collection_deposit_override() -> Weight;
+	fn clear_expired_price() -> Weight;
+	fn force_transfer_all(n: u32, ) -> Weight;
+	fn create_with_id() -> Weight;
+	fn create_bundle_swap(n: u32, ) -> Weight;
+	fn cancel_bundle_swap() -> Weight;
+	fn claim_bundle_swap(n: u32, ) -> Weight;
+	fn thaw_all(n: u32, ) -> Weight;
+	fn set_default_item_metadata() -> Weight;
+	fn burn_many(n: u32, ) -> Weight;
+	fn set_metadata_many(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_nfts using the Substrate node and recommended hardware.
@@ -136,6 +158,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionRoleOf (r:1 w:1)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAllowlist (r:1 w:0)
+	/// Proof: Nfts CollectionAllowlist (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	/// Storage: Nfts Attribute (r:1001 w:1000)
 	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemConfigOf (r:1000 w:1000)
@@ -157,7 +181,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(1_081_634_178, 2523990)
 			// Standard Error: 3_025
 			.saturating_add(Weight::from_parts(5_339_415, 0).saturating_mul(a.into()))
-			.saturating_add(T::DbWeight::get().reads(1004_u64))
+			.saturating_add(T::DbWeight::get().reads(1005_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
 			.saturating_add(T::DbWeight::get().writes(1005_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
@@ -175,14 +199,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts Account (r:0 w:1)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts DefaultItemMetadataOf (r:1 w:0)
+	/// Proof: Nfts DefaultItemMetadataOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `421`
 		//  Estimated: `4326`
 		// Minimum execution time: 52_464_000 picoseconds.
-		Weight::from_parts(52_847_000, 4326)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		Weight::from_parts(70_912_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
@@ -196,14 +226,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts Account (r:0 w:1)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts DefaultItemMetadataOf (r:1 w:0)
+	/// Proof: Nfts DefaultItemMetadataOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn force_mint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `421`
 		//  Estimated: `4326`
 		// Minimum execution time: 50_327_000 picoseconds.
-		Weight::from_parts(51_093_000, 4326)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		Weight::from_parts(68_430_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Nfts ItemConfigOf (r:1 w:1)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
@@ -216,7 +252,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Nfts Account (r:0 w:1)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:1)
 	/// Proof: Nfts ItemAttributesApprovalsOf (max_values: None, max_size: Some(681), added: 3156, mode: MaxEncodedLen)
 	/// Storage: Nfts PendingSwapOf (r:0 w:1)
@@ -241,7 +277,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Nfts Account (r:0 w:2)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	/// Storage: Nfts PendingSwapOf (r:0 w:1)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
 	fn transfer() -> Weight {
@@ -318,14 +354,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionAccount (r:0 w:2)
 	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn transfer_ownership() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `354`
-		//  Estimated: `3549`
+		//  Estimated: `3593`
 		// Minimum execution time: 24_713_000 picoseconds.
-		Weight::from_parts(25_032_000, 3549)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		Weight::from_parts(25_032_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
@@ -342,16 +380,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts OwnershipAcceptance (r:1 w:1)
+	/// Proof: Nfts OwnershipAcceptance (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionAccount (r:0 w:2)
 	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn force_collection_owner() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `277`
-		//  Estimated: `3549`
+		//  Estimated: `3593`
 		// Minimum execution time: 19_703_000 picoseconds.
-		Weight::from_parts(19_993_000, 3549)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		Weight::from_parts(19_993_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
@@ -519,7 +561,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// Storage: Nfts Collection (r:1 w:0)
+	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
 	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
@@ -532,7 +574,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 39_792_000 picoseconds.
 		Weight::from_parts(40_443_000, 3552)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Nfts Item (r:1 w:1)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
@@ -613,20 +655,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:1 w:0)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
 	fn set_price() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `484`
 		//  Estimated: `4326`
 		// Minimum execution time: 25_778_000 picoseconds.
 		Weight::from_parts(26_447_000, 4326)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts Item (r:1 w:1)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:1 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
@@ -646,27 +690,33 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	/// Storage: Nfts Item (r:10 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// The range of component `n` is `[0, 10]`.
 	fn pay_tips(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Measured:  `0 + n * (36 ±0)`
+		//  Estimated: `0 + n * (3336 ±0)`
 		// Minimum execution time: 2_789_000 picoseconds.
 		Weight::from_parts(5_528_034, 0)
 			// Standard Error: 14_405
-			.saturating_add(Weight::from_parts(3_788_038, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(5_962_611, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
 	}
 	/// Storage: Nfts Item (r:2 w:0)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts PendingSwapOf (r:0 w:1)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:1 w:0)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn create_swap() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `460`
 		//  Estimated: `7662`
 		// Minimum execution time: 22_884_000 picoseconds.
 		Weight::from_parts(23_732_000, 7662)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts PendingSwapOf (r:1 w:1)
@@ -695,7 +745,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Nfts Account (r:0 w:4)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:2)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn claim_swap() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `800`
@@ -765,10 +815,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2921).saturating_mul(n.into()))
 	}
-}
-
-// For backwards compatibility and tests
-impl WeightInfo for () {
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn lock_item_transfer_by_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `429`
+		//  Estimated: `4326`
+		// Minimum execution time: 21_973_000 picoseconds.
+		Weight::from_parts(22_406_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn unlock_item_transfer_by_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `429`
+		//  Estimated: `4326`
+		// Minimum execution time: 22_047_000 picoseconds.
+		Weight::from_parts(22_518_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: Nfts NextCollectionId (r:1 w:1)
 	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:1)
@@ -779,167 +851,595 @@ impl WeightInfo for () {
 	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionAccount (r:0 w:1)
 	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	fn create() -> Weight {
+	/// Storage: Nfts SystemCollection (r:0 w:1)
+	/// Proof: Nfts SystemCollection (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	fn force_create_system() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `182`
+		//  Measured:  `42`
 		//  Estimated: `3549`
-		// Minimum execution time: 40_664_000 picoseconds.
-		Weight::from_parts(41_224_000, 3549)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		// Minimum execution time: 26_138_000 picoseconds.
+		Weight::from_parts(26_604_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
-	/// Storage: Nfts NextCollectionId (r:1 w:1)
-	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
 	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionAccount (r:0 w:1)
-	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	fn force_create() -> Weight {
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:10 w:10)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 10]`.
+	fn set_attributes(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
+		//  Measured:  `505`
+		//  Estimated: `3549 + n * (2921 ±0)`
+		// Minimum execution time: 24_113_000 picoseconds.
+		Weight::from_parts(26_318_540, 3549)
+			// Standard Error: 58_391
+			.saturating_add(Weight::from_parts(30_716_377, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2921).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts MintPaused (r:0 w:1)
+	/// Proof: Nfts MintPaused (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	fn set_mint_paused() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `382`
 		//  Estimated: `3549`
-		// Minimum execution time: 24_725_000 picoseconds.
-		Weight::from_parts(25_147_000, 3549)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		// Minimum execution time: 19_302_000 picoseconds.
+		Weight::from_parts(19_687_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1001 w:1000)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemMetadataOf (r:1 w:0)
 	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionRoleOf (r:1 w:1)
-	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// Storage: Nfts Attribute (r:1001 w:1000)
+	/// Storage: Nfts Attribute (r:1 w:0)
 	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemConfigOf (r:1000 w:1000)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1000)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1000)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1000)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:1000)
+	/// Proof: Nfts ItemAttributesApprovalsOf (max_values: None, max_size: Some(681), added: 3156, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionMetadataOf (r:0 w:1)
 	/// Proof: Nfts CollectionMetadataOf (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
 	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionAccount (r:0 w:1)
 	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
-	/// The range of component `m` is `[0, 1000]`.
-	/// The range of component `c` is `[0, 1000]`.
-	/// The range of component `a` is `[0, 1000]`.
-	fn destroy(_m: u32, _c: u32, a: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32186 + a * (332 ±0)`
-		//  Estimated: `2523990 + a * (2921 ±0)`
-		// Minimum execution time: 1_100_509_000 picoseconds.
-		Weight::from_parts(1_081_634_178, 2523990)
-			// Standard Error: 3_025
-			.saturating_add(Weight::from_parts(5_339_415, 0).saturating_mul(a.into()))
-			.saturating_add(RocksDbWeight::get().reads(1004_u64))
-			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
-			.saturating_add(RocksDbWeight::get().writes(1005_u64))
-			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
-			.saturating_add(Weight::from_parts(0, 2921).saturating_mul(a.into()))
+	/// Storage: Nfts SystemCollection (r:0 w:1)
+	/// Proof: Nfts SystemCollection (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	/// Storage: Nfts MintPaused (r:0 w:1)
+	/// Proof: Nfts MintPaused (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 1000]`.
+	fn force_destroy(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `438 + n * (396 ±0)`
+		//  Estimated: `3549 + n * (3336 ±0)`
+		// Minimum execution time: 48_114_000 picoseconds.
+		Weight::from_parts(49_312_000, 3549)
+			// Standard Error: 11_472
+			.saturating_add(Weight::from_parts(24_780_514, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
 	}
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Storage: Nfts NextCollectionId (r:1 w:1)
+	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
 	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts Item (r:1 w:1)
-	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAccount (r:0 w:1)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn create_with_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3549`
+		// Minimum execution time: 31_412_000 picoseconds.
+		Weight::from_parts(32_057_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Storage: Nfts CollectionRoleOf (r:5 w:4)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemConfigOf (r:1 w:1)
-	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: Nfts Account (r:0 w:1)
-	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
-	fn mint() -> Weight {
+	fn set_team_roles() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `421`
-		//  Estimated: `4326`
-		// Minimum execution time: 52_464_000 picoseconds.
-		Weight::from_parts(52_847_000, 4326)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `335`
+		//  Estimated: `6078`
+		// Minimum execution time: 47_916_000 picoseconds.
+		Weight::from_parts(48_503_000, 6078)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// Storage: Nfts Item (r:1 w:1)
-	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
-	/// Storage: Nfts Collection (r:1 w:1)
+	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemConfigOf (r:1 w:1)
-	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: Nfts Account (r:0 w:1)
-	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
-	fn force_mint() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `421`
-		//  Estimated: `4326`
-		// Minimum execution time: 50_327_000 picoseconds.
-		Weight::from_parts(51_093_000, 4326)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	/// Storage: Nfts CollectionAllowlist (r:0 w:1)
+	/// Proof: Nfts CollectionAllowlist (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn add_allowed_buyer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `395`
+		//  Estimated: `4126`
+		// Minimum execution time: 21_735_000 picoseconds.
+		Weight::from_parts(22_310_000, 4126)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAllowlist (r:1 w:1)
+	/// Proof: Nfts CollectionAllowlist (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn remove_allowed_buyer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `428`
+		//  Estimated: `4126`
+		// Minimum execution time: 20_118_000 picoseconds.
+		Weight::from_parts(20_644_000, 4126)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: Nfts ItemConfigOf (r:1 w:1)
-	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Nfts Item (r:1 w:1)
+	/// Storage: Nfts Item (r:1001 w:0)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemMetadataOf (r:1 w:0)
 	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
-	/// Storage: Nfts Account (r:0 w:1)
-	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemPriceOf (r:0 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:1)
-	/// Proof: Nfts ItemAttributesApprovalsOf (max_values: None, max_size: Some(681), added: 3156, mode: MaxEncodedLen)
-	/// Storage: Nfts PendingSwapOf (r:0 w:1)
-	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
-	fn burn() -> Weight {
+	/// Storage: Nfts ItemConfigOf (r:1001 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:1 w:0)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	fn recompute_collection_status(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `438 + n * (118 ±0)`
+		//  Estimated: `3911 + n * (1429 ±0)`
+		// Minimum execution time: 21_208_000 picoseconds.
+		Weight::from_parts(21_870_000, 3911)
+			// Standard Error: 2_184
+			.saturating_add(Weight::from_parts(2_612_431, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 1429).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionDepositOverride (r:0 w:1)
+	/// Proof: Nfts CollectionDepositOverride (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn set_collection_deposit_override() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `530`
-		//  Estimated: `4326`
-		// Minimum execution time: 51_342_000 picoseconds.
-		Weight::from_parts(51_846_000, 4326)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+		//  Measured:  `267`
+		//  Estimated: `3549`
+		// Minimum execution time: 17_346_000 picoseconds.
+		Weight::from_parts(17_802_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts ItemPriceOf (r:1 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	fn clear_expired_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `411`
+		//  Estimated: `3563`
+		// Minimum execution time: 17_912_000 picoseconds.
+		Weight::from_parts(18_455_000, 3563)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
-	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemConfigOf (r:1 w:0)
-	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
-	/// Storage: Nfts Item (r:1 w:1)
+	/// Storage: Nfts Item (r:1001 w:1000)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
-	/// Storage: Nfts Account (r:0 w:2)
+	/// Storage: Nfts Account (r:0 w:2000)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
-	/// Storage: Nfts ItemPriceOf (r:0 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
-	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1000)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1000)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
-	fn transfer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `559`
-		//  Estimated: `4326`
-		// Minimum execution time: 38_309_000 picoseconds.
-		Weight::from_parts(38_672_000, 4326)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	fn force_transfer_all(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `363 + n * (116 ±0)`
+		//  Estimated: `3549 + n * (3336 ±0)`
+		// Minimum execution time: 18_114_000 picoseconds.
+		Weight::from_parts(18_503_000, 3549)
+			// Standard Error: 3_107
+			.saturating_add(Weight::from_parts(13_402_885, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
 	}
-	/// Storage: Nfts Collection (r:1 w:0)
+	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
-	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Storage: Nfts NextCollectionId (r:1 w:0)
+	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
 	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
-	/// Storage: Nfts Item (r:5000 w:5000)
-	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
-	/// The range of component `i` is `[0, 5000]`.
-	fn redeposit(i: u32, ) -> Weight {
+	/// Storage: Nfts CollectionAccount (r:0 w:1)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn create_with_id() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `729 + i * (108 ±0)`
+		//  Measured:  `182`
+		//  Estimated: `3549`
+		// Minimum execution time: 39_871_000 picoseconds.
+		Weight::from_parts(40_502_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Nfts Item (r:20 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:10 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts NextBundleSwapId (r:1 w:1)
+	/// Proof: Nfts NextBundleSwapId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingBundleSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingBundleSwapOf (max_values: None, max_size: Some(216), added: 2691, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 20]`.
+	fn create_bundle_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `293 + n * (140 ±0)`
+		//  Estimated: `1489 + n * (3336 ±0)`
+		// Minimum execution time: 21_530_000 picoseconds.
+		Weight::from_parts(18_904_000, 1489)
+			// Standard Error: 6_218
+			.saturating_add(Weight::from_parts(5_870_312, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts PendingBundleSwapOf (r:1 w:1)
+	/// Proof: Nfts PendingBundleSwapOf (max_values: None, max_size: Some(216), added: 2691, mode: MaxEncodedLen)
+	fn cancel_bundle_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `373`
+		//  Estimated: `3681`
+		// Minimum execution time: 17_902_000 picoseconds.
+		Weight::from_parts(18_416_000, 3681)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts PendingBundleSwapOf (r:1 w:1)
+	/// Proof: Nfts PendingBundleSwapOf (max_values: None, max_size: Some(216), added: 2691, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:20 w:20)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:20 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:40)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:20)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:20)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 20]`.
+	fn claim_bundle_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `830 + n * (158 ±0)`
+		//  Estimated: `3681 + n * (3336 ±0)`
+		// Minimum execution time: 71_348_000 picoseconds.
+		Weight::from_parts(43_915_000, 3681)
+			// Standard Error: 14_872
+			.saturating_add(Weight::from_parts(20_337_406, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1001 w:1000)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 1000]`.
+	fn thaw_all(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `395 + n * (60 ±0)`
+		//  Estimated: `3534 + n * (2523 ±0)`
+		// Minimum execution time: 16_402_000 picoseconds.
+		Weight::from_parts(16_871_000, 3534)
+			// Standard Error: 2_214
+			.saturating_add(Weight::from_parts(6_118_240, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2523).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts DefaultItemMetadataOf (r:0 w:1)
+	/// Proof: Nfts DefaultItemMetadataOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	fn set_default_item_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `395`
+		//  Estimated: `3549`
+		// Minimum execution time: 17_806_000 picoseconds.
+		Weight::from_parts(18_231_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:20 w:20)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:20 w:20)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:20 w:0)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:20)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:20)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:20)
+	/// Proof: Nfts ItemAttributesApprovalsOf (max_values: None, max_size: Some(681), added: 3156, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:20)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 20]`.
+	fn burn_many(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `456 + n * (74 ±0)`
+		//  Estimated: `3549 + n * (3336 ±0)`
+		// Minimum execution time: 14_926_000 picoseconds.
+		Weight::from_parts(15_302_000, 3549)
+			// Standard Error: 9_871
+			.saturating_add(Weight::from_parts(38_214_517, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:20 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:20 w:20)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 20]`.
+	fn set_metadata_many(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `505 + n * (4 ±0)`
+		//  Estimated: `3549 + n * (2615 ±0)`
+		// Minimum execution time: 41_512_000 picoseconds.
+		Weight::from_parts(22_081_000, 3549)
+			// Standard Error: 11_204
+			.saturating_add(Weight::from_parts(24_947_312, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2615).saturating_mul(n.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Nfts NextCollectionId (r:1 w:1)
+	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAccount (r:0 w:1)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn create() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `182`
+		//  Estimated: `3549`
+		// Minimum execution time: 40_664_000 picoseconds.
+		Weight::from_parts(41_224_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Nfts NextCollectionId (r:1 w:1)
+	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAccount (r:0 w:1)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn force_create() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3549`
+		// Minimum execution time: 24_725_000 picoseconds.
+		Weight::from_parts(25_147_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:1 w:0)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAllowlist (r:1 w:0)
+	/// Proof: Nfts CollectionAllowlist (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:1001 w:1000)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1000 w:1000)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionMetadataOf (r:0 w:1)
+	/// Proof: Nfts CollectionMetadataOf (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAccount (r:0 w:1)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// The range of component `m` is `[0, 1000]`.
+	/// The range of component `c` is `[0, 1000]`.
+	/// The range of component `a` is `[0, 1000]`.
+	fn destroy(_m: u32, _c: u32, a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `32186 + a * (332 ±0)`
+		//  Estimated: `2523990 + a * (2921 ±0)`
+		// Minimum execution time: 1_100_509_000 picoseconds.
+		Weight::from_parts(1_081_634_178, 2523990)
+			// Standard Error: 3_025
+			.saturating_add(Weight::from_parts(5_339_415, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(1005_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(1005_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2921).saturating_mul(a.into()))
+	}
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts DefaultItemMetadataOf (r:1 w:0)
+	/// Proof: Nfts DefaultItemMetadataOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `4326`
+		// Minimum execution time: 52_464_000 picoseconds.
+		Weight::from_parts(70_912_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts DefaultItemMetadataOf (r:1 w:0)
+	/// Proof: Nfts DefaultItemMetadataOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn force_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `4326`
+		// Minimum execution time: 50_327_000 picoseconds.
+		Weight::from_parts(68_430_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Nfts ItemConfigOf (r:1 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:1 w:0)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:1)
+	/// Proof: Nfts ItemAttributesApprovalsOf (max_values: None, max_size: Some(681), added: 3156, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	fn burn() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `530`
+		//  Estimated: `4326`
+		// Minimum execution time: 51_342_000 picoseconds.
+		Weight::from_parts(51_846_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	fn transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `559`
+		//  Estimated: `4326`
+		// Minimum execution time: 38_309_000 picoseconds.
+		Weight::from_parts(38_672_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:5000 w:5000)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// The range of component `i` is `[0, 5000]`.
+	fn redeposit(i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `729 + i * (108 ±0)`
 		//  Estimated: `3549 + i * (3336 ±0)`
 		// Minimum execution time: 17_525_000 picoseconds.
 		Weight::from_parts(17_657_000, 3549)
@@ -995,14 +1495,16 @@ impl WeightInfo for () {
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionAccount (r:0 w:2)
 	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn transfer_ownership() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `354`
-		//  Estimated: `3549`
+		//  Estimated: `3593`
 		// Minimum execution time: 24_713_000 picoseconds.
-		Weight::from_parts(25_032_000, 3549)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		Weight::from_parts(25_032_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
@@ -1019,16 +1521,20 @@ impl WeightInfo for () {
 	}
 	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts OwnershipAcceptance (r:1 w:1)
+	/// Proof: Nfts OwnershipAcceptance (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionAccount (r:0 w:2)
 	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn force_collection_owner() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `277`
-		//  Estimated: `3549`
+		//  Estimated: `3593`
 		// Minimum execution time: 19_703_000 picoseconds.
-		Weight::from_parts(19_993_000, 3549)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		Weight::from_parts(19_993_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
@@ -1196,7 +1702,7 @@ impl WeightInfo for () {
 	}
 	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
 	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
-	/// Storage: Nfts Collection (r:1 w:0)
+	/// Storage: Nfts Collection (r:1 w:1)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
 	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
@@ -1209,7 +1715,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 39_792_000 picoseconds.
 		Weight::from_parts(40_443_000, 3552)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Nfts Item (r:1 w:1)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
@@ -1290,20 +1796,22 @@ impl WeightInfo for () {
 	/// Storage: Nfts ItemConfigOf (r:1 w:0)
 	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:1 w:0)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
 	fn set_price() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `484`
 		//  Estimated: `4326`
 		// Minimum execution time: 25_778_000 picoseconds.
 		Weight::from_parts(26_447_000, 4326)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts Item (r:1 w:1)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:1 w:1)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	/// Storage: Nfts Collection (r:1 w:0)
 	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
 	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
@@ -1323,27 +1831,33 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
+	/// Storage: Nfts Item (r:10 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// The range of component `n` is `[0, 10]`.
 	fn pay_tips(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Measured:  `0 + n * (36 ±0)`
+		//  Estimated: `0 + n * (3336 ±0)`
 		// Minimum execution time: 2_789_000 picoseconds.
 		Weight::from_parts(5_528_034, 0)
 			// Standard Error: 14_405
-			.saturating_add(Weight::from_parts(3_788_038, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(5_962_611, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
 	}
 	/// Storage: Nfts Item (r:2 w:0)
 	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
 	/// Storage: Nfts PendingSwapOf (r:0 w:1)
 	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:1 w:0)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn create_swap() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `460`
 		//  Estimated: `7662`
 		// Minimum execution time: 22_884_000 picoseconds.
 		Weight::from_parts(23_732_000, 7662)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Nfts PendingSwapOf (r:1 w:1)
@@ -1372,7 +1886,7 @@ impl WeightInfo for () {
 	/// Storage: Nfts Account (r:0 w:4)
 	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	/// Storage: Nfts ItemPriceOf (r:0 w:2)
-	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
 	fn claim_swap() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `800`
@@ -1442,4 +1956,440 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2921).saturating_mul(n.into()))
 	}
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn lock_item_transfer_by_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `429`
+		//  Estimated: `4326`
+		// Minimum execution time: 21_973_000 picoseconds.
+		Weight::from_parts(22_406_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn unlock_item_transfer_by_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `429`
+		//  Estimated: `4326`
+		// Minimum execution time: 22_047_000 picoseconds.
+		Weight::from_parts(22_518_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts NextCollectionId (r:1 w:1)
+	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAccount (r:0 w:1)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Nfts SystemCollection (r:0 w:1)
+	/// Proof: Nfts SystemCollection (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	fn force_create_system() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3549`
+		// Minimum execution time: 26_138_000 picoseconds.
+		Weight::from_parts(26_604_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:10 w:10)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 10]`.
+	fn set_attributes(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `505`
+		//  Estimated: `3549 + n * (2921 ±0)`
+		// Minimum execution time: 24_113_000 picoseconds.
+		Weight::from_parts(26_318_540, 3549)
+			// Standard Error: 58_391
+			.saturating_add(Weight::from_parts(30_716_377, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2921).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts MintPaused (r:0 w:1)
+	/// Proof: Nfts MintPaused (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	fn set_mint_paused() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `382`
+		//  Estimated: `3549`
+		// Minimum execution time: 19_302_000 picoseconds.
+		Weight::from_parts(19_687_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1001 w:1000)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:1 w:0)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:1 w:0)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1000)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1000)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1000)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:1000)
+	/// Proof: Nfts ItemAttributesApprovalsOf (max_values: None, max_size: Some(681), added: 3156, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionMetadataOf (r:0 w:1)
+	/// Proof: Nfts CollectionMetadataOf (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAccount (r:0 w:1)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Nfts SystemCollection (r:0 w:1)
+	/// Proof: Nfts SystemCollection (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	/// Storage: Nfts MintPaused (r:0 w:1)
+	/// Proof: Nfts MintPaused (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 1000]`.
+	fn force_destroy(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `438 + n * (396 ±0)`
+		//  Estimated: `3549 + n * (3336 ±0)`
+		// Minimum execution time: 48_114_000 picoseconds.
+		Weight::from_parts(49_312_000, 3549)
+			// Standard Error: 11_472
+			.saturating_add(Weight::from_parts(24_780_514, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts NextCollectionId (r:1 w:1)
+	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAccount (r:0 w:1)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn create_with_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3549`
+		// Minimum execution time: 31_412_000 picoseconds.
+		Weight::from_parts(32_057_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:5 w:4)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	fn set_team_roles() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `335`
+		//  Estimated: `6078`
+		// Minimum execution time: 47_916_000 picoseconds.
+		Weight::from_parts(48_503_000, 6078)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAllowlist (r:0 w:1)
+	/// Proof: Nfts CollectionAllowlist (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn add_allowed_buyer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `395`
+		//  Estimated: `4126`
+		// Minimum execution time: 21_735_000 picoseconds.
+		Weight::from_parts(22_310_000, 4126)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAllowlist (r:1 w:1)
+	/// Proof: Nfts CollectionAllowlist (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn remove_allowed_buyer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `428`
+		//  Estimated: `4126`
+		// Minimum execution time: 20_118_000 picoseconds.
+		Weight::from_parts(20_644_000, 4126)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1001 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:1 w:0)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1001 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Attribute (r:1 w:0)
+	/// Proof: Nfts Attribute (max_values: None, max_size: Some(446), added: 2921, mode: MaxEncodedLen)
+	fn recompute_collection_status(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `438 + n * (118 ±0)`
+		//  Estimated: `3911 + n * (1429 ±0)`
+		// Minimum execution time: 21_208_000 picoseconds.
+		Weight::from_parts(21_870_000, 3911)
+			// Standard Error: 2_184
+			.saturating_add(Weight::from_parts(2_612_431, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 1429).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionDepositOverride (r:0 w:1)
+	/// Proof: Nfts CollectionDepositOverride (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	fn set_collection_deposit_override() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `267`
+		//  Estimated: `3549`
+		// Minimum execution time: 17_346_000 picoseconds.
+		Weight::from_parts(17_802_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts ItemPriceOf (r:1 w:1)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	fn clear_expired_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `411`
+		//  Estimated: `3563`
+		// Minimum execution time: 17_912_000 picoseconds.
+		Weight::from_parts(18_455_000, 3563)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1001 w:1000)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2000)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:1000)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:1000)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	fn force_transfer_all(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `363 + n * (116 ±0)`
+		//  Estimated: `3549 + n * (3336 ±0)`
+		// Minimum execution time: 18_114_000 picoseconds.
+		Weight::from_parts(18_503_000, 3549)
+			// Standard Error: 3_107
+			.saturating_add(Weight::from_parts(13_402_885, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts NextCollectionId (r:1 w:0)
+	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAccount (r:0 w:1)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn create_with_id() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `182`
+		//  Estimated: `3549`
+		// Minimum execution time: 39_871_000 picoseconds.
+		Weight::from_parts(40_502_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Nfts Item (r:20 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:10 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts NextBundleSwapId (r:1 w:1)
+	/// Proof: Nfts NextBundleSwapId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingBundleSwapOf (r:0 w:1)
+	/// Proof: Nfts PendingBundleSwapOf (max_values: None, max_size: Some(216), added: 2691, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 20]`.
+	fn create_bundle_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `293 + n * (140 ±0)`
+		//  Estimated: `1489 + n * (3336 ±0)`
+		// Minimum execution time: 21_530_000 picoseconds.
+		Weight::from_parts(18_904_000, 1489)
+			// Standard Error: 6_218
+			.saturating_add(Weight::from_parts(5_870_312, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts PendingBundleSwapOf (r:1 w:1)
+	/// Proof: Nfts PendingBundleSwapOf (max_values: None, max_size: Some(216), added: 2691, mode: MaxEncodedLen)
+	fn cancel_bundle_swap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `373`
+		//  Estimated: `3681`
+		// Minimum execution time: 17_902_000 picoseconds.
+		Weight::from_parts(18_416_000, 3681)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts PendingBundleSwapOf (r:1 w:1)
+	/// Proof: Nfts PendingBundleSwapOf (max_values: None, max_size: Some(216), added: 2691, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:20 w:20)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:20 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:40)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:20)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:20)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 20]`.
+	fn claim_bundle_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `830 + n * (158 ±0)`
+		//  Estimated: `3681 + n * (3336 ±0)`
+		// Minimum execution time: 71_348_000 picoseconds.
+		Weight::from_parts(43_915_000, 3681)
+			// Standard Error: 14_872
+			.saturating_add(Weight::from_parts(20_337_406, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1001 w:1000)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 1000]`.
+	fn thaw_all(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `395 + n * (60 ±0)`
+		//  Estimated: `3534 + n * (2523 ±0)`
+		// Minimum execution time: 16_402_000 picoseconds.
+		Weight::from_parts(16_871_000, 3534)
+			// Standard Error: 2_214
+			.saturating_add(Weight::from_parts(6_118_240, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2523).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts DefaultItemMetadataOf (r:0 w:1)
+	/// Proof: Nfts DefaultItemMetadataOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	fn set_default_item_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `395`
+		//  Estimated: `3549`
+		// Minimum execution time: 17_806_000 picoseconds.
+		Weight::from_parts(18_231_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:20 w:20)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:20 w:20)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:20 w:0)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:20)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemPriceOf (r:0 w:20)
+	/// Proof: Nfts ItemPriceOf (max_values: None, max_size: Some(98), added: 2573, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemAttributesApprovalsOf (r:0 w:20)
+	/// Proof: Nfts ItemAttributesApprovalsOf (max_values: None, max_size: Some(681), added: 3156, mode: MaxEncodedLen)
+	/// Storage: Nfts PendingSwapOf (r:0 w:20)
+	/// Proof: Nfts PendingSwapOf (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 20]`.
+	fn burn_many(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `456 + n * (74 ±0)`
+		//  Estimated: `3549 + n * (3336 ±0)`
+		// Minimum execution time: 14_926_000 picoseconds.
+		Weight::from_parts(15_302_000, 3549)
+			// Standard Error: 9_871
+			.saturating_add(Weight::from_parts(38_214_517, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
+	}
+	/// Storage: Nfts CollectionRoleOf (r:1 w:0)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:20 w:0)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:20 w:20)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 20]`.
+	fn set_metadata_many(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `505 + n * (4 ±0)`
+		//  Estimated: `3549 + n * (2615 ±0)`
+		// Minimum execution time: 41_512_000 picoseconds.
+		Weight::from_parts(22_081_000, 3549)
+			// Standard Error: 11_204
+			.saturating_add(Weight::from_parts(24_947_312, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2615).saturating_mul(n.into()))
+	}
 }
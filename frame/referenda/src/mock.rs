@@ -23,8 +23,8 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	assert_ok, ord_parameter_types, parameter_types,
 	traits::{
-		ConstU32, ConstU64, Contains, EqualPrivilegeOnly, OnInitialize, OriginTrait, Polling,
-		SortedMembers,
+		ConstBool, ConstU32, ConstU64, Contains, EqualPrivilegeOnly, OnInitialize, OriginTrait,
+		Polling, SortedMembers,
 	},
 	weights::Weight,
 };
@@ -106,6 +106,17 @@ impl pallet_scheduler::Config for Test {
 	type MaximumWeight = MaxWeight;
 	type ScheduleOrigin = EnsureRoot<u64>;
 	type MaxScheduledPerBlock = ConstU32<100>;
+	type MaxRootScheduledPerBlock = ConstU32<0>;
+	type MaxSchedules = ConstU32<10_000>;
+	type MaxSchedulesPerOrigin = ();
+	type AllowCurrentBlockScheduling = ConstBool<false>;
+	type MaxPeriodicCount = ConstU32<100>;
+	type DedupInlineCalls = ConstBool<false>;
+	type PeriodicJitter = ();
+	type MaxRecordedResults = ConstU32<0>;
+	type MaxScheduleDelay = ();
+	type MaxReappendSearch = ConstU32<0>;
+	type DispatchContext = ();
 	type WeightInfo = ();
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
@@ -0,0 +1,43 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2023 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the FRAME scheduler pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_scheduler::SchedulerWeights;
+use sp_weights::Weight;
+
+sp_api::decl_runtime_apis! {
+	pub trait SchedulerApi<BlockNumber, AccountId>
+		where
+			BlockNumber: Codec,
+			AccountId: Codec,
+	{
+		/// Returns the components of the scheduler's weight.
+		fn weight_components() -> SchedulerWeights;
+
+		/// Returns whether an anonymous task of `priority` with a call of `call_weight` would be
+		/// dispatched at `when` if it were scheduled now.
+		fn would_fit(when: BlockNumber, priority: u8, call_weight: Weight) -> bool;
+
+		/// Returns the account which scheduled the task at `(when, index)`, if the task was
+		/// scheduled by a signed origin.
+		fn task_signer(when: BlockNumber, index: u32) -> Option<AccountId>;
+	}
+}
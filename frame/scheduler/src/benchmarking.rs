@@ -76,7 +76,25 @@ fn make_task<T: Config>(
 		false => None,
 	};
 	let origin = make_origin::<T>(signed);
-	Scheduled { maybe_id, priority, call, maybe_periodic, origin, _phantom: PhantomData }
+	Scheduled {
+		maybe_id,
+		priority,
+		call,
+		maybe_periodic,
+		origin,
+		valid_until: None,
+		authorization: None,
+		_phantom: PhantomData,
+	}
+}
+
+/// A task referencing a call by hash, whose preimage is not available.
+fn make_unavailable_task<T: Config>(named: bool) -> ScheduledOf<T> {
+	let task = make_task::<T>(false, named, false, Some(BoundedInline::bound() as u32), 0);
+	if let Some(hash) = task.call.lookup_hash() {
+		T::Preimages::unnote(&hash);
+	}
+	task
 }
 
 fn bounded<T: Config>(len: u32) -> Option<Bounded<<T as Config>::RuntimeCall>> {
@@ -138,11 +156,11 @@ benchmarks! {
 		let now = BLOCK_NUMBER.into();
 		let s in 0 .. T::MaxScheduledPerBlock::get();
 		fill_schedule::<T>(now, s)?;
-		let mut executed = 0;
+		let mut summary = ServiceSummary::default();
 	}: {
-		Scheduler::<T>::service_agenda(&mut WeightMeter::max_limit(), &mut executed, now, now, 0);
+		Scheduler::<T>::service_agenda(&mut WeightMeter::max_limit(), &mut summary, now, now, 0);
 	} verify {
-		assert_eq!(executed, 0);
+		assert_eq!(summary.dispatched, 0);
 	}
 
 	// `service_task` when the task is a non-periodic, non-named, non-fetched call which is not
@@ -199,13 +217,37 @@ benchmarks! {
 	} verify {
 	}
 
+	// `service_task` when the task is a non-named call whose preimage is missing, and which is
+	// moved to the agenda of the next block.
+	service_task_postponed {
+		let now = BLOCK_NUMBER.into();
+		let task = make_unavailable_task::<T>(false);
+		let mut counter = WeightMeter::max_limit();
+	}: {
+		let result = Scheduler::<T>::service_task(&mut counter, now, now, 0, true, task);
+	} verify {
+		ensure!(Agenda::<T>::get(now + One::one()).len() == 1, "task not postponed");
+	}
+
+	// `service_task` when the task is a named call whose preimage is missing, and which is moved
+	// to the agenda of the next block along with its `Lookup` entry.
+	service_task_postponed_named {
+		let now = BLOCK_NUMBER.into();
+		let task = make_unavailable_task::<T>(true);
+		let mut counter = WeightMeter::max_limit();
+	}: {
+		let result = Scheduler::<T>::service_task(&mut counter, now, now, 0, true, task);
+	} verify {
+		ensure!(Lookup::<T>::get(u32_to_name(0)) == Some((now + One::one(), 0)), "task not postponed");
+	}
+
 	// `execute_dispatch` when the origin is `Signed`, not counting the dispatable's weight.
 	execute_dispatch_signed {
 		let mut counter = WeightMeter::max_limit();
 		let origin = make_origin::<T>(true);
 		let call = T::Preimages::realize(&make_call::<T>(None)).unwrap().0;
 	}: {
-		assert!(Scheduler::<T>::execute_dispatch(&mut counter, origin, call).is_ok());
+		assert!(Scheduler::<T>::execute_dispatch(&mut counter, origin, None, call).is_ok());
 	}
 	verify {
 	}
@@ -216,7 +258,7 @@ benchmarks! {
 		let origin = make_origin::<T>(false);
 		let call = T::Preimages::realize(&make_call::<T>(None)).unwrap().0;
 	}: {
-		assert!(Scheduler::<T>::execute_dispatch(&mut counter, origin, call).is_ok());
+		assert!(Scheduler::<T>::execute_dispatch(&mut counter, origin, None, call).is_ok());
 	}
 	verify {
 	}
@@ -281,6 +323,26 @@ benchmarks! {
 		);
 	}
 
+	schedule_named_with_fallback {
+		let s in 0 .. (T::MaxScheduledPerBlock::get() - 1);
+		let id = u32_to_name(s);
+		let when = BLOCK_NUMBER.into();
+		let periodic = Some((T::BlockNumber::one(), 100));
+		let priority = 0;
+		// Essentially no-op calls.
+		let call = Box::new(SystemCall::set_storage { items: vec![] }.into());
+		let on_failure = Box::new(SystemCall::set_storage { items: vec![] }.into());
+
+		fill_schedule::<T>(when, s)?;
+	}: _(RawOrigin::Root, id, when, periodic, priority, call, on_failure)
+	verify {
+		ensure!(
+			Agenda::<T>::get(when).len() == (s + 1) as usize,
+			"didn't add to schedule"
+		);
+		ensure!(Fallback::<T>::contains_key(id), "didn't store the fallback");
+	}
+
 	cancel_named {
 		let s in 1 .. T::MaxScheduledPerBlock::get();
 		let when = BLOCK_NUMBER.into();
@@ -303,5 +365,64 @@ benchmarks! {
 		);
 	}
 
+	set_paused {
+	}: _(RawOrigin::Root, true)
+	verify {
+		ensure!(Paused::<T>::get(), "didn't pause");
+	}
+
+	reschedule_all_at {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+		let new_when = when + One::one();
+
+		fill_schedule::<T>(when, s)?;
+	}: _(RawOrigin::Root, when, new_when)
+	verify {
+		ensure!(Agenda::<T>::get(when).is_empty(), "didn't empty the agenda");
+		ensure!(Agenda::<T>::get(new_when).len() == s as usize, "didn't move the tasks");
+		ensure!(Lookup::<T>::get(u32_to_name(0)) == Some((new_when, 0)), "didn't update lookup");
+	}
+
+	set_postponement {
+		let blocks: T::BlockNumber = 10u32.into();
+	}: _(RawOrigin::Root, Some(blocks))
+	verify {
+		ensure!(PostponementOverride::<T>::get() == Some(blocks), "didn't set the postponement");
+	}
+
+	// A sweep in which every entry of `Lookup` is stale, pointing at an empty agenda.
+	sweep_lookup {
+		let s in 0 .. T::MaxScheduledPerBlock::get();
+		let when: T::BlockNumber = BLOCK_NUMBER.into();
+		for i in 0..s {
+			Lookup::<T>::insert(u32_to_name(i), (when, i));
+		}
+	}: {
+		Scheduler::<T>::sweep_lookup(Weight::MAX);
+	} verify {
+		ensure!(Lookup::<T>::iter().next().is_none(), "didn't sweep the stale entries");
+	}
+
+	// A rebuild of `b` full agendas of named tasks, none of which has a `Lookup` entry.
+	rebuild_lookup {
+		let b in 0 .. 100;
+		let s = T::MaxScheduledPerBlock::get();
+		for block in 0..b {
+			let when: T::BlockNumber = (BLOCK_NUMBER + block).into();
+			let agenda = (0..s)
+				.map(|i| {
+					let mut task = make_task::<T>(false, true, false, None, 0);
+					task.maybe_id = Some(u32_to_name(block * s + i));
+					Some(task)
+				})
+				.collect::<Vec<_>>();
+			Agenda::<T>::insert(when, BoundedVec::truncate_from(agenda));
+		}
+	}: _(RawOrigin::Root, b)
+	verify {
+		ensure!(Lookup::<T>::iter().count() == (b * s) as usize, "didn't rebuild the lookup");
+	}
+
 	impl_benchmark_test_suite!(Scheduler, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -34,14 +34,31 @@
 //! use to call `fn schedule`.
 //!
 //! If a call is scheduled using proxy or whatever mecanism which adds filter,
-//! then those filter will not be used when dispatching the schedule call.
+//! then those filter will not be used when dispatching the schedule call, unless the runtime
+//! provides a [`DispatchContext`] which can restore them.
+//!
+//! ## Dispatch context
+//!
+//! When a task is scheduled through one of the dispatchables, `Config::DispatchContext` may
+//! capture a [`DispatchAuthorization`] from the origin, an opaque token describing the filter the
+//! origin carries (e.g. the proxy type it is dispatched under). The token is stored along with the
+//! task and handed back to the `DispatchContext` right before the call, or its fallback, is
+//! dispatched, to add the filter again to the dispatch origin.
+//!
+//! The token is only ever produced by the runtime, never supplied by the caller, and restoring it
+//! may only narrow what the origin is allowed to do: an implementation must add filters and
+//! leave the caller untouched. Should the token no longer be valid when the task is due (e.g. the
+//! proxy was removed in the meantime), restoring fails and the task is given up with the returned
+//! error rather than dispatched without its filter. Tasks scheduled by other pallets through the
+//! `schedule` traits carry no token.
 //!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
 //!
 //! * `schedule` - schedule a dispatch, which may be periodic, to occur at a specified block and
-//!   with a specified priority.
+//!   with a specified priority. Dispatches of equal priority occur in the order they were scheduled
+//!   in.
 //! * `cancel` - cancel a scheduled dispatch, specified by block number and index.
 //! * `schedule_named` - augments the `schedule` interface with an additional `Vec<u8>` parameter
 //!   that can be used for identification.
@@ -64,20 +81,22 @@ use frame_support::{
 	dispatch::{
 		DispatchError, DispatchResult, Dispatchable, GetDispatchInfo, Parameter, RawOrigin,
 	},
-	ensure,
+	ensure, storage,
 	traits::{
 		schedule::{self, DispatchTime, MaybeHashed},
-		Bounded, CallerTrait, EnsureOrigin, Get, Hash as PreimageHash, IsType, OriginTrait,
-		PalletInfoAccess, PrivilegeCmp, QueryPreimage, StorageVersion, StorePreimage,
+		Bounded, BoundedInline, CallerTrait, ConstU32, EnsureOrigin, Get, Hash as PreimageHash,
+		IsType, OriginTrait, PalletInfoAccess, PrivilegeCmp, QueryPreimage, StorageVersion,
+		StorePreimage,
 	},
 	weights::{Weight, WeightMeter},
+	WeakBoundedVec,
 };
 use frame_system::{self as system};
 use scale_info::TypeInfo;
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
-	traits::{BadOrigin, One, Saturating, Zero},
-	BoundedVec, RuntimeDebug,
+	traits::{BadOrigin, One, Saturating, UniqueSaturatedInto, Zero},
+	BoundedVec, RuntimeDebug, SaturatedConversion,
 };
 use sp_std::{borrow::Borrow, cmp::Ordering, marker::PhantomData, prelude::*};
 
@@ -88,6 +107,38 @@ pub use weights::WeightInfo;
 pub type PeriodicIndex = u32;
 /// The location of a scheduled task that can be used to remove it.
 pub type TaskAddress<BlockNumber> = (BlockNumber, u32);
+/// An opaque token from which a [`DispatchContext`] restores the filter of an origin.
+pub type DispatchAuthorization = BoundedVec<u8, ConstU32<64>>;
+/// A client-chosen label of an anonymous task, by which its origin can cancel it.
+pub type TaskTag = BoundedVec<u8, ConstU32<32>>;
+
+/// Keeps the filter of the origin scheduling a task until the task is dispatched.
+///
+/// See the [module docs](self#dispatch-context) for the security model.
+pub trait DispatchContext<RuntimeOrigin> {
+	/// The token describing the filter `origin` carries, if any must be kept.
+	fn capture(origin: &RuntimeOrigin) -> Option<DispatchAuthorization>;
+
+	/// Add the filter described by `authorization` to `origin`.
+	///
+	/// Must only add filters to `origin`. Fails if `authorization` is no longer valid, in which
+	/// case the call is not dispatched. This is not weighed, so it should be cheap.
+	fn restore(
+		origin: &mut RuntimeOrigin,
+		authorization: &DispatchAuthorization,
+	) -> Result<(), DispatchError>;
+}
+
+/// Keep no filter, scheduled calls are dispatched with the default filter of their origin.
+impl<RuntimeOrigin> DispatchContext<RuntimeOrigin> for () {
+	fn capture(_: &RuntimeOrigin) -> Option<DispatchAuthorization> {
+		None
+	}
+
+	fn restore(_: &mut RuntimeOrigin, _: &DispatchAuthorization) -> Result<(), DispatchError> {
+		Ok(())
+	}
+}
 
 pub type CallOrHashOf<T> =
 	MaybeHashed<<T as Config>::RuntimeCall, <T as frame_system::Config>::Hash>;
@@ -115,10 +166,97 @@ pub struct Scheduled<Name, Call, BlockNumber, PalletsOrigin, AccountId> {
 	maybe_periodic: Option<schedule::Period<BlockNumber>>,
 	/// The origin with which to dispatch the call.
 	origin: PalletsOrigin,
+	/// The last block at which the task may still be dispatched, if there is one. Past it, the
+	/// task is dropped without being dispatched.
+	valid_until: Option<BlockNumber>,
+	/// The token restoring the filter of the origin which scheduled the task, if there is one.
+	authorization: Option<DispatchAuthorization>,
 	_phantom: PhantomData<AccountId>,
 }
 
-use crate::{Scheduled as ScheduledV3, Scheduled as ScheduledV2};
+/// The layout of `Scheduled` in storage version 5, before tasks kept a dispatch authorization.
+#[cfg_attr(any(feature = "std", test), derive(PartialEq, Eq))]
+#[derive(Clone, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct ScheduledV5<Name, Call, BlockNumber, PalletsOrigin, AccountId> {
+	/// The unique identity for this task, if there is one.
+	maybe_id: Option<Name>,
+	/// This task's priority.
+	priority: schedule::Priority,
+	/// The call to be dispatched.
+	call: Call,
+	/// If the call is periodic, then this points to the information concerning that.
+	maybe_periodic: Option<schedule::Period<BlockNumber>>,
+	/// The origin with which to dispatch the call.
+	origin: PalletsOrigin,
+	/// The last block at which the task may still be dispatched, if there is one.
+	valid_until: Option<BlockNumber>,
+	_phantom: PhantomData<AccountId>,
+}
+
+/// The layout of `Scheduled` up to storage version 4, before tasks had a deadline.
+#[cfg_attr(any(feature = "std", test), derive(PartialEq, Eq))]
+#[derive(Clone, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct ScheduledV4<Name, Call, BlockNumber, PalletsOrigin, AccountId> {
+	/// The unique identity for this task, if there is one.
+	maybe_id: Option<Name>,
+	/// This task's priority.
+	priority: schedule::Priority,
+	/// The call to be dispatched.
+	call: Call,
+	/// If the call is periodic, then this points to the information concerning that.
+	maybe_periodic: Option<schedule::Period<BlockNumber>>,
+	/// The origin with which to dispatch the call.
+	origin: PalletsOrigin,
+	_phantom: PhantomData<AccountId>,
+}
+
+/// Why no dispatch time could be found for a task address.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum NextTimeError {
+	/// Nothing is scheduled at the block of the address.
+	UnknownBlock,
+	/// The index of the address is beyond the agenda of its block.
+	IndexOutOfRange,
+	/// The task at the address was cancelled, dispatched or moved.
+	Cancelled,
+}
+
+/// Public summary of a scheduled task, leaving out its call and origin.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct ScheduledInfo<BlockNumber> {
+	/// This task's priority.
+	pub priority: schedule::Priority,
+	/// If the task is periodic, then this points to the information concerning that.
+	pub maybe_periodic: Option<schedule::Period<BlockNumber>>,
+}
+
+/// The address of a named task along with its summary.
+pub type NamedTaskInfo<BlockNumber> = (TaskAddress<BlockNumber>, ScheduledInfo<BlockNumber>);
+
+/// The components of the scheduler's weight, for predicting how many tasks fit in a block.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub struct SchedulerWeights {
+	/// The weight `on_initialize` takes before servicing any agenda.
+	pub service_agendas_base: Weight,
+	/// The weight of servicing an empty agenda.
+	pub service_agenda_base: Weight,
+	/// The extra weight of servicing an agenda for each task in it.
+	pub service_agenda_per_item: Weight,
+	/// The weight of servicing an anonymous one-off task, leaving out its call.
+	pub service_task_base: Weight,
+	/// The extra weight of servicing a named task.
+	pub named_task_marginal: Weight,
+	/// The extra weight of servicing a periodic task.
+	pub periodic_task_marginal: Weight,
+	/// The weight of dispatching a task's call, on top of the call's own weight.
+	pub execute_dispatch: Weight,
+	/// The weight charged for `schedule`.
+	pub schedule: Weight,
+	/// The weight charged for `cancel`.
+	pub cancel: Weight,
+}
+
+use crate::{ScheduledV4 as ScheduledV3, ScheduledV4 as ScheduledV2};
 
 pub type ScheduledV2Of<T> = ScheduledV2<
 	Vec<u8>,
@@ -136,6 +274,22 @@ pub type ScheduledV3Of<T> = ScheduledV3<
 	<T as frame_system::Config>::AccountId,
 >;
 
+pub type ScheduledV4Of<T> = ScheduledV4<
+	TaskName,
+	Bounded<<T as Config>::RuntimeCall>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config>::PalletsOrigin,
+	<T as frame_system::Config>::AccountId,
+>;
+
+pub type ScheduledV5Of<T> = ScheduledV5<
+	TaskName,
+	Bounded<<T as Config>::RuntimeCall>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config>::PalletsOrigin,
+	<T as frame_system::Config>::AccountId,
+>;
+
 pub type ScheduledOf<T> = Scheduled<
 	TaskName,
 	Bounded<<T as Config>::RuntimeCall>,
@@ -144,21 +298,60 @@ pub type ScheduledOf<T> = Scheduled<
 	<T as frame_system::Config>::AccountId,
 >;
 
+/// A task stored with an `Origin` other than `PalletsOrigin`, e.g. before the latter changed.
+type ScheduledWithOrigin<T, Origin> = Scheduled<
+	TaskName,
+	Bounded<<T as Config>::RuntimeCall>,
+	<T as frame_system::Config>::BlockNumber,
+	Origin,
+	<T as frame_system::Config>::AccountId,
+>;
+
+/// The outcome of trying to place a task in an agenda; on failure the task is handed back.
+type PlacementResult<T, R> = Result<R, (DispatchError, Box<ScheduledOf<T>>)>;
+
+/// The maximum length of an agenda, including the room reserved for root.
+pub struct MaxAgendaLen<T>(PhantomData<T>);
+impl<T: Config> Get<u32> for MaxAgendaLen<T> {
+	fn get() -> u32 {
+		T::MaxScheduledPerBlock::get().saturating_add(T::MaxRootScheduledPerBlock::get())
+	}
+}
+
+/// Weights of servicing a task composed from the benchmarks of its individual features.
+///
+/// Each feature of a task is benchmarked on its own and contributes its weight on top of
+/// `service_task_base`, so that a new feature only needs one more benchmark and one more term
+/// rather than a benchmark for every combination.
 pub(crate) trait MarginalWeightInfo: WeightInfo {
 	fn service_task(maybe_lookup_len: Option<usize>, named: bool, periodic: bool) -> Weight {
-		let base = Self::service_task_base();
 		let mut total = match maybe_lookup_len {
-			None => base,
+			None => Self::service_task_base(),
 			Some(l) => Self::service_task_fetched(l as u32),
 		};
 		if named {
-			total.saturating_accrue(Self::service_task_named().saturating_sub(base));
+			total.saturating_accrue(Self::named_task_marginal());
 		}
 		if periodic {
-			total.saturating_accrue(Self::service_task_periodic().saturating_sub(base));
+			total.saturating_accrue(Self::periodic_task_marginal());
 		}
 		total
 	}
+	/// The extra weight of servicing a named task over an anonymous one.
+	fn named_task_marginal() -> Weight {
+		Self::service_task_named().saturating_sub(Self::service_task_base())
+	}
+	/// The extra weight of servicing a periodic task over a one-off one.
+	fn periodic_task_marginal() -> Weight {
+		Self::service_task_periodic().saturating_sub(Self::service_task_base())
+	}
+	/// The weight of a task whose preimage is missing, moved as it is to a later block.
+	fn item_postponed(named: bool) -> Weight {
+		match named {
+			true => Self::service_task_postponed_named(),
+			false => Self::service_task_postponed(),
+		}
+	}
 }
 impl<T: WeightInfo> MarginalWeightInfo for T {}
 
@@ -169,7 +362,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -217,12 +410,91 @@ pub mod pallet {
 
 		/// The maximum number of scheduled calls in the queue for a single block.
 		///
+		/// Must be non-zero and at most `MaxSchedules`.
+		///
 		/// NOTE:
 		/// + Dependent pallets' benchmarks might require a higher limit for the setting. Set a
 		/// higher limit under `runtime-benchmarks` feature.
 		#[pallet::constant]
 		type MaxScheduledPerBlock: Get<u32>;
 
+		/// The number of extra calls a single block's queue may hold beyond
+		/// `MaxScheduledPerBlock`, reserved for calls scheduled with the root origin.
+		#[pallet::constant]
+		type MaxRootScheduledPerBlock: Get<u32>;
+
+		/// The maximum number of scheduled calls across all the blocks.
+		///
+		/// Periodic tasks are always able to carry on, only new schedules are rejected once the
+		/// limit is reached. Should there be more tasks than the limit, e.g. since it was lowered,
+		/// the tasks being renewed or postponed are shed, lowest priority first.
+		#[pallet::constant]
+		type MaxSchedules: Get<u32>;
+
+		/// The maximum number of scheduled calls a single origin may have across all the blocks,
+		/// if any.
+		///
+		/// Like `MaxSchedules`, only new schedules are rejected once an origin reaches it. Must be
+		/// at most `MaxSchedules`.
+		#[pallet::constant]
+		type MaxSchedulesPerOrigin: Get<Option<u32>>;
+
+		/// Whether a task targeting the current block, or the one just before it, is accepted.
+		///
+		/// If `true`, such a task is scheduled for the next block, just like `After(0)`. Otherwise
+		/// scheduling it fails with `TargetBlockNumberInPast`, as does targeting any earlier block
+		/// either way.
+		#[pallet::constant]
+		type AllowCurrentBlockScheduling: Get<bool>;
+
+		/// The maximum number of repetitions a periodic task may be scheduled with.
+		#[pallet::constant]
+		type MaxPeriodicCount: Get<u32>;
+
+		/// Whether identical inline calls are stored once and shared between the tasks
+		/// scheduling them.
+		///
+		/// If `true`, an inline call is kept in `InlineCallDedup` and the task only references
+		/// it by hash, until the last task referencing it is dispatched or canceled.
+		#[pallet::constant]
+		type DedupInlineCalls: Get<bool>;
+
+		/// The bound on the delay added to the next occurrence of a periodic task, if any.
+		///
+		/// If set, a periodic task is renewed at `now + period + delay`, where the delay is below
+		/// the bound and derived from the parent block hash and the address of the task. This
+		/// spreads out the periodic tasks which would otherwise fall due in the same blocks.
+		#[pallet::constant]
+		type PeriodicJitter: Get<Option<Self::BlockNumber>>;
+
+		/// The maximum number of dispatch results of named tasks kept in `LastResults`.
+		///
+		/// Zero disables the recording. Once the limit is reached, recording the result of a new
+		/// name evicts the result recorded the longest ago.
+		#[pallet::constant]
+		type MaxRecordedResults: Get<u32>;
+
+		/// The maximum number of blocks ahead of the current one a task may be scheduled for, if
+		/// any.
+		///
+		/// Applies to new schedules and reschedules, whether given as `At` or `After`, but not to
+		/// the renewal of periodic tasks. Note that `After(x)` targets the block `now + x + 1`.
+		#[pallet::constant]
+		type MaxScheduleDelay: Get<Option<Self::BlockNumber>>;
+
+		/// The number of blocks following its target which are tried when a task postponed or
+		/// renewed by the scheduler finds the agenda of that block full.
+		///
+		/// The task goes to the first of them with a free slot and is only dropped if there is
+		/// none. Each block tried beyond the target costs an extra read.
+		#[pallet::constant]
+		type MaxReappendSearch: Get<u32>;
+
+		/// Keeps the filter of the origin scheduling a task until the task is dispatched.
+		///
+		/// Use `()` to dispatch every task with the default filter of its origin.
+		type DispatchContext: DispatchContext<<Self as Config>::RuntimeOrigin>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -239,10 +511,41 @@ pub mod pallet {
 		_,
 		Twox64Concat,
 		T::BlockNumber,
-		BoundedVec<Option<ScheduledOf<T>>, T::MaxScheduledPerBlock>,
+		BoundedVec<Option<ScheduledOf<T>>, MaxAgendaLen<T>>,
 		ValueQuery,
 	>;
 
+	/// The number of tasks currently scheduled, across all the agendas.
+	///
+	/// NOTE: Tasks scheduled before this counter was introduced are not accounted for.
+	#[pallet::storage]
+	#[pallet::getter(fn task_count)]
+	pub type TaskCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The number of tasks currently scheduled by each origin, keyed by the Blake2-256 hash of
+	/// the encoded origin.
+	///
+	/// NOTE: Tasks scheduled before this counter was introduced are not accounted for.
+	#[pallet::storage]
+	pub type PerOriginCount<T: Config> = StorageMap<_, Identity, [u8; 32], u32, ValueQuery>;
+
+	/// Whether the dispatch of scheduled tasks is halted.
+	#[pallet::storage]
+	#[pallet::getter(fn paused)]
+	pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// The number of blocks by which a task whose preimage is missing is postponed, if not the
+	/// default of a single block.
+	#[pallet::storage]
+	pub type PostponementOverride<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	/// Inline calls shared between tasks, along with the number of tasks referencing them.
+	///
+	/// Only used if `DedupInlineCalls` is enabled.
+	#[pallet::storage]
+	pub type InlineCallDedup<T: Config> =
+		StorageMap<_, Identity, PreimageHash, (BoundedInline, u32)>;
+
 	/// Lookup from a name to the block number and index of the task.
 	///
 	/// For v3 -> v4 the previously unbounded identities are Blake2-256 hashed to form the v4
@@ -251,6 +554,54 @@ pub mod pallet {
 	pub(crate) type Lookup<T: Config> =
 		StorageMap<_, Twox64Concat, TaskName, TaskAddress<T::BlockNumber>>;
 
+	/// The name of the last `Lookup` entry checked by `on_idle`, while a sweep of the stale
+	/// entries is ongoing.
+	#[pallet::storage]
+	pub(crate) type LookupSweepCursor<T: Config> = StorageValue<_, TaskName>;
+
+	/// The block of the last agenda scanned by `rebuild_lookup`, while a rebuild is ongoing.
+	#[pallet::storage]
+	pub(crate) type LookupRebuildCursor<T: Config> = StorageValue<_, T::BlockNumber>;
+
+	/// The address of the last anonymous task scheduled with a tag, along with the nonce of the
+	/// task, keyed by the Blake2-256 hash of its origin and tag.
+	///
+	/// An entry is removed once its task leaves that address, i.e. is dispatched, cancelled,
+	/// rescheduled or dropped.
+	#[pallet::storage]
+	pub(crate) type TagLookup<T: Config> =
+		StorageMap<_, Identity, [u8; 32], (TaskAddress<T::BlockNumber>, u64)>;
+
+	/// The `TagLookup` key and nonce of the tagged task at an address.
+	#[pallet::storage]
+	pub(crate) type TaskTags<T: Config> =
+		StorageMap<_, Twox64Concat, TaskAddress<T::BlockNumber>, ([u8; 32], u64)>;
+
+	/// The nonce given to the next tagged task, telling it apart from the tasks which took its
+	/// address before.
+	#[pallet::storage]
+	pub(crate) type TagNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The call dispatched with the origin of a named task right after the call of the task
+	/// failed, e.g. to compensate for it.
+	#[pallet::storage]
+	pub type Fallback<T: Config> =
+		StorageMap<_, Twox64Concat, TaskName, Bounded<<T as Config>::RuntimeCall>>;
+
+	/// The block at which the named task was last dispatched, along with the result.
+	///
+	/// Only recorded if `MaxRecordedResults` is non-zero. An entry is removed once the task of
+	/// that name is rescheduled, a new task takes the name, or it's the oldest of
+	/// `MaxRecordedResults` entries and another name is recorded.
+	#[pallet::storage]
+	pub type LastResults<T: Config> =
+		CountedStorageMap<_, Twox64Concat, TaskName, (T::BlockNumber, DispatchResult)>;
+
+	/// The names in `LastResults`, from the one recorded the longest ago to the latest one.
+	#[pallet::storage]
+	pub(crate) type ResultOrder<T: Config> =
+		StorageValue<_, WeakBoundedVec<TaskName, T::MaxRecordedResults>, ValueQuery>;
+
 	/// Events type.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -259,18 +610,57 @@ pub mod pallet {
 		Scheduled { when: T::BlockNumber, index: u32 },
 		/// Canceled some task.
 		Canceled { when: T::BlockNumber, index: u32 },
-		/// Dispatched some task.
+		/// Dispatched some task as `origin`, followed by its fallback if the task failed and has
+		/// one.
 		Dispatched {
 			task: TaskAddress<T::BlockNumber>,
 			id: Option<TaskName>,
+			origin: T::PalletsOrigin,
 			result: DispatchResult,
+			fallback_result: Option<DispatchResult>,
 		},
-		/// The call for the provided hash was not found so the task has been aborted.
+		/// The call for the provided hash was not found and the task couldn't be postponed to a
+		/// later block, or its preimage doesn't resolve to a call of the scheduled length, so it
+		/// has been aborted.
 		CallUnavailable { task: TaskAddress<T::BlockNumber>, id: Option<TaskName> },
-		/// The given task was unable to be renewed since the agenda is full at that block.
+		/// The given task was unable to be renewed since the agenda is full at that block and at
+		/// the `MaxReappendSearch` following ones.
 		PeriodicFailed { task: TaskAddress<T::BlockNumber>, id: Option<TaskName> },
+		/// The given periodic task was renewed to run next at `next`, with `remaining`
+		/// occurrences left including that one.
+		PeriodicRescheduled {
+			task: TaskAddress<T::BlockNumber>,
+			id: Option<TaskName>,
+			next: T::BlockNumber,
+			remaining: u32,
+		},
 		/// The given task can never be executed since it is overweight.
 		PermanentlyOverweight { task: TaskAddress<T::BlockNumber>, id: Option<TaskName> },
+		/// The given task was dropped without being dispatched since its deadline has passed.
+		Expired { task: TaskAddress<T::BlockNumber>, id: Option<TaskName> },
+		/// The given task was dropped without being dispatched since there are more than
+		/// `MaxSchedules` tasks.
+		Dropped { task: TaskAddress<T::BlockNumber>, id: Option<TaskName> },
+		/// The agendas due by `block` were serviced. Emitted on every block, with zero counts
+		/// while the scheduler is paused.
+		AgendaProcessed {
+			block: T::BlockNumber,
+			dispatched: u32,
+			postponed: u32,
+			dropped: u32,
+			weight_used: Weight,
+		},
+		/// The dispatch of scheduled tasks has been halted.
+		Paused,
+		/// The dispatch of scheduled tasks has been resumed.
+		Resumed,
+		/// Tasks whose preimage is missing are now postponed by `blocks`, or by a single block if
+		/// `None`.
+		PostponementSet { blocks: Option<T::BlockNumber> },
+		/// `agendas` agendas were scanned to rebuild `Lookup`, which is done if `complete`.
+		LookupRebuilt { agendas: u32, complete: bool },
+		/// The `Lookup` entry of `id` was removed since it didn't lead to a task of that name.
+		StaleLookupRemoved { id: TaskName },
 	}
 
 	#[pallet::error]
@@ -285,16 +675,68 @@ pub mod pallet {
 		RescheduleNoChange,
 		/// Attempt to use a non-named function on a named task.
 		Named,
+		/// There are already `MaxSchedules` tasks scheduled.
+		TooManySchedules,
+		/// The origin already has `MaxSchedulesPerOrigin` tasks scheduled.
+		TooManyOriginSchedules,
+		/// The deadline of the task is before the block it is scheduled for.
+		InvalidDeadline,
+		/// The periodic task repeats more than `MaxPeriodicCount` times.
+		PeriodTooLong,
+		/// The period of a periodic task is zero.
+		ZeroPeriod,
+		/// Tasks can't be postponed by zero blocks.
+		ZeroPostponement,
+		/// The target block is more than `MaxScheduleDelay` blocks ahead.
+		ScheduleTooFarOut,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		/// Execute the scheduled calls
 		fn on_initialize(now: T::BlockNumber) -> Weight {
+			if Self::paused() {
+				// Hold the agendas in place, they are serviced as overdue once resumed.
+				if IncompleteSince::<T>::get().is_none() {
+					IncompleteSince::<T>::put(now);
+				}
+				let weight = T::DbWeight::get().reads_writes(2, 1);
+				Self::deposit_event(Event::AgendaProcessed {
+					block: now,
+					dispatched: 0,
+					postponed: 0,
+					dropped: 0,
+					weight_used: weight,
+				});
+				return weight
+			}
 			let mut weight_counter = WeightMeter::from_limit(T::MaximumWeight::get());
-			Self::service_agendas(&mut weight_counter, now, u32::max_value());
+			let summary = Self::service_agendas(&mut weight_counter, now, u32::MAX);
+			Self::deposit_event(Event::AgendaProcessed {
+				block: now,
+				dispatched: summary.dispatched,
+				postponed: summary.postponed,
+				dropped: summary.dropped,
+				weight_used: weight_counter.consumed,
+			});
 			weight_counter.consumed
 		}
+
+		/// Remove the stale `Lookup` entries, as many as the remaining weight allows.
+		fn on_idle(_now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::sweep_lookup(remaining_weight)
+		}
+
+		fn integrity_test() {
+			sp_std::if_std! {
+				sp_io::TestExternalities::new_empty().execute_with(Self::check_limits);
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
@@ -311,12 +753,16 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::ScheduleOrigin::ensure_origin(origin.clone())?;
 			let origin = <T as Config>::RuntimeOrigin::from(origin);
-			Self::do_schedule(
+			Self::do_schedule_best_effort(
 				DispatchTime::At(when),
-				maybe_periodic,
-				priority,
-				origin.caller().clone(),
-				T::Preimages::bound(*call)?,
+				Self::anonymous_task(
+					maybe_periodic,
+					priority,
+					origin.caller().clone(),
+					T::Preimages::bound(*call)?,
+					T::DispatchContext::capture(&origin),
+				),
+				0,
 			)?;
 			Ok(())
 		}
@@ -332,6 +778,8 @@ pub mod pallet {
 		}
 
 		/// Schedule a named task.
+		///
+		/// The `id` is a fixed-size `TaskName`, so the weight does not depend on it.
 		#[pallet::call_index(2)]
 		#[pallet::weight(<T as Config>::WeightInfo::schedule_named(T::MaxScheduledPerBlock::get()))]
 		pub fn schedule_named(
@@ -344,13 +792,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::ScheduleOrigin::ensure_origin(origin.clone())?;
 			let origin = <T as Config>::RuntimeOrigin::from(origin);
-			Self::do_schedule_named(
+			Self::do_schedule_named_authorized(
 				id,
 				DispatchTime::At(when),
 				maybe_periodic,
 				priority,
 				origin.caller().clone(),
 				T::Preimages::bound(*call)?,
+				T::DispatchContext::capture(&origin),
 			)?;
 			Ok(())
 		}
@@ -377,12 +826,16 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::ScheduleOrigin::ensure_origin(origin.clone())?;
 			let origin = <T as Config>::RuntimeOrigin::from(origin);
-			Self::do_schedule(
+			Self::do_schedule_best_effort(
 				DispatchTime::After(after),
-				maybe_periodic,
-				priority,
-				origin.caller().clone(),
-				T::Preimages::bound(*call)?,
+				Self::anonymous_task(
+					maybe_periodic,
+					priority,
+					origin.caller().clone(),
+					T::Preimages::bound(*call)?,
+					T::DispatchContext::capture(&origin),
+				),
+				0,
 			)?;
 			Ok(())
 		}
@@ -400,16 +853,324 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::ScheduleOrigin::ensure_origin(origin.clone())?;
 			let origin = <T as Config>::RuntimeOrigin::from(origin);
-			Self::do_schedule_named(
+			Self::do_schedule_named_authorized(
 				id,
 				DispatchTime::After(after),
 				maybe_periodic,
 				priority,
 				origin.caller().clone(),
 				T::Preimages::bound(*call)?,
+				T::DispatchContext::capture(&origin),
+			)?;
+			Ok(())
+		}
+
+		/// Anonymously schedule a task, falling back to a later block if the agenda of `when` is
+		/// full.
+		///
+		/// Up to `max_search` blocks following `when` are tried, the task is placed in the first
+		/// one with a free slot. The `Scheduled` event holds the block actually chosen.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule(T::MaxScheduledPerBlock::get())
+			.saturating_mul((*max_search as u64).saturating_add(1)))]
+		pub fn schedule_best_effort(
+			origin: OriginFor<T>,
+			when: T::BlockNumber,
+			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+			max_search: u32,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_schedule_best_effort(
+				DispatchTime::At(when),
+				Self::anonymous_task(
+					maybe_periodic,
+					priority,
+					origin.caller().clone(),
+					T::Preimages::bound(*call)?,
+					T::DispatchContext::capture(&origin),
+				),
+				max_search,
+			)?;
+			Ok(())
+		}
+
+		/// Halt or resume the dispatch of all scheduled tasks.
+		///
+		/// While paused, the agendas are kept as they are and nothing is dispatched. Once
+		/// resumed, the tasks which became due in the meantime are serviced as overdue.
+		///
+		/// Emits `Paused` or `Resumed`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_paused())]
+		pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+			ensure_root(origin)?;
+			Paused::<T>::put(paused);
+			if paused {
+				Self::deposit_event(Event::Paused);
+			} else {
+				Self::deposit_event(Event::Resumed);
+			}
+			Ok(())
+		}
+
+		/// Anonymously schedule a task `delay` blocks after the block of the `anchor` task.
+		///
+		/// Fails with `NotFound` if the `anchor` is no longer scheduled.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule(T::MaxScheduledPerBlock::get())
+			.saturating_add(T::DbWeight::get().reads(1)))]
+		pub fn schedule_after_task(
+			origin: OriginFor<T>,
+			anchor: TaskAddress<T::BlockNumber>,
+			delay: T::BlockNumber,
+			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let anchor_time =
+				Self::next_dispatch_time_detailed(anchor).map_err(|_| Error::<T>::NotFound)?;
+			Self::do_schedule_best_effort(
+				DispatchTime::At(anchor_time.saturating_add(delay)),
+				Self::anonymous_task(
+					maybe_periodic,
+					priority,
+					origin.caller().clone(),
+					T::Preimages::bound(*call)?,
+					T::DispatchContext::capture(&origin),
+				),
+				0,
+			)?;
+			Ok(())
+		}
+
+		/// Anonymously schedule a task which is dropped if not dispatched by `valid_until`.
+		///
+		/// A task postponed for lack of weight is given up with an `Expired` event once the
+		/// deadline has passed, which bounds how long it may be deferred. The deadline applies
+		/// to every occurrence of a periodic task.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule(T::MaxScheduledPerBlock::get()))]
+		pub fn schedule_with_deadline(
+			origin: OriginFor<T>,
+			when: T::BlockNumber,
+			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
+			priority: schedule::Priority,
+			valid_until: T::BlockNumber,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_schedule_with_deadline(
+				DispatchTime::At(when),
+				maybe_periodic,
+				priority,
+				origin.caller().clone(),
+				T::Preimages::bound(*call)?,
+				valid_until,
+				T::DispatchContext::capture(&origin),
+			)?;
+			Ok(())
+		}
+
+		/// Schedule a named task along with `on_failure`, a call dispatched right after the call
+		/// of the task fails.
+		///
+		/// The fallback is dispatched with the origin of the task, each time its call returns an
+		/// error, and is dropped along with the task.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_named_with_fallback(
+			T::MaxScheduledPerBlock::get()
+		))]
+		pub fn schedule_named_with_fallback(
+			origin: OriginFor<T>,
+			id: TaskName,
+			when: T::BlockNumber,
+			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+			on_failure: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_schedule_named_authorized(
+				id,
+				DispatchTime::At(when),
+				maybe_periodic,
+				priority,
+				origin.caller().clone(),
+				T::Preimages::bound(*call)?,
+				T::DispatchContext::capture(&origin),
 			)?;
+			Self::note_fallback(id, T::Preimages::bound(*on_failure)?);
+			Ok(())
+		}
+
+		/// Set the number of blocks by which a task whose preimage is missing is postponed, or
+		/// restore the default of a single block with `None`.
+		///
+		/// Meant for incidents such as a lagging preimage provider, where retrying in the next
+		/// block only wastes weight.
+		///
+		/// Emits `PostponementSet`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_postponement())]
+		pub fn set_postponement(
+			origin: OriginFor<T>,
+			blocks: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(!matches!(blocks, Some(b) if b.is_zero()), Error::<T>::ZeroPostponement);
+			PostponementOverride::<T>::set(blocks);
+			Self::deposit_event(Event::PostponementSet { blocks });
 			Ok(())
 		}
+
+		/// Move all the tasks scheduled at block `when` to `new_when`, e.g. when a planned
+		/// maintenance slips.
+		///
+		/// Nothing is moved if the origin may not cancel one of the tasks, or if they don't all
+		/// fit in the agenda at `new_when`. Named tasks keep their name and periodic tasks their
+		/// period.
+		///
+		/// Emits `Canceled` and `Scheduled` for each moved task.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::reschedule_all_at(MaxAgendaLen::<T>::get()))]
+		pub fn reschedule_all_at(
+			origin: OriginFor<T>,
+			when: T::BlockNumber,
+			new_when: T::BlockNumber,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_reschedule_all_at(
+				Some(origin.caller().clone()),
+				when,
+				DispatchTime::At(new_when),
+			)?;
+			Ok(())
+		}
+
+		/// Rebuild the `Lookup` entries of the named tasks from the agendas, e.g. once
+		/// `try_state` found them out of sync.
+		///
+		/// Up to `max_blocks` agendas are scanned, resuming after the last agenda scanned by the
+		/// previous call, so a rebuild can be spread over several blocks. Each named task found
+		/// gets its `Lookup` entry pointed at the slot it is in. Entries leading to no task are
+		/// left to the sweep in `on_idle`.
+		///
+		/// Emits `LookupRebuilt`.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::rebuild_lookup(*max_blocks))]
+		pub fn rebuild_lookup(origin: OriginFor<T>, max_blocks: u32) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let scanned = Self::do_rebuild_lookup(max_blocks);
+			Ok(Some(<T as Config>::WeightInfo::rebuild_lookup(scanned)).into())
+		}
+
+		/// Anonymously schedule a task which its origin can later cancel by `tag`.
+		///
+		/// Tags aren't unique: scheduling with a tag the origin already used makes it refer to
+		/// the new task, leaving the previous one cancellable by its address only. The tag no
+		/// longer refers to the task once it's dispatched, cancelled or rescheduled.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule(T::MaxScheduledPerBlock::get())
+			.saturating_add(T::DbWeight::get().reads_writes(2, 4)))]
+		pub fn schedule_tagged(
+			origin: OriginFor<T>,
+			tag: TaskTag,
+			when: T::BlockNumber,
+			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let address = Self::do_schedule_best_effort(
+				DispatchTime::At(when),
+				Self::anonymous_task(
+					maybe_periodic,
+					priority,
+					origin.caller().clone(),
+					T::Preimages::bound(*call)?,
+					T::DispatchContext::capture(&origin),
+				),
+				0,
+			)?;
+			Self::tag_task(Self::tag_key(origin.caller(), &tag), address);
+			Ok(())
+		}
+
+		/// Cancel the anonymous task last scheduled by the origin with `tag`.
+		///
+		/// Fails with `NotFound` if that task was already dispatched or cancelled.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel(T::MaxScheduledPerBlock::get())
+			.saturating_add(T::DbWeight::get().reads_writes(2, 2)))]
+		pub fn cancel_tagged(origin: OriginFor<T>, tag: TaskTag) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_cancel_tagged(origin.caller().clone(), &tag)
+		}
+
+		/// Schedule a named task, replacing any task already scheduled under `id`.
+		///
+		/// Unlike `schedule_named`, a taken `id` doesn't fail: its task is cancelled first, as
+		/// by `cancel_named`, so the caller must be allowed to cancel it.
+		///
+		/// Emits `Canceled` for the replaced task, if any, then `Scheduled`.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_named(T::MaxScheduledPerBlock::get())
+			.saturating_add(<T as Config>::WeightInfo::cancel_named(T::MaxScheduledPerBlock::get())))]
+		pub fn schedule_named_or_replace(
+			origin: OriginFor<T>,
+			id: TaskName,
+			when: T::BlockNumber,
+			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_schedule_named_or_replace(
+				id,
+				DispatchTime::At(when),
+				maybe_periodic,
+				priority,
+				call,
+				&origin,
+			)
+		}
+
+		/// Schedule a named task after a delay, replacing any task already scheduled under `id`.
+		///
+		/// See `schedule_named_or_replace`.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_named(T::MaxScheduledPerBlock::get())
+			.saturating_add(<T as Config>::WeightInfo::cancel_named(T::MaxScheduledPerBlock::get())))]
+		pub fn schedule_named_after_or_replace(
+			origin: OriginFor<T>,
+			id: TaskName,
+			after: T::BlockNumber,
+			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_schedule_named_or_replace(
+				id,
+				DispatchTime::After(after),
+				maybe_periodic,
+				priority,
+				call,
+				&origin,
+			)
+		}
 	}
 }
 
@@ -433,7 +1194,7 @@ impl<T: Config<Hash = PreimageHash>> Pallet<T> {
 			}
 		}
 
-		Agenda::<T>::translate::<
+		migration::v4::Agenda::<T>::translate::<
 			Vec<Option<ScheduledV1<<T as Config>::RuntimeCall, T::BlockNumber>>>,
 			_,
 		>(|_, agenda| {
@@ -458,7 +1219,7 @@ impl<T: Config<Hash = PreimageHash>> Pallet<T> {
 								weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 1));
 							}
 
-							Some(Scheduled {
+							Some(ScheduledV4 {
 								maybe_id: schedule.maybe_id.map(|x| blake2_256(&x[..])),
 								priority: schedule.priority,
 								call,
@@ -503,7 +1264,7 @@ impl<T: Config<Hash = PreimageHash>> Pallet<T> {
 			}
 		}
 
-		Agenda::<T>::translate::<Vec<Option<ScheduledV2Of<T>>>, _>(|_, agenda| {
+		migration::v4::Agenda::<T>::translate::<Vec<Option<ScheduledV2Of<T>>>, _>(|_, agenda| {
 			Some(BoundedVec::truncate_from(
 				agenda
 					.into_iter()
@@ -523,7 +1284,7 @@ impl<T: Config<Hash = PreimageHash>> Pallet<T> {
 								weight.saturating_accrue(T::DbWeight::get().reads_writes(0, 1));
 							}
 
-							Some(Scheduled {
+							Some(ScheduledV4 {
 								maybe_id: schedule.maybe_id.map(|x| blake2_256(&x[..])),
 								priority: schedule.priority,
 								call,
@@ -569,83 +1330,88 @@ impl<T: Config<Hash = PreimageHash>> Pallet<T> {
 			}
 		}
 
-		Agenda::<T>::translate::<Vec<Option<ScheduledV3Of<T>>>, _>(|block, agenda| {
-			log::info!("Migrating agenda of block: {:?}", &block);
-			Some(BoundedVec::truncate_from(
-				agenda
-					.into_iter()
-					.map(|schedule| {
-						weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
-						schedule
-							.and_then(|schedule| {
-								if let Some(id) = schedule.maybe_id.as_ref() {
-									let name = blake2_256(id);
-									if let Some(item) = old::Lookup::<T>::take(id) {
-										Lookup::<T>::insert(name, item);
-										log::info!("Migrated name for id: {:?}", id);
+		migration::v4::Agenda::<T>::translate::<Vec<Option<ScheduledV3Of<T>>>, _>(
+			|block, agenda| {
+				log::info!("Migrating agenda of block: {:?}", &block);
+				Some(BoundedVec::truncate_from(
+					agenda
+						.into_iter()
+						.map(|schedule| {
+							weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+							schedule
+								.and_then(|schedule| {
+									if let Some(id) = schedule.maybe_id.as_ref() {
+										let name = blake2_256(id);
+										if let Some(item) = old::Lookup::<T>::take(id) {
+											Lookup::<T>::insert(name, item);
+											log::info!("Migrated name for id: {:?}", id);
+										} else {
+											log::error!("No name in Lookup for id: {:?}", &id);
+										}
+										weight.saturating_accrue(
+											T::DbWeight::get().reads_writes(2, 2),
+										);
 									} else {
-										log::error!("No name in Lookup for id: {:?}", &id);
+										log::info!("Schedule is unnamed");
 									}
-									weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
-								} else {
-									log::info!("Schedule is unnamed");
-								}
 
-								let call = match schedule.call {
-									MaybeHashed::Hash(h) => {
-										let bounded = Bounded::from_legacy_hash(h);
-										// Check that the call can be decoded in the new runtime.
-										if let Err(err) = T::Preimages::peek::<
-											<T as Config>::RuntimeCall,
-										>(&bounded)
-										{
-											log::error!(
-												"Dropping undecodable call {}: {:?}",
-												&h,
-												&err
+									let call = match schedule.call {
+										MaybeHashed::Hash(h) => {
+											let bounded = Bounded::from_legacy_hash(h);
+											// Check that the call can be decoded in the new
+											// runtime.
+											if let Err(err) = T::Preimages::peek::<
+												<T as Config>::RuntimeCall,
+											>(&bounded)
+											{
+												log::error!(
+													"Dropping undecodable call {}: {:?}",
+													&h,
+													&err
+												);
+												return None
+											}
+											weight.saturating_accrue(T::DbWeight::get().reads(1));
+											log::info!("Migrated call by hash, hash: {:?}", h);
+											bounded
+										},
+										MaybeHashed::Value(v) => {
+											let call = T::Preimages::bound(v)
+												.map_err(|e| {
+													log::error!("Could not bound Call: {:?}", e)
+												})
+												.ok()?;
+											if call.lookup_needed() {
+												weight.saturating_accrue(
+													T::DbWeight::get().reads_writes(0, 1),
+												);
+											}
+											log::info!(
+												"Migrated call by value, hash: {:?}",
+												call.hash()
 											);
-											return None
-										}
-										weight.saturating_accrue(T::DbWeight::get().reads(1));
-										log::info!("Migrated call by hash, hash: {:?}", h);
-										bounded
-									},
-									MaybeHashed::Value(v) => {
-										let call = T::Preimages::bound(v)
-											.map_err(|e| {
-												log::error!("Could not bound Call: {:?}", e)
-											})
-											.ok()?;
-										if call.lookup_needed() {
-											weight.saturating_accrue(
-												T::DbWeight::get().reads_writes(0, 1),
-											);
-										}
-										log::info!(
-											"Migrated call by value, hash: {:?}",
-											call.hash()
-										);
-										call
-									},
-								};
-
-								Some(Scheduled {
-									maybe_id: schedule.maybe_id.map(|x| blake2_256(&x[..])),
-									priority: schedule.priority,
-									call,
-									maybe_periodic: schedule.maybe_periodic,
-									origin: schedule.origin,
-									_phantom: Default::default(),
+											call
+										},
+									};
+
+									Some(ScheduledV4 {
+										maybe_id: schedule.maybe_id.map(|x| blake2_256(&x[..])),
+										priority: schedule.priority,
+										call,
+										maybe_periodic: schedule.maybe_periodic,
+										origin: schedule.origin,
+										_phantom: Default::default(),
+									})
 								})
-							})
-							.or_else(|| {
-								log::info!("Schedule in agenda for block {:?} is empty - nothing to do here.", &block);
-								None
-							})
-					})
-					.collect::<Vec<_>>(),
-			))
-		});
+								.or_else(|| {
+									log::info!("Schedule in agenda for block {:?} is empty - nothing to do here.", &block);
+									None
+								})
+						})
+						.collect::<Vec<_>>(),
+				))
+			},
+		);
 
 		#[allow(deprecated)]
 		frame_support::storage::migration::remove_storage_prefix(
@@ -661,61 +1427,446 @@ impl<T: Config<Hash = PreimageHash>> Pallet<T> {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Get the address and a summary of the named task `id`, if it's still scheduled.
+	///
+	/// Returns `None` if `id` is not a valid `TaskName` or no such task exists.
+	pub fn task_by_name(id: &[u8]) -> Option<NamedTaskInfo<T::BlockNumber>> {
+		let name = TaskName::try_from(id).ok()?;
+		let (when, index) = Lookup::<T>::get(name)?;
+		Some(((when, index), Self::agenda_slot(when, index)?))
+	}
+
+	/// Get the number of slots of the agenda at `when`, including those left empty by tasks
+	/// which were cancelled, dispatched or moved.
+	///
+	/// Together with `agenda_slot`, this allows to go through an agenda without depending on
+	/// the layout of `Agenda`, which may change between versions.
+	pub fn agenda_len(when: T::BlockNumber) -> u32 {
+		Agenda::<T>::decode_len(when).unwrap_or(0) as u32
+	}
+
+	/// Get a summary of the task at `index` of the agenda at `when`, if the slot isn't empty.
+	pub fn agenda_slot(when: T::BlockNumber, index: u32) -> Option<ScheduledInfo<T::BlockNumber>> {
+		let agenda = Agenda::<T>::get(when);
+		let task = agenda.get(index as usize)?.as_ref()?;
+		Some(ScheduledInfo { priority: task.priority, maybe_periodic: task.maybe_periodic })
+	}
+
+	/// Get the account which scheduled the task at `address`, if the task exists and was
+	/// scheduled by a signed origin.
+	///
+	/// `None` is returned for tasks scheduled by any other origin, such as root.
+	pub fn task_signer((when, index): TaskAddress<T::BlockNumber>) -> Option<T::AccountId> {
+		let agenda = Agenda::<T>::get(when);
+		let task = agenda.get(index as usize)?.as_ref()?;
+		match task.origin.as_system_ref() {
+			Some(RawOrigin::Signed(who)) => Some(who.clone()),
+			_ => None,
+		}
+	}
+
+	/// Get the block at which the task at `address` is going to be dispatched, telling why if
+	/// there is no such task.
+	pub fn next_dispatch_time_detailed(
+		(when, index): TaskAddress<T::BlockNumber>,
+	) -> Result<T::BlockNumber, NextTimeError> {
+		if !Agenda::<T>::contains_key(when) {
+			return Err(NextTimeError::UnknownBlock)
+		}
+		match Agenda::<T>::get(when).get(index as usize) {
+			Some(Some(_)) => Ok(when),
+			Some(None) => Err(NextTimeError::Cancelled),
+			None => Err(NextTimeError::IndexOutOfRange),
+		}
+	}
+
+	/// Get the components of the scheduler's weight, as given by `T::WeightInfo`.
+	///
+	/// The dispatch weight is the larger of the signed and unsigned one, and `schedule` and
+	/// `cancel` are charged for a full agenda.
+	pub fn weight_components() -> SchedulerWeights {
+		let service_agenda_base = T::WeightInfo::service_agenda_base(0);
+		let max = T::MaxScheduledPerBlock::get();
+		SchedulerWeights {
+			service_agendas_base: T::WeightInfo::service_agendas_base(),
+			service_agenda_base,
+			service_agenda_per_item: T::WeightInfo::service_agenda_base(1)
+				.saturating_sub(service_agenda_base),
+			service_task_base: T::WeightInfo::service_task_base(),
+			named_task_marginal: T::WeightInfo::named_task_marginal(),
+			periodic_task_marginal: T::WeightInfo::periodic_task_marginal(),
+			execute_dispatch: T::WeightInfo::execute_dispatch_signed()
+				.max(T::WeightInfo::execute_dispatch_unsigned()),
+			schedule: T::WeightInfo::schedule(max),
+			cancel: T::WeightInfo::cancel(max),
+		}
+	}
+
+	/// Whether an anonymous task of `priority` with a call of `call_weight`, if scheduled now,
+	/// would be dispatched when the agenda at `when` is serviced rather than postponed.
+	///
+	/// This walks the tasks serviced before it with the same weight accounting as
+	/// `on_initialize`, without changing any state. Only the agenda at `when` is assumed to be
+	/// serviced in that block, so overdue agendas and tasks scheduled in between aren't taken
+	/// into account. The dispatch of the call is counted at the larger of its signed and unsigned
+	/// weight, and whether the agenda has room for the task isn't checked.
+	pub fn would_fit(
+		when: T::BlockNumber,
+		priority: schedule::Priority,
+		call_weight: Weight,
+	) -> bool {
+		let agenda = Agenda::<T>::get(when);
+		let max_len = T::MaxScheduledPerBlock::get() as usize;
+		let index = if agenda.len() < max_len {
+			agenda.len()
+		} else {
+			agenda.iter().take(max_len).position(Option::is_none).unwrap_or(agenda.len())
+		} as u32;
+		let mut ahead = agenda
+			.iter()
+			.enumerate()
+			.filter_map(|(i, task)| task.as_ref().map(|task| ((task.priority, i as u32), task)))
+			.filter(|(key, _)| *key < (priority, index))
+			.collect::<Vec<_>>();
+		ahead.sort_by_key(|(key, _)| *key);
+		let tasks = agenda.iter().flatten().count() as u32 + 1;
+
+		let mut weight = WeightMeter::from_limit(T::MaximumWeight::get());
+		if !weight.check_accrue(T::WeightInfo::service_agendas_base()) ||
+			!weight.can_accrue(T::WeightInfo::service_agenda_base(MaxAgendaLen::<T>::get())) ||
+			!weight.check_accrue(T::WeightInfo::service_agenda_base(tasks))
+		{
+			return false
+		}
+		for (_, task) in ahead {
+			let Ok((call, lookup_len)) = Self::peek_call(&task.call) else {
+				// The task is postponed or dropped without being dispatched.
+				weight.check_accrue(T::WeightInfo::item_postponed(task.maybe_id.is_some()));
+				continue
+			};
+			let base_weight = T::WeightInfo::service_task(
+				lookup_len.map(|x| x as usize),
+				task.maybe_id.is_some(),
+				task.maybe_periodic.is_some(),
+			);
+			if !weight.check_accrue(base_weight) {
+				// Servicing stops at the first task which doesn't fit.
+				return false
+			}
+			let dispatch_weight = match task.origin.as_system_ref() {
+				Some(&RawOrigin::Signed(_)) => T::WeightInfo::execute_dispatch_signed(),
+				_ => T::WeightInfo::execute_dispatch_unsigned(),
+			};
+			// An overweight task is skipped, leaving its weight to the following ones.
+			weight.check_accrue(dispatch_weight.saturating_add(call.get_dispatch_info().weight));
+		}
+
+		let dispatch_weight = T::WeightInfo::execute_dispatch_signed()
+			.max(T::WeightInfo::execute_dispatch_unsigned());
+		weight.check_accrue(T::WeightInfo::service_task_base()) &&
+			weight.can_accrue(dispatch_weight.saturating_add(call_weight))
+	}
+
+	/// Get the number of blocks by which a task whose preimage is missing is postponed.
+	pub fn postponement() -> T::BlockNumber {
+		PostponementOverride::<T>::get().unwrap_or_else(One::one)
+	}
+
+	/// Get the block at which the named task `id` was last dispatched and the result, if it was
+	/// recorded.
+	pub fn last_result(id: TaskName) -> Option<(T::BlockNumber, DispatchResult)> {
+		LastResults::<T>::get(id)
+	}
+
+	/// Get the number of tasks currently scheduled by `origin`.
+	pub fn origin_task_count(origin: &T::PalletsOrigin) -> u32 {
+		PerOriginCount::<T>::get(origin.using_encoded(blake2_256))
+	}
+
 	/// Helper to migrate scheduler when the pallet origin type has changed.
 	pub fn migrate_origin<OldOrigin: Into<T::PalletsOrigin> + codec::Decode>() {
-		Agenda::<T>::translate::<
-			Vec<
-				Option<
-					Scheduled<
-						TaskName,
-						Bounded<<T as Config>::RuntimeCall>,
-						T::BlockNumber,
-						OldOrigin,
-						T::AccountId,
-					>,
-				>,
-			>,
-			_,
-		>(|_, agenda| {
-			Some(BoundedVec::truncate_from(
-				agenda
-					.into_iter()
-					.map(|schedule| {
-						schedule.map(|schedule| Scheduled {
-							maybe_id: schedule.maybe_id,
-							priority: schedule.priority,
-							call: schedule.call,
-							maybe_periodic: schedule.maybe_periodic,
-							origin: schedule.origin.into(),
-							_phantom: Default::default(),
-						})
+		Agenda::<T>::translate::<Vec<Option<ScheduledWithOrigin<T, OldOrigin>>>, _>(|_, agenda| {
+			Some(Self::migrate_agenda_origin(agenda))
+		});
+	}
+
+	/// Like `migrate_origin`, but migrate no more than `max_blocks` agendas, resuming after the
+	/// last agenda migrated by the previous call.
+	///
+	/// Returns the weight consumed and whether all the agendas are migrated. Tasks should not
+	/// be scheduled before then, since an agenda not yet visited is expected to hold the old
+	/// origin type.
+	pub fn migrate_origin_step<OldOrigin: Into<T::PalletsOrigin> + codec::Decode>(
+		max_blocks: u32,
+	) -> (Weight, bool) {
+		let mut weight = T::DbWeight::get().reads(1);
+		let mut blocks = match migration::MigrateOriginCursor::<T>::get() {
+			Some(last) => Agenda::<T>::iter_keys_from(Agenda::<T>::hashed_key_for(last)),
+			None => Agenda::<T>::iter_keys(),
+		};
+		let batch = blocks.by_ref().take(max_blocks as usize).collect::<Vec<_>>();
+		let done = blocks.next().is_none();
+		weight.saturating_accrue(T::DbWeight::get().reads(batch.len() as u64 + 1));
+
+		for block in &batch {
+			let key = Agenda::<T>::hashed_key_for(block);
+			match storage::unhashed::get::<Vec<Option<ScheduledWithOrigin<T, OldOrigin>>>>(&key) {
+				Some(agenda) => Agenda::<T>::insert(block, Self::migrate_agenda_origin(agenda)),
+				// Just like `translate`, drop what can't be decoded.
+				None => storage::unhashed::kill(&key),
+			}
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+		}
+
+		match batch.last() {
+			Some(last) if !done => migration::MigrateOriginCursor::<T>::put(last),
+			_ => migration::MigrateOriginCursor::<T>::kill(),
+		}
+		weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+		(weight, done)
+	}
+
+	/// Remove the `Lookup` entries which don't lead to a task of their name, along with their
+	/// fallback, resuming after the last entry checked by the previous call.
+	///
+	/// As many entries are checked as `limit` allows, and nothing is emitted. Once all of them
+	/// are checked, the next call starts over. Nothing is done unless `limit` allows checking at
+	/// least one entry. Returns the weight consumed.
+	pub(crate) fn sweep_lookup(limit: Weight) -> Weight {
+		let mut meter = WeightMeter::from_limit(limit);
+		let base = T::WeightInfo::sweep_lookup(0);
+		let per_entry = T::WeightInfo::sweep_lookup(1).saturating_sub(base);
+		if !meter.can_accrue(base.saturating_add(per_entry)) || !meter.check_accrue(base) {
+			return Weight::zero()
+		}
+
+		let mut entries = match LookupSweepCursor::<T>::get() {
+			Some(last) => Lookup::<T>::iter_from(Lookup::<T>::hashed_key_for(last)),
+			None => Lookup::<T>::iter(),
+		}
+		.peekable();
+		let mut batch = Vec::new();
+		while entries.peek().is_some() && meter.check_accrue(per_entry) {
+			batch.extend(entries.next());
+		}
+		let done = entries.peek().is_none();
+
+		for (name, (when, index)) in &batch {
+			let agenda = Agenda::<T>::get(when);
+			let slot = agenda.get(*index as usize).and_then(Option::as_ref);
+			if !matches!(slot, Some(task) if task.maybe_id == Some(*name)) {
+				Lookup::<T>::remove(name);
+				Self::drop_fallback(name);
+			}
+		}
+
+		match batch.last() {
+			Some((last, _)) if !done => LookupSweepCursor::<T>::put(last),
+			// Nothing was checked, keep the progress made so far.
+			None if !done => {},
+			_ => LookupSweepCursor::<T>::kill(),
+		}
+		meter.consumed
+	}
+
+	/// Point the `Lookup` entries of the named tasks of up to `max_blocks` agendas at their
+	/// tasks, resuming after the last agenda scanned by the previous call.
+	///
+	/// Returns the number of agendas scanned.
+	pub(crate) fn do_rebuild_lookup(max_blocks: u32) -> u32 {
+		let mut agendas = match LookupRebuildCursor::<T>::get() {
+			Some(last) => Agenda::<T>::iter_from(Agenda::<T>::hashed_key_for(last)),
+			None => Agenda::<T>::iter(),
+		}
+		.peekable();
+
+		let mut scanned = 0;
+		let mut last = None;
+		for (when, agenda) in agendas.by_ref().take(max_blocks as usize) {
+			for (index, task) in agenda.iter().enumerate() {
+				if let Some(name) = task.as_ref().and_then(|task| task.maybe_id) {
+					Lookup::<T>::insert(name, (when, index as u32));
+				}
+			}
+			scanned.saturating_inc();
+			last = Some(when);
+		}
+
+		let complete = agendas.peek().is_none();
+		if complete {
+			LookupRebuildCursor::<T>::kill();
+		} else if let Some(last) = last {
+			LookupRebuildCursor::<T>::put(last);
+		}
+		Self::deposit_event(Event::LookupRebuilt { agendas: scanned, complete });
+		scanned
+	}
+
+	fn migrate_agenda_origin<OldOrigin: Into<T::PalletsOrigin>>(
+		agenda: Vec<Option<ScheduledWithOrigin<T, OldOrigin>>>,
+	) -> BoundedVec<Option<ScheduledOf<T>>, MaxAgendaLen<T>> {
+		BoundedVec::truncate_from(
+			agenda
+				.into_iter()
+				.map(|schedule| {
+					schedule.map(|schedule| Scheduled {
+						maybe_id: schedule.maybe_id,
+						priority: schedule.priority,
+						call: schedule.call,
+						maybe_periodic: schedule.maybe_periodic,
+						origin: schedule.origin.into(),
+						valid_until: schedule.valid_until,
+						authorization: schedule.authorization,
+						_phantom: Default::default(),
 					})
-					.collect::<Vec<_>>(),
-			))
+				})
+				.collect::<Vec<_>>(),
+		)
+	}
+
+	fn resolve_time(when: DispatchTime<T::BlockNumber>) -> Result<T::BlockNumber, DispatchError> {
+		let now = frame_system::Pallet::<T>::block_number();
+
+		let when = match when {
+			DispatchTime::At(x) => x,
+			// The current block has already completed it's scheduled tasks, so
+			// Schedule the task at lest one block after this current block.
+			DispatchTime::After(x) => now.saturating_add(x).saturating_add(One::one()),
+		};
+		if let Some(max_delay) = T::MaxScheduleDelay::get() {
+			ensure!(when <= now.saturating_add(max_delay), Error::<T>::ScheduleTooFarOut);
+		}
+
+		if when <= now {
+			// Only a client lagging a block behind is catered for.
+			let lagging = when.saturating_add(One::one()) >= now;
+			if !(T::AllowCurrentBlockScheduling::get() && lagging) {
+				return Err(Error::<T>::TargetBlockNumberInPast.into())
+			}
+			// The current block's agenda may already be serviced, so defer to the next block.
+			return Ok(now.saturating_add(One::one()))
+		}
+
+		Ok(when)
+	}
+
+	/// The delay added to the next occurrence of the periodic task at `(when, index)`, below
+	/// `PeriodicJitter`.
+	fn periodic_jitter(when: T::BlockNumber, index: u32) -> T::BlockNumber {
+		let bound: u64 = match T::PeriodicJitter::get() {
+			Some(bound) if !bound.is_zero() => bound.unique_saturated_into(),
+			_ => return Zero::zero(),
+		};
+		let seed =
+			(frame_system::Pallet::<T>::parent_hash(), when, index).using_encoded(blake2_256);
+		let mut random = [0u8; 8];
+		random.copy_from_slice(&seed[..8]);
+		(u64::from_le_bytes(random) % bound).saturated_into()
+	}
+
+	/// Check `maybe_periodic` against the limits and remove one from the number of repetitions
+	/// since one is scheduled now. A task that runs only once is not periodic.
+	fn sanitize_periodic(
+		maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
+	) -> Result<Option<schedule::Period<T::BlockNumber>>, DispatchError> {
+		if let Some((period, count)) = maybe_periodic {
+			ensure!(!period.is_zero(), Error::<T>::ZeroPeriod);
+			ensure!(count <= T::MaxPeriodicCount::get(), Error::<T>::PeriodTooLong);
+		}
+		Ok(maybe_periodic.filter(|p| p.1 > 1).map(|(p, c)| (p, c - 1)))
+	}
+
+	/// Ensure that both `MaxSchedules` and `MaxSchedulesPerOrigin` leave room for another task
+	/// of `origin`.
+	fn ensure_can_schedule(origin: &T::PalletsOrigin) -> DispatchResult {
+		ensure!(Self::task_count() < T::MaxSchedules::get(), Error::<T>::TooManySchedules);
+		if let Some(max) = T::MaxSchedulesPerOrigin::get() {
+			ensure!(Self::origin_task_count(origin) < max, Error::<T>::TooManyOriginSchedules);
+		}
+		Ok(())
+	}
+
+	/// Account for a new task of `origin` in `TaskCount` and `PerOriginCount`.
+	fn note_task_added(origin: &T::PalletsOrigin) {
+		TaskCount::<T>::mutate(|count| count.saturating_inc());
+		PerOriginCount::<T>::mutate(origin.using_encoded(blake2_256), |count| {
+			count.saturating_inc()
 		});
 	}
 
-	fn resolve_time(when: DispatchTime<T::BlockNumber>) -> Result<T::BlockNumber, DispatchError> {
-		let now = frame_system::Pallet::<T>::block_number();
+	/// Account for the removal of a task of `origin` from `TaskCount` and `PerOriginCount`.
+	fn note_task_removed(origin: &T::PalletsOrigin) {
+		TaskCount::<T>::mutate(|count| count.saturating_dec());
+		PerOriginCount::<T>::mutate_exists(origin.using_encoded(blake2_256), |maybe_count| {
+			*maybe_count = maybe_count.map(|count| count.saturating_sub(1)).filter(|c| *c > 0);
+		});
+	}
 
-		let when = match when {
-			DispatchTime::At(x) => x,
-			// The current block has already completed it's scheduled tasks, so
-			// Schedule the task at lest one block after this current block.
-			DispatchTime::After(x) => now.saturating_add(x).saturating_add(One::one()),
-		};
+	/// Store an inline `call` once in `InlineCallDedup` and reference it by hash instead, if
+	/// `DedupInlineCalls` is enabled.
+	///
+	/// The hash is domain-separated so that it never clashes with the hash of a preimage.
+	fn share_inline_call(
+		call: Bounded<<T as Config>::RuntimeCall>,
+	) -> Bounded<<T as Config>::RuntimeCall> {
+		match call {
+			Bounded::Inline(data) if T::DedupInlineCalls::get() => {
+				let hash: PreimageHash =
+					(b"scheduler/inline", &data).using_encoded(blake2_256).into();
+				let len = data.len() as u32;
+				InlineCallDedup::<T>::mutate(hash, |maybe_shared| match maybe_shared {
+					Some((_, refs)) => refs.saturating_inc(),
+					None => *maybe_shared = Some((data, 1)),
+				});
+				Bounded::Lookup { hash, len }
+			},
+			call => call,
+		}
+	}
 
-		if when <= now {
-			return Err(Error::<T>::TargetBlockNumberInPast.into())
+	/// Release a reference to a call shared through `InlineCallDedup`, removing it once unused.
+	///
+	/// Returns whether `call` was a shared call.
+	fn unshare_call(call: &Bounded<<T as Config>::RuntimeCall>) -> bool {
+		let Bounded::Lookup { hash, .. } = call else { return false };
+		InlineCallDedup::<T>::mutate_exists(hash, |maybe_shared| match maybe_shared {
+			Some((_, refs)) => {
+				refs.saturating_dec();
+				if refs.is_zero() {
+					*maybe_shared = None;
+				}
+				true
+			},
+			None => false,
+		})
+	}
+
+	/// Release the `call` of a task which is not going to be dispatched anymore.
+	fn drop_call(call: &Bounded<<T as Config>::RuntimeCall>) {
+		if !Self::unshare_call(call) {
+			T::Preimages::drop(call);
 		}
+	}
 
-		Ok(when)
+	/// Get the `call` of a task along with the length of its looked up data, if any.
+	fn peek_call(
+		call: &Bounded<<T as Config>::RuntimeCall>,
+	) -> Result<(<T as Config>::RuntimeCall, Option<u32>), DispatchError> {
+		if let Bounded::Lookup { hash, len } = call {
+			if let Some((data, _)) = InlineCallDedup::<T>::get(hash) {
+				let call = <T as Config>::RuntimeCall::decode(&mut &data[..])
+					.map_err(|_| DispatchError::Corruption)?;
+				return Ok((call, Some(*len)))
+			}
+		}
+		T::Preimages::peek(call)
 	}
 
 	fn place_task(
 		when: T::BlockNumber,
 		what: ScheduledOf<T>,
-	) -> Result<TaskAddress<T::BlockNumber>, (DispatchError, ScheduledOf<T>)> {
+	) -> PlacementResult<T, TaskAddress<T::BlockNumber>> {
 		let maybe_name = what.maybe_id;
 		let index = Self::push_to_agenda(when, what)?;
 		let address = (when, index);
@@ -726,25 +1877,68 @@ impl<T: Config> Pallet<T> {
 		Ok(address)
 	}
 
-	fn push_to_agenda(
-		when: T::BlockNumber,
-		what: ScheduledOf<T>,
-	) -> Result<u32, (DispatchError, ScheduledOf<T>)> {
+	/// Like `place_task`, but if the agenda at `when` is full, up to `max_search` following
+	/// blocks are tried.
+	fn place_task_best_effort(
+		mut when: T::BlockNumber,
+		mut what: ScheduledOf<T>,
+		max_search: u32,
+	) -> PlacementResult<T, TaskAddress<T::BlockNumber>> {
+		let mut searched = 0;
+		loop {
+			match Self::place_task(when, what) {
+				Err((DispatchError::Exhausted, task)) if searched < max_search => {
+					what = *task;
+					when.saturating_inc();
+					searched.saturating_inc();
+				},
+				result => return result,
+			}
+		}
+	}
+
+	fn push_to_agenda(when: T::BlockNumber, what: ScheduledOf<T>) -> PlacementResult<T, u32> {
 		let mut agenda = Agenda::<T>::get(when);
-		let index = if (agenda.len() as u32) < T::MaxScheduledPerBlock::get() {
+		let index = Self::push_to(&mut agenda, what)
+			.map_err(|what| (DispatchError::Exhausted, Box::new(what)))?;
+		Agenda::<T>::insert(when, agenda);
+		Ok(index)
+	}
+
+	/// Put `what` in the `agenda`, returning its index, or `what` back if there is no room for
+	/// it.
+	///
+	/// The task is appended, so that tasks of equal priority are serviced in the order they were
+	/// scheduled in. Only once the agenda is full is the first hole left by a cancelled or
+	/// dispatched task reused.
+	fn push_to(
+		agenda: &mut BoundedVec<Option<ScheduledOf<T>>, MaxAgendaLen<T>>,
+		what: ScheduledOf<T>,
+	) -> Result<u32, ScheduledOf<T>> {
+		let max_len = Self::agenda_len_limit(&what.origin);
+		if (agenda.len() as u32) < max_len {
 			// will always succeed due to the above check.
 			let _ = agenda.try_push(Some(what));
-			agenda.len() as u32 - 1
+			Ok(agenda.len() as u32 - 1)
+		} else if let Some(hole_index) =
+			agenda.iter().take(max_len as usize).position(|i| i.is_none())
+		{
+			agenda[hole_index] = Some(what);
+			Ok(hole_index as u32)
 		} else {
-			if let Some(hole_index) = agenda.iter().position(|i| i.is_none()) {
-				agenda[hole_index] = Some(what);
-				hole_index as u32
-			} else {
-				return Err((DispatchError::Exhausted, what))
-			}
-		};
-		Agenda::<T>::insert(when, agenda);
-		Ok(index)
+			Err(what)
+		}
+	}
+
+	/// The number of slots of an agenda which a task of `origin` may use.
+	///
+	/// Root may use the room reserved for it, other origins are kept to the first slots.
+	fn agenda_len_limit(origin: &T::PalletsOrigin) -> u32 {
+		let root: T::PalletsOrigin = system::RawOrigin::Root.into();
+		match T::OriginPrivilegeCmp::cmp_privilege(origin, &root) {
+			Some(Ordering::Equal) => MaxAgendaLen::<T>::get(),
+			_ => T::MaxScheduledPerBlock::get(),
+		}
 	}
 
 	/// Remove trailing `None` items of an agenda at `when`. If all items are `None` remove the
@@ -770,24 +1964,66 @@ impl<T: Config> Pallet<T> {
 		origin: T::PalletsOrigin,
 		call: Bounded<<T as Config>::RuntimeCall>,
 	) -> Result<TaskAddress<T::BlockNumber>, DispatchError> {
-		let when = Self::resolve_time(when)?;
+		let task = Self::anonymous_task(maybe_periodic, priority, origin, call, None);
+		Self::do_schedule_best_effort(when, task, 0)
+	}
 
-		let lookup_hash = call.lookup_hash();
+	fn do_schedule_with_deadline(
+		when: DispatchTime<T::BlockNumber>,
+		maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
+		priority: schedule::Priority,
+		origin: T::PalletsOrigin,
+		call: Bounded<<T as Config>::RuntimeCall>,
+		valid_until: T::BlockNumber,
+		authorization: Option<DispatchAuthorization>,
+	) -> Result<TaskAddress<T::BlockNumber>, DispatchError> {
+		let mut task = Self::anonymous_task(maybe_periodic, priority, origin, call, authorization);
+		task.valid_until = Some(valid_until);
+		Self::do_schedule_best_effort(when, task, 0)
+	}
 
-		// sanitize maybe_periodic
-		let maybe_periodic = maybe_periodic
-			.filter(|p| p.1 > 1 && !p.0.is_zero())
-			// Remove one from the number of repetitions since we will schedule one now.
-			.map(|(p, c)| (p, c - 1));
-		let task = Scheduled {
+	/// An anonymous task with no deadline, yet to be scheduled.
+	fn anonymous_task(
+		maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
+		priority: schedule::Priority,
+		origin: T::PalletsOrigin,
+		call: Bounded<<T as Config>::RuntimeCall>,
+		authorization: Option<DispatchAuthorization>,
+	) -> ScheduledOf<T> {
+		Scheduled {
 			maybe_id: None,
 			priority,
 			call,
 			maybe_periodic,
 			origin,
+			valid_until: None,
+			authorization,
 			_phantom: PhantomData,
-		};
-		let res = Self::place_task(when, task).map_err(|x| x.0)?;
+		}
+	}
+
+	/// Schedule the anonymous `task` at `when`, or in the first of the `max_search` following
+	/// blocks with a free slot.
+	fn do_schedule_best_effort(
+		when: DispatchTime<T::BlockNumber>,
+		mut task: ScheduledOf<T>,
+		max_search: u32,
+	) -> Result<TaskAddress<T::BlockNumber>, DispatchError> {
+		let when = Self::resolve_time(when)?;
+		if let Some(deadline) = task.valid_until {
+			ensure!(deadline >= when, Error::<T>::InvalidDeadline);
+		}
+		let origin = task.origin.clone();
+		Self::ensure_can_schedule(&origin)?;
+		task.maybe_periodic = Self::sanitize_periodic(task.maybe_periodic)?;
+
+		let lookup_hash = task.call.lookup_hash();
+		task.call = Self::share_inline_call(task.call);
+		let res = Self::place_task_best_effort(when, task, max_search).map_err(|(err, task)| {
+			Self::unshare_call(&task.call);
+			err
+		})?;
+		Self::note_task_added(&origin);
 
 		if let Some(hash) = lookup_hash {
 			// Request the call to be made available.
@@ -818,10 +2054,13 @@ impl<T: Config> Pallet<T> {
 			)
 		})?;
 		if let Some(s) = scheduled {
-			T::Preimages::drop(&s.call);
+			Self::drop_call(&s.call);
 			if let Some(id) = s.maybe_id {
 				Lookup::<T>::remove(id);
+				Self::drop_fallback(&id);
 			}
+			Self::note_task_removed(&s.origin);
+			Self::forget_tag((when, index));
 			Self::cleanup_agenda(when);
 			Self::deposit_event(Event::Canceled { when, index });
 			Ok(())
@@ -830,6 +2069,52 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The key of the `TagLookup` entry of the tasks scheduled by `origin` with `tag`.
+	fn tag_key(origin: &T::PalletsOrigin, tag: &TaskTag) -> [u8; 32] {
+		(origin, tag).using_encoded(blake2_256)
+	}
+
+	/// Make the `TagLookup` entry `key` refer to the anonymous task just scheduled at `address`.
+	fn tag_task(key: [u8; 32], address: TaskAddress<T::BlockNumber>) {
+		let nonce = TagNonce::<T>::mutate(|nonce| {
+			let this = *nonce;
+			nonce.saturating_inc();
+			this
+		});
+		if let Some((previous, _)) = TagLookup::<T>::get(key) {
+			// The previous task of that tag can't be reached by it anymore.
+			TaskTags::<T>::remove(previous);
+		}
+		TagLookup::<T>::insert(key, (address, nonce));
+		TaskTags::<T>::insert(address, (key, nonce));
+	}
+
+	/// Remove the tag of the task which was at `address`, if it had one, as it left that address.
+	fn forget_tag(address: TaskAddress<T::BlockNumber>) {
+		if let Some((key, nonce)) = TaskTags::<T>::take(address) {
+			TagLookup::<T>::mutate_exists(key, |maybe_entry| {
+				if matches!(maybe_entry, Some((_, n)) if *n == nonce) {
+					*maybe_entry = None;
+				}
+			});
+		}
+	}
+
+	/// Cancel the anonymous task last scheduled by `origin` with `tag`.
+	///
+	/// The task at the address the tag points at must have the nonce recorded with the tag,
+	/// otherwise the slot was reused by another task.
+	fn do_cancel_tagged(origin: T::PalletsOrigin, tag: &TaskTag) -> DispatchResult {
+		let key = Self::tag_key(&origin, tag);
+		let (address, nonce) = TagLookup::<T>::get(key).ok_or(Error::<T>::NotFound)?;
+		ensure!(TaskTags::<T>::get(address) == Some((key, nonce)), Error::<T>::NotFound);
+		Self::do_cancel(Some(origin), address)
+	}
+
+	/// Move the anonymous task at `(when, index)` to `new_time`.
+	///
+	/// A periodic task keeps its period and remaining count, only its next occurrence is moved.
+	/// The following occurrences then come every `period` blocks from `new_time`.
 	fn do_reschedule(
 		(when, index): TaskAddress<T::BlockNumber>,
 		new_time: DispatchTime<T::BlockNumber>,
@@ -845,12 +2130,64 @@ impl<T: Config> Pallet<T> {
 			ensure!(!matches!(task, Some(Scheduled { maybe_id: Some(_), .. })), Error::<T>::Named);
 			task.take().ok_or(Error::<T>::NotFound)
 		})?;
+		Self::forget_tag((when, index));
 		Self::cleanup_agenda(when);
 		Self::deposit_event(Event::Canceled { when, index });
 
 		Self::place_task(new_time, task).map_err(|x| x.0)
 	}
 
+	/// Move all the tasks of the agenda at `when` to `new_time`, returning how many were moved.
+	///
+	/// Nothing is moved unless `origin`, if given, may manage every task and they all fit in the
+	/// agenda at `new_time`.
+	fn do_reschedule_all_at(
+		origin: Option<T::PalletsOrigin>,
+		when: T::BlockNumber,
+		new_time: DispatchTime<T::BlockNumber>,
+	) -> Result<u32, DispatchError> {
+		let new_time = Self::resolve_time(new_time)?;
+		ensure!(new_time != when, Error::<T>::RescheduleNoChange);
+
+		let tasks = Agenda::<T>::get(when)
+			.into_iter()
+			.enumerate()
+			.filter_map(|(index, maybe_task)| maybe_task.map(|task| (index as u32, task)))
+			.collect::<Vec<_>>();
+		ensure!(!tasks.is_empty(), Error::<T>::NotFound);
+		if let Some(ref o) = origin {
+			for (_, task) in tasks.iter() {
+				if matches!(
+					T::OriginPrivilegeCmp::cmp_privilege(o, &task.origin),
+					Some(Ordering::Less) | None
+				) {
+					return Err(BadOrigin.into())
+				}
+			}
+		}
+
+		let mut new_agenda = Agenda::<T>::get(new_time);
+		let mut moved = Vec::with_capacity(tasks.len());
+		for (index, task) in tasks {
+			let maybe_name = task.maybe_id;
+			let new_index =
+				Self::push_to(&mut new_agenda, task).map_err(|_| DispatchError::Exhausted)?;
+			moved.push((index, new_index, maybe_name));
+		}
+		Agenda::<T>::remove(when);
+		Agenda::<T>::insert(new_time, new_agenda);
+
+		for &(index, new_index, maybe_name) in moved.iter() {
+			if let Some(name) = maybe_name {
+				Lookup::<T>::insert(name, (new_time, new_index));
+			}
+			Self::forget_tag((when, index));
+			Self::deposit_event(Event::Canceled { when, index });
+			Self::deposit_event(Event::Scheduled { when: new_time, index: new_index });
+		}
+		Ok(moved.len() as u32)
+	}
+
 	fn do_schedule_named(
 		id: TaskName,
 		when: DispatchTime<T::BlockNumber>,
@@ -858,6 +2195,45 @@ impl<T: Config> Pallet<T> {
 		priority: schedule::Priority,
 		origin: T::PalletsOrigin,
 		call: Bounded<<T as Config>::RuntimeCall>,
+	) -> Result<TaskAddress<T::BlockNumber>, DispatchError> {
+		Self::do_schedule_named_authorized(id, when, maybe_periodic, priority, origin, call, None)
+	}
+
+	/// Schedule a named task on behalf of `origin`, first cancelling any task already scheduled
+	/// under `id`.
+	fn do_schedule_named_or_replace(
+		id: TaskName,
+		when: DispatchTime<T::BlockNumber>,
+		maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
+		priority: schedule::Priority,
+		call: Box<<T as Config>::RuntimeCall>,
+		origin: &<T as Config>::RuntimeOrigin,
+	) -> DispatchResult {
+		if Lookup::<T>::contains_key(&id) {
+			Self::do_cancel_named(Some(origin.caller().clone()), id)?;
+		}
+		Self::do_schedule_named_authorized(
+			id,
+			when,
+			maybe_periodic,
+			priority,
+			origin.caller().clone(),
+			T::Preimages::bound(*call)?,
+			T::DispatchContext::capture(origin),
+		)?;
+		Ok(())
+	}
+
+	/// Schedule a named task which restores the filter described by `authorization`, if any,
+	/// before being dispatched.
+	fn do_schedule_named_authorized(
+		id: TaskName,
+		when: DispatchTime<T::BlockNumber>,
+		maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
+		priority: schedule::Priority,
+		origin: T::PalletsOrigin,
+		call: Bounded<<T as Config>::RuntimeCall>,
+		authorization: Option<DispatchAuthorization>,
 	) -> Result<TaskAddress<T::BlockNumber>, DispatchError> {
 		// ensure id it is unique
 		if Lookup::<T>::contains_key(&id) {
@@ -865,24 +2241,27 @@ impl<T: Config> Pallet<T> {
 		}
 
 		let when = Self::resolve_time(when)?;
+		Self::ensure_can_schedule(&origin)?;
+		let maybe_periodic = Self::sanitize_periodic(maybe_periodic)?;
 
 		let lookup_hash = call.lookup_hash();
-
-		// sanitize maybe_periodic
-		let maybe_periodic = maybe_periodic
-			.filter(|p| p.1 > 1 && !p.0.is_zero())
-			// Remove one from the number of repetitions since we will schedule one now.
-			.map(|(p, c)| (p, c - 1));
+		Self::forget_result(id);
 
 		let task = Scheduled {
 			maybe_id: Some(id),
 			priority,
-			call,
+			call: Self::share_inline_call(call),
 			maybe_periodic,
-			origin,
+			origin: origin.clone(),
+			valid_until: None,
+			authorization,
 			_phantom: Default::default(),
 		};
-		let res = Self::place_task(when, task).map_err(|x| x.0)?;
+		let res = Self::place_task(when, task).map_err(|(err, task)| {
+			Self::unshare_call(&task.call);
+			err
+		})?;
+		Self::note_task_added(&origin);
 
 		if let Some(hash) = lookup_hash {
 			// Request the call to be made available.
@@ -892,34 +2271,62 @@ impl<T: Config> Pallet<T> {
 		Ok(res)
 	}
 
+	/// Cancel the task named `id`.
+	///
+	/// A `Lookup` entry which doesn't lead to a task of that name is stale: there is nothing to
+	/// cancel, but the entry is removed and `StaleLookupRemoved` is emitted. The cleanup succeeds
+	/// so that it isn't reverted along with a failed call.
 	fn do_cancel_named(origin: Option<T::PalletsOrigin>, id: TaskName) -> DispatchResult {
-		Lookup::<T>::try_mutate_exists(id, |lookup| -> DispatchResult {
-			if let Some((when, index)) = lookup.take() {
-				let i = index as usize;
-				Agenda::<T>::try_mutate(when, |agenda| -> DispatchResult {
-					if let Some(s) = agenda.get_mut(i) {
-						if let (Some(ref o), Some(ref s)) = (origin, s.borrow()) {
-							if matches!(
-								T::OriginPrivilegeCmp::cmp_privilege(o, &s.origin),
-								Some(Ordering::Less) | None
-							) {
-								return Err(BadOrigin.into())
-							}
-							T::Preimages::drop(&s.call);
-						}
-						*s = None;
-					}
-					Ok(())
-				})?;
-				Self::cleanup_agenda(when);
-				Self::deposit_event(Event::Canceled { when, index });
-				Ok(())
-			} else {
-				return Err(Error::<T>::NotFound.into())
+		let (when, index) = Lookup::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+		let mut agenda = Agenda::<T>::get(when);
+		let slot = match agenda.get_mut(index as usize) {
+			Some(slot) if matches!(slot, Some(ref task) if task.maybe_id == Some(id)) => slot,
+			_ => {
+				Lookup::<T>::remove(id);
+				Self::drop_fallback(&id);
+				Self::deposit_event(Event::StaleLookupRemoved { id });
+				return Ok(())
+			},
+		};
+		if let (Some(ref o), Some(ref s)) = (origin, slot.borrow()) {
+			if matches!(
+				T::OriginPrivilegeCmp::cmp_privilege(o, &s.origin),
+				Some(Ordering::Less) | None
+			) {
+				return Err(BadOrigin.into())
 			}
-		})
+		}
+		if let Some(s) = slot.take() {
+			Self::drop_call(&s.call);
+			Self::note_task_removed(&s.origin);
+		}
+		Agenda::<T>::insert(when, agenda);
+		Lookup::<T>::remove(id);
+		Self::drop_fallback(&id);
+		Self::cleanup_agenda(when);
+		Self::deposit_event(Event::Canceled { when, index });
+		Ok(())
+	}
+
+	/// Store `fallback` for the task named `id`, which was just scheduled, requesting its
+	/// preimage like the call of the task.
+	fn note_fallback(id: TaskName, fallback: Bounded<<T as Config>::RuntimeCall>) {
+		let lookup_hash = fallback.lookup_hash();
+		Fallback::<T>::insert(id, Self::share_inline_call(fallback));
+		if let Some(hash) = lookup_hash {
+			// Request the call to be made available.
+			T::Preimages::request(&hash);
+		}
+	}
+
+	/// Remove the fallback of the task named `id`, if any, releasing its call.
+	fn drop_fallback(id: &TaskName) {
+		if let Some(call) = Fallback::<T>::take(id) {
+			Self::drop_call(&call);
+		}
 	}
 
+	/// Move the named task `id` to `new_time`, with the same semantics as `do_reschedule`.
 	fn do_reschedule_named(
 		id: TaskName,
 		new_time: DispatchTime<T::BlockNumber>,
@@ -939,8 +2346,44 @@ impl<T: Config> Pallet<T> {
 		})?;
 		Self::cleanup_agenda(when);
 		Self::deposit_event(Event::Canceled { when, index });
+		Self::forget_result(id);
 		Self::place_task(new_time, task).map_err(|x| x.0)
 	}
+
+	/// Record the `result` of the named task `id` dispatched at `now`, evicting the oldest entry
+	/// of `LastResults` if it has `MaxRecordedResults` entries and none for `id`.
+	fn record_result(id: TaskName, now: T::BlockNumber, result: DispatchResult) {
+		ResultOrder::<T>::mutate(|order| {
+			if LastResults::<T>::contains_key(id) {
+				order.retain(|name| *name != id);
+			}
+			// More than one entry is evicted if the limit was lowered.
+			while !order.is_empty() && order.len() >= T::MaxRecordedResults::get() as usize {
+				LastResults::<T>::remove(order.remove(0));
+			}
+			let pushed = order.try_push(id);
+			debug_assert!(pushed.is_ok(), "room was made above; qed");
+		});
+		LastResults::<T>::insert(id, (now, result));
+	}
+
+	/// Remove the result recorded for `id`, if any.
+	fn forget_result(id: TaskName) {
+		if LastResults::<T>::take(id).is_some() {
+			ResultOrder::<T>::mutate(|order| order.retain(|name| *name != id));
+		}
+	}
+}
+
+/// The tasks serviced within a block, by outcome.
+#[derive(Default)]
+struct ServiceSummary {
+	/// Tasks which were dispatched.
+	dispatched: u32,
+	/// Tasks left for execution in a later block.
+	postponed: u32,
+	/// Tasks which we don't know can ever be executed.
+	dropped: u32,
 }
 
 enum ServiceTaskError {
@@ -948,25 +2391,27 @@ enum ServiceTaskError {
 	Unavailable,
 	/// Could not be executed due to weight limitations.
 	Overweight,
+	/// Moved to a later block since its preimage is missing for now.
+	Postponed,
 }
 use ServiceTaskError::*;
 
 impl<T: Config> Pallet<T> {
 	/// Service up to `max` agendas queue starting from earliest incompletely executed agenda.
-	fn service_agendas(weight: &mut WeightMeter, now: T::BlockNumber, max: u32) {
+	fn service_agendas(weight: &mut WeightMeter, now: T::BlockNumber, max: u32) -> ServiceSummary {
+		let mut summary = ServiceSummary::default();
 		if !weight.check_accrue(T::WeightInfo::service_agendas_base()) {
-			return
+			return summary
 		}
 
 		let mut incomplete_since = now + One::one();
 		let mut when = IncompleteSince::<T>::take().unwrap_or(now);
-		let mut executed = 0;
 
-		let max_items = T::MaxScheduledPerBlock::get();
+		let max_items = MaxAgendaLen::<T>::get();
 		let mut count_down = max;
 		let service_agenda_base_weight = T::WeightInfo::service_agenda_base(max_items);
 		while count_down > 0 && when <= now && weight.can_accrue(service_agenda_base_weight) {
-			if !Self::service_agenda(weight, &mut executed, now, when, u32::max_value()) {
+			if !Self::service_agenda(weight, &mut summary, now, when, u32::MAX) {
 				incomplete_since = incomplete_since.min(when);
 			}
 			when.saturating_inc();
@@ -976,13 +2421,14 @@ impl<T: Config> Pallet<T> {
 		if incomplete_since <= now {
 			IncompleteSince::<T>::put(incomplete_since);
 		}
+		summary
 	}
 
 	/// Returns `true` if the agenda was fully completed, `false` if it should be revisited at a
 	/// later block.
 	fn service_agenda(
 		weight: &mut WeightMeter,
-		executed: &mut u32,
+		summary: &mut ServiceSummary,
 		now: T::BlockNumber,
 		when: T::BlockNumber,
 		max: u32,
@@ -995,7 +2441,9 @@ impl<T: Config> Pallet<T> {
 				maybe_item.as_ref().map(|item| (index as u32, item.priority))
 			})
 			.collect::<Vec<_>>();
-		ordered.sort_by_key(|k| k.1);
+		// Tasks of equal priority are serviced in the order of their index in the agenda, which
+		// is the order they were scheduled in unless a freed slot was reused.
+		ordered.sort_by_key(|&(index, priority)| (priority, index));
 		let within_limit =
 			weight.check_accrue(T::WeightInfo::service_agenda_base(ordered.len() as u32));
 		debug_assert!(within_limit, "weight limit should have been checked in advance");
@@ -1019,7 +2467,13 @@ impl<T: Config> Pallet<T> {
 				postponed += 1;
 				break
 			}
-			let result = Self::service_task(weight, now, when, agenda_index, *executed == 0, task);
+			if matches!(task.valid_until, Some(deadline) if now > deadline) {
+				weight.check_accrue(base_weight);
+				Self::expire_task(when, agenda_index, task);
+				continue
+			}
+			let is_first = summary.dispatched == 0;
+			let result = Self::service_task(weight, now, when, agenda_index, is_first, task);
 			agenda[agenda_index as usize] = match result {
 				Err((Unavailable, slot)) => {
 					dropped += 1;
@@ -1029,26 +2483,122 @@ impl<T: Config> Pallet<T> {
 					postponed += 1;
 					slot
 				},
+				Err((Postponed, slot)) => {
+					summary.postponed.saturating_inc();
+					slot
+				},
 				Ok(()) => {
-					*executed += 1;
+					summary.dispatched += 1;
 					None
 				},
 			};
+			if agenda[agenda_index as usize].is_none() {
+				Self::forget_tag((when, agenda_index));
+			}
 		}
 		if postponed > 0 || dropped > 0 {
 			Agenda::<T>::insert(when, agenda);
 		} else {
 			Agenda::<T>::remove(when);
 		}
+		summary.postponed.saturating_accrue(postponed);
+		summary.dropped.saturating_accrue(dropped);
 
 		postponed == 0
 	}
 
+	/// Drop the given task without dispatching it, since its deadline has passed.
+	fn expire_task(when: T::BlockNumber, agenda_index: u32, task: ScheduledOf<T>) {
+		Self::forget_task(&task);
+		Self::forget_tag((when, agenda_index));
+		Self::deposit_event(Event::Expired { task: (when, agenda_index), id: task.maybe_id });
+	}
+
+	/// Release everything held for a task taken out of its agenda for good.
+	fn forget_task(task: &ScheduledOf<T>) {
+		if let Some(ref id) = task.maybe_id {
+			Lookup::<T>::remove(id);
+			Self::drop_fallback(id);
+		}
+		Self::drop_call(&task.call);
+		Self::note_task_removed(&task.origin);
+	}
+
+	/// Put `task`, taken from `from` and still accounted for in `TaskCount`, back into the agenda
+	/// at `when`.
+	///
+	/// This doesn't add to `TaskCount`, but the count may exceed `MaxSchedules` if the limit was
+	/// lowered. In that case the task of lowest priority out of `task` and those it could replace
+	/// in the agenda at `when` is dropped, bringing the count back towards the limit.
+	///
+	/// Otherwise, if the agenda at `when` is full, the task goes to the first of the
+	/// `MaxReappendSearch` following blocks with a free slot.
+	///
+	/// Returns the block `task` was re-appended to, `None` if it was the one dropped.
+	fn reappend_task(
+		weight: &mut WeightMeter,
+		from: TaskAddress<T::BlockNumber>,
+		when: T::BlockNumber,
+		task: ScheduledOf<T>,
+	) -> PlacementResult<T, Option<T::BlockNumber>> {
+		if Self::task_count() <= T::MaxSchedules::get() {
+			let max_search = T::MaxReappendSearch::get();
+			let result = Self::place_task_best_effort(when, task, max_search);
+			let searched = match &result {
+				Ok((placed, _)) => placed.saturating_sub(when).unique_saturated_into(),
+				Err(_) => max_search as u64,
+			};
+			weight.check_accrue(T::DbWeight::get().reads(searched));
+			return result.map(|(placed, _)| Some(placed))
+		}
+
+		weight.check_accrue(T::DbWeight::get().reads_writes(2, 4));
+		let mut agenda = Agenda::<T>::get(when);
+		let lowest = agenda
+			.iter()
+			.take(Self::agenda_len_limit(&task.origin) as usize)
+			.enumerate()
+			.filter_map(|(index, maybe_item)| {
+				maybe_item.as_ref().map(|item| (index, item.priority))
+			})
+			.filter(|&(_, priority)| priority > task.priority)
+			.max_by_key(|&(index, priority)| (priority, index));
+		let index = match lowest {
+			Some((index, _)) => index,
+			None => {
+				Self::drop_task(from, task);
+				return Ok(None)
+			},
+		};
+
+		let maybe_name = task.maybe_id;
+		let dropped = agenda[index].replace(task);
+		Agenda::<T>::insert(when, agenda);
+		if let Some(dropped) = dropped {
+			Self::forget_tag((when, index as u32));
+			Self::drop_task((when, index as u32), dropped);
+		}
+		let address = (when, index as u32);
+		if let Some(name) = maybe_name {
+			Lookup::<T>::insert(name, address)
+		}
+		Self::deposit_event(Event::Scheduled { when, index: address.1 });
+		Ok(Some(when))
+	}
+
+	/// Drop the given task without dispatching it, since there are more than `MaxSchedules`.
+	fn drop_task(address: TaskAddress<T::BlockNumber>, task: ScheduledOf<T>) {
+		Self::forget_task(&task);
+		Self::deposit_event(Event::Dropped { task: address, id: task.maybe_id });
+	}
+
 	/// Service (i.e. execute) the given task, being careful not to overflow the `weight` counter.
 	///
 	/// This involves:
-	/// - removing and potentially replacing the `Lookup` entry for the task.
 	/// - realizing the task's call which can include a preimage lookup.
+	/// - moving the task to a later block as it is if its preimage is missing for now, or dropping
+	///   it if its preimage is noted but can't be resolved into a call.
+	/// - removing and potentially replacing the `Lookup` entry for the task.
 	/// - Rescheduling the task for execution in a later agenda if periodic.
 	fn service_task(
 		weight: &mut WeightMeter,
@@ -1058,13 +2608,40 @@ impl<T: Config> Pallet<T> {
 		is_first: bool,
 		mut task: ScheduledOf<T>,
 	) -> Result<(), (ServiceTaskError, Option<ScheduledOf<T>>)> {
-		if let Some(ref id) = task.maybe_id {
-			Lookup::<T>::remove(id);
-		}
-
-		let (call, lookup_len) = match T::Preimages::peek(&task.call) {
+		let (call, lookup_len) = match Self::peek_call(&task.call) {
 			Ok(c) => c,
-			Err(_) => return Err((Unavailable, Some(task))),
+			Err(e) if e == DispatchError::Corruption || T::Preimages::have(&task.call) => {
+				// The preimage is there, but doesn't decode into a call or has another length than
+				// the one it was scheduled with. Waiting won't change that, so drop the task.
+				weight.check_accrue(T::WeightInfo::service_task(
+					task.call.lookup_len().map(|x| x as usize),
+					task.maybe_id.is_some(),
+					task.maybe_periodic.is_some(),
+				));
+				Self::forget_task(&task);
+				Self::deposit_event(Event::CallUnavailable {
+					task: (when, agenda_index),
+					id: task.maybe_id,
+				});
+				return Err((Unavailable, None))
+			},
+			Err(_) => {
+				weight.check_accrue(T::WeightInfo::item_postponed(task.maybe_id.is_some()));
+				// The preimage may yet be noted, retry after the postponement. The task keeps its
+				// origin, period and name, the `Lookup` entry follows it.
+				let next = now.saturating_add(Self::postponement());
+				return match Self::reappend_task(weight, (when, agenda_index), next, task) {
+					Ok(Some(_)) => Err((Postponed, None)),
+					Ok(None) => Err((Unavailable, None)),
+					Err((_, task)) => {
+						Self::deposit_event(Event::CallUnavailable {
+							task: (when, agenda_index),
+							id: task.maybe_id,
+						});
+						Err((Unavailable, Some(*task)))
+					},
+				}
+			},
 		};
 
 		weight.check_accrue(T::WeightInfo::service_task(
@@ -1073,8 +2650,9 @@ impl<T: Config> Pallet<T> {
 			task.maybe_periodic.is_some(),
 		));
 
-		match Self::execute_dispatch(weight, task.origin.clone(), call) {
-			Err(Unavailable) => {
+		match Self::execute_dispatch(weight, task.origin.clone(), task.authorization.as_ref(), call)
+		{
+			Err(Unavailable | Postponed) => {
 				debug_assert!(false, "Checked to exist with `peek`");
 				Self::deposit_event(Event::CallUnavailable {
 					task: (when, agenda_index),
@@ -1083,7 +2661,7 @@ impl<T: Config> Pallet<T> {
 				Err((Unavailable, Some(task)))
 			},
 			Err(Overweight) if is_first => {
-				T::Preimages::drop(&task.call);
+				Self::drop_call(&task.call);
 				Self::deposit_event(Event::PermanentlyOverweight {
 					task: (when, agenda_index),
 					id: task.maybe_id,
@@ -1092,10 +2670,28 @@ impl<T: Config> Pallet<T> {
 			},
 			Err(Overweight) => Err((Overweight, Some(task))),
 			Ok(result) => {
+				let fallback_result = match (result, task.maybe_id) {
+					(Err(_), Some(ref id)) => Self::dispatch_fallback(
+						weight,
+						id,
+						task.origin.clone(),
+						task.authorization.as_ref(),
+					),
+					_ => None,
+				};
+				if let Some(id) = task.maybe_id {
+					Lookup::<T>::remove(id);
+					if !T::MaxRecordedResults::get().is_zero() {
+						weight.check_accrue(T::DbWeight::get().reads_writes(3, 4));
+						Self::record_result(id, now, result);
+					}
+				}
 				Self::deposit_event(Event::Dispatched {
 					task: (when, agenda_index),
 					id: task.maybe_id,
+					origin: task.origin.clone(),
 					result,
+					fallback_result,
 				});
 				if let &Some((period, count)) = &task.maybe_periodic {
 					if count > 1 {
@@ -1103,13 +2699,26 @@ impl<T: Config> Pallet<T> {
 					} else {
 						task.maybe_periodic = None;
 					}
-					let wake = now.saturating_add(period);
-					match Self::place_task(wake, task) {
-						Ok(_) => {},
+					let wake = now
+						.saturating_add(period)
+						.saturating_add(Self::periodic_jitter(when, agenda_index));
+					let id = task.maybe_id;
+					match Self::reappend_task(weight, (when, agenda_index), wake, task) {
+						Ok(Some(next)) => Self::deposit_event(Event::PeriodicRescheduled {
+							task: (when, agenda_index),
+							id,
+							next,
+							remaining: count,
+						}),
+						Ok(None) => {},
 						Err((_, task)) => {
 							// TODO: Leave task in storage somewhere for it to be rescheduled
 							// manually.
-							T::Preimages::drop(&task.call);
+							Self::drop_call(&task.call);
+							if let Some(ref id) = task.maybe_id {
+								Self::drop_fallback(id);
+							}
+							Self::note_task_removed(&task.origin);
 							Self::deposit_event(Event::PeriodicFailed {
 								task: (when, agenda_index),
 								id: task.maybe_id,
@@ -1117,22 +2726,50 @@ impl<T: Config> Pallet<T> {
 						},
 					}
 				} else {
-					T::Preimages::drop(&task.call);
+					Self::drop_call(&task.call);
+					if let Some(ref id) = task.maybe_id {
+						Self::drop_fallback(id);
+					}
+					Self::note_task_removed(&task.origin);
 				}
 				Ok(())
 			},
 		}
 	}
 
+	/// Dispatch the fallback of the task named `id`, if it has one, as `origin` restricted by
+	/// the `authorization` of the task.
+	///
+	/// A fallback which doesn't fit in the remaining `weight` isn't dispatched and is reported as
+	/// `Exhausted`.
+	fn dispatch_fallback(
+		weight: &mut WeightMeter,
+		id: &TaskName,
+		origin: T::PalletsOrigin,
+		authorization: Option<&DispatchAuthorization>,
+	) -> Option<DispatchResult> {
+		weight.check_accrue(T::DbWeight::get().reads(1));
+		let fallback = Fallback::<T>::get(id)?;
+		Some(match Self::peek_call(&fallback) {
+			Ok((call, _)) => Self::execute_dispatch(weight, origin, authorization, call)
+				.unwrap_or(Err(DispatchError::Exhausted)),
+			Err(_) => Err(DispatchError::Unavailable),
+		})
+	}
+
 	/// Make a dispatch to the given `call` from the given `origin`, ensuring that the `weight`
 	/// counter does not exceed its limit and that it is counted accurately (e.g. accounted using
 	/// post info if available).
 	///
+	/// The filter described by `authorization`, if any, is restored on the origin first. Should
+	/// that fail, the call is not dispatched and the error is returned as its result.
+	///
 	/// NOTE: Only the weight for this function will be counted (origin lookup, dispatch and the
 	/// call itself).
 	fn execute_dispatch(
 		weight: &mut WeightMeter,
 		origin: T::PalletsOrigin,
+		authorization: Option<&DispatchAuthorization>,
 		call: <T as Config>::RuntimeCall,
 	) -> Result<DispatchResult, ServiceTaskError> {
 		let base_weight = match origin.as_system_ref() {
@@ -1147,7 +2784,13 @@ impl<T: Config> Pallet<T> {
 			return Err(Overweight)
 		}
 
-		let dispatch_origin = origin.into();
+		let mut dispatch_origin: <T as Config>::RuntimeOrigin = origin.into();
+		if let Some(authorization) = authorization {
+			if let Err(error) = T::DispatchContext::restore(&mut dispatch_origin, authorization) {
+				weight.check_accrue(base_weight);
+				return Ok(Err(error))
+			}
+		}
 		let (maybe_actual_call_weight, result) = match call.dispatch(dispatch_origin) {
 			Ok(post_info) => (post_info.actual_weight, Ok(())),
 			Err(error_and_info) =>
@@ -1158,6 +2801,68 @@ impl<T: Config> Pallet<T> {
 		weight.check_accrue(call_weight);
 		Ok(result)
 	}
+
+	/// Ensure the limits of the config are consistent with each other.
+	fn check_limits() {
+		let max_schedules = T::MaxSchedules::get();
+		assert!(T::MaxScheduledPerBlock::get() > 0, "`MaxScheduledPerBlock` must be non-zero");
+		assert!(
+			T::MaxScheduledPerBlock::get() <= max_schedules,
+			"`MaxScheduledPerBlock` must be at most `MaxSchedules`"
+		);
+		if let Some(per_origin) = T::MaxSchedulesPerOrigin::get() {
+			assert!(
+				per_origin <= max_schedules,
+				"`MaxSchedulesPerOrigin` must be at most `MaxSchedules`"
+			);
+		}
+	}
+
+	/// Ensure the correctness of the state of this pallet.
+	///
+	/// * Every `Lookup` entry must point at a present task carrying the same name in `Agenda`.
+	/// * No agenda may hold more than `MaxScheduledPerBlock` tasks of non-root origins.
+	/// * Every `Fallback` entry must belong to a task in `Lookup`.
+	/// * `ResultOrder` must hold exactly the names in `LastResults`.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), &'static str> {
+		for (name, (when, index)) in Lookup::<T>::iter() {
+			let agenda = Agenda::<T>::get(when);
+			let task = agenda
+				.get(index as usize)
+				.and_then(Option::as_ref)
+				.ok_or("`Lookup` entry points at an empty agenda slot")?;
+			ensure!(task.maybe_id == Some(name), "`Lookup` entry points at a task of another name");
+		}
+		ensure!(
+			Fallback::<T>::iter_keys().all(Lookup::<T>::contains_key),
+			"`Fallback` entry of an unknown task"
+		);
+		let order = ResultOrder::<T>::get();
+		ensure!(
+			order.len() == LastResults::<T>::count() as usize &&
+				order.iter().all(LastResults::<T>::contains_key),
+			"`ResultOrder` doesn't match `LastResults`"
+		);
+
+		let root: T::PalletsOrigin = system::RawOrigin::Root.into();
+		for agenda in Agenda::<T>::iter_values() {
+			let non_root = agenda
+				.iter()
+				.flatten()
+				.filter(|task| {
+					T::OriginPrivilegeCmp::cmp_privilege(&task.origin, &root) !=
+						Some(Ordering::Equal)
+				})
+				.count();
+			ensure!(
+				non_root <= T::MaxScheduledPerBlock::get() as usize,
+				"Agenda holds more than `MaxScheduledPerBlock` non-root tasks"
+			);
+		}
+
+		Ok(())
+	}
 }
 
 impl<T: Config<Hash = PreimageHash>>
@@ -1189,8 +2894,8 @@ impl<T: Config<Hash = PreimageHash>>
 		Self::do_reschedule(address, when)
 	}
 
-	fn next_dispatch_time((when, index): Self::Address) -> Result<T::BlockNumber, ()> {
-		Agenda::<T>::get(when).get(index as usize).ok_or(()).map(|_| when)
+	fn next_dispatch_time(address: Self::Address) -> Result<T::BlockNumber, ()> {
+		Self::next_dispatch_time_detailed(address).map_err(|_| ())
 	}
 }
 
@@ -1211,12 +2916,17 @@ impl<T: Config<Hash = PreimageHash>>
 		let call = call.as_value().ok_or(())?;
 		let call = T::Preimages::bound(call).map_err(|_| ())?.transmute();
 		let name = blake2_256(&id[..]);
-		Self::do_schedule_named(name, when, maybe_periodic, priority, origin, call).map_err(|_| ())
+		// The trait has no room for the error, so at least leave a trace of it.
+		Self::do_schedule_named(name, when, maybe_periodic, priority, origin, call).map_err(|e| {
+			log::debug!(target: "runtime::scheduler", "Failed to schedule named task: {:?}", e);
+		})
 	}
 
 	fn cancel_named(id: Vec<u8>) -> Result<(), ()> {
 		let name = blake2_256(&id[..]);
-		Self::do_cancel_named(None, name).map_err(|_| ())
+		Self::do_cancel_named(None, name).map_err(|e| {
+			log::debug!(target: "runtime::scheduler", "Failed to cancel named task: {:?}", e);
+		})
 	}
 
 	fn reschedule_named(
@@ -1230,7 +2940,7 @@ impl<T: Config<Hash = PreimageHash>>
 	fn next_dispatch_time(id: Vec<u8>) -> Result<T::BlockNumber, ()> {
 		let name = blake2_256(&id[..]);
 		Lookup::<T>::get(name)
-			.and_then(|(when, index)| Agenda::<T>::get(when).get(index as usize).map(|_| when))
+			.and_then(|address| Self::next_dispatch_time_detailed(address).ok())
 			.ok_or(())
 	}
 }
@@ -1261,11 +2971,8 @@ impl<T: Config> schedule::v3::Anon<T::BlockNumber, <T as Config>::RuntimeCall, T
 		Self::do_reschedule(address, when).map_err(map_err_to_v3_err::<T>)
 	}
 
-	fn next_dispatch_time((when, index): Self::Address) -> Result<T::BlockNumber, DispatchError> {
-		Agenda::<T>::get(when)
-			.get(index as usize)
-			.ok_or(DispatchError::Unavailable)
-			.map(|_| when)
+	fn next_dispatch_time(address: Self::Address) -> Result<T::BlockNumber, DispatchError> {
+		Self::next_dispatch_time_detailed(address).map_err(|_| DispatchError::Unavailable)
 	}
 }
 
@@ -1300,7 +3007,7 @@ impl<T: Config> schedule::v3::Named<T::BlockNumber, <T as Config>::RuntimeCall,
 
 	fn next_dispatch_time(id: TaskName) -> Result<T::BlockNumber, DispatchError> {
 		Lookup::<T>::get(id)
-			.and_then(|(when, index)| Agenda::<T>::get(when).get(index as usize).map(|_| when))
+			.and_then(|address| Self::next_dispatch_time_detailed(address).ok())
 			.ok_or(DispatchError::Unavailable)
 	}
 }
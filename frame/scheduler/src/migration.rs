@@ -23,6 +23,11 @@ use frame_support::traits::OnRuntimeUpgrade;
 /// The log target.
 const TARGET: &'static str = "runtime::scheduler::migration";
 
+/// The last agenda migrated by `Pallet::migrate_origin_step`, while that migration is ongoing.
+#[frame_support::storage_alias]
+pub(crate) type MigrateOriginCursor<T: Config> =
+	StorageValue<Pallet<T>, <T as frame_system::Config>::BlockNumber>;
+
 pub mod v1 {
 	use super::*;
 	use frame_support::pallet_prelude::*;
@@ -173,13 +178,13 @@ pub mod v3 {
 			assert_eq!(StorageVersion::get::<Pallet<T>>(), 4, "Must upgrade");
 
 			// Check that everything decoded fine.
-			for k in crate::Agenda::<T>::iter_keys() {
-				assert!(crate::Agenda::<T>::try_get(k).is_ok(), "Cannot decode V4 Agenda");
+			for k in v4::Agenda::<T>::iter_keys() {
+				assert!(v4::Agenda::<T>::try_get(k).is_ok(), "Cannot decode V4 Agenda");
 			}
 
 			let old_agendas: u32 =
 				Decode::decode(&mut &state[..]).expect("pre_upgrade provides a valid state; qed");
-			let new_agendas = crate::Agenda::<T>::iter_keys().count() as u32;
+			let new_agendas = v4::Agenda::<T>::iter_keys().count() as u32;
 			if old_agendas != new_agendas {
 				// This is not necessarily an error, but can happen when there are Calls
 				// in an Agenda that are not valid anymore in the new runtime.
@@ -202,6 +207,15 @@ pub mod v4 {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 
+	#[frame_support::storage_alias]
+	pub(crate) type Agenda<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::BlockNumber,
+		BoundedVec<Option<ScheduledV4Of<T>>, MaxAgendaLen<T>>,
+		ValueQuery,
+	>;
+
 	/// This migration cleans up empty agendas of the V4 scheduler.
 	///
 	/// This should be run on a scheduler that does not have
@@ -312,6 +326,188 @@ pub mod v4 {
 	}
 }
 
+pub mod v5 {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[frame_support::storage_alias]
+	pub(crate) type Agenda<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::BlockNumber,
+		BoundedVec<Option<ScheduledV5Of<T>>, MaxAgendaLen<T>>,
+		ValueQuery,
+	>;
+
+	/// Migrate the scheduler pallet from V4 to V5, giving every task no deadline.
+	pub struct MigrateToV5<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 4, "Can only upgrade from version 4");
+
+			let agendas = v4::Agenda::<T>::iter_keys().count() as u32;
+			log::info!(target: TARGET, "Trying to migrate {} agendas...", agendas);
+
+			Ok(agendas.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let version = StorageVersion::get::<Pallet<T>>();
+			if version != 4 {
+				log::warn!(
+					target: TARGET,
+					"skipping v4 to v5 migration: executed on wrong storage version.\
+				Expected version 4, found {:?}",
+					version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			Agenda::<T>::translate::<BoundedVec<Option<ScheduledV4Of<T>>, MaxAgendaLen<T>>, _>(
+				|_, agenda| {
+					weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+					Some(BoundedVec::truncate_from(
+						agenda
+							.into_iter()
+							.map(|schedule| {
+								schedule.map(|schedule| ScheduledV5 {
+									maybe_id: schedule.maybe_id,
+									priority: schedule.priority,
+									call: schedule.call,
+									maybe_periodic: schedule.maybe_periodic,
+									origin: schedule.origin,
+									valid_until: None,
+									_phantom: Default::default(),
+								})
+							})
+							.collect::<Vec<_>>(),
+					))
+				},
+			);
+
+			StorageVersion::new(5).put::<Pallet<T>>();
+
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 5, "Must upgrade");
+
+			for k in Agenda::<T>::iter_keys() {
+				assert!(Agenda::<T>::try_get(k).is_ok(), "Cannot decode V5 Agenda");
+			}
+
+			let old_agendas: u32 =
+				Decode::decode(&mut &state[..]).expect("pre_upgrade provides a valid state; qed");
+			let new_agendas = Agenda::<T>::iter_keys().count() as u32;
+			assert_eq!(old_agendas, new_agendas, "Must keep all the agendas");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v6 {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	/// Migrate the scheduler pallet from V5 to V6, giving every task no dispatch authorization.
+	///
+	/// `TaskCount` and `PerOriginCount` are initialized with the number of tasks found in the
+	/// agendas.
+	pub struct MigrateToV6<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 5, "Can only upgrade from version 5");
+
+			let agendas = v5::Agenda::<T>::iter_keys().count() as u32;
+			log::info!(target: TARGET, "Trying to migrate {} agendas...", agendas);
+
+			Ok(agendas.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let version = StorageVersion::get::<Pallet<T>>();
+			if version != 5 {
+				log::warn!(
+					target: TARGET,
+					"skipping v5 to v6 migration: executed on wrong storage version.\
+				Expected version 5, found {:?}",
+					version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut weight = T::DbWeight::get().reads_writes(1, 2);
+			let mut tasks = 0u32;
+			crate::Agenda::<T>::translate::<BoundedVec<Option<ScheduledV5Of<T>>, MaxAgendaLen<T>>, _>(
+				|_, agenda| {
+					weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+					tasks.saturating_accrue(agenda.iter().flatten().count() as u32);
+					for task in agenda.iter().flatten() {
+						PerOriginCount::<T>::mutate(
+							task.origin.using_encoded(blake2_256),
+							|count| count.saturating_inc(),
+						);
+						weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+					}
+					Some(BoundedVec::truncate_from(
+						agenda
+							.into_iter()
+							.map(|schedule| {
+								schedule.map(|schedule| Scheduled {
+									maybe_id: schedule.maybe_id,
+									priority: schedule.priority,
+									call: schedule.call,
+									maybe_periodic: schedule.maybe_periodic,
+									origin: schedule.origin,
+									valid_until: schedule.valid_until,
+									authorization: None,
+									_phantom: Default::default(),
+								})
+							})
+							.collect::<Vec<_>>(),
+					))
+				},
+			);
+			TaskCount::<T>::put(tasks);
+
+			StorageVersion::new(6).put::<Pallet<T>>();
+
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 6, "Must upgrade");
+
+			for k in crate::Agenda::<T>::iter_keys() {
+				assert!(crate::Agenda::<T>::try_get(k).is_ok(), "Cannot decode V6 Agenda");
+			}
+
+			let old_agendas: u32 =
+				Decode::decode(&mut &state[..]).expect("pre_upgrade provides a valid state; qed");
+			let new_agendas = crate::Agenda::<T>::iter_keys().count() as u32;
+			assert_eq!(old_agendas, new_agendas, "Must keep all the agendas");
+
+			let tasks = crate::Agenda::<T>::iter_values()
+				.map(|agenda| agenda.iter().flatten().count() as u32)
+				.sum::<u32>();
+			assert_eq!(TaskCount::<T>::get(), tasks, "Must count all the tasks");
+			let per_origin = PerOriginCount::<T>::iter_values().sum::<u32>();
+			assert_eq!(per_origin, tasks, "Must count all the tasks per origin");
+
+			Ok(())
+		}
+	}
+}
+
 #[cfg(test)]
 #[cfg(feature = "try-runtime")]
 mod test {
@@ -387,7 +583,8 @@ mod test {
 			let _w = v3::MigrateToV4::<Test>::on_runtime_upgrade();
 			v3::MigrateToV4::<Test>::post_upgrade(state).unwrap();
 
-			let mut x = Agenda::<Test>::iter().map(|x| (x.0, x.1.into_inner())).collect::<Vec<_>>();
+			let mut x =
+				v4::Agenda::<Test>::iter().map(|x| (x.0, x.1.into_inner())).collect::<Vec<_>>();
 			x.sort_by_key(|x| x.0);
 
 			let bound_large_call = Preimage::bound(large_call).unwrap();
@@ -399,7 +596,7 @@ mod test {
 				(
 					0,
 					vec![
-						Some(ScheduledOf::<Test> {
+						Some(ScheduledV4Of::<Test> {
 							maybe_id: None,
 							priority: 10,
 							call: bound_small_call.clone(),
@@ -408,7 +605,7 @@ mod test {
 							_phantom: PhantomData::<u64>::default(),
 						}),
 						None,
-						Some(ScheduledOf::<Test> {
+						Some(ScheduledV4Of::<Test> {
 							maybe_id: Some(blake2_256(&[0u8; 32])),
 							priority: 123,
 							call: bound_large_call.clone(),
@@ -416,7 +613,7 @@ mod test {
 							origin: signed(0),
 							_phantom: PhantomData::<u64>::default(),
 						}),
-						Some(ScheduledOf::<Test> {
+						Some(ScheduledV4Of::<Test> {
 							maybe_id: Some(blake2_256(&[255u8; 320])),
 							priority: 123,
 							call: Bounded::from_legacy_hash(bound_hashed_call.hash()),
@@ -430,7 +627,7 @@ mod test {
 				(
 					1,
 					vec![
-						Some(ScheduledOf::<Test> {
+						Some(ScheduledV4Of::<Test> {
 							maybe_id: None,
 							priority: 11,
 							call: bound_small_call.clone(),
@@ -439,7 +636,7 @@ mod test {
 							_phantom: PhantomData::<u64>::default(),
 						}),
 						None,
-						Some(ScheduledOf::<Test> {
+						Some(ScheduledV4Of::<Test> {
 							maybe_id: Some(blake2_256(&[1u8; 32])),
 							priority: 123,
 							call: bound_large_call.clone(),
@@ -447,7 +644,7 @@ mod test {
 							origin: signed(1),
 							_phantom: PhantomData::<u64>::default(),
 						}),
-						Some(ScheduledOf::<Test> {
+						Some(ScheduledV4Of::<Test> {
 							maybe_id: Some(blake2_256(&[254u8; 320])),
 							priority: 123,
 							call: Bounded::from_legacy_hash(bound_hashed_call.hash()),
@@ -500,7 +697,8 @@ mod test {
 			// But the migration itself works:
 			let _w = v3::MigrateToV4::<Test>::on_runtime_upgrade();
 
-			let mut x = Agenda::<Test>::iter().map(|x| (x.0, x.1.into_inner())).collect::<Vec<_>>();
+			let mut x =
+				v4::Agenda::<Test>::iter().map(|x| (x.0, x.1.into_inner())).collect::<Vec<_>>();
 			x.sort_by_key(|x| x.0);
 			// The call becomes `None`.
 			let expected = vec![(0, vec![None])];
@@ -518,7 +716,7 @@ mod test {
 
 			let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
 			let bounded_call = Preimage::bound(call).unwrap();
-			let some = Some(ScheduledOf::<Test> {
+			let some = Some(ScheduledV4Of::<Test> {
 				maybe_id: None,
 				priority: 1,
 				call: bounded_call,
@@ -529,10 +727,8 @@ mod test {
 
 			// Put some empty, and some non-empty agendas in there.
 			let test_data: Vec<(
-				BoundedVec<Option<ScheduledOf<Test>>, <Test as Config>::MaxScheduledPerBlock>,
-				Option<
-					BoundedVec<Option<ScheduledOf<Test>>, <Test as Config>::MaxScheduledPerBlock>,
-				>,
+				BoundedVec<Option<ScheduledV4Of<Test>>, MaxAgendaLen<Test>>,
+				Option<BoundedVec<Option<ScheduledV4Of<Test>>, MaxAgendaLen<Test>>>,
 			)> = vec![
 				(bounded_vec![some.clone()], Some(bounded_vec![some.clone()])),
 				(bounded_vec![None, some.clone()], Some(bounded_vec![None, some.clone()])),
@@ -545,7 +741,7 @@ mod test {
 
 			// Insert all the agendas.
 			for (i, test) in test_data.iter().enumerate() {
-				Agenda::<Test>::insert(i as u64, test.0.clone());
+				v4::Agenda::<Test>::insert(i as u64, test.0.clone());
 			}
 
 			// Run the migration.
@@ -557,18 +753,105 @@ mod test {
 			for (i, test) in test_data.iter().enumerate() {
 				match test.1.clone() {
 					None => assert!(
-						!Agenda::<Test>::contains_key(i as u64),
+						!v4::Agenda::<Test>::contains_key(i as u64),
 						"Agenda {} should be removed",
 						i
 					),
 					Some(new) => {
-						assert_eq!(Agenda::<Test>::get(i as u64), new, "Agenda wrong {}", i)
+						assert_eq!(v4::Agenda::<Test>::get(i as u64), new, "Agenda wrong {}", i)
 					},
 				}
 			}
 		});
 	}
 
+	#[test]
+	fn migration_v4_to_v5_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(4).put::<Scheduler>();
+
+			let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+			let bounded_call = Preimage::bound(call).unwrap();
+			let old = |i: u64| ScheduledV4Of::<Test> {
+				maybe_id: Some([i as u8; 32]),
+				priority: i as u8,
+				call: bounded_call.clone(),
+				maybe_periodic: Some((2, i as u32)),
+				origin: signed(i),
+				_phantom: Default::default(),
+			};
+			v4::Agenda::<Test>::insert(1, BoundedVec::truncate_from(vec![Some(old(1)), None]));
+			v4::Agenda::<Test>::insert(2, BoundedVec::truncate_from(vec![None, Some(old(2))]));
+
+			let state = v5::MigrateToV5::<Test>::pre_upgrade().unwrap();
+			let _w = v5::MigrateToV5::<Test>::on_runtime_upgrade();
+			v5::MigrateToV5::<Test>::post_upgrade(state).unwrap();
+
+			let new = |i: u64| ScheduledV5Of::<Test> {
+				maybe_id: Some([i as u8; 32]),
+				priority: i as u8,
+				call: bounded_call.clone(),
+				maybe_periodic: Some((2, i as u32)),
+				origin: signed(i),
+				valid_until: None,
+				_phantom: Default::default(),
+			};
+			assert_eq!(v5::Agenda::<Test>::get(1).into_inner(), vec![Some(new(1)), None]);
+			assert_eq!(v5::Agenda::<Test>::get(2).into_inner(), vec![None, Some(new(2))]);
+			assert_eq!(StorageVersion::get::<Scheduler>(), 5);
+		});
+	}
+
+	#[test]
+	fn migration_v5_to_v6_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(5).put::<Scheduler>();
+
+			let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+			let bounded_call = Preimage::bound(call).unwrap();
+			let old = |i: u64| ScheduledV5Of::<Test> {
+				maybe_id: Some([i as u8; 32]),
+				priority: i as u8,
+				call: bounded_call.clone(),
+				maybe_periodic: Some((2, i as u32)),
+				origin: signed(i),
+				valid_until: Some(10 + i),
+				_phantom: Default::default(),
+			};
+			v5::Agenda::<Test>::insert(1, BoundedVec::truncate_from(vec![Some(old(1)), None]));
+			v5::Agenda::<Test>::insert(2, BoundedVec::truncate_from(vec![None, Some(old(2))]));
+			v5::Agenda::<Test>::insert(
+				3,
+				BoundedVec::truncate_from(vec![Some(old(3)), Some(old(1))]),
+			);
+			assert_eq!(TaskCount::<Test>::get(), 0);
+
+			let state = v6::MigrateToV6::<Test>::pre_upgrade().unwrap();
+			let _w = v6::MigrateToV6::<Test>::on_runtime_upgrade();
+			v6::MigrateToV6::<Test>::post_upgrade(state).unwrap();
+
+			let new = |i: u64| ScheduledOf::<Test> {
+				maybe_id: Some([i as u8; 32]),
+				priority: i as u8,
+				call: bounded_call.clone(),
+				maybe_periodic: Some((2, i as u32)),
+				origin: signed(i),
+				valid_until: Some(10 + i),
+				authorization: None,
+				_phantom: Default::default(),
+			};
+			assert_eq!(Agenda::<Test>::get(1).into_inner(), vec![Some(new(1)), None]);
+			assert_eq!(Agenda::<Test>::get(2).into_inner(), vec![None, Some(new(2))]);
+			assert_eq!(Agenda::<Test>::get(3).into_inner(), vec![Some(new(3)), Some(new(1))]);
+			assert_eq!(TaskCount::<Test>::get(), 4);
+			assert_eq!(Scheduler::origin_task_count(&signed(1)), 2);
+			assert_eq!(Scheduler::origin_task_count(&signed(2)), 1);
+			assert_eq!(Scheduler::origin_task_count(&signed(3)), 1);
+			assert_eq!(Scheduler::origin_task_count(&signed(4)), 0);
+			assert_eq!(StorageVersion::get::<Scheduler>(), 6);
+		});
+	}
+
 	fn signed(i: u64) -> OriginCaller {
 		system::RawOrigin::Signed(i).into()
 	}
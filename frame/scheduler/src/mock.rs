@@ -93,6 +93,69 @@ pub mod logger {
 	}
 }
 
+// Restrictor module, dispatching calls with a filter added to the origin like proxy does, and
+// keeping that filter for the calls it schedules.
+#[frame_support::pallet]
+pub mod restrictor {
+	use super::{DispatchAuthorization, DispatchContext, OriginTrait};
+	use frame_support::{
+		dispatch::Dispatchable, pallet_prelude::*, parameter_types, traits::Contains,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::BadOrigin;
+
+	parameter_types! {
+		static Active: bool = false;
+		pub static Revoked: bool = false;
+	}
+
+	/// The only token handed out, standing for `Config::Filter`.
+	const TOKEN: &[u8] = b"restricted";
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The filter added to the origin of the calls dispatched by this pallet.
+		type Filter: Contains<<Self as frame_system::Config>::RuntimeCall>;
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::call_index(0)]
+		#[pallet::weight(Weight::zero())]
+		pub fn dispatch_restricted(
+			mut origin: OriginFor<T>,
+			call: Box<<T as frame_system::Config>::RuntimeCall>,
+		) -> DispatchResult {
+			origin.add_filter(T::Filter::contains);
+			Active::set(true);
+			let result = call.dispatch(origin);
+			Active::set(false);
+			result.map(|_| ()).map_err(|e| e.error)
+		}
+	}
+
+	/// Restores `Config::Filter` on the calls scheduled from within `dispatch_restricted`, as
+	/// long as the restriction is not revoked.
+	pub struct Context<T>(PhantomData<T>);
+	impl<T: Config> DispatchContext<OriginFor<T>> for Context<T> {
+		fn capture(_: &OriginFor<T>) -> Option<DispatchAuthorization> {
+			Active::get().then(|| DispatchAuthorization::truncate_from(TOKEN.to_vec()))
+		}
+
+		fn restore(
+			origin: &mut OriginFor<T>,
+			authorization: &DispatchAuthorization,
+		) -> Result<(), DispatchError> {
+			ensure!(&authorization[..] == TOKEN && !Revoked::get(), BadOrigin);
+			origin.add_filter(T::Filter::contains);
+			Ok(())
+		}
+	}
+}
+
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -106,6 +169,7 @@ frame_support::construct_runtime!(
 		Logger: logger::{Pallet, Call, Event<T>},
 		Scheduler: scheduler::{Pallet, Call, Storage, Event<T>},
 		Preimage: pallet_preimage::{Pallet, Call, Storage, Event<T>},
+		Restrictor: restrictor::{Pallet, Call},
 	}
 );
 
@@ -152,6 +216,17 @@ impl system::Config for Test {
 impl logger::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 }
+
+// The filter of `restrictor`, forbidding to log numbers of 100 and above.
+pub struct RestrictedFilter;
+impl Contains<RuntimeCall> for RestrictedFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		!matches!(call, RuntimeCall::Logger(LoggerCall::log_without_filter { i, .. }) if *i >= 100)
+	}
+}
+impl restrictor::Config for Test {
+	type Filter = RestrictedFilter;
+}
 ord_parameter_types! {
 	pub const One: u64 = 1;
 }
@@ -185,6 +260,12 @@ impl WeightInfo for TestWeightInfo {
 	fn service_task_fetched(s: u32) -> Weight {
 		Weight::from_parts((s << 8) as u64 + 0b0010_0100, 0)
 	}
+	fn service_task_postponed() -> Weight {
+		Weight::from_parts(0b0010_1000, 0)
+	}
+	fn service_task_postponed_named() -> Weight {
+		Weight::from_parts(0b0011_1000, 0)
+	}
 	fn execute_dispatch_signed() -> Weight {
 		Weight::from_parts(0b0100_0000, 0)
 	}
@@ -200,13 +281,41 @@ impl WeightInfo for TestWeightInfo {
 	fn schedule_named(_s: u32) -> Weight {
 		Weight::from_parts(50, 0)
 	}
+	fn schedule_named_with_fallback(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
 	fn cancel_named(_s: u32) -> Weight {
 		Weight::from_parts(50, 0)
 	}
+	fn set_paused() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_postponement() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn reschedule_all_at(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn sweep_lookup(s: u32) -> Weight {
+		Weight::from_parts(10 * s as u64 + 5, 0)
+	}
+	fn rebuild_lookup(b: u32) -> Weight {
+		Weight::from_parts(10 * b as u64 + 5, 0)
+	}
 }
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
 		BlockWeights::get().max_block;
+	pub storage AllowCurrentBlockScheduling: bool = false;
+	pub storage MaxSchedules: u32 = 100;
+	pub storage MaxSchedulesPerOrigin: Option<u32> = None;
+	pub storage MaxRootScheduledPerBlock: u32 = 0;
+	pub storage MaxPeriodicCount: u32 = u32::MAX;
+	pub storage DedupInlineCalls: bool = false;
+	pub storage PeriodicJitter: Option<u64> = None;
+	pub storage MaxRecordedResults: u32 = 0;
+	pub storage MaxScheduleDelay: Option<u64> = None;
+	pub storage MaxReappendSearch: u32 = 0;
 }
 
 impl Config for Test {
@@ -217,6 +326,17 @@ impl Config for Test {
 	type MaximumWeight = MaximumSchedulerWeight;
 	type ScheduleOrigin = EitherOfDiverse<EnsureRoot<u64>, EnsureSignedBy<One, u64>>;
 	type MaxScheduledPerBlock = ConstU32<10>;
+	type MaxRootScheduledPerBlock = MaxRootScheduledPerBlock;
+	type MaxSchedules = MaxSchedules;
+	type MaxSchedulesPerOrigin = MaxSchedulesPerOrigin;
+	type AllowCurrentBlockScheduling = AllowCurrentBlockScheduling;
+	type MaxPeriodicCount = MaxPeriodicCount;
+	type DedupInlineCalls = DedupInlineCalls;
+	type PeriodicJitter = PeriodicJitter;
+	type MaxRecordedResults = MaxRecordedResults;
+	type MaxScheduleDelay = MaxScheduleDelay;
+	type MaxReappendSearch = MaxReappendSearch;
+	type DispatchContext = restrictor::Context<Test>;
 	type WeightInfo = TestWeightInfo;
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
@@ -240,3 +360,14 @@ pub fn run_to_block(n: u64) {
 pub fn root() -> OriginCaller {
 	system::RawOrigin::Root.into()
 }
+
+/// The pre-`PalletsOrigin` origins used by the migration tests: `3` was root and `2` was none.
+impl From<u32> for OriginCaller {
+	fn from(origin: u32) -> Self {
+		match origin {
+			3u32 => system::RawOrigin::Root.into(),
+			2u32 => system::RawOrigin::None.into(),
+			_ => unreachable!("test make no use of it"),
+		}
+	}
+}
@@ -23,7 +23,10 @@ use crate::mock::{
 };
 use frame_support::{
 	assert_err, assert_noop, assert_ok,
-	traits::{Contains, GetStorageVersion, OnInitialize, QueryPreimage, StorePreimage},
+	traits::{
+		Contains, GetStorageVersion, IntegrityTest, OnIdle, OnInitialize, QueryPreimage,
+		StorePreimage,
+	},
 	Hashable,
 };
 use sp_runtime::traits::Hash;
@@ -152,6 +155,119 @@ fn periodic_scheduling_works() {
 	});
 }
 
+#[test]
+fn periodic_rescheduling_emits_the_next_occurrence() {
+	new_test_ext().execute_with(|| {
+		// at #4, every 3 blocks, 3 times.
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			Some((3, 3)),
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(logger::Call::log {
+				i: 42,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+		let rescheduled = || {
+			System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::Scheduler(event @ crate::Event::PeriodicRescheduled { .. }) =>
+						Some(event),
+					_ => None,
+				})
+				.collect::<Vec<_>>()
+		};
+
+		run_to_block(4);
+		assert_eq!(
+			rescheduled(),
+			vec![crate::Event::PeriodicRescheduled {
+				task: (4, 0),
+				id: Some([1u8; 32]),
+				next: 7,
+				remaining: 2
+			}]
+		);
+		run_to_block(7);
+		assert_eq!(
+			rescheduled()[1..],
+			[crate::Event::PeriodicRescheduled {
+				task: (7, 0),
+				id: Some([1u8; 32]),
+				next: 10,
+				remaining: 1
+			}]
+		);
+		// The last occurrence isn't renewed.
+		run_to_block(10);
+		assert_eq!(logger::log().len(), 3);
+		assert_eq!(rescheduled().len(), 2);
+	});
+}
+
+#[test]
+fn periodic_jitter_spreads_out_aligned_tasks() {
+	new_test_ext().execute_with(|| {
+		PeriodicJitter::set(&Some(5));
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		for name in [[1u8; 32], [2u8; 32]] {
+			assert_ok!(Scheduler::do_schedule_named(
+				name,
+				DispatchTime::At(4),
+				Some((10, 10)),
+				127,
+				root(),
+				Preimage::bound(call.clone()).unwrap()
+			));
+		}
+		run_to_block(200);
+		assert_eq!(logger::log().len(), 20);
+
+		let dispatched_at = |name| {
+			System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::Scheduler(crate::Event::Dispatched { task, id, .. })
+						if id == Some(name) =>
+						Some(task.0),
+					_ => None,
+				})
+				.collect::<Vec<_>>()
+		};
+		let first = dispatched_at([1u8; 32]);
+		let second = dispatched_at([2u8; 32]);
+		assert_ne!(first, second);
+		for blocks in [first, second] {
+			assert_eq!(blocks[0], 4);
+			assert!(blocks.windows(2).all(|w| (10..15).contains(&(w[1] - w[0]))));
+		}
+	});
+}
+
+#[test]
+fn periodic_jitter_is_opt_in() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			Some((10, 3)),
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+				i: 42,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+		run_to_block(4);
+		assert_eq!(Agenda::<Test>::get(14).len(), 1);
+	});
+}
+
 #[test]
 fn reschedule_works() {
 	new_test_ext().execute_with(|| {
@@ -191,6 +307,42 @@ fn reschedule_works() {
 	});
 }
 
+#[test]
+fn reschedule_keeps_periodic_state() {
+	new_test_ext().execute_with(|| {
+		// at #4, every 3 blocks, 3 times.
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			Some((3, 3)),
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(logger::Call::log {
+				i: 42,
+				weight: Weight::from_parts(10, 0)
+			}))
+			.unwrap()
+		));
+		run_to_block(4);
+		assert_eq!(logger::log().len(), 1);
+
+		// The next occurrence moves from #7 to #8, the period and remaining count are kept.
+		assert_eq!(Scheduler::do_reschedule((7, 0), DispatchTime::At(8)).unwrap(), (8, 0));
+		assert_eq!(Agenda::<Test>::get(8)[0].as_ref().unwrap().maybe_periodic, Some((3, 1)));
+		run_to_block(7);
+		assert_eq!(logger::log().len(), 1);
+		run_to_block(8);
+		assert_eq!(logger::log().len(), 2);
+
+		// The following occurrences come from the new time.
+		run_to_block(10);
+		assert_eq!(logger::log().len(), 2);
+		run_to_block(11);
+		assert_eq!(logger::log().len(), 3);
+		run_to_block(100);
+		assert_eq!(logger::log().len(), 3);
+	});
+}
+
 #[test]
 fn reschedule_named_works() {
 	new_test_ext().execute_with(|| {
@@ -281,6 +433,115 @@ fn reschedule_named_perodic_works() {
 	});
 }
 
+#[test]
+fn reschedule_all_at_moves_a_mixed_agenda() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let log = |i| {
+			let call =
+				RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) });
+			Preimage::bound(call).unwrap()
+		};
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+		let signed_call = RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 7,
+			weight: Weight::from_parts(10, 0),
+		});
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			Some((3, 2)),
+			127,
+			root(),
+			log(42)
+		));
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), log(69)));
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), log(2)));
+		assert_ok!(Scheduler::do_cancel(None, (4, 1)));
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(6), None, 127, root(), log(1)));
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(5),
+			None,
+			127,
+			signed.clone(),
+			Preimage::bound(signed_call).unwrap()
+		));
+
+		// Each origin may only move the agendas holding nothing but tasks it may cancel.
+		assert_noop!(
+			Scheduler::reschedule_all_at(system::RawOrigin::Signed(1).into(), 4, 6),
+			BadOrigin
+		);
+		assert_noop!(Scheduler::reschedule_all_at(RuntimeOrigin::root(), 5, 6), BadOrigin);
+		assert_noop!(
+			Scheduler::reschedule_all_at(RuntimeOrigin::root(), 4, 4),
+			Error::<Test>::RescheduleNoChange
+		);
+		assert_noop!(
+			Scheduler::reschedule_all_at(RuntimeOrigin::root(), 3, 6),
+			Error::<Test>::NotFound
+		);
+
+		assert_ok!(Scheduler::reschedule_all_at(RuntimeOrigin::root(), 4, 6));
+		assert!(Agenda::<Test>::get(4).is_empty());
+		assert_eq!(Agenda::<Test>::get(6).len(), 3);
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((6, 1)));
+		System::assert_has_event(Event::Canceled { when: 4, index: 2 }.into());
+		System::assert_last_event(Event::Scheduled { when: 6, index: 2 }.into());
+		assert_ok!(Scheduler::reschedule_all_at(system::RawOrigin::Signed(1).into(), 5, 7));
+
+		run_to_block(5);
+		assert!(logger::log().is_empty());
+		run_to_block(7);
+		assert_eq!(
+			logger::log(),
+			vec![(root(), 1u32), (root(), 42u32), (root(), 2u32), (signed, 7u32)]
+		);
+		// The periodic task carries on from its new block.
+		run_to_block(9);
+		assert_eq!(logger::log().len(), 5);
+		assert_eq!(Scheduler::task_count(), 0);
+	});
+}
+
+#[test]
+fn reschedule_all_at_fails_if_the_destination_is_full() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+		for _ in 0..2 {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				bound.clone()
+			));
+		}
+		for _ in 0..9 {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(6),
+				None,
+				127,
+				root(),
+				bound.clone()
+			));
+		}
+
+		// Only one of the two tasks would fit in the ten slots, so none is moved.
+		assert_noop!(
+			Scheduler::reschedule_all_at(RuntimeOrigin::root(), 4, 6),
+			DispatchError::Exhausted
+		);
+
+		assert_ok!(Scheduler::do_cancel(None, (6, 0)));
+		assert_ok!(Scheduler::reschedule_all_at(RuntimeOrigin::root(), 4, 6));
+		assert!(Agenda::<Test>::get(4).is_empty());
+		assert_eq!(Agenda::<Test>::get(6).iter().flatten().count(), 10);
+	});
+}
+
 #[test]
 fn cancel_named_scheduling_works_with_normal_cancel() {
 	new_test_ext().execute_with(|| {
@@ -319,6 +580,157 @@ fn cancel_named_scheduling_works_with_normal_cancel() {
 	});
 }
 
+#[test]
+fn task_by_name_works() {
+	new_test_ext().execute_with(|| {
+		// at #4, every 3 blocks, 3 times.
+		Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			Some((3, 3)),
+			100,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+				i: 69,
+				weight: Weight::from_parts(10, 0),
+			}))
+			.unwrap(),
+		)
+		.unwrap();
+		// Another task takes the first slot of the same agenda.
+		Scheduler::do_schedule_named(
+			[2u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+				i: 42,
+				weight: Weight::from_parts(10, 0),
+			}))
+			.unwrap(),
+		)
+		.unwrap();
+
+		assert_eq!(
+			Scheduler::task_by_name(&[1u8; 32]),
+			Some(((4, 0), ScheduledInfo { priority: 100, maybe_periodic: Some((3, 2)) }))
+		);
+		assert_eq!(
+			Scheduler::task_by_name(&[2u8; 32]),
+			Some(((4, 1), ScheduledInfo { priority: 127, maybe_periodic: None }))
+		);
+
+		// The periodic task moves on to its next dispatch.
+		run_to_block(4);
+		assert_eq!(
+			Scheduler::task_by_name(&[1u8; 32]),
+			Some(((7, 0), ScheduledInfo { priority: 100, maybe_periodic: Some((3, 1)) }))
+		);
+		// The one-off task is gone once executed.
+		assert_eq!(Scheduler::task_by_name(&[2u8; 32]), None);
+
+		// Cancelled tasks can't be found.
+		assert_ok!(Scheduler::do_cancel_named(None, [1u8; 32]));
+		assert_eq!(Scheduler::task_by_name(&[1u8; 32]), None);
+
+		// Neither can tasks that never existed nor ids which are no valid task names.
+		assert_eq!(Scheduler::task_by_name(&[3u8; 32]), None);
+		assert_eq!(Scheduler::task_by_name(&[1u8; 31]), None);
+	});
+}
+
+#[test]
+fn agenda_accessors_skip_over_holes() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+		for (priority, maybe_periodic) in [(10, Some((3, 3))), (20, None), (30, None)] {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
+				maybe_periodic,
+				priority,
+				root(),
+				bound.clone()
+			));
+		}
+		assert_ok!(Scheduler::do_cancel(None, (4, 1)));
+
+		assert_eq!(Scheduler::agenda_len(4), 3);
+		let slots = (0..Scheduler::agenda_len(4))
+			.map(|index| Scheduler::agenda_slot(4, index))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			slots,
+			vec![
+				Some(ScheduledInfo { priority: 10, maybe_periodic: Some((3, 2)) }),
+				None,
+				Some(ScheduledInfo { priority: 30, maybe_periodic: None }),
+			]
+		);
+		assert_eq!(Scheduler::agenda_slot(4, 3), None);
+
+		// Trailing holes are trimmed.
+		assert_ok!(Scheduler::do_cancel(None, (4, 2)));
+		assert_eq!(Scheduler::agenda_len(4), 1);
+
+		// An agenda which doesn't exist is empty.
+		assert_eq!(Scheduler::agenda_len(5), 0);
+		assert_eq!(Scheduler::agenda_slot(5, 0), None);
+	});
+}
+
+#[test]
+fn scheduling_reuses_holes_only_once_the_agenda_is_full() {
+	let max_per_block = <Test as Config>::MaxScheduledPerBlock::get();
+	new_test_ext().execute_with(|| {
+		let bound = |i| {
+			Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+				i,
+				weight: Weight::from_parts(10, 0),
+			}))
+			.unwrap()
+		};
+		for i in 0..4 {
+			assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), bound(i)));
+		}
+		assert_ok!(Scheduler::do_cancel(None, (4, 2)));
+		assert_ok!(Scheduler::do_cancel(None, (4, 1)));
+
+		// The task is appended rather than put in a hole.
+		let address = Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			bound(4),
+		)
+		.unwrap();
+		assert_eq!(address, (4, 4));
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some(address));
+		for i in 5..max_per_block {
+			let address =
+				Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), bound(i)).unwrap();
+			assert_eq!(address, (4, i));
+		}
+
+		// Only once the agenda is full is the first hole taken.
+		let address =
+			Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), bound(100)).unwrap();
+		assert_eq!(address, (4, 1));
+		assert_eq!(Scheduler::agenda_len(4), max_per_block);
+
+		// Tasks of equal priority run in the order they were scheduled in, except the one put in
+		// a hole.
+		run_to_block(4);
+		let mut expected = vec![0, 100, 3];
+		expected.extend(4..max_per_block);
+		assert_eq!(logger::log().into_iter().map(|(_, i)| i).collect::<Vec<_>>(), expected);
+	});
+}
+
 #[test]
 fn cancel_named_periodic_scheduling_works() {
 	new_test_ext().execute_with(|| {
@@ -421,793 +833,2708 @@ fn scheduler_does_not_delete_permanently_overweight_call() {
 		assert_eq!(logger::log(), vec![]);
 
 		// Assert the `PermanentlyOverweight` event.
-		assert_eq!(
-			System::events().last().unwrap().event,
-			crate::Event::PermanentlyOverweight { task: (4, 0), id: None }.into(),
-		);
+		assert!(System::events().iter().any(|record| record.event ==
+			crate::Event::PermanentlyOverweight { task: (4, 0), id: None }.into()));
 		// The call is still in the agenda.
 		assert!(Agenda::<Test>::get(4)[0].is_some());
 	});
 }
 
 #[test]
-fn scheduler_handles_periodic_failure() {
+fn permanently_overweight_task_is_only_tried_once() {
 	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
-	let max_per_block = <Test as Config>::MaxScheduledPerBlock::get();
-
 	new_test_ext().execute_with(|| {
-		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: (max_weight / 3) * 2 });
-		let bound = Preimage::bound(call).unwrap();
-
-		assert_ok!(Scheduler::do_schedule(
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight });
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
 			DispatchTime::At(4),
-			Some((4, u32::MAX)),
+			None,
 			127,
 			root(),
-			bound.clone(),
+			Preimage::bound(call).unwrap(),
 		));
-		// Executes 5 times till block 20.
-		run_to_block(20);
-		assert_eq!(logger::log().len(), 5);
+		run_to_block(4);
+		System::assert_has_event(
+			crate::Event::PermanentlyOverweight { task: (4, 0), id: Some([1u8; 32]) }.into(),
+		);
 
-		// Block 28 will already be full.
-		for _ in 0..max_per_block {
+		// The agenda is not revisited in later blocks.
+		run_to_block(10);
+		let overweight = System::events()
+			.iter()
+			.filter(|record| {
+				matches!(
+					record.event,
+					RuntimeEvent::Scheduler(crate::Event::PermanentlyOverweight { .. })
+				)
+			})
+			.count();
+		assert_eq!(overweight, 1);
+		assert_eq!(IncompleteSince::<Test>::get(), None);
+		assert!(logger::log().is_empty());
+	});
+}
+
+#[test]
+fn unavailable_task_which_cannot_be_postponed_is_reported() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+		let len = call.using_encoded(|x| x.len()) as u32;
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Bounded::Lookup { hash, len }
+		));
+		// Fill the agenda of the next block, leaving no room to postpone the task to.
+		let filler =
+			RuntimeCall::Logger(LoggerCall::log { i: 69, weight: Weight::from_parts(10, 0) });
+		for _ in 0..<Test as Config>::MaxScheduledPerBlock::get() {
 			assert_ok!(Scheduler::do_schedule(
-				DispatchTime::At(28),
+				DispatchTime::At(5),
 				None,
-				120,
+				127,
 				root(),
-				bound.clone(),
+				Preimage::bound(filler.clone()).unwrap()
 			));
 		}
 
-		// Going to block 24 will emit a `PeriodicFailed` event.
-		run_to_block(24);
-		assert_eq!(logger::log().len(), 6);
-
-		assert_eq!(
-			System::events().last().unwrap().event,
-			crate::Event::PeriodicFailed { task: (24, 0), id: None }.into(),
+		run_to_block(4);
+		System::assert_has_event(
+			crate::Event::CallUnavailable { task: (4, 0), id: Some([1u8; 32]) }.into(),
 		);
+		assert!(Agenda::<Test>::get(4)[0].is_some());
+		assert!(logger::log().is_empty());
 	});
 }
 
 #[test]
-fn scheduler_handles_periodic_unavailable_preimage() {
-	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
-
+fn postponed_task_spills_to_a_later_block_when_the_next_is_full() {
 	new_test_ext().execute_with(|| {
-		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: (max_weight / 3) * 2 });
+		MaxReappendSearch::set(&1);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
 		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
 		let len = call.using_encoded(|x| x.len()) as u32;
-		// Important to use here `Bounded::Lookup` to ensure that we request the hash.
-		let bound = Bounded::Lookup { hash, len };
-		// The preimage isn't requested yet.
-		assert!(!Preimage::is_requested(&hash));
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Bounded::Lookup { hash, len }
+		));
+		let filler =
+			RuntimeCall::Logger(LoggerCall::log { i: 69, weight: Weight::from_parts(10, 0) });
+		for _ in 0..<Test as Config>::MaxScheduledPerBlock::get() {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(5),
+				None,
+				127,
+				root(),
+				Preimage::bound(filler.clone()).unwrap()
+			));
+		}
 
+		run_to_block(4);
+		// The agenda of the next block is full, the task goes to the one after.
+		System::assert_has_event(crate::Event::Scheduled { when: 6, index: 0 }.into());
+		assert!(Agenda::<Test>::get(6)[0].is_some());
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((6, 0)));
+		assert!(Agenda::<Test>::get(4).is_empty());
+	});
+}
+
+#[test]
+fn periodic_task_spills_to_a_later_block_when_the_next_is_full() {
+	let max_per_block = <Test as Config>::MaxScheduledPerBlock::get();
+	new_test_ext().execute_with(|| {
+		MaxReappendSearch::set(&2);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
 		assert_ok!(Scheduler::do_schedule(
 			DispatchTime::At(4),
-			Some((4, u32::MAX)),
+			Some((4, 3)),
 			127,
 			root(),
 			bound.clone(),
 		));
+		// The blocks of the next occurrence and the one after are full.
+		for when in [8, 9] {
+			for _ in 0..max_per_block {
+				assert_ok!(Scheduler::do_schedule(
+					DispatchTime::At(when),
+					None,
+					127,
+					root(),
+					bound.clone(),
+				));
+			}
+		}
 
-		// The preimage is requested.
-		assert!(Preimage::is_requested(&hash));
-
-		// Note the preimage.
-		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(1), call.encode()));
-
-		// Executes 1 times till block 4.
 		run_to_block(4);
-		assert_eq!(logger::log().len(), 1);
-
-		// Unnote the preimage
-		Preimage::unnote(&hash);
-
-		// Does not ever execute again.
-		run_to_block(100);
-		assert_eq!(logger::log().len(), 1);
-
-		// The preimage is not requested anymore.
-		assert!(!Preimage::is_requested(&hash));
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Scheduler(crate::Event::PeriodicRescheduled {
+				task: (4, 0),
+				next: 10,
+				..
+			})
+		)));
+		assert_eq!(Agenda::<Test>::get(10)[0].as_ref().unwrap().maybe_periodic, Some((4, 1)));
+
+		// With no free slot within the search, the task is dropped as before.
+		for _ in 1..max_per_block {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(10),
+				None,
+				127,
+				root(),
+				bound.clone(),
+			));
+		}
+		for when in [14, 15, 16] {
+			for _ in 0..max_per_block {
+				assert_ok!(Scheduler::do_schedule(
+					DispatchTime::At(when),
+					None,
+					127,
+					root(),
+					bound.clone(),
+				));
+			}
+		}
+		run_to_block(10);
+		System::assert_has_event(crate::Event::PeriodicFailed { task: (10, 0), id: None }.into());
 	});
 }
 
 #[test]
-fn scheduler_respects_priority_ordering() {
+fn postponed_task_expires_after_its_deadline() {
 	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
 	new_test_ext().execute_with(|| {
-		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight / 3 });
+		let call = |i| RuntimeCall::Logger(LoggerCall::log { i, weight: max_weight / 3 * 2 });
 		assert_ok!(Scheduler::do_schedule(
 			DispatchTime::At(4),
 			None,
-			1,
+			0,
 			root(),
-			Preimage::bound(call).unwrap(),
+			Preimage::bound(call(42)).unwrap(),
 		));
-		let call = RuntimeCall::Logger(LoggerCall::log { i: 69, weight: max_weight / 3 });
-		assert_ok!(Scheduler::do_schedule(
+		// Neither fits next to the first one, nor gets another chance in time.
+		assert_ok!(Scheduler::do_schedule_with_deadline(
 			DispatchTime::At(4),
 			None,
-			0,
+			127,
 			root(),
-			Preimage::bound(call).unwrap(),
+			Preimage::bound(call(69)).unwrap(),
+			4,
+			None,
+		));
+		// But this one can wait for a block.
+		assert_ok!(Scheduler::schedule_with_deadline(
+			RuntimeOrigin::root(),
+			4,
+			None,
+			255,
+			5,
+			Box::new(call(96)),
 		));
+		assert_eq!(Scheduler::task_count(), 3);
+
 		run_to_block(4);
-		assert_eq!(logger::log(), vec![(root(), 69u32), (root(), 42u32)]);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert_eq!(Scheduler::task_count(), 2);
+
+		run_to_block(5);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 96u32)]);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == crate::Event::Expired { task: (4, 1), id: None }.into()));
+		assert_eq!(Scheduler::task_count(), 0);
+		assert!(!Agenda::<Test>::contains_key(4));
+
+		// Nothing is left to run.
+		run_to_block(10);
+		assert_eq!(logger::log().len(), 2);
 	});
 }
 
 #[test]
-fn scheduler_respects_priority_ordering_with_soft_deadlines() {
+fn deadline_cannot_precede_the_task() {
 	new_test_ext().execute_with(|| {
-		let max_weight: Weight = <Test as Config>::MaximumWeight::get();
-		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight / 5 * 2 });
-		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_noop!(
+			Scheduler::schedule_with_deadline(
+				RuntimeOrigin::root(),
+				4,
+				None,
+				127,
+				3,
+				Box::new(call.clone())
+			),
+			Error::<Test>::InvalidDeadline
+		);
+
+		// A deadline at the scheduled block is enough for a task which fits.
+		assert_ok!(Scheduler::schedule_with_deadline(
+			RuntimeOrigin::root(),
+			4,
 			None,
-			255,
+			127,
+			4,
+			Box::new(call)
+		));
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+	});
+}
+
+#[test]
+fn pausing_holds_tasks_until_resumed() {
+	new_test_ext().execute_with(|| {
+		let call =
+			|i| RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) });
+		// at #3, every 2 blocks, 3 times.
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(3),
+			Some((2, 3)),
+			127,
 			root(),
-			Preimage::bound(call).unwrap(),
+			Preimage::bound(call(69)).unwrap()
 		));
-		let call = RuntimeCall::Logger(LoggerCall::log { i: 69, weight: max_weight / 5 * 2 });
 		assert_ok!(Scheduler::do_schedule(
 			DispatchTime::At(4),
 			None,
 			127,
 			root(),
-			Preimage::bound(call).unwrap(),
+			Preimage::bound(call(42)).unwrap()
 		));
-		let call = RuntimeCall::Logger(LoggerCall::log { i: 2600, weight: max_weight / 5 * 4 });
+		run_to_block(2);
+
+		assert_noop!(Scheduler::set_paused(RuntimeOrigin::signed(1), true), BadOrigin);
+		assert_ok!(Scheduler::set_paused(RuntimeOrigin::root(), true));
+		System::assert_last_event(crate::Event::Paused.into());
+		assert!(Scheduler::paused());
+
+		// Nothing is dispatched and the agendas are kept as they are.
+		run_to_block(10);
+		assert!(logger::log().is_empty());
+		System::assert_last_event(
+			crate::Event::AgendaProcessed {
+				block: 10,
+				dispatched: 0,
+				postponed: 0,
+				dropped: 0,
+				weight_used: <Test as frame_system::Config>::DbWeight::get().reads_writes(2, 1),
+			}
+			.into(),
+		);
+		assert_eq!(Agenda::<Test>::get(3)[0].as_ref().unwrap().maybe_periodic, Some((2, 2)));
+		assert_eq!(Agenda::<Test>::get(4).len(), 1);
+		assert_eq!(IncompleteSince::<Test>::get(), Some(3));
+
+		// Scheduling still works while paused.
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(4),
+			DispatchTime::At(11),
 			None,
-			126,
+			127,
 			root(),
-			Preimage::bound(call).unwrap(),
+			Preimage::bound(call(7)).unwrap()
 		));
 
-		// 2600 does not fit with 69 or 42, but has higher priority, so will go through
-		run_to_block(4);
-		assert_eq!(logger::log(), vec![(root(), 2600u32)]);
-		// 69 and 42 fit together
-		run_to_block(5);
-		assert_eq!(logger::log(), vec![(root(), 2600u32), (root(), 69u32), (root(), 42u32)]);
+		assert_ok!(Scheduler::set_paused(RuntimeOrigin::root(), false));
+		System::assert_last_event(crate::Event::Resumed.into());
+
+		// The overdue tasks are serviced, the periodic one carries on from there.
+		run_to_block(11);
+		assert_eq!(logger::log(), vec![(root(), 69u32), (root(), 42u32), (root(), 7u32)]);
+		assert_eq!(Agenda::<Test>::get(13)[0].as_ref().unwrap().maybe_periodic, Some((2, 1)));
+		run_to_block(100);
+		assert_eq!(logger::log().iter().filter(|(_, i)| *i == 69).count(), 3);
+		assert_eq!(IncompleteSince::<Test>::get(), None);
+		assert_eq!(Scheduler::task_count(), 0);
 	});
 }
 
 #[test]
-fn on_initialize_weight_is_correct() {
+fn agenda_processed_summarizes_each_block() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
 	new_test_ext().execute_with(|| {
-		let call_weight = Weight::from_parts(25, 0);
+		let agenda_processed = |block: u64| {
+			System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::Scheduler(e @ crate::Event::AgendaProcessed { .. }) => Some(e),
+					_ => None,
+				})
+				.find(
+					|e| matches!(e, crate::Event::AgendaProcessed { block: b, .. } if *b == block),
+				)
+				.unwrap()
+		};
 
-		// Named
-		let call = RuntimeCall::Logger(LoggerCall::log {
-			i: 3,
-			weight: call_weight + Weight::from_parts(1, 0),
-		});
-		assert_ok!(Scheduler::do_schedule_named(
-			[1u8; 32],
-			DispatchTime::At(3),
+		// The preimage of this one is never noted.
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 1, weight: Weight::from_parts(10, 0) });
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+		let len = call.using_encoded(|x| x.len()) as u32;
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
 			None,
-			255,
+			0,
 			root(),
-			Preimage::bound(call).unwrap(),
+			Bounded::Lookup { hash, len },
 		));
-		let call = RuntimeCall::Logger(LoggerCall::log {
-			i: 42,
-			weight: call_weight + Weight::from_parts(2, 0),
-		});
-		// Anon Periodic
+		// 42 and 69 do not fit together.
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight / 3 * 2 });
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(2),
-			Some((1000, 3)),
-			128,
+			DispatchTime::At(4),
+			None,
+			10,
 			root(),
 			Preimage::bound(call).unwrap(),
 		));
-		let call = RuntimeCall::Logger(LoggerCall::log {
-			i: 69,
-			weight: call_weight + Weight::from_parts(3, 0),
-		});
-		// Anon
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 69, weight: max_weight / 3 * 2 });
 		assert_ok!(Scheduler::do_schedule(
-			DispatchTime::At(2),
+			DispatchTime::At(4),
 			None,
-			127,
-			root(),
-			Preimage::bound(call).unwrap(),
-		));
-		// Named Periodic
-		let call = RuntimeCall::Logger(LoggerCall::log {
-			i: 2600,
-			weight: call_weight + Weight::from_parts(4, 0),
-		});
-		assert_ok!(Scheduler::do_schedule_named(
-			[2u8; 32],
-			DispatchTime::At(1),
-			Some((1000, 3)),
-			126,
+			20,
 			root(),
 			Preimage::bound(call).unwrap(),
 		));
 
-		// Will include the named periodic only
+		// The summary is deposited even when there is nothing to do.
+		run_to_block(3);
 		assert_eq!(
-			Scheduler::on_initialize(1),
-			TestWeightInfo::service_agendas_base() +
-				TestWeightInfo::service_agenda_base(1) +
-				<TestWeightInfo as MarginalWeightInfo>::service_task(None, true, true) +
-				TestWeightInfo::execute_dispatch_unsigned() +
-				call_weight + Weight::from_parts(4, 0)
+			agenda_processed(3),
+			crate::Event::AgendaProcessed {
+				block: 3,
+				dispatched: 0,
+				postponed: 0,
+				dropped: 0,
+				weight_used: TestWeightInfo::service_agendas_base() +
+					TestWeightInfo::service_agenda_base(0),
+			}
 		);
-		assert_eq!(IncompleteSince::<Test>::get(), None);
-		assert_eq!(logger::log(), vec![(root(), 2600u32)]);
 
-		// Will include anon and anon periodic
-		assert_eq!(
-			Scheduler::on_initialize(2),
-			TestWeightInfo::service_agendas_base() +
-				TestWeightInfo::service_agenda_base(2) +
-				<TestWeightInfo as MarginalWeightInfo>::service_task(None, false, true) +
-				TestWeightInfo::execute_dispatch_unsigned() +
-				call_weight + Weight::from_parts(3, 0) +
-				<TestWeightInfo as MarginalWeightInfo>::service_task(None, false, false) +
-				TestWeightInfo::execute_dispatch_unsigned() +
-				call_weight + Weight::from_parts(2, 0)
-		);
-		assert_eq!(IncompleteSince::<Test>::get(), None);
-		assert_eq!(logger::log(), vec![(root(), 2600u32), (root(), 69u32), (root(), 42u32)]);
-
-		// Will include named only
-		assert_eq!(
-			Scheduler::on_initialize(3),
-			TestWeightInfo::service_agendas_base() +
-				TestWeightInfo::service_agenda_base(1) +
-				<TestWeightInfo as MarginalWeightInfo>::service_task(None, true, false) +
-				TestWeightInfo::execute_dispatch_unsigned() +
-				call_weight + Weight::from_parts(1, 0)
-		);
-		assert_eq!(IncompleteSince::<Test>::get(), None);
-		assert_eq!(
-			logger::log(),
-			vec![(root(), 2600u32), (root(), 69u32), (root(), 42u32), (root(), 3u32)]
-		);
+		// 69 is postponed for weight, 1 is carried over to the next block for its preimage.
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert!(matches!(
+			agenda_processed(4),
+			crate::Event::AgendaProcessed {
+				block: 4,
+				dispatched: 1,
+				postponed: 2,
+				dropped: 0,
+				weight_used,
+			} if weight_used.ref_time() > (max_weight / 3 * 2).ref_time()
+		));
 
-		// Will contain none
-		let actual_weight = Scheduler::on_initialize(4);
-		assert_eq!(
-			actual_weight,
-			TestWeightInfo::service_agendas_base() + TestWeightInfo::service_agenda_base(0)
-		);
+		run_to_block(5);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 69u32)]);
+		assert!(matches!(
+			agenda_processed(5),
+			crate::Event::AgendaProcessed { block: 5, dispatched: 1, postponed: 1, dropped: 0, .. }
+		));
 	});
 }
 
 #[test]
-fn root_calls_works() {
+fn scheduler_handles_periodic_failure() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+	let max_per_block = <Test as Config>::MaxScheduledPerBlock::get();
+
 	new_test_ext().execute_with(|| {
-		let call = Box::new(RuntimeCall::Logger(LoggerCall::log {
-			i: 69,
-			weight: Weight::from_parts(10, 0),
-		}));
-		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log {
-			i: 42,
-			weight: Weight::from_parts(10, 0),
-		}));
-		assert_ok!(
-			Scheduler::schedule_named(RuntimeOrigin::root(), [1u8; 32], 4, None, 127, call,)
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: (max_weight / 3) * 2 });
+		let bound = Preimage::bound(call).unwrap();
+
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			Some((4, u32::MAX)),
+			127,
+			root(),
+			bound.clone(),
+		));
+		// Executes 5 times till block 20.
+		run_to_block(20);
+		assert_eq!(logger::log().len(), 5);
+
+		// Block 28 will already be full.
+		for _ in 0..max_per_block {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(28),
+				None,
+				120,
+				root(),
+				bound.clone(),
+			));
+		}
+
+		// Going to block 24 will emit a `PeriodicFailed` event.
+		run_to_block(24);
+		assert_eq!(logger::log().len(), 6);
+
+		// The block's summary follows it.
+		let events = System::events();
+		assert_eq!(
+			events[events.len() - 2].event,
+			crate::Event::PeriodicFailed { task: (24, 0), id: None }.into(),
 		);
-		assert_ok!(Scheduler::schedule(RuntimeOrigin::root(), 4, None, 127, call2));
-		run_to_block(3);
-		// Scheduled calls are in the agenda.
-		assert_eq!(Agenda::<Test>::get(4).len(), 2);
-		assert!(logger::log().is_empty());
-		assert_ok!(Scheduler::cancel_named(RuntimeOrigin::root(), [1u8; 32]));
-		assert_ok!(Scheduler::cancel(RuntimeOrigin::root(), 4, 1));
-		// Scheduled calls are made NONE, so should not effect state
-		run_to_block(100);
-		assert!(logger::log().is_empty());
 	});
 }
 
 #[test]
-fn fails_to_schedule_task_in_the_past() {
+fn scheduler_handles_periodic_unavailable_preimage() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+
 	new_test_ext().execute_with(|| {
-		run_to_block(3);
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: (max_weight / 3) * 2 });
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+		let len = call.using_encoded(|x| x.len()) as u32;
+		// Important to use here `Bounded::Lookup` to ensure that we request the hash.
+		let bound = Bounded::Lookup { hash, len };
+		// The preimage isn't requested yet.
+		assert!(!Preimage::is_requested(&hash));
 
-		let call1 = Box::new(RuntimeCall::Logger(LoggerCall::log {
-			i: 69,
-			weight: Weight::from_parts(10, 0),
-		}));
-		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log {
-			i: 42,
-			weight: Weight::from_parts(10, 0),
-		}));
-		let call3 = Box::new(RuntimeCall::Logger(LoggerCall::log {
-			i: 42,
-			weight: Weight::from_parts(10, 0),
-		}));
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			Some((4, u32::MAX)),
+			127,
+			root(),
+			bound.clone(),
+		));
 
-		assert_noop!(
-			Scheduler::schedule_named(RuntimeOrigin::root(), [1u8; 32], 2, None, 127, call1),
-			Error::<Test>::TargetBlockNumberInPast,
-		);
+		// The preimage is requested.
+		assert!(Preimage::is_requested(&hash));
 
-		assert_noop!(
-			Scheduler::schedule(RuntimeOrigin::root(), 2, None, 127, call2),
-			Error::<Test>::TargetBlockNumberInPast,
-		);
+		// Note the preimage.
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(1), call.encode()));
 
-		assert_noop!(
-			Scheduler::schedule(RuntimeOrigin::root(), 3, None, 127, call3),
-			Error::<Test>::TargetBlockNumberInPast,
-		);
+		// Executes 1 times till block 4.
+		run_to_block(4);
+		assert_eq!(logger::log().len(), 1);
+
+		// Unnote the preimage
+		Preimage::unnote(&hash);
+
+		// Does not ever execute again.
+		run_to_block(100);
+		assert_eq!(logger::log().len(), 1);
+
+		// The preimage is not requested anymore.
+		assert!(!Preimage::is_requested(&hash));
 	});
 }
 
 #[test]
-fn should_use_origin() {
+fn scheduler_drops_task_whose_preimage_has_another_length() {
 	new_test_ext().execute_with(|| {
-		let call = Box::new(RuntimeCall::Logger(LoggerCall::log {
-			i: 69,
-			weight: Weight::from_parts(10, 0),
-		}));
-		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log {
-			i: 42,
-			weight: Weight::from_parts(10, 0),
-		}));
-		assert_ok!(Scheduler::schedule_named(
-			system::RawOrigin::Signed(1).into(),
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+		let len = call.using_encoded(|x| x.len()) as u32;
+		// The resolved preimage is longer than the length the call is scheduled with.
+		let bound = Bounded::Lookup { hash, len: len - 1 };
+
+		assert_ok!(Scheduler::do_schedule_named(
 			[1u8; 32],
-			4,
+			DispatchTime::At(4),
 			None,
 			127,
-			call,
+			root(),
+			bound,
 		));
-		assert_ok!(Scheduler::schedule(system::RawOrigin::Signed(1).into(), 4, None, 127, call2,));
-		run_to_block(3);
-		// Scheduled calls are in the agenda.
-		assert_eq!(Agenda::<Test>::get(4).len(), 2);
-		assert!(logger::log().is_empty());
-		assert_ok!(Scheduler::cancel_named(system::RawOrigin::Signed(1).into(), [1u8; 32]));
-		assert_ok!(Scheduler::cancel(system::RawOrigin::Signed(1).into(), 4, 1));
-		// Scheduled calls are made NONE, so should not effect state
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(1), call.encode()));
+		assert_eq!(Scheduler::origin_task_count(&root()), 1);
+
+		// The task is dropped rather than postponed.
+		run_to_block(4);
+		assert!(System::events().iter().any(|record| record.event ==
+			crate::Event::CallUnavailable { task: (4, 0), id: Some([1u8; 32]) }.into()));
+		assert_eq!(Scheduler::agenda_slot(4, 0), None);
+		assert!(!Lookup::<Test>::contains_key([1u8; 32]));
+		assert!(!Preimage::is_requested(&hash));
+		assert_eq!(Scheduler::origin_task_count(&root()), 0);
+
 		run_to_block(100);
-		assert!(logger::log().is_empty());
+		assert_eq!(logger::log(), vec![]);
+		assert!((5..=100).all(|when| Agenda::<Test>::get(when).is_empty()));
 	});
 }
 
 #[test]
-fn should_check_origin() {
+fn scheduler_drops_task_whose_preimage_does_not_decode() {
 	new_test_ext().execute_with(|| {
-		let call = Box::new(RuntimeCall::Logger(LoggerCall::log {
-			i: 69,
-			weight: Weight::from_parts(10, 0),
-		}));
-		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log {
-			i: 42,
-			weight: Weight::from_parts(10, 0),
-		}));
-		assert_noop!(
-			Scheduler::schedule_named(
-				system::RawOrigin::Signed(2).into(),
-				[1u8; 32],
-				4,
-				None,
-				127,
-				call
-			),
-			BadOrigin
-		);
-		assert_noop!(
-			Scheduler::schedule(system::RawOrigin::Signed(2).into(), 4, None, 127, call2),
-			BadOrigin
-		);
+		let data = vec![255u8; 8];
+		let hash = <Test as frame_system::Config>::Hashing::hash(&data);
+		let bound = Bounded::Lookup { hash, len: data.len() as u32 };
+
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), bound));
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(1), data));
+
+		run_to_block(4);
+		assert!(System::events().iter().any(|record| record.event ==
+			crate::Event::CallUnavailable { task: (4, 0), id: None }.into()));
+		assert_eq!(Scheduler::agenda_slot(4, 0), None);
+		assert!(!Preimage::is_requested(&hash));
+		assert_eq!(Scheduler::origin_task_count(&root()), 0);
 	});
 }
 
 #[test]
-fn should_check_origin_for_cancel() {
+fn scheduler_respects_priority_ordering() {
+	let max_weight: Weight = <Test as Config>::MaximumWeight::get();
 	new_test_ext().execute_with(|| {
-		let call = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
-			i: 69,
-			weight: Weight::from_parts(10, 0),
-		}));
-		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
-			i: 42,
-			weight: Weight::from_parts(10, 0),
-		}));
-		assert_ok!(Scheduler::schedule_named(
-			system::RawOrigin::Signed(1).into(),
-			[1u8; 32],
-			4,
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight / 3 });
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
 			None,
-			127,
-			call,
+			1,
+			root(),
+			Preimage::bound(call).unwrap(),
 		));
-		assert_ok!(Scheduler::schedule(system::RawOrigin::Signed(1).into(), 4, None, 127, call2,));
-		run_to_block(3);
-		// Scheduled calls are in the agenda.
-		assert_eq!(Agenda::<Test>::get(4).len(), 2);
-		assert!(logger::log().is_empty());
-		assert_noop!(
-			Scheduler::cancel_named(system::RawOrigin::Signed(2).into(), [1u8; 32]),
-			BadOrigin
-		);
-		assert_noop!(Scheduler::cancel(system::RawOrigin::Signed(2).into(), 4, 1), BadOrigin);
-		assert_noop!(Scheduler::cancel_named(system::RawOrigin::Root.into(), [1u8; 32]), BadOrigin);
-		assert_noop!(Scheduler::cancel(system::RawOrigin::Root.into(), 4, 1), BadOrigin);
-		run_to_block(5);
-		assert_eq!(
-			logger::log(),
-			vec![
-				(system::RawOrigin::Signed(1).into(), 69u32),
-				(system::RawOrigin::Signed(1).into(), 42u32)
-			]
-		);
-	});
-}
-
-#[test]
-fn migration_to_v4_works() {
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 69, weight: max_weight / 3 });
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			0,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 69u32), (root(), 42u32)]);
+	});
+}
+
+#[test]
+fn equal_priority_tasks_run_in_scheduling_order() {
 	new_test_ext().execute_with(|| {
-		for i in 0..3u64 {
-			let k = i.twox_64_concat();
-			let old = vec![
-				Some(ScheduledV1 {
-					maybe_id: None,
-					priority: i as u8 + 10,
-					call: RuntimeCall::Logger(LoggerCall::log {
-						i: 96,
-						weight: Weight::from_parts(100, 0),
-					}),
-					maybe_periodic: None,
-				}),
+		for (i, priority) in [(1, 50), (2, 50), (3, 10), (4, 50), (5, 10), (6, 50)] {
+			let call =
+				RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) });
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
 				None,
-				Some(ScheduledV1 {
-					maybe_id: Some(b"test".to_vec()),
-					priority: 123,
-					call: RuntimeCall::Logger(LoggerCall::log {
-						i: 69,
-						weight: Weight::from_parts(10, 0),
-					}),
-					maybe_periodic: Some((456u64, 10)),
-				}),
-			];
-			frame_support::migration::put_storage_value(b"Scheduler", b"Agenda", &k, old);
+				priority,
+				root(),
+				Preimage::bound(call).unwrap(),
+			));
 		}
+		run_to_block(4);
+		let order: Vec<u32> = logger::log().into_iter().map(|(_, i)| i).collect();
+		assert_eq!(order, vec![3, 5, 1, 2, 4, 6]);
+	});
+}
 
-		Scheduler::migrate_v1_to_v4();
+#[test]
+fn scheduler_respects_priority_ordering_with_soft_deadlines() {
+	new_test_ext().execute_with(|| {
+		let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight / 5 * 2 });
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			255,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 69, weight: max_weight / 5 * 2 });
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 2600, weight: max_weight / 5 * 4 });
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			126,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
 
-		let mut x = Agenda::<Test>::iter().map(|x| (x.0, x.1.into_inner())).collect::<Vec<_>>();
-		x.sort_by_key(|x| x.0);
-		let expected = vec![
-			(
-				0,
-				vec![
-					Some(ScheduledOf::<Test> {
-						maybe_id: None,
-						priority: 10,
-						call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
-							i: 96,
-							weight: Weight::from_parts(100, 0),
-						}))
-						.unwrap(),
-						maybe_periodic: None,
-						origin: root(),
-						_phantom: PhantomData::<u64>::default(),
-					}),
-					None,
-					Some(ScheduledOf::<Test> {
-						maybe_id: Some(blake2_256(&b"test"[..])),
-						priority: 123,
-						call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
-							i: 69,
-							weight: Weight::from_parts(10, 0),
-						}))
-						.unwrap(),
-						maybe_periodic: Some((456u64, 10)),
-						origin: root(),
-						_phantom: PhantomData::<u64>::default(),
-					}),
-				],
-			),
-			(
+		// 2600 does not fit with 69 or 42, but has higher priority, so will go through
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 2600u32)]);
+		// 69 and 42 fit together
+		run_to_block(5);
+		assert_eq!(logger::log(), vec![(root(), 2600u32), (root(), 69u32), (root(), 42u32)]);
+	});
+}
+
+#[test]
+fn on_initialize_weight_is_correct() {
+	new_test_ext().execute_with(|| {
+		let call_weight = Weight::from_parts(25, 0);
+
+		// Named
+		let call = RuntimeCall::Logger(LoggerCall::log {
+			i: 3,
+			weight: call_weight + Weight::from_parts(1, 0),
+		});
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(3),
+			None,
+			255,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		let call = RuntimeCall::Logger(LoggerCall::log {
+			i: 42,
+			weight: call_weight + Weight::from_parts(2, 0),
+		});
+		// Anon Periodic
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(2),
+			Some((1000, 3)),
+			128,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		let call = RuntimeCall::Logger(LoggerCall::log {
+			i: 69,
+			weight: call_weight + Weight::from_parts(3, 0),
+		});
+		// Anon
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(2),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+		// Named Periodic
+		let call = RuntimeCall::Logger(LoggerCall::log {
+			i: 2600,
+			weight: call_weight + Weight::from_parts(4, 0),
+		});
+		assert_ok!(Scheduler::do_schedule_named(
+			[2u8; 32],
+			DispatchTime::At(1),
+			Some((1000, 3)),
+			126,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+
+		// Will include the named periodic only
+		assert_eq!(
+			Scheduler::on_initialize(1),
+			TestWeightInfo::service_agendas_base() +
+				TestWeightInfo::service_agenda_base(1) +
+				<TestWeightInfo as MarginalWeightInfo>::service_task(None, true, true) +
+				TestWeightInfo::execute_dispatch_unsigned() +
+				call_weight + Weight::from_parts(4, 0)
+		);
+		assert_eq!(IncompleteSince::<Test>::get(), None);
+		assert_eq!(logger::log(), vec![(root(), 2600u32)]);
+
+		// Will include anon and anon periodic
+		assert_eq!(
+			Scheduler::on_initialize(2),
+			TestWeightInfo::service_agendas_base() +
+				TestWeightInfo::service_agenda_base(2) +
+				<TestWeightInfo as MarginalWeightInfo>::service_task(None, false, true) +
+				TestWeightInfo::execute_dispatch_unsigned() +
+				call_weight + Weight::from_parts(3, 0) +
+				<TestWeightInfo as MarginalWeightInfo>::service_task(None, false, false) +
+				TestWeightInfo::execute_dispatch_unsigned() +
+				call_weight + Weight::from_parts(2, 0)
+		);
+		assert_eq!(IncompleteSince::<Test>::get(), None);
+		assert_eq!(logger::log(), vec![(root(), 2600u32), (root(), 69u32), (root(), 42u32)]);
+
+		// Will include named only
+		assert_eq!(
+			Scheduler::on_initialize(3),
+			TestWeightInfo::service_agendas_base() +
+				TestWeightInfo::service_agenda_base(1) +
+				<TestWeightInfo as MarginalWeightInfo>::service_task(None, true, false) +
+				TestWeightInfo::execute_dispatch_unsigned() +
+				call_weight + Weight::from_parts(1, 0)
+		);
+		assert_eq!(IncompleteSince::<Test>::get(), None);
+		assert_eq!(
+			logger::log(),
+			vec![(root(), 2600u32), (root(), 69u32), (root(), 42u32), (root(), 3u32)]
+		);
+
+		// Will contain none
+		let actual_weight = Scheduler::on_initialize(4);
+		assert_eq!(
+			actual_weight,
+			TestWeightInfo::service_agendas_base() + TestWeightInfo::service_agenda_base(0)
+		);
+	});
+}
+
+#[test]
+fn service_task_weight_is_the_sum_of_its_features() {
+	type W = TestWeightInfo;
+	// Every feature of `TestWeightInfo` has a weight of its own bit on top of the base one.
+	let base = 0b0000_0100;
+	let (named, periodic) = (0b0001_0000, 0b0000_1000);
+	let fetched = |len: u64| (len << 8) + 0b0010_0000;
+	for maybe_len in [None, Some(0), Some(3)] {
+		for (is_named, is_periodic) in [(false, false), (true, false), (false, true), (true, true)]
+		{
+			let mut expected = base;
+			if let Some(len) = maybe_len {
+				expected += fetched(len as u64);
+			}
+			if is_named {
+				expected += named;
+			}
+			if is_periodic {
+				expected += periodic;
+			}
+			assert_eq!(
+				<W as MarginalWeightInfo>::service_task(maybe_len, is_named, is_periodic),
+				Weight::from_parts(expected, 0),
+			);
+		}
+	}
+
+	// The benchmarked weights of the single features are kept as they are, except that a
+	// feature never brings the weight below the base one.
+	type S = crate::weights::SubstrateWeight<Test>;
+	let service_task = <S as MarginalWeightInfo>::service_task;
+	assert_eq!(service_task(None, false, false), S::service_task_base());
+	assert_eq!(service_task(Some(3), false, false), S::service_task_fetched(3));
+	assert_eq!(
+		service_task(None, true, false),
+		S::service_task_named().max(S::service_task_base())
+	);
+	assert_eq!(
+		service_task(None, false, true),
+		S::service_task_periodic().max(S::service_task_base())
+	);
+	assert_eq!(
+		service_task(Some(3), true, true),
+		S::service_task_fetched(3) +
+			<S as MarginalWeightInfo>::named_task_marginal() +
+			<S as MarginalWeightInfo>::periodic_task_marginal()
+	);
+}
+
+#[test]
+fn postponing_a_task_uses_the_postponed_weight() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+		let len = call.using_encoded(|x| x.len()) as u32;
+		let hashed = Bounded::Lookup { hash, len };
+
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), hashed.clone()));
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(5),
+			None,
+			127,
+			root(),
+			hashed
+		));
+		run_to_block(3);
+
+		assert_eq!(
+			Scheduler::on_initialize(4),
+			TestWeightInfo::service_agendas_base() +
+				TestWeightInfo::service_agenda_base(1) +
+				<TestWeightInfo as MarginalWeightInfo>::item_postponed(false)
+		);
+		// The named task has its `Lookup` entry moved as well.
+		assert_eq!(
+			Scheduler::on_initialize(5),
+			TestWeightInfo::service_agendas_base() +
+				TestWeightInfo::service_agenda_base(2) +
+				<TestWeightInfo as MarginalWeightInfo>::item_postponed(false) +
+				<TestWeightInfo as MarginalWeightInfo>::item_postponed(true)
+		);
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((6, 0)));
+		assert!(logger::log().is_empty());
+	});
+}
+
+#[test]
+fn postponement_override_takes_precedence_until_cleared() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+		let len = call.using_encoded(|x| x.len()) as u32;
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Bounded::Lookup { hash, len }
+		));
+
+		assert_noop!(Scheduler::set_postponement(RuntimeOrigin::signed(1), Some(3)), BadOrigin);
+		assert_noop!(
+			Scheduler::set_postponement(RuntimeOrigin::root(), Some(0)),
+			Error::<Test>::ZeroPostponement
+		);
+		assert_ok!(Scheduler::set_postponement(RuntimeOrigin::root(), Some(3)));
+		System::assert_last_event(Event::PostponementSet { blocks: Some(3) }.into());
+		assert_eq!(Scheduler::postponement(), 3);
+
+		// The missing preimage postpones the task by the overridden number of blocks.
+		run_to_block(4);
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((7, 0)));
+
+		// Once cleared, the default of a single block applies again.
+		assert_ok!(Scheduler::set_postponement(RuntimeOrigin::root(), None));
+		System::assert_last_event(Event::PostponementSet { blocks: None }.into());
+		assert_eq!(PostponementOverride::<Test>::get(), None);
+		run_to_block(7);
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((8, 0)));
+
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(0), call.encode()));
+		run_to_block(8);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+	});
+}
+
+#[test]
+fn root_calls_works() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 69,
+			weight: Weight::from_parts(10, 0),
+		}));
+		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+		assert_ok!(
+			Scheduler::schedule_named(RuntimeOrigin::root(), [1u8; 32], 4, None, 127, call,)
+		);
+		assert_ok!(Scheduler::schedule(RuntimeOrigin::root(), 4, None, 127, call2));
+		run_to_block(3);
+		// Scheduled calls are in the agenda.
+		assert_eq!(Agenda::<Test>::get(4).len(), 2);
+		assert!(logger::log().is_empty());
+		assert_ok!(Scheduler::cancel_named(RuntimeOrigin::root(), [1u8; 32]));
+		assert_ok!(Scheduler::cancel(RuntimeOrigin::root(), 4, 1));
+		// Scheduled calls are made NONE, so should not effect state
+		run_to_block(100);
+		assert!(logger::log().is_empty());
+	});
+}
+
+#[test]
+fn schedule_named_or_replace_replaces_existing_task() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let call = |i| {
+			Box::new(RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) }))
+		};
+
+		// every 2 blocks, forever.
+		assert_ok!(Scheduler::schedule_named(
+			RuntimeOrigin::root(),
+			[1u8; 32],
+			4,
+			Some((2, u32::MAX)),
+			127,
+			call(69)
+		));
+		// A duplicate fails with the plain calls.
+		assert_noop!(
+			Scheduler::schedule_named(RuntimeOrigin::root(), [1u8; 32], 5, None, 127, call(42)),
+			Error::<Test>::FailedToSchedule
+		);
+		assert_noop!(
+			Scheduler::schedule_named_after(
+				RuntimeOrigin::root(),
+				[1u8; 32],
+				4,
+				None,
+				127,
+				call(42)
+			),
+			Error::<Test>::FailedToSchedule
+		);
+		assert_eq!(Scheduler::task_count(), 1);
+
+		// A replacement on an unused name is a plain schedule.
+		assert_ok!(Scheduler::schedule_named_or_replace(
+			RuntimeOrigin::root(),
+			[2u8; 32],
+			6,
+			None,
+			127,
+			call(7)
+		));
+		System::assert_last_event(crate::Event::Scheduled { when: 6, index: 0 }.into());
+
+		assert_ok!(Scheduler::schedule_named_after_or_replace(
+			RuntimeOrigin::root(),
+			[1u8; 32],
+			4,
+			None,
+			127,
+			call(42)
+		));
+		let events = System::events();
+		assert_eq!(
+			events[events.len() - 2].event,
+			crate::Event::Canceled { when: 4, index: 0 }.into()
+		);
+		System::assert_last_event(crate::Event::Scheduled { when: 6, index: 1 }.into());
+		assert_eq!(Scheduler::task_count(), 2);
+
+		// Only the replacement runs, the periodic chain is gone.
+		run_to_block(100);
+		assert_eq!(logger::log(), vec![(root(), 7u32), (root(), 42u32)]);
+		assert_eq!(Scheduler::task_count(), 0);
+	});
+}
+
+#[test]
+fn schedule_named_or_replace_cannot_replace_a_task_of_another_origin() {
+	new_test_ext().execute_with(|| {
+		let call = |i| {
+			Box::new(RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) }))
+		};
+		assert_ok!(Scheduler::schedule_named(
+			RuntimeOrigin::root(),
+			[1u8; 32],
+			4,
+			None,
+			127,
+			call(69)
+		));
+		assert_noop!(
+			Scheduler::schedule_named_or_replace(
+				system::RawOrigin::Signed(1).into(),
+				[1u8; 32],
+				4,
+				None,
+				127,
+				call(42)
+			),
+			BadOrigin
+		);
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 69u32)]);
+	});
+}
+
+#[test]
+fn fails_to_schedule_task_in_the_past() {
+	new_test_ext().execute_with(|| {
+		run_to_block(3);
+
+		let call1 = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 69,
+			weight: Weight::from_parts(10, 0),
+		}));
+		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+		let call3 = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+
+		assert_noop!(
+			Scheduler::schedule_named(RuntimeOrigin::root(), [1u8; 32], 2, None, 127, call1),
+			Error::<Test>::TargetBlockNumberInPast,
+		);
+
+		assert_noop!(
+			Scheduler::schedule(RuntimeOrigin::root(), 2, None, 127, call2),
+			Error::<Test>::TargetBlockNumberInPast,
+		);
+
+		assert_noop!(
+			Scheduler::schedule(RuntimeOrigin::root(), 3, None, 127, call3),
+			Error::<Test>::TargetBlockNumberInPast,
+		);
+	});
+}
+
+#[test]
+fn scheduling_at_current_block_is_rejected_by_default() {
+	new_test_ext().execute_with(|| {
+		run_to_block(3);
+
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_noop!(
+			Scheduler::do_schedule(
+				DispatchTime::At(3),
+				None,
+				127,
+				root(),
+				Preimage::bound(call.clone()).unwrap()
+			),
+			Error::<Test>::TargetBlockNumberInPast,
+		);
+		// The next block is the earliest accepted target.
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap()
+		));
+		assert!(Agenda::<Test>::get(3).is_empty());
+		assert_eq!(Agenda::<Test>::get(4).len(), 1);
+	});
+}
+
+#[test]
+fn scheduling_at_current_block_is_deferred_when_allowed() {
+	new_test_ext().execute_with(|| {
+		AllowCurrentBlockScheduling::set(&true);
+		run_to_block(3);
+
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		// Blocks further in the past are still rejected.
+		assert_noop!(
+			Scheduler::do_schedule(
+				DispatchTime::At(1),
+				None,
+				127,
+				root(),
+				Preimage::bound(call.clone()).unwrap()
+			),
+			Error::<Test>::TargetBlockNumberInPast
+		);
+		// Both the current and the previous block end up in the next block.
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(3),
+			None,
+			127,
+			root(),
+			Preimage::bound(call.clone()).unwrap()
+		));
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(2),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap()
+		));
+		assert!(Agenda::<Test>::get(3).is_empty());
+		assert_eq!(Agenda::<Test>::get(4).len(), 2);
+
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 42u32)]);
+	});
+}
+
+#[test]
+fn scheduling_is_bounded_by_max_schedule_delay() {
+	new_test_ext().execute_with(|| {
+		MaxScheduleDelay::set(&Some(10));
+		run_to_block(3);
+
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+		// `At` may target up to `now + MaxScheduleDelay`.
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(13), None, 127, root(), bound.clone()));
+		assert_noop!(
+			Scheduler::do_schedule(DispatchTime::At(14), None, 127, root(), bound.clone()),
+			Error::<Test>::ScheduleTooFarOut,
+		);
+		// `After(x)` targets `now + x + 1`.
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::After(9),
+			None,
+			127,
+			root(),
+			bound.clone()
+		));
+		assert_noop!(
+			Scheduler::do_schedule(DispatchTime::After(10), None, 127, root(), bound.clone()),
+			Error::<Test>::ScheduleTooFarOut,
+		);
+		assert_eq!(Agenda::<Test>::get(13).len(), 2);
+
+		// Without a bound, any block is accepted.
+		MaxScheduleDelay::set(&None);
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(1_000),
+			None,
+			127,
+			root(),
+			bound.clone()
+		));
+		assert_ok!(Scheduler::do_schedule(DispatchTime::After(1_000), None, 127, root(), bound));
+	});
+}
+
+#[test]
+fn schedule_best_effort_uses_next_free_block() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let max: u32 = <Test as Config>::MaxScheduledPerBlock::get();
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call.clone()).unwrap();
+
+		// Fill up the agenda of #4.
+		for _ in 0..max {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				bound.clone()
+			));
+		}
+
+		// Without searching, a full agenda is an error.
+		assert_noop!(
+			Scheduler::schedule_best_effort(
+				RuntimeOrigin::root(),
+				4,
+				None,
+				127,
+				Box::new(call.clone()),
+				0
+			),
+			DispatchError::Exhausted
+		);
+
+		// With searching, the next free block is used.
+		assert_ok!(Scheduler::schedule_best_effort(
+			RuntimeOrigin::root(),
+			4,
+			None,
+			127,
+			Box::new(call),
+			2
+		));
+		System::assert_last_event(crate::Event::Scheduled { when: 5, index: 0 }.into());
+		assert_eq!(Agenda::<Test>::get(4).len() as u32, max);
+		assert_eq!(Agenda::<Test>::get(5).len(), 1);
+
+		run_to_block(5);
+		assert_eq!(logger::log().len() as u32, max + 1);
+	});
+}
+
+#[test]
+fn root_can_exceed_max_scheduled_per_block() {
+	new_test_ext().execute_with(|| {
+		MaxRootScheduledPerBlock::set(&2);
+		let max: u32 = <Test as Config>::MaxScheduledPerBlock::get();
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+
+		// Fill up the agenda of #4 with user tasks.
+		for _ in 0..max {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
+				None,
+				127,
+				signed.clone(),
+				bound.clone()
+			));
+		}
+		assert_noop!(
+			Scheduler::do_schedule(DispatchTime::At(4), None, 127, signed.clone(), bound.clone()),
+			DispatchError::Exhausted
+		);
+
+		// Root still gets in, up to its own bound.
+		assert_eq!(
+			Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), bound.clone()),
+			Ok((4, max))
+		);
+		// ... which users can't use, even once a slot is free.
+		assert_noop!(
+			Scheduler::do_schedule(DispatchTime::At(4), None, 127, signed.clone(), bound.clone()),
+			DispatchError::Exhausted
+		);
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			bound.clone()
+		));
+		assert_noop!(
+			Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), bound.clone()),
+			DispatchError::Exhausted
+		);
+		assert_eq!(Agenda::<Test>::get(4).len() as u32, max + 2);
+
+		// A slot freed by a user task can be taken by a user again.
+		assert_ok!(Scheduler::do_cancel(None, (4, 0)));
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			signed.clone(),
+			bound.clone()
+		));
+	});
+}
+
+#[test]
+fn try_state_detects_desynced_storage() {
+	new_test_ext().execute_with(|| {
+		MaxRootScheduledPerBlock::set(&1);
+		let max: u32 = <Test as Config>::MaxScheduledPerBlock::get();
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+
+		for _ in 0..max {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
+				None,
+				127,
+				signed.clone(),
+				bound.clone()
+			));
+		}
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			bound.clone()
+		));
+		assert_ok!(Scheduler::do_try_state());
+
+		// A `Lookup` entry pointing at a task of another name.
+		Lookup::<Test>::insert([1u8; 32], (4, 0));
+		assert!(Scheduler::do_try_state().is_err());
+		Lookup::<Test>::insert([1u8; 32], (4, max));
+		assert_ok!(Scheduler::do_try_state());
+
+		// A `Lookup` entry pointing at an empty slot.
+		Lookup::<Test>::insert([2u8; 32], (5, 0));
+		assert!(Scheduler::do_try_state().is_err());
+		Lookup::<Test>::remove([2u8; 32]);
+		assert_ok!(Scheduler::do_try_state());
+
+		// A user task sneaking into the room reserved for root.
+		Agenda::<Test>::mutate(4, |agenda| {
+			agenda[max as usize].as_mut().unwrap().origin = signed.clone();
+		});
+		assert!(Scheduler::do_try_state().is_err());
+	});
+}
+
+#[test]
+fn periodic_scheduling_respects_limits() {
+	new_test_ext().execute_with(|| {
+		MaxPeriodicCount::set(&5);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+
+		// One repetition over the limit.
+		assert_noop!(
+			Scheduler::do_schedule(DispatchTime::At(4), Some((3, 6)), 127, root(), bound.clone()),
+			Error::<Test>::PeriodTooLong
+		);
+		assert_noop!(
+			Scheduler::do_schedule_named(
+				[1u8; 32],
+				DispatchTime::At(4),
+				Some((3, 6)),
+				127,
+				root(),
+				bound.clone()
+			),
+			Error::<Test>::PeriodTooLong
+		);
+		// A zero period is rejected, even for a single run.
+		assert_noop!(
+			Scheduler::do_schedule(DispatchTime::At(4), Some((0, 3)), 127, root(), bound.clone()),
+			Error::<Test>::ZeroPeriod
+		);
+		assert_noop!(
+			Scheduler::do_schedule_named(
+				[1u8; 32],
+				DispatchTime::At(4),
+				Some((0, 1)),
+				127,
+				root(),
+				bound.clone()
+			),
+			Error::<Test>::ZeroPeriod
+		);
+
+		// Exactly at the limit is fine.
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			Some((1, 5)),
+			127,
+			root(),
+			bound.clone()
+		));
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			Some((1, 5)),
+			127,
+			root(),
+			bound
+		));
+		run_to_block(100);
+		assert_eq!(logger::log().len(), 10);
+	});
+}
+
+#[test]
+fn scheduling_respects_max_schedules() {
+	new_test_ext().execute_with(|| {
+		MaxSchedules::set(&3);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+
+		// at #4, every 3 blocks, 3 times.
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			Some((3, 3)),
+			127,
+			root(),
+			bound.clone()
+		));
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(5), None, 127, root(), bound.clone()));
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(6),
+			None,
+			127,
+			root(),
+			bound.clone()
+		));
+		assert_eq!(Scheduler::task_count(), 3);
+
+		// The limit is global, not per block.
+		assert_noop!(
+			Scheduler::do_schedule(DispatchTime::At(10), None, 127, root(), bound.clone()),
+			Error::<Test>::TooManySchedules
+		);
+		assert_noop!(
+			Scheduler::do_schedule_named(
+				[2u8; 32],
+				DispatchTime::At(10),
+				None,
+				127,
+				root(),
+				bound.clone()
+			),
+			Error::<Test>::TooManySchedules
+		);
+
+		// The periodic task carries on while the limit is reached.
+		run_to_block(4);
+		assert_eq!(logger::log().len(), 1);
+		assert_eq!(Scheduler::task_count(), 3);
+		assert_eq!(Agenda::<Test>::get(7).len(), 1);
+
+		// Dispatching a one-off task frees a slot.
+		run_to_block(5);
+		assert_eq!(Scheduler::task_count(), 2);
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(10), None, 127, root(), bound.clone()));
+		assert_noop!(
+			Scheduler::do_schedule(DispatchTime::At(10), None, 127, root(), bound.clone()),
+			Error::<Test>::TooManySchedules
+		);
+
+		// So does cancelling one.
+		assert_ok!(Scheduler::do_cancel_named(None, [1u8; 32]));
+		assert_eq!(Scheduler::task_count(), 2);
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(10), None, 127, root(), bound));
+		assert_eq!(Scheduler::task_count(), 3);
+
+		// The periodic task is gone after its last run.
+		run_to_block(10);
+		assert_eq!(logger::log().len(), 6);
+		assert_eq!(Scheduler::task_count(), 0);
+	});
+}
+
+#[test]
+fn reappended_tasks_are_shed_above_max_schedules() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let log = |i| {
+			let call =
+				RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) });
+			Preimage::bound(call).unwrap()
+		};
+		// at #4, every 3 blocks, 3 times, with priorities 30, 10 and 20.
+		for (i, priority) in [(30, 30), (10, 10), (20, 20)] {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
+				Some((3, 3)),
+				priority,
+				root(),
+				log(i)
+			));
+		}
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(7), None, 200, root(), log(200)));
+		assert_eq!(Scheduler::task_count(), 4);
+
+		// The limit is lowered below the number of existing tasks.
+		MaxSchedules::set(&2);
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 10), (root(), 20), (root(), 30)]);
+		// The first renewal replaces the lower priority task at #7, the second sheds itself.
+		System::assert_has_event(Event::Dropped { task: (7, 0), id: None }.into());
+		System::assert_has_event(Event::Dropped { task: (4, 2), id: None }.into());
+		System::assert_has_event(
+			Event::PeriodicRescheduled { task: (4, 1), id: None, next: 7, remaining: 2 }.into(),
+		);
+		assert_eq!(Scheduler::task_count(), 2);
+		assert_eq!(
+			Agenda::<Test>::get(7).iter().flatten().map(|t| t.priority).collect::<Vec<_>>(),
+			vec![10, 30]
+		);
+
+		// Once within the limit, the remaining tasks carry on.
+		run_to_block(10);
+		assert_eq!(logger::log().len(), 7);
+		assert_eq!(Scheduler::task_count(), 0);
+	});
+}
+
+#[test]
+fn scheduling_respects_max_schedules_per_origin() {
+	new_test_ext().execute_with(|| {
+		MaxSchedulesPerOrigin::set(&Some(2));
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), bound.clone()));
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(5),
+			None,
+			127,
+			root(),
+			bound.clone()
+		));
+		assert_eq!(Scheduler::origin_task_count(&root()), 2);
+
+		// Root has exhausted its quota.
+		assert_noop!(
+			Scheduler::do_schedule(DispatchTime::At(6), None, 127, root(), bound.clone()),
+			Error::<Test>::TooManyOriginSchedules
+		);
+		assert_noop!(
+			Scheduler::do_schedule_named(
+				[2u8; 32],
+				DispatchTime::At(6),
+				None,
+				127,
+				root(),
+				bound.clone()
+			),
+			Error::<Test>::TooManyOriginSchedules
+		);
+
+		// Another origin can still schedule.
+		assert_eq!(Scheduler::origin_task_count(&signed), 0);
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(6),
+			None,
+			127,
+			signed.clone(),
+			bound.clone()
+		));
+		assert_eq!(Scheduler::origin_task_count(&signed), 1);
+		assert_eq!(Scheduler::task_count(), 3);
+
+		// Cancelling or dispatching a task gives the room back to its origin.
+		assert_ok!(Scheduler::do_cancel_named(None, [1u8; 32]));
+		assert_eq!(Scheduler::origin_task_count(&root()), 1);
+		run_to_block(4);
+		assert_eq!(Scheduler::origin_task_count(&root()), 0);
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(7), None, 127, root(), bound.clone()));
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(8), None, 127, root(), bound));
+		assert_eq!(Scheduler::origin_task_count(&root()), 2);
+
+		run_to_block(8);
+		assert_eq!(logger::log().len(), 3);
+		assert_eq!(Scheduler::origin_task_count(&root()), 0);
+		assert_eq!(Scheduler::origin_task_count(&signed), 0);
+		assert_eq!(PerOriginCount::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn should_use_origin() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 69,
+			weight: Weight::from_parts(10, 0),
+		}));
+		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+		assert_ok!(Scheduler::schedule_named(
+			system::RawOrigin::Signed(1).into(),
+			[1u8; 32],
+			4,
+			None,
+			127,
+			call,
+		));
+		assert_ok!(Scheduler::schedule(system::RawOrigin::Signed(1).into(), 4, None, 127, call2,));
+		run_to_block(3);
+		// Scheduled calls are in the agenda.
+		assert_eq!(Agenda::<Test>::get(4).len(), 2);
+		assert!(logger::log().is_empty());
+		assert_ok!(Scheduler::cancel_named(system::RawOrigin::Signed(1).into(), [1u8; 32]));
+		assert_ok!(Scheduler::cancel(system::RawOrigin::Signed(1).into(), 4, 1));
+		// Scheduled calls are made NONE, so should not effect state
+		run_to_block(100);
+		assert!(logger::log().is_empty());
+	});
+}
+
+#[test]
+fn dispatched_event_reports_origin() {
+	new_test_ext().execute_with(|| {
+		let call = RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		});
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call.clone()).unwrap()
+		));
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			signed.clone(),
+			Preimage::bound(call).unwrap()
+		));
+
+		run_to_block(4);
+		let dispatched = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::Scheduler(crate::Event::Dispatched {
+					task,
+					id,
+					origin,
+					result,
+					..
+				}) => Some((task, id, origin, result)),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(
+			dispatched,
+			vec![((4, 0), None, root(), Ok(())), ((4, 1), Some([1u8; 32]), signed, Ok(())),]
+		);
+	});
+}
+
+#[test]
+fn identical_inline_calls_are_stored_once() {
+	new_test_ext().execute_with(|| {
+		DedupInlineCalls::set(&true);
+		let call = RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		});
+		let bounded = Preimage::bound(call.clone()).unwrap();
+		assert!(matches!(bounded, Bounded::Inline(_)));
+
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), bounded.clone()));
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(5),
+			None,
+			127,
+			root(),
+			bounded.clone()
+		));
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(6), None, 127, root(), bounded));
+
+		// The blob is stored once and the tasks only reference it.
+		let shared = InlineCallDedup::<Test>::iter().collect::<Vec<_>>();
+		assert_eq!(shared.len(), 1);
+		let (hash, (data, refs)) = shared[0].clone();
+		assert_eq!(refs, 3);
+		assert_eq!(data.to_vec(), call.encode());
+		assert!(matches!(
+			Agenda::<Test>::get(4)[0].as_ref().unwrap().call,
+			Bounded::Lookup { hash: h, .. } if h == hash
+		));
+		assert!(!Preimage::is_requested(&hash));
+
+		// Dispatching and canceling release the references.
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert_eq!(InlineCallDedup::<Test>::get(hash).unwrap().1, 2);
+		assert_ok!(Scheduler::do_cancel_named(None, [1u8; 32]));
+		assert_eq!(InlineCallDedup::<Test>::get(hash).unwrap().1, 1);
+		run_to_block(6);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 42u32)]);
+		assert_eq!(InlineCallDedup::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn inline_calls_are_not_shared_by_default() {
+	new_test_ext().execute_with(|| {
+		let call = RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		});
+		let bounded = Preimage::bound(call).unwrap();
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), bounded.clone()));
+		assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), bounded.clone()));
+
+		assert_eq!(InlineCallDedup::<Test>::iter().count(), 0);
+		assert_eq!(Agenda::<Test>::get(4)[0].as_ref().unwrap().call, bounded);
+	});
+}
+
+#[test]
+fn should_check_origin() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 69,
+			weight: Weight::from_parts(10, 0),
+		}));
+		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+		assert_noop!(
+			Scheduler::schedule_named(
+				system::RawOrigin::Signed(2).into(),
+				[1u8; 32],
+				4,
+				None,
+				127,
+				call,
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Scheduler::schedule(system::RawOrigin::Signed(2).into(), 4, None, 127, call2),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn should_check_origin_for_cancel() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 69,
+			weight: Weight::from_parts(10, 0),
+		}));
+		let call2 = Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}));
+		assert_ok!(Scheduler::schedule_named(
+			system::RawOrigin::Signed(1).into(),
+			[1u8; 32],
+			4,
+			None,
+			127,
+			call,
+		));
+		assert_ok!(Scheduler::schedule(system::RawOrigin::Signed(1).into(), 4, None, 127, call2,));
+		run_to_block(3);
+		// Scheduled calls are in the agenda.
+		assert_eq!(Agenda::<Test>::get(4).len(), 2);
+		assert!(logger::log().is_empty());
+		assert_noop!(
+			Scheduler::cancel_named(system::RawOrigin::Signed(2).into(), [1u8; 32]),
+			BadOrigin
+		);
+		assert_noop!(Scheduler::cancel(system::RawOrigin::Signed(2).into(), 4, 1), BadOrigin);
+		assert_noop!(Scheduler::cancel_named(system::RawOrigin::Root.into(), [1u8; 32]), BadOrigin);
+		assert_noop!(Scheduler::cancel(system::RawOrigin::Root.into(), 4, 1), BadOrigin);
+		run_to_block(5);
+		assert_eq!(
+			logger::log(),
+			vec![
+				(system::RawOrigin::Signed(1).into(), 69u32),
+				(system::RawOrigin::Signed(1).into(), 42u32)
+			]
+		);
+	});
+}
+
+#[test]
+fn migration_to_v4_works() {
+	new_test_ext().execute_with(|| {
+		for i in 0..3u64 {
+			let k = i.twox_64_concat();
+			let old = vec![
+				Some(ScheduledV1 {
+					maybe_id: None,
+					priority: i as u8 + 10,
+					call: RuntimeCall::Logger(LoggerCall::log {
+						i: 96,
+						weight: Weight::from_parts(100, 0),
+					}),
+					maybe_periodic: None,
+				}),
+				None,
+				Some(ScheduledV1 {
+					maybe_id: Some(b"test".to_vec()),
+					priority: 123,
+					call: RuntimeCall::Logger(LoggerCall::log {
+						i: 69,
+						weight: Weight::from_parts(10, 0),
+					}),
+					maybe_periodic: Some((456u64, 10)),
+				}),
+			];
+			frame_support::migration::put_storage_value(b"Scheduler", b"Agenda", &k, old);
+		}
+
+		Scheduler::migrate_v1_to_v4();
+
+		let mut x = crate::migration::v4::Agenda::<Test>::iter()
+			.map(|x| (x.0, x.1.into_inner()))
+			.collect::<Vec<_>>();
+		x.sort_by_key(|x| x.0);
+		let expected = vec![
+			(
+				0,
+				vec![
+					Some(ScheduledV4Of::<Test> {
+						maybe_id: None,
+						priority: 10,
+						call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+							i: 96,
+							weight: Weight::from_parts(100, 0),
+						}))
+						.unwrap(),
+						maybe_periodic: None,
+						origin: root(),
+						_phantom: PhantomData::<u64>::default(),
+					}),
+					None,
+					Some(ScheduledV4Of::<Test> {
+						maybe_id: Some(blake2_256(&b"test"[..])),
+						priority: 123,
+						call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+							i: 69,
+							weight: Weight::from_parts(10, 0),
+						}))
+						.unwrap(),
+						maybe_periodic: Some((456u64, 10)),
+						origin: root(),
+						_phantom: PhantomData::<u64>::default(),
+					}),
+				],
+			),
+			(
+				1,
+				vec![
+					Some(ScheduledV4Of::<Test> {
+						maybe_id: None,
+						priority: 11,
+						call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+							i: 96,
+							weight: Weight::from_parts(100, 0),
+						}))
+						.unwrap(),
+						maybe_periodic: None,
+						origin: root(),
+						_phantom: PhantomData::<u64>::default(),
+					}),
+					None,
+					Some(ScheduledV4Of::<Test> {
+						maybe_id: Some(blake2_256(&b"test"[..])),
+						priority: 123,
+						call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+							i: 69,
+							weight: Weight::from_parts(10, 0),
+						}))
+						.unwrap(),
+						maybe_periodic: Some((456u64, 10)),
+						origin: root(),
+						_phantom: PhantomData::<u64>::default(),
+					}),
+				],
+			),
+			(
+				2,
+				vec![
+					Some(ScheduledV4Of::<Test> {
+						maybe_id: None,
+						priority: 12,
+						call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+							i: 96,
+							weight: Weight::from_parts(100, 0),
+						}))
+						.unwrap(),
+						maybe_periodic: None,
+						origin: root(),
+						_phantom: PhantomData::<u64>::default(),
+					}),
+					None,
+					Some(ScheduledV4Of::<Test> {
+						maybe_id: Some(blake2_256(&b"test"[..])),
+						priority: 123,
+						call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+							i: 69,
+							weight: Weight::from_parts(10, 0),
+						}))
+						.unwrap(),
+						maybe_periodic: Some((456u64, 10)),
+						origin: root(),
+						_phantom: PhantomData::<u64>::default(),
+					}),
+				],
+			),
+		];
+		for (i, j) in x.iter().zip(expected.iter()) {
+			assert_eq!(i.0, j.0);
+			for (x, y) in i.1.iter().zip(j.1.iter()) {
+				assert_eq!(x, y);
+			}
+		}
+		assert_eq_uvec!(x, expected);
+
+		assert_eq!(Scheduler::on_chain_storage_version(), 4);
+	});
+}
+
+#[test]
+fn test_migrate_origin() {
+	new_test_ext().execute_with(|| {
+		for i in 0..3u64 {
+			let k = i.twox_64_concat();
+			let old: Vec<Option<Scheduled<[u8; 32], Bounded<RuntimeCall>, u64, u32, u64>>> = vec![
+				Some(Scheduled {
+					maybe_id: None,
+					priority: i as u8 + 10,
+					call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+						i: 96,
+						weight: Weight::from_parts(100, 0),
+					}))
+					.unwrap(),
+					origin: 3u32,
+					maybe_periodic: None,
+					valid_until: None,
+					authorization: None,
+					_phantom: Default::default(),
+				}),
+				None,
+				Some(Scheduled {
+					maybe_id: Some(blake2_256(&b"test"[..])),
+					priority: 123,
+					origin: 2u32,
+					call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+						i: 69,
+						weight: Weight::from_parts(10, 0),
+					}))
+					.unwrap(),
+					maybe_periodic: Some((456u64, 10)),
+					valid_until: None,
+					authorization: None,
+					_phantom: Default::default(),
+				}),
+			];
+			frame_support::migration::put_storage_value(b"Scheduler", b"Agenda", &k, old);
+		}
+
+		Scheduler::migrate_origin::<u32>();
+
+		assert_eq_uvec!(
+			Agenda::<Test>::iter().map(|x| (x.0, x.1.into_inner())).collect::<Vec<_>>(),
+			vec![
+				(
+					0,
+					vec![
+						Some(ScheduledOf::<Test> {
+							maybe_id: None,
+							priority: 10,
+							call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+								i: 96,
+								weight: Weight::from_parts(100, 0)
+							}))
+							.unwrap(),
+							maybe_periodic: None,
+							origin: system::RawOrigin::Root.into(),
+							valid_until: None,
+							authorization: None,
+							_phantom: PhantomData::<u64>::default(),
+						}),
+						None,
+						Some(Scheduled {
+							maybe_id: Some(blake2_256(&b"test"[..])),
+							priority: 123,
+							call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+								i: 69,
+								weight: Weight::from_parts(10, 0)
+							}))
+							.unwrap(),
+							maybe_periodic: Some((456u64, 10)),
+							origin: system::RawOrigin::None.into(),
+							valid_until: None,
+							authorization: None,
+							_phantom: PhantomData::<u64>::default(),
+						}),
+					]
+				),
+				(
+					1,
+					vec![
+						Some(Scheduled {
+							maybe_id: None,
+							priority: 11,
+							call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+								i: 96,
+								weight: Weight::from_parts(100, 0)
+							}))
+							.unwrap(),
+							maybe_periodic: None,
+							origin: system::RawOrigin::Root.into(),
+							valid_until: None,
+							authorization: None,
+							_phantom: PhantomData::<u64>::default(),
+						}),
+						None,
+						Some(Scheduled {
+							maybe_id: Some(blake2_256(&b"test"[..])),
+							priority: 123,
+							call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+								i: 69,
+								weight: Weight::from_parts(10, 0)
+							}))
+							.unwrap(),
+							maybe_periodic: Some((456u64, 10)),
+							origin: system::RawOrigin::None.into(),
+							valid_until: None,
+							authorization: None,
+							_phantom: PhantomData::<u64>::default(),
+						}),
+					]
+				),
+				(
+					2,
+					vec![
+						Some(Scheduled {
+							maybe_id: None,
+							priority: 12,
+							call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+								i: 96,
+								weight: Weight::from_parts(100, 0)
+							}))
+							.unwrap(),
+							maybe_periodic: None,
+							origin: system::RawOrigin::Root.into(),
+							valid_until: None,
+							authorization: None,
+							_phantom: PhantomData::<u64>::default(),
+						}),
+						None,
+						Some(Scheduled {
+							maybe_id: Some(blake2_256(&b"test"[..])),
+							priority: 123,
+							call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+								i: 69,
+								weight: Weight::from_parts(10, 0)
+							}))
+							.unwrap(),
+							maybe_periodic: Some((456u64, 10)),
+							origin: system::RawOrigin::None.into(),
+							valid_until: None,
+							authorization: None,
+							_phantom: PhantomData::<u64>::default(),
+						}),
+					]
+				)
+			]
+		);
+	});
+}
+
+#[test]
+fn migrate_origin_step_works() {
+	new_test_ext().execute_with(|| {
+		let call = Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		}))
+		.unwrap();
+		type OldScheduled =
+			Scheduled<[u8; 32], Bounded<RuntimeCall>, u64, system::RawOrigin<u64>, u64>;
+		let old_origin = |i: u64| match i % 2 {
+			0 => system::RawOrigin::<u64>::Root,
+			_ => system::RawOrigin::Signed(i),
+		};
+		for i in 0..10u64 {
+			let old: Vec<Option<OldScheduled>> = vec![
+				Some(Scheduled {
+					maybe_id: None,
+					priority: i as u8,
+					call: call.clone(),
+					maybe_periodic: None,
+					origin: old_origin(i),
+					valid_until: None,
+					authorization: None,
+					_phantom: Default::default(),
+				}),
+				None,
+			];
+			frame_support::migration::put_storage_value(
+				b"Scheduler",
+				b"Agenda",
+				&i.twox_64_concat(),
+				old,
+			);
+		}
+		// An undecodable agenda is dropped.
+		frame_support::migration::put_storage_value(
+			b"Scheduler",
+			b"Agenda",
+			&10u64.twox_64_concat(),
+			vec![255u8; 3],
+		);
+
+		// 11 agendas, 3 at a time.
+		let mut steps = vec![];
+		loop {
+			let (weight, done) = Scheduler::migrate_origin_step::<system::RawOrigin<u64>>(3);
+			assert!(weight.any_gt(Weight::zero()));
+			steps.push(done);
+			if done {
+				break
+			}
+			assert!(crate::migration::MigrateOriginCursor::<Test>::exists());
+		}
+		assert_eq!(steps, vec![false, false, false, true]);
+		assert!(!crate::migration::MigrateOriginCursor::<Test>::exists());
+
+		assert_eq_uvec!(
+			Agenda::<Test>::iter().map(|x| (x.0, x.1.into_inner())).collect::<Vec<_>>(),
+			(0..10u64)
+				.map(|i| (
+					i,
+					vec![
+						Some(ScheduledOf::<Test> {
+							maybe_id: None,
+							priority: i as u8,
+							call: call.clone(),
+							maybe_periodic: None,
+							origin: old_origin(i).into(),
+							valid_until: None,
+							authorization: None,
+							_phantom: Default::default(),
+						}),
+						None,
+					]
+				))
+				.collect::<Vec<_>>()
+		);
+	});
+}
+
+#[test]
+fn postponed_named_task_can_be_rescheduled() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(1000, 0) });
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+		let len = call.using_encoded(|x| x.len()) as u32;
+		// Important to use here `Bounded::Lookup` to ensure that we request the hash.
+		let hashed = Bounded::Lookup { hash, len };
+		let name: [u8; 32] = hash.as_ref().try_into().unwrap();
+
+		let address = Scheduler::do_schedule_named(
+			name,
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			hashed.clone(),
+		)
+		.unwrap();
+		assert!(Preimage::is_requested(&hash));
+		assert!(Lookup::<Test>::contains_key(name));
+
+		// Run to a very large block.
+		run_to_block(10);
+		// It was not executed.
+		assert!(logger::log().is_empty());
+		assert!(Preimage::is_requested(&hash));
+		// It was postponed block after block, the lookup following it.
+		assert_eq!(Lookup::<Test>::get(name), Some((11, 0)));
+
+		// The agenda contains the call as it was.
+		let agenda = Agenda::<Test>::iter().collect::<Vec<_>>();
+		assert_eq!(agenda.len(), 1);
+		assert_eq!(
+			agenda[0],
+			(
+				11,
+				vec![Some(Scheduled {
+					maybe_id: Some(name),
+					priority: 127,
+					call: hashed,
+					maybe_periodic: None,
+					origin: root(),
+					valid_until: None,
+					authorization: None,
+					_phantom: Default::default(),
+				})]
+				.try_into()
+				.unwrap()
+			)
+		);
+
+		// Manually re-scheduling the call by address errors.
+		assert_err!(
+			Scheduler::do_reschedule(address, DispatchTime::At(20)),
+			Error::<Test>::NotFound
+		);
+		assert_err!(Scheduler::do_reschedule((11, 0), DispatchTime::At(20)), Error::<Test>::Named);
+		// Manually re-schedule the call by name works.
+		assert_ok!(Scheduler::do_reschedule_named(name, DispatchTime::At(20)));
+
+		// Finally add the preimage.
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(0), call.encode()));
+		run_to_block(19);
+		assert!(logger::log().is_empty());
+		run_to_block(20);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert!(!Lookup::<Test>::contains_key(name));
+		assert_eq!(Agenda::<Test>::iter().count(), 0);
+		assert_eq!(Scheduler::task_count(), 0);
+	});
+}
+
+#[test]
+fn postponed_periodic_task_keeps_its_origin_and_period() {
+	new_test_ext().execute_with(|| {
+		let call = RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 42,
+			weight: Weight::from_parts(10, 0),
+		});
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
+		let len = call.using_encoded(|x| x.len()) as u32;
+		let name = [1u8; 32];
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+
+		assert_ok!(Scheduler::do_schedule_named(
+			name,
+			DispatchTime::At(4),
+			Some((3, 3)),
+			127,
+			signed.clone(),
+			Bounded::Lookup { hash, len },
+		));
+
+		// The preimage is missing, the task is carried over.
+		run_to_block(6);
+		assert!(logger::log().is_empty());
+		assert_eq!(Lookup::<Test>::get(name), Some((7, 0)));
+		let task = Agenda::<Test>::get(7)[0].clone().unwrap();
+		assert_eq!(task.maybe_id, Some(name));
+		assert_eq!(task.maybe_periodic, Some((3, 2)));
+		assert_eq!(task.origin, signed);
+		assert_eq!(Scheduler::origin_task_count(&signed), 1);
+		assert_ok!(Scheduler::do_try_state());
+
+		// Once noted, it is dispatched from its origin and carries on with its period.
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(0), call.encode()));
+		run_to_block(7);
+		assert_eq!(logger::log(), vec![(signed.clone(), 42u32)]);
+		let task = Agenda::<Test>::get(10)[0].clone().unwrap();
+		assert_eq!(task.maybe_id, Some(name));
+		assert_eq!(task.maybe_periodic, Some((3, 1)));
+		assert_eq!(task.origin, signed);
+		assert_eq!(Lookup::<Test>::get(name), Some((10, 0)));
+
+		run_to_block(13);
+		assert_eq!(logger::log(), vec![(signed.clone(), 42u32); 3]);
+		assert!(!Lookup::<Test>::contains_key(name));
+		assert_eq!(Scheduler::origin_task_count(&signed), 0);
+	});
+}
+
+/// Using the scheduler as `v3::Anon` works.
+#[test]
+fn scheduler_v3_anon_basic_works() {
+	use frame_support::traits::schedule::v3::Anon;
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+
+		// Schedule a call.
+		let _address = <Scheduler as Anon<_, _, _>>::schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		)
+		.unwrap();
+
+		run_to_block(3);
+		// Did not execute till block 3.
+		assert!(logger::log().is_empty());
+		// Executes in block 4.
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		// ... but not again.
+		run_to_block(100);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+	});
+}
+
+#[test]
+fn scheduler_v3_anon_cancel_works() {
+	use frame_support::traits::schedule::v3::Anon;
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+
+		// Schedule a call.
+		let address = <Scheduler as Anon<_, _, _>>::schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			bound.clone(),
+		)
+		.unwrap();
+		// Cancel the call.
+		assert_ok!(<Scheduler as Anon<_, _, _>>::cancel(address));
+		// It did not get executed.
+		run_to_block(100);
+		assert!(logger::log().is_empty());
+		// Cannot cancel again.
+		assert_err!(<Scheduler as Anon<_, _, _>>::cancel(address), DispatchError::Unavailable);
+	});
+}
+
+#[test]
+fn scheduler_v3_anon_reschedule_works() {
+	use frame_support::traits::schedule::v3::Anon;
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+
+		// Schedule a call.
+		let address = <Scheduler as Anon<_, _, _>>::schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		)
+		.unwrap();
+
+		run_to_block(3);
+		// Did not execute till block 3.
+		assert!(logger::log().is_empty());
+
+		// Cannot re-schedule into the same block.
+		assert_noop!(
+			<Scheduler as Anon<_, _, _>>::reschedule(address, DispatchTime::At(4)),
+			Error::<Test>::RescheduleNoChange
+		);
+		// Cannot re-schedule into the past.
+		assert_noop!(
+			<Scheduler as Anon<_, _, _>>::reschedule(address, DispatchTime::At(3)),
+			Error::<Test>::TargetBlockNumberInPast
+		);
+		// Re-schedule to block 5.
+		assert_ok!(<Scheduler as Anon<_, _, _>>::reschedule(address, DispatchTime::At(5)));
+		// Scheduled for block 5.
+		run_to_block(4);
+		assert!(logger::log().is_empty());
+		run_to_block(5);
+		// Does execute in block 5.
+		assert_eq!(logger::log(), vec![(root(), 42)]);
+		// Cannot re-schedule executed task.
+		assert_noop!(
+			<Scheduler as Anon<_, _, _>>::reschedule(address, DispatchTime::At(10)),
+			DispatchError::Unavailable
+		);
+	});
+}
+
+#[test]
+fn scheduler_v3_anon_next_schedule_time_works() {
+	use frame_support::traits::schedule::v3::Anon;
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+
+		// Schedule a call.
+		let address = <Scheduler as Anon<_, _, _>>::schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			bound.clone(),
+		)
+		.unwrap();
+
+		run_to_block(3);
+		// Did not execute till block 3.
+		assert!(logger::log().is_empty());
+
+		// Scheduled for block 4.
+		assert_eq!(<Scheduler as Anon<_, _, _>>::next_dispatch_time(address), Ok(4));
+		// Block 4 executes it.
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42)]);
+
+		// It has no dispatch time anymore.
+		assert_noop!(
+			<Scheduler as Anon<_, _, _>>::next_dispatch_time(address),
+			DispatchError::Unavailable
+		);
+	});
+}
+
+#[test]
+fn schedule_after_task_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let anchor = Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call.clone()).unwrap(),
+		)
+		.unwrap();
+
+		// Anchored to the block of the task, not to the current one.
+		let follow_up =
+			RuntimeCall::Logger(LoggerCall::log { i: 69, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::schedule_after_task(
+			RuntimeOrigin::root(),
+			anchor,
+			1,
+			None,
+			127,
+			Box::new(follow_up.clone()),
+		));
+		System::assert_last_event(crate::Event::Scheduled { when: 5, index: 0 }.into());
+
+		// A cancelled anchor can't be used.
+		assert_ok!(Scheduler::do_cancel(None, anchor));
+		assert_noop!(
+			Scheduler::schedule_after_task(
+				RuntimeOrigin::root(),
+				anchor,
 				1,
-				vec![
-					Some(ScheduledOf::<Test> {
-						maybe_id: None,
-						priority: 11,
-						call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
-							i: 96,
-							weight: Weight::from_parts(100, 0),
-						}))
-						.unwrap(),
-						maybe_periodic: None,
-						origin: root(),
-						_phantom: PhantomData::<u64>::default(),
-					}),
-					None,
-					Some(ScheduledOf::<Test> {
-						maybe_id: Some(blake2_256(&b"test"[..])),
-						priority: 123,
-						call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
-							i: 69,
-							weight: Weight::from_parts(10, 0),
-						}))
-						.unwrap(),
-						maybe_periodic: Some((456u64, 10)),
-						origin: root(),
-						_phantom: PhantomData::<u64>::default(),
-					}),
-				],
-			),
-			(
-				2,
-				vec![
-					Some(ScheduledOf::<Test> {
-						maybe_id: None,
-						priority: 12,
-						call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
-							i: 96,
-							weight: Weight::from_parts(100, 0),
-						}))
-						.unwrap(),
-						maybe_periodic: None,
-						origin: root(),
-						_phantom: PhantomData::<u64>::default(),
-					}),
-					None,
-					Some(ScheduledOf::<Test> {
-						maybe_id: Some(blake2_256(&b"test"[..])),
-						priority: 123,
-						call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
-							i: 69,
-							weight: Weight::from_parts(10, 0),
-						}))
-						.unwrap(),
-						maybe_periodic: Some((456u64, 10)),
-						origin: root(),
-						_phantom: PhantomData::<u64>::default(),
-					}),
-				],
+				None,
+				127,
+				Box::new(follow_up),
 			),
-		];
-		for (i, j) in x.iter().zip(expected.iter()) {
-			assert_eq!(i.0, j.0);
-			for (x, y) in i.1.iter().zip(j.1.iter()) {
-				assert_eq!(x, y);
-			}
-		}
-		assert_eq_uvec!(x, expected);
+			Error::<Test>::NotFound
+		);
 
-		assert_eq!(Scheduler::on_chain_storage_version(), 4);
+		run_to_block(5);
+		assert_eq!(logger::log(), vec![(root(), 69u32)]);
 	});
 }
 
+/// The reason why a task address has no dispatch time is reported.
 #[test]
-fn test_migrate_origin() {
+fn next_dispatch_time_detailed_works() {
+	use frame_support::traits::schedule::v3::Anon;
 	new_test_ext().execute_with(|| {
-		for i in 0..3u64 {
-			let k = i.twox_64_concat();
-			let old: Vec<Option<Scheduled<[u8; 32], Bounded<RuntimeCall>, u64, u32, u64>>> = vec![
-				Some(Scheduled {
-					maybe_id: None,
-					priority: i as u8 + 10,
-					call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
-						i: 96,
-						weight: Weight::from_parts(100, 0),
-					}))
-					.unwrap(),
-					origin: 3u32,
-					maybe_periodic: None,
-					_phantom: Default::default(),
-				}),
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+		for _ in 0..2 {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
 				None,
-				Some(Scheduled {
-					maybe_id: Some(blake2_256(&b"test"[..])),
-					priority: 123,
-					origin: 2u32,
-					call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
-						i: 69,
-						weight: Weight::from_parts(10, 0),
-					}))
-					.unwrap(),
-					maybe_periodic: Some((456u64, 10)),
-					_phantom: Default::default(),
-				}),
-			];
-			frame_support::migration::put_storage_value(b"Scheduler", b"Agenda", &k, old);
-		}
-
-		impl Into<OriginCaller> for u32 {
-			fn into(self) -> OriginCaller {
-				match self {
-					3u32 => system::RawOrigin::Root.into(),
-					2u32 => system::RawOrigin::None.into(),
-					_ => unreachable!("test make no use of it"),
-				}
-			}
+				127,
+				root(),
+				bound.clone()
+			));
 		}
 
-		Scheduler::migrate_origin::<u32>();
+		assert_eq!(Scheduler::next_dispatch_time_detailed((4, 0)), Ok(4));
+		assert_eq!(
+			Scheduler::next_dispatch_time_detailed((5, 0)),
+			Err(NextTimeError::UnknownBlock)
+		);
+		assert_eq!(
+			Scheduler::next_dispatch_time_detailed((4, 2)),
+			Err(NextTimeError::IndexOutOfRange)
+		);
 
-		assert_eq_uvec!(
-			Agenda::<Test>::iter().map(|x| (x.0, x.1.into_inner())).collect::<Vec<_>>(),
-			vec![
-				(
-					0,
-					vec![
-						Some(ScheduledOf::<Test> {
-							maybe_id: None,
-							priority: 10,
-							call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
-								i: 96,
-								weight: Weight::from_parts(100, 0)
-							}))
-							.unwrap(),
-							maybe_periodic: None,
-							origin: system::RawOrigin::Root.into(),
-							_phantom: PhantomData::<u64>::default(),
-						}),
-						None,
-						Some(Scheduled {
-							maybe_id: Some(blake2_256(&b"test"[..])),
-							priority: 123,
-							call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
-								i: 69,
-								weight: Weight::from_parts(10, 0)
-							}))
-							.unwrap(),
-							maybe_periodic: Some((456u64, 10)),
-							origin: system::RawOrigin::None.into(),
-							_phantom: PhantomData::<u64>::default(),
-						}),
-					]
-				),
-				(
-					1,
-					vec![
-						Some(Scheduled {
-							maybe_id: None,
-							priority: 11,
-							call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
-								i: 96,
-								weight: Weight::from_parts(100, 0)
-							}))
-							.unwrap(),
-							maybe_periodic: None,
-							origin: system::RawOrigin::Root.into(),
-							_phantom: PhantomData::<u64>::default(),
-						}),
-						None,
-						Some(Scheduled {
-							maybe_id: Some(blake2_256(&b"test"[..])),
-							priority: 123,
-							call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
-								i: 69,
-								weight: Weight::from_parts(10, 0)
-							}))
-							.unwrap(),
-							maybe_periodic: Some((456u64, 10)),
-							origin: system::RawOrigin::None.into(),
-							_phantom: PhantomData::<u64>::default(),
-						}),
-					]
-				),
-				(
-					2,
-					vec![
-						Some(Scheduled {
-							maybe_id: None,
-							priority: 12,
-							call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
-								i: 96,
-								weight: Weight::from_parts(100, 0)
-							}))
-							.unwrap(),
-							maybe_periodic: None,
-							origin: system::RawOrigin::Root.into(),
-							_phantom: PhantomData::<u64>::default(),
-						}),
-						None,
-						Some(Scheduled {
-							maybe_id: Some(blake2_256(&b"test"[..])),
-							priority: 123,
-							call: Preimage::bound(RuntimeCall::Logger(LoggerCall::log {
-								i: 69,
-								weight: Weight::from_parts(10, 0)
-							}))
-							.unwrap(),
-							maybe_periodic: Some((456u64, 10)),
-							origin: system::RawOrigin::None.into(),
-							_phantom: PhantomData::<u64>::default(),
-						}),
-					]
-				)
-			]
+		// A cancelled task leaves its slot empty.
+		assert_ok!(Scheduler::do_cancel(None, (4, 0)));
+		assert_eq!(Scheduler::next_dispatch_time_detailed((4, 0)), Err(NextTimeError::Cancelled));
+		assert_noop!(
+			<Scheduler as Anon<_, _, _>>::next_dispatch_time((4, 0)),
+			DispatchError::Unavailable
 		);
+		assert_eq!(<Scheduler as Anon<_, _, _>>::next_dispatch_time((4, 1)), Ok(4));
 	});
 }
 
+/// The account which scheduled a task is only reported for signed origins.
 #[test]
-fn postponed_named_task_cannot_be_rescheduled() {
+fn task_signer_works() {
 	new_test_ext().execute_with(|| {
 		let call =
-			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(1000, 0) });
-		let hash = <Test as frame_system::Config>::Hashing::hash_of(&call);
-		let len = call.using_encoded(|x| x.len()) as u32;
-		// Important to use here `Bounded::Lookup` to ensure that we request the hash.
-		let hashed = Bounded::Lookup { hash, len };
-		let name: [u8; 32] = hash.as_ref().try_into().unwrap();
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+		let origins: [OriginCaller; 3] = [
+			system::RawOrigin::Signed(1).into(),
+			system::RawOrigin::Root.into(),
+			system::RawOrigin::None.into(),
+		];
+		for origin in origins {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
+				None,
+				127,
+				origin,
+				bound.clone()
+			));
+		}
+
+		assert_eq!(Scheduler::task_signer((4, 0)), Some(1));
+		assert_eq!(Scheduler::task_signer((4, 1)), None);
+		assert_eq!(Scheduler::task_signer((4, 2)), None);
+		// Unknown and cancelled tasks have no signer either.
+		assert_eq!(Scheduler::task_signer((4, 3)), None);
+		assert_ok!(Scheduler::do_cancel(None, (4, 0)));
+		assert_eq!(Scheduler::task_signer((4, 0)), None);
+	});
+}
+
+/// Re-scheduling a task changes its next dispatch time.
+#[test]
+fn scheduler_v3_anon_reschedule_and_next_schedule_time_work() {
+	use frame_support::traits::schedule::v3::Anon;
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
 
-		let address = Scheduler::do_schedule_named(
-			name,
+		// Schedule a call.
+		let old_address = <Scheduler as Anon<_, _, _>>::schedule(
 			DispatchTime::At(4),
 			None,
 			127,
 			root(),
-			hashed.clone(),
+			bound.clone(),
 		)
 		.unwrap();
-		assert!(Preimage::is_requested(&hash));
-		assert!(Lookup::<Test>::contains_key(name));
 
-		// Run to a very large block.
-		run_to_block(10);
-		// It was not executed.
+		run_to_block(3);
+		// Did not execute till block 3.
 		assert!(logger::log().is_empty());
-		assert!(Preimage::is_requested(&hash));
-		// Postponing removes the lookup.
-		assert!(!Lookup::<Test>::contains_key(name));
 
-		// The agenda still contains the call.
-		let agenda = Agenda::<Test>::iter().collect::<Vec<_>>();
-		assert_eq!(agenda.len(), 1);
-		assert_eq!(
-			agenda[0].1,
-			vec![Some(Scheduled {
-				maybe_id: Some(name),
-				priority: 127,
-				call: hashed,
-				maybe_periodic: None,
-				origin: root().into(),
-				_phantom: Default::default(),
-			})]
-		);
+		// Scheduled for block 4.
+		assert_eq!(<Scheduler as Anon<_, _, _>>::next_dispatch_time(old_address), Ok(4));
+		// Re-schedule to block 5.
+		let address =
+			<Scheduler as Anon<_, _, _>>::reschedule(old_address, DispatchTime::At(5)).unwrap();
+		assert!(address != old_address);
+		// Scheduled for block 5.
+		assert_eq!(<Scheduler as Anon<_, _, _>>::next_dispatch_time(address), Ok(5));
 
-		// Finally add the preimage.
-		assert_ok!(Preimage::note(call.encode().into()));
-		run_to_block(1000);
-		// It did not execute.
+		// Block 4 does nothing.
+		run_to_block(4);
 		assert!(logger::log().is_empty());
-		assert!(Preimage::is_requested(&hash));
+		// Block 5 executes it.
+		run_to_block(5);
+		assert_eq!(logger::log(), vec![(root(), 42)]);
+	});
+}
 
-		// Manually re-schedule the call by name does not work.
-		assert_err!(
-			Scheduler::do_reschedule_named(name, DispatchTime::At(1001)),
-			Error::<Test>::NotFound
-		);
-		// Manually re-scheduling the call by address errors.
-		assert_err!(
-			Scheduler::do_reschedule(address, DispatchTime::At(1001)),
-			Error::<Test>::Named
+#[test]
+fn scheduler_v3_anon_schedule_agenda_overflows() {
+	use frame_support::traits::schedule::v3::Anon;
+	let max: u32 = <Test as Config>::MaxScheduledPerBlock::get();
+
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+
+		// Schedule the maximal number allowed per block.
+		for _ in 0..max {
+			<Scheduler as Anon<_, _, _>>::schedule(
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				bound.clone(),
+			)
+			.unwrap();
+		}
+
+		// One more time and it errors.
+		assert_noop!(
+			<Scheduler as Anon<_, _, _>>::schedule(DispatchTime::At(4), None, 127, root(), bound,),
+			DispatchError::Exhausted
 		);
+
+		run_to_block(4);
+		// All scheduled calls are executed.
+		assert_eq!(logger::log().len() as u32, max);
 	});
 }
 
-/// Using the scheduler as `v3::Anon` works.
+/// Cancelling and scheduling does not overflow the agenda but fills holes.
 #[test]
-fn scheduler_v3_anon_basic_works() {
+fn scheduler_v3_anon_cancel_and_schedule_fills_holes() {
+	use frame_support::traits::schedule::v3::Anon;
+	let max: u32 = <Test as Config>::MaxScheduledPerBlock::get();
+	assert!(max > 3, "This test only makes sense for MaxScheduledPerBlock > 3");
+
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+		let mut addrs = Vec::<_>::default();
+
+		// Schedule the maximal number allowed per block.
+		for _ in 0..max {
+			addrs.push(
+				<Scheduler as Anon<_, _, _>>::schedule(
+					DispatchTime::At(4),
+					None,
+					127,
+					root(),
+					bound.clone(),
+				)
+				.unwrap(),
+			);
+		}
+		// Cancel three of them.
+		for addr in addrs.into_iter().take(3) {
+			<Scheduler as Anon<_, _, _>>::cancel(addr).unwrap();
+		}
+		// Schedule three new ones.
+		for i in 0..3 {
+			let (_block, index) = <Scheduler as Anon<_, _, _>>::schedule(
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				bound.clone(),
+			)
+			.unwrap();
+			assert_eq!(i, index);
+		}
+
+		run_to_block(4);
+		// Maximum number of calls are executed.
+		assert_eq!(logger::log().len() as u32, max);
+	});
+}
+
+/// Re-scheduling does not overflow the agenda but fills holes.
+#[test]
+fn scheduler_v3_anon_reschedule_fills_holes() {
 	use frame_support::traits::schedule::v3::Anon;
+	let max: u32 = <Test as Config>::MaxScheduledPerBlock::get();
+	assert!(max > 3, "pre-condition: This test only makes sense for MaxScheduledPerBlock > 3");
+
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+		let mut addrs = Vec::<_>::default();
+
+		// Schedule the maximal number allowed per block.
+		for _ in 0..max {
+			addrs.push(
+				<Scheduler as Anon<_, _, _>>::schedule(
+					DispatchTime::At(4),
+					None,
+					127,
+					root(),
+					bound.clone(),
+				)
+				.unwrap(),
+			);
+		}
+		let mut new_addrs = Vec::<_>::default();
+		// Reversed last three elements of block 4.
+		let last_three = addrs.into_iter().rev().take(3).collect::<Vec<_>>();
+		// Re-schedule three of them to block 5.
+		for addr in last_three.iter().cloned() {
+			new_addrs
+				.push(<Scheduler as Anon<_, _, _>>::reschedule(addr, DispatchTime::At(5)).unwrap());
+		}
+		// Re-scheduling them back into block 3 should result in the same addrs.
+		for (old, want) in new_addrs.into_iter().zip(last_three.into_iter().rev()) {
+			let new = <Scheduler as Anon<_, _, _>>::reschedule(old, DispatchTime::At(4)).unwrap();
+			assert_eq!(new, want);
+		}
+
+		run_to_block(4);
+		// Maximum number of calls are executed.
+		assert_eq!(logger::log().len() as u32, max);
+	});
+}
+
+/// The scheduler can be used as `v3::Named` trait.
+#[test]
+fn scheduler_v3_named_basic_works() {
+	use frame_support::traits::schedule::v3::Named;
+
 	new_test_ext().execute_with(|| {
 		let call =
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let name = [1u8; 32];
 
 		// Schedule a call.
-		let _address = <Scheduler as Anon<_, _, _>>::schedule(
+		let _address = <Scheduler as Named<_, _, _>>::schedule_named(
+			name,
 			DispatchTime::At(4),
 			None,
 			127,
@@ -1228,16 +3555,19 @@ fn scheduler_v3_anon_basic_works() {
 	});
 }
 
+/// A named task can be cancelled by its name.
 #[test]
-fn scheduler_v3_anon_cancel_works() {
-	use frame_support::traits::schedule::v3::Anon;
+fn scheduler_v3_named_cancel_named_works() {
+	use frame_support::traits::schedule::v3::Named;
 	new_test_ext().execute_with(|| {
 		let call =
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
 		let bound = Preimage::bound(call).unwrap();
+		let name = [1u8; 32];
 
 		// Schedule a call.
-		let address = <Scheduler as Anon<_, _, _>>::schedule(
+		<Scheduler as Named<_, _, _>>::schedule_named(
+			name,
 			DispatchTime::At(4),
 			None,
 			127,
@@ -1245,7 +3575,85 @@ fn scheduler_v3_anon_cancel_works() {
 			bound.clone(),
 		)
 		.unwrap();
-		// Cancel the call.
+		// Cancel the call by name.
+		assert_ok!(<Scheduler as Named<_, _, _>>::cancel_named(name));
+		// It did not get executed.
+		run_to_block(100);
+		assert!(logger::log().is_empty());
+		// Cannot cancel again.
+		assert_noop!(<Scheduler as Named<_, _, _>>::cancel_named(name), DispatchError::Unavailable);
+	});
+}
+
+/// Rescheduling a named task tells an unknown name apart from an unchanged time.
+#[test]
+fn scheduler_named_reschedule_named_errors_are_distinct() {
+	use frame_support::traits::schedule::{v2, v3};
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+
+		// Through `v3::Named`.
+		let name = [1u8; 32];
+		assert_ok!(<Scheduler as v3::Named<_, _, _>>::schedule_named(
+			name,
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call.clone()).unwrap(),
+		));
+		assert_noop!(
+			<Scheduler as v3::Named<_, _, _>>::reschedule_named([2u8; 32], DispatchTime::At(5)),
+			DispatchError::Unavailable
+		);
+		assert_noop!(
+			<Scheduler as v3::Named<_, _, _>>::reschedule_named(name, DispatchTime::At(4)),
+			Error::<Test>::RescheduleNoChange
+		);
+
+		// Through `v2::Named`.
+		let id = vec![1u8];
+		assert_ok!(<Scheduler as v2::Named<_, _, _>>::schedule_named(
+			id.clone(),
+			DispatchTime::At(6),
+			None,
+			127,
+			root(),
+			MaybeHashed::Value(call),
+		));
+		assert_noop!(
+			<Scheduler as v2::Named<_, _, _>>::reschedule_named(vec![2u8], DispatchTime::At(7)),
+			Error::<Test>::NotFound
+		);
+		assert_noop!(
+			<Scheduler as v2::Named<_, _, _>>::reschedule_named(id, DispatchTime::At(6)),
+			Error::<Test>::RescheduleNoChange
+		);
+	});
+}
+
+/// A named task can also be cancelled by its address.
+#[test]
+fn scheduler_v3_named_cancel_without_name_works() {
+	use frame_support::traits::schedule::v3::{Anon, Named};
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let bound = Preimage::bound(call).unwrap();
+		let name = [1u8; 32];
+
+		// Schedule a call.
+		let address = <Scheduler as Named<_, _, _>>::schedule_named(
+			name,
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			bound.clone(),
+		)
+		.unwrap();
+		// Cancel the call by address.
 		assert_ok!(<Scheduler as Anon<_, _, _>>::cancel(address));
 		// It did not get executed.
 		run_to_block(100);
@@ -1255,15 +3663,18 @@ fn scheduler_v3_anon_cancel_works() {
 	});
 }
 
+/// A named task can be re-scheduled by its name but not by its address.
 #[test]
-fn scheduler_v3_anon_reschedule_works() {
-	use frame_support::traits::schedule::v3::Anon;
+fn scheduler_v3_named_reschedule_named_works() {
+	use frame_support::traits::schedule::v3::{Anon, Named};
 	new_test_ext().execute_with(|| {
 		let call =
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let name = [1u8; 32];
 
 		// Schedule a call.
-		let address = <Scheduler as Anon<_, _, _>>::schedule(
+		let address = <Scheduler as Named<_, _, _>>::schedule_named(
+			name,
 			DispatchTime::At(4),
 			None,
 			127,
@@ -1276,18 +3687,23 @@ fn scheduler_v3_anon_reschedule_works() {
 		// Did not execute till block 3.
 		assert!(logger::log().is_empty());
 
+		// Cannot re-schedule by address.
+		assert_noop!(
+			<Scheduler as Anon<_, _, _>>::reschedule(address, DispatchTime::At(10)),
+			Error::<Test>::Named,
+		);
 		// Cannot re-schedule into the same block.
 		assert_noop!(
-			<Scheduler as Anon<_, _, _>>::reschedule(address, DispatchTime::At(4)),
+			<Scheduler as Named<_, _, _>>::reschedule_named(name, DispatchTime::At(4)),
 			Error::<Test>::RescheduleNoChange
 		);
 		// Cannot re-schedule into the past.
 		assert_noop!(
-			<Scheduler as Anon<_, _, _>>::reschedule(address, DispatchTime::At(3)),
+			<Scheduler as Named<_, _, _>>::reschedule_named(name, DispatchTime::At(3)),
 			Error::<Test>::TargetBlockNumberInPast
 		);
 		// Re-schedule to block 5.
-		assert_ok!(<Scheduler as Anon<_, _, _>>::reschedule(address, DispatchTime::At(5)));
+		assert_ok!(<Scheduler as Named<_, _, _>>::reschedule_named(name, DispatchTime::At(5)));
 		// Scheduled for block 5.
 		run_to_block(4);
 		assert!(logger::log().is_empty());
@@ -1295,6 +3711,11 @@ fn scheduler_v3_anon_reschedule_works() {
 		// Does execute in block 5.
 		assert_eq!(logger::log(), vec![(root(), 42)]);
 		// Cannot re-schedule executed task.
+		assert_noop!(
+			<Scheduler as Named<_, _, _>>::reschedule_named(name, DispatchTime::At(10)),
+			DispatchError::Unavailable
+		);
+		// Also not by address.
 		assert_noop!(
 			<Scheduler as Anon<_, _, _>>::reschedule(address, DispatchTime::At(10)),
 			DispatchError::Unavailable
@@ -1303,15 +3724,17 @@ fn scheduler_v3_anon_reschedule_works() {
 }
 
 #[test]
-fn scheduler_v3_anon_next_schedule_time_works() {
-	use frame_support::traits::schedule::v3::Anon;
+fn scheduler_v3_named_next_schedule_time_works() {
+	use frame_support::traits::schedule::v3::{Anon, Named};
 	new_test_ext().execute_with(|| {
 		let call =
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
 		let bound = Preimage::bound(call).unwrap();
+		let name = [1u8; 32];
 
 		// Schedule a call.
-		let address = <Scheduler as Anon<_, _, _>>::schedule(
+		let address = <Scheduler as Named<_, _, _>>::schedule_named(
+			name,
 			DispatchTime::At(4),
 			None,
 			127,
@@ -1325,12 +3748,19 @@ fn scheduler_v3_anon_next_schedule_time_works() {
 		assert!(logger::log().is_empty());
 
 		// Scheduled for block 4.
+		assert_eq!(<Scheduler as Named<_, _, _>>::next_dispatch_time(name), Ok(4));
+		// Also works by address.
 		assert_eq!(<Scheduler as Anon<_, _, _>>::next_dispatch_time(address), Ok(4));
 		// Block 4 executes it.
 		run_to_block(4);
 		assert_eq!(logger::log(), vec![(root(), 42)]);
 
 		// It has no dispatch time anymore.
+		assert_noop!(
+			<Scheduler as Named<_, _, _>>::next_dispatch_time(name),
+			DispatchError::Unavailable
+		);
+		// Also not by address.
 		assert_noop!(
 			<Scheduler as Anon<_, _, _>>::next_dispatch_time(address),
 			DispatchError::Unavailable
@@ -1338,516 +3768,918 @@ fn scheduler_v3_anon_next_schedule_time_works() {
 	});
 }
 
-/// Re-scheduling a task changes its next dispatch time.
 #[test]
-fn scheduler_v3_anon_reschedule_and_next_schedule_time_work() {
-	use frame_support::traits::schedule::v3::Anon;
+fn cancel_last_task_removes_agenda() {
 	new_test_ext().execute_with(|| {
+		let when = 4;
 		let call =
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
-		let bound = Preimage::bound(call).unwrap();
+		let address = Scheduler::do_schedule(
+			DispatchTime::At(when),
+			None,
+			127,
+			root(),
+			Preimage::bound(call.clone()).unwrap(),
+		)
+		.unwrap();
+		let address2 = Scheduler::do_schedule(
+			DispatchTime::At(when),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		)
+		.unwrap();
+		// two tasks at agenda.
+		assert!(Agenda::<Test>::get(when).len() == 2);
+		assert_ok!(Scheduler::do_cancel(None, address));
+		// still two tasks at agenda, `None` and `Some`.
+		assert!(Agenda::<Test>::get(when).len() == 2);
+		// cancel last task from `when` agenda.
+		assert_ok!(Scheduler::do_cancel(None, address2));
+		// if all tasks `None`, agenda fully removed.
+		assert!(Agenda::<Test>::get(when).len() == 0);
+	});
+}
 
-		// Schedule a call.
-		let old_address = <Scheduler as Anon<_, _, _>>::schedule(
+#[test]
+fn cancel_named_last_task_removes_agenda() {
+	new_test_ext().execute_with(|| {
+		let when = 4;
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(when),
+			None,
+			127,
+			root(),
+			Preimage::bound(call.clone()).unwrap(),
+		)
+		.unwrap();
+		Scheduler::do_schedule_named(
+			[2u8; 32],
+			DispatchTime::At(when),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		)
+		.unwrap();
+		// two tasks at agenda.
+		assert!(Agenda::<Test>::get(when).len() == 2);
+		assert_ok!(Scheduler::do_cancel_named(None, [2u8; 32]));
+		// removes trailing `None` and leaves one task.
+		assert!(Agenda::<Test>::get(when).len() == 1);
+		// cancel last task from `when` agenda.
+		assert_ok!(Scheduler::do_cancel_named(None, [1u8; 32]));
+		// if all tasks `None`, agenda fully removed.
+		assert!(Agenda::<Test>::get(when).len() == 0);
+	});
+}
+
+#[test]
+fn cancel_named_removes_stale_lookup() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let address = Scheduler::do_schedule(
 			DispatchTime::At(4),
 			None,
 			127,
 			root(),
-			bound.clone(),
+			Preimage::bound(call).unwrap(),
 		)
 		.unwrap();
 
-		run_to_block(3);
-		// Did not execute till block 3.
-		assert!(logger::log().is_empty());
+		// Stale entries pointing at a missing agenda, an empty slot and an unnamed task.
+		Lookup::<Test>::insert([1u8; 32], (5, 0));
+		Lookup::<Test>::insert([2u8; 32], (4, 1));
+		Lookup::<Test>::insert([3u8; 32], address);
+
+		System::set_block_number(1);
+		for id in [[1u8; 32], [2u8; 32], [3u8; 32]] {
+			// The removal of the entry is kept, as the call succeeds.
+			assert_ok!(Scheduler::cancel_named(RuntimeOrigin::root(), id));
+			assert!(!Lookup::<Test>::contains_key(id));
+			System::assert_last_event(crate::Event::StaleLookupRemoved { id }.into());
+			// With the entry gone, there is nothing left to cancel.
+			assert_noop!(
+				Scheduler::cancel_named(RuntimeOrigin::root(), id),
+				Error::<Test>::NotFound
+			);
+		}
 
-		// Scheduled for block 4.
-		assert_eq!(<Scheduler as Anon<_, _, _>>::next_dispatch_time(old_address), Ok(4));
-		// Re-schedule to block 5.
-		let address =
-			<Scheduler as Anon<_, _, _>>::reschedule(old_address, DispatchTime::At(5)).unwrap();
-		assert!(address != old_address);
-		// Scheduled for block 5.
-		assert_eq!(<Scheduler as Anon<_, _, _>>::next_dispatch_time(address), Ok(5));
+		// The task at `address` is left untouched.
+		assert_eq!(Agenda::<Test>::get(4).len(), 1);
+		assert_eq!(Scheduler::origin_task_count(&root()), 1);
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+	});
+}
+
+#[test]
+fn reschedule_last_task_removes_agenda() {
+	new_test_ext().execute_with(|| {
+		let when = 4;
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let address = Scheduler::do_schedule(
+			DispatchTime::At(when),
+			None,
+			127,
+			root(),
+			Preimage::bound(call.clone()).unwrap(),
+		)
+		.unwrap();
+		let address2 = Scheduler::do_schedule(
+			DispatchTime::At(when),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		)
+		.unwrap();
+		// two tasks at agenda.
+		assert!(Agenda::<Test>::get(when).len() == 2);
+		assert_ok!(Scheduler::do_cancel(None, address));
+		// still two tasks at agenda, `None` and `Some`.
+		assert!(Agenda::<Test>::get(when).len() == 2);
+		// reschedule last task from `when` agenda.
+		assert_eq!(
+			Scheduler::do_reschedule(address2, DispatchTime::At(when + 1)).unwrap(),
+			(when + 1, 0)
+		);
+		// if all tasks `None`, agenda fully removed.
+		assert!(Agenda::<Test>::get(when).len() == 0);
+	});
+}
+
+#[test]
+fn reschedule_named_last_task_removes_agenda() {
+	new_test_ext().execute_with(|| {
+		let when = 4;
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(when),
+			None,
+			127,
+			root(),
+			Preimage::bound(call.clone()).unwrap(),
+		)
+		.unwrap();
+		Scheduler::do_schedule_named(
+			[2u8; 32],
+			DispatchTime::At(when),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		)
+		.unwrap();
+		// two tasks at agenda.
+		assert!(Agenda::<Test>::get(when).len() == 2);
+		assert_ok!(Scheduler::do_cancel_named(None, [1u8; 32]));
+		// still two tasks at agenda, `None` and `Some`.
+		assert!(Agenda::<Test>::get(when).len() == 2);
+		// reschedule last task from `when` agenda.
+		assert_eq!(
+			Scheduler::do_reschedule_named([2u8; 32], DispatchTime::At(when + 1)).unwrap(),
+			(when + 1, 0)
+		);
+		// if all tasks `None`, agenda fully removed.
+		assert!(Agenda::<Test>::get(when).len() == 0);
+	});
+}
+
+#[test]
+fn failing_task_dispatches_its_fallback() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+		// `log` is filtered out for signed origins, so the task fails.
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 1, weight: Weight::from_parts(10, 0) });
+		let fallback = RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 2,
+			weight: Weight::from_parts(10, 0),
+		});
+		assert_ok!(Scheduler::schedule_named_with_fallback(
+			RuntimeOrigin::signed(1),
+			[1u8; 32],
+			4,
+			None,
+			127,
+			Box::new(call.clone()),
+			Box::new(fallback.clone())
+		));
+		assert!(Fallback::<Test>::contains_key([1u8; 32]));
+
+		// The name is taken, and nothing else is stored.
+		assert_noop!(
+			Scheduler::schedule_named_with_fallback(
+				RuntimeOrigin::signed(1),
+				[1u8; 32],
+				5,
+				None,
+				127,
+				Box::new(call),
+				Box::new(fallback)
+			),
+			Error::<Test>::FailedToSchedule
+		);
+
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(signed.clone(), 2)]);
+		System::assert_has_event(
+			crate::Event::Dispatched {
+				task: (4, 0),
+				id: Some([1u8; 32]),
+				origin: signed,
+				result: Err(DispatchError::from(frame_system::Error::<Test>::CallFiltered)),
+				fallback_result: Some(Ok(())),
+			}
+			.into(),
+		);
+		// The fallback is gone with the task.
+		assert!(!Fallback::<Test>::contains_key([1u8; 32]));
+	});
+}
+
+#[test]
+fn fallback_is_kept_until_the_task_is_gone() {
+	new_test_ext().execute_with(|| {
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+		let call = RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 1,
+			weight: Weight::from_parts(10, 0),
+		});
+		let fallback = RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 2,
+			weight: Weight::from_parts(10, 0),
+		});
+		assert_ok!(Scheduler::schedule_named_with_fallback(
+			RuntimeOrigin::signed(1),
+			[1u8; 32],
+			4,
+			Some((10, 3)),
+			127,
+			Box::new(call),
+			Box::new(fallback)
+		));
+
+		// A successful task doesn't dispatch its fallback, which is kept for the next occurrence.
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(signed.clone(), 1)]);
+		System::assert_has_event(
+			crate::Event::Dispatched {
+				task: (4, 0),
+				id: Some([1u8; 32]),
+				origin: signed,
+				result: Ok(()),
+				fallback_result: None,
+			}
+			.into(),
+		);
+		assert!(Fallback::<Test>::contains_key([1u8; 32]));
+
+		// And is dropped when the task is canceled.
+		assert_ok!(Scheduler::cancel_named(RuntimeOrigin::signed(1), [1u8; 32]));
+		assert!(!Fallback::<Test>::contains_key([1u8; 32]));
+		assert_ok!(Scheduler::do_try_state());
+	});
+}
+
+#[test]
+fn fallback_preimage_is_requested_until_the_task_is_gone() {
+	new_test_ext().execute_with(|| {
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 1, weight: Weight::from_parts(10, 0) });
+		let fallback = RuntimeCall::Logger(LoggerCall::log_without_filter {
+			i: 2,
+			weight: Weight::from_parts(10, 0),
+		});
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&fallback);
+		let len = fallback.using_encoded(|x| x.len()) as u32;
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			signed.clone(),
+			Preimage::bound(call).unwrap()
+		));
+		// Important to use here `Bounded::Lookup` to ensure that we request the hash.
+		Scheduler::note_fallback([1u8; 32], Bounded::Lookup { hash, len });
+		assert!(Preimage::is_requested(&hash));
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(0), fallback.encode()));
 
-		// Block 4 does nothing.
+		// The failing task dispatches its fallback, then releases it.
 		run_to_block(4);
-		assert!(logger::log().is_empty());
-		// Block 5 executes it.
-		run_to_block(5);
-		assert_eq!(logger::log(), vec![(root(), 42)]);
+		assert_eq!(logger::log(), vec![(signed, 2)]);
+		assert!(!Preimage::is_requested(&hash));
+		assert!(Preimage::len(&hash).is_none());
 	});
 }
 
 #[test]
-fn scheduler_v3_anon_schedule_agenda_overflows() {
-	use frame_support::traits::schedule::v3::Anon;
-	let max: u32 = <Test as Config>::MaxScheduledPerBlock::get();
-
+fn named_task_results_are_recorded() {
 	new_test_ext().execute_with(|| {
-		let call =
-			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
-		let bound = Preimage::bound(call).unwrap();
-
-		// Schedule the maximal number allowed per block.
-		for _ in 0..max {
-			<Scheduler as Anon<_, _, _>>::schedule(
-				DispatchTime::At(4),
+		MaxRecordedResults::set(&2);
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+		let ok = RuntimeCall::Logger(LoggerCall::log { i: 1, weight: Weight::from_parts(10, 0) });
+		// `log` is filtered out for signed origins, so this one fails.
+		let failing =
+			RuntimeCall::Logger(LoggerCall::log { i: 2, weight: Weight::from_parts(10, 0) });
+		for (id, when, origin, call) in [
+			([1u8; 32], 4, root(), ok.clone()),
+			([2u8; 32], 4, signed, failing),
+			([3u8; 32], 5, root(), ok.clone()),
+		] {
+			assert_ok!(Scheduler::do_schedule_named(
+				id,
+				DispatchTime::At(when),
 				None,
 				127,
-				root(),
-				bound.clone(),
-			)
-			.unwrap();
+				origin,
+				Preimage::bound(call).unwrap()
+			));
 		}
+		assert_eq!(Scheduler::last_result([1u8; 32]), None);
 
-		// One more time and it errors.
-		assert_noop!(
-			<Scheduler as Anon<_, _, _>>::schedule(DispatchTime::At(4), None, 127, root(), bound,),
-			DispatchError::Exhausted
+		run_to_block(4);
+		assert_eq!(Scheduler::last_result([1u8; 32]), Some((4, Ok(()))));
+		assert_eq!(
+			Scheduler::last_result([2u8; 32]),
+			Some((4, Err(DispatchError::from(frame_system::Error::<Test>::CallFiltered))))
 		);
+		assert_eq!(Scheduler::last_result([3u8; 32]), None);
 
-		run_to_block(4);
-		// All scheduled calls are executed.
-		assert_eq!(logger::log().len() as u32, max);
+		// `LastResults` is full, so the oldest result is evicted.
+		run_to_block(5);
+		assert_eq!(Scheduler::last_result([1u8; 32]), None);
+		assert_eq!(Scheduler::last_result([3u8; 32]), Some((5, Ok(()))));
+		assert_eq!(LastResults::<Test>::count(), 2);
+
+		// A new task taking the name prunes the previous result, which is then recorded again.
+		assert_ok!(Scheduler::do_schedule_named(
+			[3u8; 32],
+			DispatchTime::At(8),
+			None,
+			127,
+			root(),
+			Preimage::bound(ok).unwrap()
+		));
+		assert_eq!(Scheduler::last_result([3u8; 32]), None);
+		assert_eq!(LastResults::<Test>::count(), 1);
+		assert_ok!(Scheduler::do_try_state());
+		run_to_block(8);
+		assert_eq!(Scheduler::last_result([3u8; 32]), Some((8, Ok(()))));
+		assert!(Scheduler::last_result([2u8; 32]).is_some());
+		assert_eq!(LastResults::<Test>::count(), 2);
+		assert_ok!(Scheduler::do_try_state());
 	});
 }
 
-/// Cancelling and scheduling does not overflow the agenda but fills holes.
 #[test]
-fn scheduler_v3_anon_cancel_and_schedule_fills_holes() {
-	use frame_support::traits::schedule::v3::Anon;
-	let max: u32 = <Test as Config>::MaxScheduledPerBlock::get();
-	assert!(max > 3, "This test only makes sense for MaxScheduledPerBlock > 3");
-
+fn lowering_max_recorded_results_evicts_the_oldest_results() {
 	new_test_ext().execute_with(|| {
-		let call =
-			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
-		let bound = Preimage::bound(call).unwrap();
-		let mut addrs = Vec::<_>::default();
-
-		// Schedule the maximal number allowed per block.
-		for _ in 0..max {
-			addrs.push(
-				<Scheduler as Anon<_, _, _>>::schedule(
-					DispatchTime::At(4),
-					None,
-					127,
-					root(),
-					bound.clone(),
-				)
-				.unwrap(),
-			);
-		}
-		// Cancel three of them.
-		for addr in addrs.into_iter().take(3) {
-			<Scheduler as Anon<_, _, _>>::cancel(addr).unwrap();
-		}
-		// Schedule three new ones.
-		for i in 0..3 {
-			let (_block, index) = <Scheduler as Anon<_, _, _>>::schedule(
-				DispatchTime::At(4),
+		MaxRecordedResults::set(&3);
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 1, weight: Weight::from_parts(10, 0) });
+		for i in 1..=4u8 {
+			assert_ok!(Scheduler::do_schedule_named(
+				[i; 32],
+				DispatchTime::At(3 + i as u64),
 				None,
 				127,
 				root(),
-				bound.clone(),
-			)
-			.unwrap();
-			assert_eq!(i, index);
+				Preimage::bound(call.clone()).unwrap()
+			));
 		}
+		run_to_block(6);
+		assert_eq!(LastResults::<Test>::count(), 3);
 
-		run_to_block(4);
-		// Maximum number of calls are executed.
-		assert_eq!(logger::log().len() as u32, max);
+		// The result of the only task left makes room for itself, down to the new limit.
+		MaxRecordedResults::set(&1);
+		run_to_block(7);
+		assert_eq!(LastResults::<Test>::iter_keys().collect::<Vec<_>>(), vec![[4u8; 32]]);
+		assert_ok!(Scheduler::do_try_state());
 	});
 }
 
-/// Re-scheduling does not overflow the agenda but fills holes.
 #[test]
-fn scheduler_v3_anon_reschedule_fills_holes() {
-	use frame_support::traits::schedule::v3::Anon;
-	let max: u32 = <Test as Config>::MaxScheduledPerBlock::get();
-	assert!(max > 3, "pre-condition: This test only makes sense for MaxScheduledPerBlock > 3");
-
+fn rescheduling_prunes_the_last_result() {
 	new_test_ext().execute_with(|| {
-		let call =
-			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
-		let bound = Preimage::bound(call).unwrap();
-		let mut addrs = Vec::<_>::default();
-
-		// Schedule the maximal number allowed per block.
-		for _ in 0..max {
-			addrs.push(
-				<Scheduler as Anon<_, _, _>>::schedule(
-					DispatchTime::At(4),
-					None,
-					127,
-					root(),
-					bound.clone(),
-				)
-				.unwrap(),
-			);
-		}
-		let mut new_addrs = Vec::<_>::default();
-		// Reversed last three elements of block 4.
-		let last_three = addrs.into_iter().rev().take(3).collect::<Vec<_>>();
-		// Re-schedule three of them to block 5.
-		for addr in last_three.iter().cloned() {
-			new_addrs
-				.push(<Scheduler as Anon<_, _, _>>::reschedule(addr, DispatchTime::At(5)).unwrap());
-		}
-		// Re-scheduling them back into block 3 should result in the same addrs.
-		for (old, want) in new_addrs.into_iter().zip(last_three.into_iter().rev()) {
-			let new = <Scheduler as Anon<_, _, _>>::reschedule(old, DispatchTime::At(4)).unwrap();
-			assert_eq!(new, want);
-		}
-
+		MaxRecordedResults::set(&1);
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 1, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			Some((3, 2)),
+			127,
+			root(),
+			Preimage::bound(call).unwrap()
+		));
 		run_to_block(4);
-		// Maximum number of calls are executed.
-		assert_eq!(logger::log().len() as u32, max);
+		assert_eq!(Scheduler::last_result([1u8; 32]), Some((4, Ok(()))));
+
+		assert_ok!(Scheduler::do_reschedule_named([1u8; 32], DispatchTime::At(10)));
+		assert_eq!(Scheduler::last_result([1u8; 32]), None);
+		run_to_block(10);
+		assert_eq!(Scheduler::last_result([1u8; 32]), Some((10, Ok(()))));
 	});
 }
 
-/// The scheduler can be used as `v3::Named` trait.
 #[test]
-fn scheduler_v3_named_basic_works() {
-	use frame_support::traits::schedule::v3::Named;
-
+fn results_are_not_recorded_by_default() {
 	new_test_ext().execute_with(|| {
-		let call =
-			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
-		let name = [1u8; 32];
-
-		// Schedule a call.
-		let _address = <Scheduler as Named<_, _, _>>::schedule_named(
-			name,
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 1, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
 			DispatchTime::At(4),
 			None,
 			127,
 			root(),
-			Preimage::bound(call).unwrap(),
-		)
-		.unwrap();
+			Preimage::bound(call).unwrap()
+		));
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 1)]);
+		assert_eq!(Scheduler::last_result([1u8; 32]), None);
+	});
+}
+
+#[test]
+fn integrity_test_accepts_consistent_limits() {
+	new_test_ext().execute_with(|| {
+		Scheduler::check_limits();
+		MaxSchedulesPerOrigin::set(&Some(100));
+		Scheduler::check_limits();
+	});
+	Scheduler::integrity_test();
+}
+
+#[test]
+#[should_panic(expected = "`MaxScheduledPerBlock` must be at most `MaxSchedules`")]
+fn integrity_test_rejects_fewer_schedules_than_per_block() {
+	new_test_ext().execute_with(|| {
+		MaxSchedules::set(&5);
+		Scheduler::check_limits();
+	});
+}
+
+#[test]
+#[should_panic(expected = "`MaxSchedulesPerOrigin` must be at most `MaxSchedules`")]
+fn integrity_test_rejects_more_schedules_per_origin_than_in_total() {
+	new_test_ext().execute_with(|| {
+		MaxSchedulesPerOrigin::set(&Some(101));
+		Scheduler::check_limits();
+	});
+}
+
+#[test]
+fn scheduled_calls_keep_the_filter_restored_by_the_dispatch_context() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+		let log = |i: u32| {
+			Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
+				i,
+				weight: Weight::from_parts(10, 0),
+			}))
+		};
+		let schedule = |i: u32| {
+			Box::new(RuntimeCall::Scheduler(Call::schedule {
+				when: 4,
+				maybe_periodic: None,
+				priority: 127,
+				call: log(i),
+			}))
+		};
+
+		// Scheduled through the restrictor, the calls carry its filter.
+		assert_ok!(Restrictor::dispatch_restricted(RuntimeOrigin::signed(1), schedule(42)));
+		assert_ok!(Restrictor::dispatch_restricted(RuntimeOrigin::signed(1), schedule(142)));
+		// Scheduled directly, they only get the base filter.
+		assert_ok!(Scheduler::schedule(RuntimeOrigin::signed(1), 4, None, 127, log(242)));
+		let token = DispatchAuthorization::truncate_from(b"restricted".to_vec());
+		let authorizations: Vec<_> =
+			Agenda::<Test>::get(4).into_iter().map(|t| t.unwrap().authorization).collect();
+		assert_eq!(authorizations, vec![Some(token.clone()), Some(token), None]);
 
-		run_to_block(3);
-		// Did not execute till block 3.
-		assert!(logger::log().is_empty());
-		// Executes in block 4.
 		run_to_block(4);
-		assert_eq!(logger::log(), vec![(root(), 42u32)]);
-		// ... but not again.
-		run_to_block(100);
-		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert_eq!(logger::log(), vec![(signed.clone(), 42), (signed.clone(), 242)]);
+		System::assert_has_event(
+			crate::Event::Dispatched {
+				task: (4, 1),
+				id: None,
+				origin: signed,
+				result: Err(DispatchError::from(frame_system::Error::<Test>::CallFiltered)),
+				fallback_result: None,
+			}
+			.into(),
+		);
 	});
 }
 
-/// A named task can be cancelled by its name.
 #[test]
-fn scheduler_v3_named_cancel_named_works() {
-	use frame_support::traits::schedule::v3::Named;
+fn scheduled_calls_are_not_dispatched_if_the_filter_cannot_be_restored() {
 	new_test_ext().execute_with(|| {
-		let call =
-			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
-		let bound = Preimage::bound(call).unwrap();
-		let name = [1u8; 32];
+		run_to_block(1);
+		let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+		// The fallback runs under the same filter as the call, and fails alike.
+		let call = RuntimeCall::Scheduler(Call::schedule_named_with_fallback {
+			id: [1u8; 32],
+			when: 4,
+			maybe_periodic: None,
+			priority: 127,
+			call: Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
+				i: 42,
+				weight: Weight::from_parts(10, 0),
+			})),
+			on_failure: Box::new(RuntimeCall::Logger(LoggerCall::log_without_filter {
+				i: 69,
+				weight: Weight::from_parts(10, 0),
+			})),
+		});
+		assert_ok!(Restrictor::dispatch_restricted(RuntimeOrigin::signed(1), Box::new(call)));
 
-		// Schedule a call.
-		<Scheduler as Named<_, _, _>>::schedule_named(
-			name,
-			DispatchTime::At(4),
-			None,
-			127,
-			root(),
-			bound.clone(),
-		)
-		.unwrap();
-		// Cancel the call by name.
-		assert_ok!(<Scheduler as Named<_, _, _>>::cancel_named(name));
-		// It did not get executed.
-		run_to_block(100);
+		restrictor::Revoked::set(true);
+		run_to_block(4);
 		assert!(logger::log().is_empty());
-		// Cannot cancel again.
-		assert_noop!(<Scheduler as Named<_, _, _>>::cancel_named(name), DispatchError::Unavailable);
+		System::assert_has_event(
+			crate::Event::Dispatched {
+				task: (4, 0),
+				id: Some([1u8; 32]),
+				origin: signed,
+				result: Err(BadOrigin.into()),
+				fallback_result: Some(Err(BadOrigin.into())),
+			}
+			.into(),
+		);
 	});
 }
 
-/// A named task can also be cancelled by its address.
 #[test]
-fn scheduler_v3_named_cancel_without_name_works() {
-	use frame_support::traits::schedule::v3::{Anon, Named};
+fn on_idle_sweeps_stale_lookup_entries() {
 	new_test_ext().execute_with(|| {
 		let call =
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
-		let bound = Preimage::bound(call).unwrap();
-		let name = [1u8; 32];
-
-		// Schedule a call.
-		let address = <Scheduler as Named<_, _, _>>::schedule_named(
-			name,
+		for i in 0..3u8 {
+			assert_ok!(Scheduler::do_schedule_named(
+				[i; 32],
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				Preimage::bound(call.clone()).unwrap(),
+			));
+		}
+		assert_ok!(Scheduler::do_schedule(
 			DispatchTime::At(4),
 			None,
 			127,
 			root(),
-			bound.clone(),
-		)
-		.unwrap();
-		// Cancel the call by address.
-		assert_ok!(<Scheduler as Anon<_, _, _>>::cancel(address));
-		// It did not get executed.
-		run_to_block(100);
-		assert!(logger::log().is_empty());
-		// Cannot cancel again.
-		assert_err!(<Scheduler as Anon<_, _, _>>::cancel(address), DispatchError::Unavailable);
+			Preimage::bound(call.clone()).unwrap(),
+		));
+		assert_ok!(Scheduler::do_cancel(None, (4, 3)));
+
+		// Entries pointing at no agenda, beyond an agenda, at an empty slot and at another task.
+		Lookup::<Test>::insert([10u8; 32], (5, 0));
+		Lookup::<Test>::insert([11u8; 32], (4, 9));
+		Lookup::<Test>::insert([12u8; 32], (4, 3));
+		Lookup::<Test>::insert([13u8; 32], (4, 0));
+		Fallback::<Test>::insert([10u8; 32], Preimage::bound(call).unwrap());
+		assert!(Scheduler::do_try_state().is_err());
+
+		// Not even enough weight to start.
+		assert_eq!(Scheduler::on_idle(1, Weight::from_parts(4, 0)), Weight::zero());
+		assert!(!LookupSweepCursor::<Test>::exists());
+
+		// Two of the seven entries are checked at a time.
+		let limit = Weight::from_parts(25, 0);
+		for _ in 0..3 {
+			assert_eq!(Scheduler::on_idle(1, limit), limit);
+			assert!(LookupSweepCursor::<Test>::exists());
+		}
+		assert_eq!(Scheduler::on_idle(1, limit), Weight::from_parts(15, 0));
+		assert!(!LookupSweepCursor::<Test>::exists());
+
+		let mut names = Lookup::<Test>::iter_keys().collect::<Vec<_>>();
+		names.sort();
+		assert_eq!(names, vec![[0u8; 32], [1u8; 32], [2u8; 32]]);
+		assert!(!Fallback::<Test>::contains_key([10u8; 32]));
+		assert_ok!(Scheduler::do_try_state());
+
+		// The next sweep starts over, leaving the consistent entries alone.
+		assert_eq!(Scheduler::on_idle(2, Weight::MAX), Weight::from_parts(35, 0));
+		assert_eq!(Lookup::<Test>::iter_keys().count(), 3);
+	});
+}
+
+#[test]
+fn on_idle_sweep_keeps_its_cursor_without_room_for_an_entry() {
+	new_test_ext().execute_with(|| {
+		for i in 10..14u8 {
+			Lookup::<Test>::insert([i; 32], (5, 0));
+		}
+
+		// One entry at a time.
+		let limit = Weight::from_parts(15, 0);
+		assert_eq!(Scheduler::on_idle(1, limit), limit);
+		let cursor = LookupSweepCursor::<Test>::get();
+		assert!(cursor.is_some());
+		assert_eq!(Lookup::<Test>::iter_keys().count(), 3);
+
+		// Room for the base weight but not for an entry: nothing done, nothing charged.
+		assert_eq!(Scheduler::on_idle(2, Weight::from_parts(14, 0)), Weight::zero());
+		assert_eq!(LookupSweepCursor::<Test>::get(), cursor);
+		assert_eq!(Lookup::<Test>::iter_keys().count(), 3);
+
+		// The sweep resumes where it stopped.
+		for _ in 0..3 {
+			assert_eq!(Scheduler::on_idle(3, limit), limit);
+		}
+		assert_eq!(Lookup::<Test>::iter_keys().count(), 0);
+		assert!(!LookupSweepCursor::<Test>::exists());
 	});
 }
 
-/// A named task can be re-scheduled by its name but not by its address.
 #[test]
-fn scheduler_v3_named_reschedule_named_works() {
-	use frame_support::traits::schedule::v3::{Anon, Named};
+fn rebuild_lookup_repairs_corrupt_entries_in_steps() {
 	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		let call =
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
-		let name = [1u8; 32];
-
-		// Schedule a call.
-		let address = <Scheduler as Named<_, _, _>>::schedule_named(
-			name,
-			DispatchTime::At(4),
-			None,
-			127,
-			root(),
-			Preimage::bound(call).unwrap(),
-		)
-		.unwrap();
+		for when in 4..8u64 {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(when),
+				None,
+				127,
+				root(),
+				Preimage::bound(call.clone()).unwrap(),
+			));
+			assert_ok!(Scheduler::do_schedule_named(
+				[when as u8; 32],
+				DispatchTime::At(when),
+				None,
+				127,
+				root(),
+				Preimage::bound(call.clone()).unwrap(),
+			));
+		}
 
-		run_to_block(3);
-		// Did not execute till block 3.
-		assert!(logger::log().is_empty());
+		// Entries which are missing or point at another task.
+		Lookup::<Test>::remove([4u8; 32]);
+		Lookup::<Test>::insert([5u8; 32], (4, 0));
+		Lookup::<Test>::remove([6u8; 32]);
+		assert!(Scheduler::do_try_state().is_err());
+		assert_noop!(Scheduler::cancel_named(root().into(), [4u8; 32]), Error::<Test>::NotFound);
 
-		// Cannot re-schedule by address.
 		assert_noop!(
-			<Scheduler as Anon<_, _, _>>::reschedule(address, DispatchTime::At(10)),
-			Error::<Test>::Named,
-		);
-		// Cannot re-schedule into the same block.
-		assert_noop!(
-			<Scheduler as Named<_, _, _>>::reschedule_named(name, DispatchTime::At(4)),
-			Error::<Test>::RescheduleNoChange
-		);
-		// Cannot re-schedule into the past.
-		assert_noop!(
-			<Scheduler as Named<_, _, _>>::reschedule_named(name, DispatchTime::At(3)),
-			Error::<Test>::TargetBlockNumberInPast
-		);
-		// Re-schedule to block 5.
-		assert_ok!(<Scheduler as Named<_, _, _>>::reschedule_named(name, DispatchTime::At(5)));
-		// Scheduled for block 5.
-		run_to_block(4);
-		assert!(logger::log().is_empty());
-		run_to_block(5);
-		// Does execute in block 5.
-		assert_eq!(logger::log(), vec![(root(), 42)]);
-		// Cannot re-schedule executed task.
-		assert_noop!(
-			<Scheduler as Named<_, _, _>>::reschedule_named(name, DispatchTime::At(10)),
-			DispatchError::Unavailable
-		);
-		// Also not by address.
-		assert_noop!(
-			<Scheduler as Anon<_, _, _>>::reschedule(address, DispatchTime::At(10)),
-			DispatchError::Unavailable
+			Scheduler::rebuild_lookup(RuntimeOrigin::signed(1), 2),
+			DispatchError::BadOrigin
 		);
+
+		// Two of the four agendas are scanned at a time.
+		assert_ok!(Scheduler::rebuild_lookup(RuntimeOrigin::root(), 2));
+		System::assert_last_event(Event::LookupRebuilt { agendas: 2, complete: false }.into());
+		assert!(LookupRebuildCursor::<Test>::exists());
+		assert_ok!(Scheduler::rebuild_lookup(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::LookupRebuilt { agendas: 0, complete: false }.into());
+		assert!(LookupRebuildCursor::<Test>::exists());
+		assert_ok!(Scheduler::rebuild_lookup(RuntimeOrigin::root(), 2));
+		System::assert_last_event(Event::LookupRebuilt { agendas: 2, complete: true }.into());
+		assert!(!LookupRebuildCursor::<Test>::exists());
+
+		assert_ok!(Scheduler::do_try_state());
+		for when in 4..8u64 {
+			assert_eq!(Lookup::<Test>::get([when as u8; 32]), Some((when, 1)));
+		}
+
+		// The named tasks can be cancelled again.
+		for when in 4..8u8 {
+			assert_ok!(Scheduler::cancel_named(root().into(), [when; 32]));
+		}
+		run_to_block(8);
+		assert_eq!(logger::log().len(), 4);
+
+		// The next rebuild starts over.
+		assert_ok!(Scheduler::rebuild_lookup(RuntimeOrigin::root(), 10));
+		System::assert_last_event(Event::LookupRebuilt { agendas: 0, complete: true }.into());
 	});
 }
 
 #[test]
-fn scheduler_v3_named_next_schedule_time_works() {
-	use frame_support::traits::schedule::v3::{Anon, Named};
+fn weight_components_match_weight_info() {
+	type W = <Test as Config>::WeightInfo;
+	let max = <Test as Config>::MaxScheduledPerBlock::get();
+
+	let components = Scheduler::weight_components();
+	assert_eq!(components.service_agendas_base, W::service_agendas_base());
+	assert_eq!(components.service_agenda_base, W::service_agenda_base(0));
+	assert_eq!(
+		components.service_agenda_base + components.service_agenda_per_item * max as u64,
+		W::service_agenda_base(max)
+	);
+	assert_eq!(components.service_task_base, W::service_task_base());
+	assert_eq!(
+		components.service_task_base + components.named_task_marginal,
+		W::service_task_named()
+	);
+	assert_eq!(
+		components.service_task_base + components.periodic_task_marginal,
+		W::service_task_periodic()
+	);
+	assert_eq!(components.execute_dispatch, W::execute_dispatch_unsigned());
+	assert_eq!(components.schedule, W::schedule(max));
+	assert_eq!(components.cancel, W::cancel(max));
+}
+
+#[test]
+fn tagged_task_can_be_cancelled_by_tag() {
 	new_test_ext().execute_with(|| {
-		let call =
+		run_to_block(1);
+		let tag: TaskTag = b"payout".to_vec().try_into().unwrap();
+		let tagged =
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
-		let bound = Preimage::bound(call).unwrap();
-		let name = [1u8; 32];
-
-		// Schedule a call.
-		let address = <Scheduler as Named<_, _, _>>::schedule_named(
-			name,
-			DispatchTime::At(4),
+		let other =
+			RuntimeCall::Logger(LoggerCall::log { i: 69, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::schedule(RuntimeOrigin::root(), 4, None, 127, Box::new(other)));
+		assert_ok!(Scheduler::schedule_tagged(
+			RuntimeOrigin::root(),
+			tag.clone(),
+			4,
 			None,
 			127,
-			root(),
-			bound.clone(),
-		)
-		.unwrap();
-
-		run_to_block(3);
-		// Did not execute till block 3.
-		assert!(logger::log().is_empty());
-
-		// Scheduled for block 4.
-		assert_eq!(<Scheduler as Named<_, _, _>>::next_dispatch_time(name), Ok(4));
-		// Also works by address.
-		assert_eq!(<Scheduler as Anon<_, _, _>>::next_dispatch_time(address), Ok(4));
-		// Block 4 executes it.
-		run_to_block(4);
-		assert_eq!(logger::log(), vec![(root(), 42)]);
+			Box::new(tagged)
+		));
 
-		// It has no dispatch time anymore.
-		assert_noop!(
-			<Scheduler as Named<_, _, _>>::next_dispatch_time(name),
-			DispatchError::Unavailable
-		);
-		// Also not by address.
+		// The tag is scoped to the origin which scheduled the task.
 		assert_noop!(
-			<Scheduler as Anon<_, _, _>>::next_dispatch_time(address),
-			DispatchError::Unavailable
+			Scheduler::cancel_tagged(system::RawOrigin::Signed(1).into(), tag.clone()),
+			Error::<Test>::NotFound
 		);
+
+		assert_ok!(Scheduler::cancel_tagged(RuntimeOrigin::root(), tag.clone()));
+		System::assert_last_event(crate::Event::Canceled { when: 4, index: 1 }.into());
+		assert_noop!(Scheduler::cancel_tagged(RuntimeOrigin::root(), tag), Error::<Test>::NotFound);
+
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 69u32)]);
 	});
 }
 
 #[test]
-fn cancel_last_task_removes_agenda() {
+fn duplicate_tag_cancels_the_most_recent_task() {
 	new_test_ext().execute_with(|| {
-		let when = 4;
-		let call =
+		run_to_block(1);
+		let tag: TaskTag = b"payout".to_vec().try_into().unwrap();
+		let first =
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
-		let address = Scheduler::do_schedule(
-			DispatchTime::At(when),
+		let second =
+			RuntimeCall::Logger(LoggerCall::log { i: 69, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::schedule_tagged(
+			RuntimeOrigin::root(),
+			tag.clone(),
+			4,
 			None,
 			127,
-			root(),
-			Preimage::bound(call.clone()).unwrap(),
-		)
-		.unwrap();
-		let address2 = Scheduler::do_schedule(
-			DispatchTime::At(when),
+			Box::new(first)
+		));
+		assert_ok!(Scheduler::schedule_tagged(
+			RuntimeOrigin::root(),
+			tag.clone(),
+			5,
 			None,
 			127,
-			root(),
-			Preimage::bound(call).unwrap(),
-		)
-		.unwrap();
-		// two tasks at agenda.
-		assert!(Agenda::<Test>::get(when).len() == 2);
-		assert_ok!(Scheduler::do_cancel(None, address));
-		// still two tasks at agenda, `None` and `Some`.
-		assert!(Agenda::<Test>::get(when).len() == 2);
-		// cancel last task from `when` agenda.
-		assert_ok!(Scheduler::do_cancel(None, address2));
-		// if all tasks `None`, agenda fully removed.
-		assert!(Agenda::<Test>::get(when).len() == 0);
+			Box::new(second)
+		));
+
+		assert_ok!(Scheduler::cancel_tagged(RuntimeOrigin::root(), tag.clone()));
+		System::assert_last_event(crate::Event::Canceled { when: 5, index: 0 }.into());
+		// The first task is only reachable by its address now.
+		assert_noop!(Scheduler::cancel_tagged(RuntimeOrigin::root(), tag), Error::<Test>::NotFound);
+
+		run_to_block(5);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
 	});
 }
 
 #[test]
-fn cancel_named_last_task_removes_agenda() {
+fn cancel_tagged_ignores_a_reused_slot() {
 	new_test_ext().execute_with(|| {
-		let when = 4;
-		let call =
+		run_to_block(1);
+		let tag: TaskTag = b"payout".to_vec().try_into().unwrap();
+		let tagged =
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
-		Scheduler::do_schedule_named(
-			[1u8; 32],
-			DispatchTime::At(when),
-			None,
-			127,
-			root(),
-			Preimage::bound(call.clone()).unwrap(),
-		)
-		.unwrap();
-		Scheduler::do_schedule_named(
-			[2u8; 32],
-			DispatchTime::At(when),
+		let other =
+			RuntimeCall::Logger(LoggerCall::log { i: 69, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::schedule_tagged(
+			RuntimeOrigin::root(),
+			tag.clone(),
+			4,
 			None,
 			127,
-			root(),
-			Preimage::bound(call).unwrap(),
-		)
-		.unwrap();
-		// two tasks at agenda.
-		assert!(Agenda::<Test>::get(when).len() == 2);
-		assert_ok!(Scheduler::do_cancel_named(None, [2u8; 32]));
-		// removes trailing `None` and leaves one task.
-		assert!(Agenda::<Test>::get(when).len() == 1);
-		// cancel last task from `when` agenda.
-		assert_ok!(Scheduler::do_cancel_named(None, [1u8; 32]));
-		// if all tasks `None`, agenda fully removed.
-		assert!(Agenda::<Test>::get(when).len() == 0);
+			Box::new(tagged)
+		));
+		assert_ok!(Scheduler::cancel(RuntimeOrigin::root(), 4, 0));
+		assert_eq!(TagLookup::<Test>::iter().count(), 0);
+		assert_eq!(TaskTags::<Test>::iter().count(), 0);
+		assert_ok!(Scheduler::schedule(RuntimeOrigin::root(), 4, None, 127, Box::new(other)));
+		System::assert_last_event(crate::Event::Scheduled { when: 4, index: 0 }.into());
+
+		// The slot of the tagged task now holds another one, which is left alone.
+		assert_noop!(Scheduler::cancel_tagged(RuntimeOrigin::root(), tag), Error::<Test>::NotFound);
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 69u32)]);
 	});
 }
 
 #[test]
-fn reschedule_last_task_removes_agenda() {
+fn tag_is_removed_once_its_task_leaves_its_address() {
 	new_test_ext().execute_with(|| {
-		let when = 4;
+		run_to_block(1);
+		let tag: TaskTag = b"payout".to_vec().try_into().unwrap();
 		let call =
 			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
-		let address = Scheduler::do_schedule(
-			DispatchTime::At(when),
-			None,
-			127,
-			root(),
-			Preimage::bound(call.clone()).unwrap(),
-		)
-		.unwrap();
-		let address2 = Scheduler::do_schedule(
-			DispatchTime::At(when),
-			None,
-			127,
-			root(),
-			Preimage::bound(call).unwrap(),
-		)
-		.unwrap();
-		// two tasks at agenda.
-		assert!(Agenda::<Test>::get(when).len() == 2);
-		assert_ok!(Scheduler::do_cancel(None, address));
-		// still two tasks at agenda, `None` and `Some`.
-		assert!(Agenda::<Test>::get(when).len() == 2);
-		// reschedule last task from `when` agenda.
-		assert_eq!(
-			Scheduler::do_reschedule(address2, DispatchTime::At(when + 1)).unwrap(),
-			(when + 1, 0)
+		let schedule_tagged = |when| {
+			Scheduler::schedule_tagged(
+				RuntimeOrigin::root(),
+				tag.clone(),
+				when,
+				None,
+				127,
+				Box::new(call.clone()),
+			)
+		};
+
+		// Dispatched.
+		assert_ok!(schedule_tagged(2));
+		run_to_block(2);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert_eq!(TagLookup::<Test>::iter().count(), 0);
+		assert_eq!(TaskTags::<Test>::iter().count(), 0);
+
+		// Rescheduled.
+		assert_ok!(schedule_tagged(4));
+		assert_ok!(Scheduler::do_reschedule((4, 0), DispatchTime::At(5)));
+		assert_eq!(TagLookup::<Test>::iter().count(), 0);
+		assert_eq!(TaskTags::<Test>::iter().count(), 0);
+		assert_noop!(
+			Scheduler::cancel_tagged(RuntimeOrigin::root(), tag.clone()),
+			Error::<Test>::NotFound
 		);
-		// if all tasks `None`, agenda fully removed.
-		assert!(Agenda::<Test>::get(when).len() == 0);
+
+		// Tagged again, which only leaves the entries of the newest task.
+		assert_ok!(schedule_tagged(6));
+		assert_ok!(schedule_tagged(7));
+		assert_eq!(TaskTags::<Test>::iter().count(), 1);
+		assert_ok!(Scheduler::cancel_tagged(RuntimeOrigin::root(), tag.clone()));
+		System::assert_last_event(crate::Event::Canceled { when: 7, index: 0 }.into());
+		assert_eq!(TagLookup::<Test>::iter().count(), 0);
+		assert_eq!(TaskTags::<Test>::iter().count(), 0);
 	});
 }
 
 #[test]
-fn reschedule_named_last_task_removes_agenda() {
+fn would_fit_accounts_for_the_tasks_ahead() {
 	new_test_ext().execute_with(|| {
-		let when = 4;
-		let call =
-			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
-		Scheduler::do_schedule_named(
-			[1u8; 32],
-			DispatchTime::At(when),
-			None,
-			127,
-			root(),
-			Preimage::bound(call.clone()).unwrap(),
-		)
-		.unwrap();
-		Scheduler::do_schedule_named(
-			[2u8; 32],
-			DispatchTime::At(when),
+		run_to_block(1);
+		let max = MaximumSchedulerWeight::get();
+
+		// An empty agenda takes anything within the limit, less the servicing overhead.
+		assert!(Scheduler::would_fit(4, 127, Weight::from_parts(10, 0)));
+		assert!(!Scheduler::would_fit(4, 127, max));
+		assert!(!Scheduler::would_fit(4, 0, max));
+
+		// A task taking two thirds of the limit is serviced before any of lower priority.
+		let heavy = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max / 3 * 2 });
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
 			None,
-			127,
+			100,
 			root(),
-			Preimage::bound(call).unwrap(),
-		)
-		.unwrap();
-		// two tasks at agenda.
-		assert!(Agenda::<Test>::get(when).len() == 2);
-		assert_ok!(Scheduler::do_cancel_named(None, [1u8; 32]));
-		// still two tasks at agenda, `None` and `Some`.
-		assert!(Agenda::<Test>::get(when).len() == 2);
-		// reschedule last task from `when` agenda.
-		assert_eq!(
-			Scheduler::do_reschedule_named([2u8; 32], DispatchTime::At(when + 1)).unwrap(),
-			(when + 1, 0)
-		);
-		// if all tasks `None`, agenda fully removed.
-		assert!(Agenda::<Test>::get(when).len() == 0);
+			Preimage::bound(heavy).unwrap()
+		));
+		assert!(!Scheduler::would_fit(4, 127, max / 2));
+		assert!(Scheduler::would_fit(4, 127, max / 4));
+		assert!(Scheduler::would_fit(4, 50, max / 2));
+
+		// The prediction holds once the tasks of lower priority are scheduled: the larger one is
+		// postponed, which leaves room for the smaller one after it.
+		for (i, weight) in [(1u32, max / 2), (2, max / 4)] {
+			let call = RuntimeCall::Logger(LoggerCall::log { i, weight });
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				Preimage::bound(call).unwrap()
+			));
+		}
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 2u32)]);
+		assert_eq!(Agenda::<Test>::get(4).iter().flatten().count(), 1);
 	});
 }
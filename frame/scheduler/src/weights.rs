@@ -54,12 +54,20 @@ pub trait WeightInfo {
 	fn service_task_fetched(s: u32, ) -> Weight;
 	fn service_task_named() -> Weight;
 	fn service_task_periodic() -> Weight;
+	fn service_task_postponed() -> Weight;
+	fn service_task_postponed_named() -> Weight;
 	fn execute_dispatch_signed() -> Weight;
 	fn execute_dispatch_unsigned() -> Weight;
 	fn schedule(s: u32, ) -> Weight;
 	fn cancel(s: u32, ) -> Weight;
 	fn schedule_named(s: u32, ) -> Weight;
+	fn schedule_named_with_fallback(s: u32, ) -> Weight;
 	fn cancel_named(s: u32, ) -> Weight;
+	fn set_paused() -> Weight;
+	fn set_postponement() -> Weight;
+	fn reschedule_all_at(s: u32, ) -> Weight;
+	fn sweep_lookup(s: u32, ) -> Weight;
+	fn rebuild_lookup(b: u32, ) -> Weight;
 }
 
 /// Weights for pallet_scheduler using the Substrate node and recommended hardware.
@@ -131,6 +139,42 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 5_477_000 picoseconds.
 		Weight::from_parts(5_733_000, 0)
 	}
+	/// Storage: Scheduler InlineCallDedup (r:1 w:0)
+	/// Proof: Scheduler InlineCallDedup (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Preimage PreimageFor (r:1 w:0)
+	/// Proof: Preimage PreimageFor (max_values: None, max_size: Some(4194344), added: 4196819, mode: Measured)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler PostponementOverride (r:1 w:0)
+	/// Proof: Scheduler PostponementOverride (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn service_task_postponed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `213`
+		//  Estimated: `110487`
+		// Minimum execution time: 14_802_000 picoseconds.
+		Weight::from_parts(15_216_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler InlineCallDedup (r:1 w:0)
+	/// Proof: Scheduler InlineCallDedup (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Preimage PreimageFor (r:1 w:0)
+	/// Proof: Preimage PreimageFor (max_values: None, max_size: Some(4194344), added: 4196819, mode: Measured)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:0 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler PostponementOverride (r:1 w:0)
+	/// Proof: Scheduler PostponementOverride (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn service_task_postponed_named() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `213`
+		//  Estimated: `110487`
+		// Minimum execution time: 16_930_000 picoseconds.
+		Weight::from_parts(17_385_000, 110487)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 	fn execute_dispatch_signed() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -195,6 +239,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Fallback (r:0 w:1)
+	/// Proof: Scheduler Fallback (max_values: None, max_size: Some(171), added: 2646, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 511]`.
+	fn schedule_named_with_fallback(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `596 + s * (178 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 19_874_000 picoseconds.
+		Weight::from_parts(27_102_518, 110487)
+			// Standard Error: 951
+			.saturating_add(Weight::from_parts(334_760, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Scheduler Lookup (r:1 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
 	/// The range of component `s` is `[1, 512]`.
 	fn cancel_named(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -207,6 +269,87 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: Scheduler Paused (r:0 w:1)
+	/// Proof: Scheduler Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn set_paused() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_214_000 picoseconds.
+		Weight::from_parts(6_503_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler PostponementOverride (r:0 w:1)
+	/// Proof: Scheduler PostponementOverride (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_postponement() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_298_000 picoseconds.
+		Weight::from_parts(6_587_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler Agenda (r:2 w:2)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:0 w:512)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 512]`.
+	fn reschedule_all_at(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118 + s * (177 ±0)`
+		//  Estimated: `219984`
+		// Minimum execution time: 24_318_000 picoseconds.
+		Weight::from_parts(22_786_411, 219984)
+			// Standard Error: 1_942
+			.saturating_add(Weight::from_parts(1_507_224, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+	}
+	/// Storage: Scheduler LookupSweepCursor (r:1 w:1)
+	/// Proof: Scheduler LookupSweepCursor (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:513 w:512)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:512 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Fallback (r:512 w:512)
+	/// Proof: Scheduler Fallback (max_values: None, max_size: Some(171), added: 2646, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 512]`.
+	fn sweep_lookup(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `80 + s * (223 ±0)`
+		//  Estimated: `1517 + s * (109497 ±0)`
+		// Minimum execution time: 4_122_000 picoseconds.
+		Weight::from_parts(4_388_000, 1517)
+			// Standard Error: 3_215
+			.saturating_add(Weight::from_parts(6_941_387, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 109497).saturating_mul(s.into()))
+	}
+	/// Storage: Scheduler LookupRebuildCursor (r:1 w:1)
+	/// Proof: Scheduler LookupRebuildCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:101 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:0 w:51200)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `b` is `[0, 100]`.
+	fn rebuild_lookup(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118 + b * (101_207 ±0)`
+		//  Estimated: `110487 + b * (109497 ±0)`
+		// Minimum execution time: 9_851_000 picoseconds.
+		Weight::from_parts(10_216_000, 110487)
+			// Standard Error: 287_419
+			.saturating_add(Weight::from_parts(1_364_208_935, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((512_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 109497).saturating_mul(b.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -277,6 +420,42 @@ impl WeightInfo for () {
 		// Minimum execution time: 5_477_000 picoseconds.
 		Weight::from_parts(5_733_000, 0)
 	}
+	/// Storage: Scheduler InlineCallDedup (r:1 w:0)
+	/// Proof: Scheduler InlineCallDedup (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Preimage PreimageFor (r:1 w:0)
+	/// Proof: Preimage PreimageFor (max_values: None, max_size: Some(4194344), added: 4196819, mode: Measured)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler PostponementOverride (r:1 w:0)
+	/// Proof: Scheduler PostponementOverride (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn service_task_postponed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `213`
+		//  Estimated: `110487`
+		// Minimum execution time: 14_802_000 picoseconds.
+		Weight::from_parts(15_216_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler InlineCallDedup (r:1 w:0)
+	/// Proof: Scheduler InlineCallDedup (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Preimage PreimageFor (r:1 w:0)
+	/// Proof: Preimage PreimageFor (max_values: None, max_size: Some(4194344), added: 4196819, mode: Measured)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:0 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler PostponementOverride (r:1 w:0)
+	/// Proof: Scheduler PostponementOverride (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn service_task_postponed_named() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `213`
+		//  Estimated: `110487`
+		// Minimum execution time: 16_930_000 picoseconds.
+		Weight::from_parts(17_385_000, 110487)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 	fn execute_dispatch_signed() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -341,6 +520,24 @@ impl WeightInfo for () {
 	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Fallback (r:0 w:1)
+	/// Proof: Scheduler Fallback (max_values: None, max_size: Some(171), added: 2646, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 511]`.
+	fn schedule_named_with_fallback(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `596 + s * (178 ±0)`
+		//  Estimated: `110487`
+		// Minimum execution time: 19_874_000 picoseconds.
+		Weight::from_parts(27_102_518, 110487)
+			// Standard Error: 951
+			.saturating_add(Weight::from_parts(334_760, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Scheduler Lookup (r:1 w:1)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
 	/// The range of component `s` is `[1, 512]`.
 	fn cancel_named(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -353,4 +550,85 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: Scheduler Paused (r:0 w:1)
+	/// Proof: Scheduler Paused (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn set_paused() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_214_000 picoseconds.
+		Weight::from_parts(6_503_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler PostponementOverride (r:0 w:1)
+	/// Proof: Scheduler PostponementOverride (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_postponement() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_298_000 picoseconds.
+		Weight::from_parts(6_587_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler Agenda (r:2 w:2)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:0 w:512)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 512]`.
+	fn reschedule_all_at(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118 + s * (177 ±0)`
+		//  Estimated: `219984`
+		// Minimum execution time: 24_318_000 picoseconds.
+		Weight::from_parts(22_786_411, 219984)
+			// Standard Error: 1_942
+			.saturating_add(Weight::from_parts(1_507_224, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+	}
+	/// Storage: Scheduler LookupSweepCursor (r:1 w:1)
+	/// Proof: Scheduler LookupSweepCursor (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:513 w:512)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:512 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Fallback (r:512 w:512)
+	/// Proof: Scheduler Fallback (max_values: None, max_size: Some(171), added: 2646, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 512]`.
+	fn sweep_lookup(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `80 + s * (223 ±0)`
+		//  Estimated: `1517 + s * (109497 ±0)`
+		// Minimum execution time: 4_122_000 picoseconds.
+		Weight::from_parts(4_388_000, 1517)
+			// Standard Error: 3_215
+			.saturating_add(Weight::from_parts(6_941_387, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 109497).saturating_mul(s.into()))
+	}
+	/// Storage: Scheduler LookupRebuildCursor (r:1 w:1)
+	/// Proof: Scheduler LookupRebuildCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:101 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Lookup (r:0 w:51200)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `b` is `[0, 100]`.
+	fn rebuild_lookup(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118 + b * (101_207 ±0)`
+		//  Estimated: `110487 + b * (109497 ±0)`
+		// Minimum execution time: 9_851_000 picoseconds.
+		Weight::from_parts(10_216_000, 110487)
+			// Standard Error: 287_419
+			.saturating_add(Weight::from_parts(1_364_208_935, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((512_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 109497).saturating_mul(b.into()))
+	}
 }
@@ -384,6 +384,9 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 								#frame_support::sp_tracing::enter_span!(
 									#frame_support::sp_tracing::trace_span!(stringify!(#fn_name))
 								);
+								// The conversion is the identity for calls returning
+								// `DispatchResultWithPostInfo`.
+								#[allow(clippy::useless_conversion)]
 								#maybe_allow_attrs
 								<#pallet_ident<#type_use_gen>>::#fn_name(origin, #( #args_name, )* )
 									.map(Into::into).map_err(Into::into)